@@ -22,7 +22,7 @@ fn write_parallel_zip1_to_buffered(bench: &mut Bencher) {
     let path = "tests/images/valid/custom/crowskull/crow_rle.exr";
 
     let mut image = read_first_flat_layer_from_file(path).unwrap();
-    image.layer_data.encoding.compression = Compression::ZIP1;
+    image.layer_data.encoding.compression = Compression::ZIP1(None);
 
     bench.iter(||{
         let mut result = Vec::new();
@@ -35,7 +35,7 @@ fn write_nonparallel_zip1_to_buffered(bench: &mut Bencher) {
     let path = "tests/images/valid/custom/crowskull/crow_rle.exr";
 
     let mut image = read_first_flat_layer_from_file(path).unwrap();
-    image.layer_data.encoding.compression = Compression::ZIP1;
+    image.layer_data.encoding.compression = Compression::ZIP1(None);
 
     bench.iter(||{
         let mut result = Vec::new();
@@ -48,7 +48,7 @@ fn write_parallel_zip16_to_buffered(bench: &mut Bencher) {
     let path = "tests/images/valid/custom/crowskull/crow_rle.exr";
 
     let mut image = read_first_flat_layer_from_file(path).unwrap();
-    image.layer_data.encoding.compression = Compression::ZIP16;
+    image.layer_data.encoding.compression = Compression::ZIP16(None);
 
     bench.iter(||{
         let mut result = Vec::new();
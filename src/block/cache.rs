@@ -0,0 +1,205 @@
+//! Random access to the blocks of a file, decompressing each block only when it is first requested.
+//! Keeps the most recently used blocks in memory, up to a maximum number of bytes.
+//! Can be shared across threads, for example as the backend of a texture system.
+
+use std::collections::{HashMap, BTreeMap};
+use std::io::{Read, Seek};
+use std::sync::{Arc, Mutex};
+use std::convert::TryFrom;
+
+use crate::block::UncompressedBlock;
+use crate::block::chunk::{Chunk, TileCoordinates};
+use crate::block::samples::Sample;
+use crate::error::{Error, Result};
+use crate::io::{PeekRead, Tracking, Data};
+use crate::math::Vec2;
+use crate::meta::{MetaData, OffsetTables, BlockDescription};
+use crate::meta::attribute::SampleType;
+use crate::prelude::f16;
+
+
+/// A file handle that decompresses individual blocks on demand,
+/// and caches the most recently used blocks in memory.
+/// Obtain one by calling `Reader::into_block_cache`.
+///
+/// Only supports flat layers. The offset tables of the file are kept in memory.
+#[derive(Debug)]
+pub struct BlockCache<R> {
+    meta_data: MetaData,
+    pedantic: bool,
+    max_cached_bytes: usize,
+
+    /// The byte position of each block in the file, by layer index and tile coordinates.
+    chunk_offsets: HashMap<(usize, TileCoordinates), u64>,
+
+    reader: Mutex<PeekRead<Tracking<R>>>,
+    cached: Mutex<CachedBlocks>,
+}
+
+/// The cached blocks and the order in which they were last used.
+#[derive(Debug, Default)]
+struct CachedBlocks {
+    blocks: HashMap<(usize, TileCoordinates), (Arc<UncompressedBlock>, u64)>,
+    last_uses: BTreeMap<u64, (usize, TileCoordinates)>,
+    next_use: u64,
+    byte_size: usize,
+}
+
+
+impl<R: Read + Seek> BlockCache<R> {
+
+    /// Create a cache from the remaining bytes of a reader, which must start at the offset tables.
+    pub(crate) fn new(
+        meta_data: MetaData, offset_tables: OffsetTables, reader: PeekRead<Tracking<R>>,
+        pedantic: bool, max_cached_bytes: usize
+    ) -> Result<Self>
+    {
+        let mut chunk_offsets = HashMap::with_capacity(offset_tables.iter().map(|table| table.len()).sum());
+
+        for (layer_index, (header, offset_table)) in meta_data.headers.iter().zip(&offset_tables).enumerate() {
+            for (tile, &offset) in header.blocks_increasing_y_order().zip(offset_table) { // same order as in the file
+                chunk_offsets.insert((layer_index, tile.location), offset);
+            }
+        }
+
+        Ok(BlockCache {
+            meta_data, pedantic, max_cached_bytes, chunk_offsets,
+            reader: Mutex::new(reader),
+            cached: Mutex::new(CachedBlocks::default()),
+        })
+    }
+
+    /// The decoded exr meta data from the file.
+    pub fn meta_data(&self) -> &MetaData { &self.meta_data }
+
+    /// The number of bytes of all blocks that are currently cached.
+    pub fn cached_byte_size(&self) -> usize {
+        self.cached.lock().expect("block cache lock poisoned").byte_size
+    }
+
+    /// Remove all blocks from the cache.
+    pub fn clear(&self) {
+        *self.cached.lock().expect("block cache lock poisoned") = CachedBlocks::default();
+    }
+
+    /// Return the decompressed tile at the specified tile index, in the specified resolution level of a layer.
+    /// For scan line layers, the tile index is `(0, block_index)` and the level is `(0, 0)`.
+    /// Reads and decompresses the block, unless it is already cached.
+    pub fn get_tile(&self, layer_index: usize, level: Vec2<usize>, tile_index: Vec2<usize>) -> Result<Arc<UncompressedBlock>> {
+        let key = (layer_index, TileCoordinates { tile_index, level_index: level });
+
+        if let Some(block) = self.cached.lock().expect("block cache lock poisoned").get(key) {
+            return Ok(block);
+        }
+
+        let header = self.meta_data.headers.get(layer_index).ok_or(Error::invalid("layer index"))?;
+        if header.deep { return Err(Error::unsupported("cached deep data blocks")); }
+
+        let offset = *self.chunk_offsets.get(&key).ok_or(Error::invalid("tile index"))?;
+
+        let chunk = {
+            let mut reader = self.reader.lock().expect("block cache lock poisoned");
+            reader.skip_to(usize::try_from(offset).map_err(|_| Error::invalid("chunk offset"))?)?;
+            Chunk::read(&mut *reader, &self.meta_data)?
+        };
+
+        if chunk.layer_index != layer_index {
+            return Err(Error::invalid("chunk layer index"));
+        }
+
+        // decompress without holding any lock, so other threads can access the cache meanwhile
+        let block = Arc::new(UncompressedBlock::decompress_chunk(chunk, &self.meta_data, self.pedantic)?);
+
+        if block.index.level != level {
+            return Err(Error::invalid("chunk level index"));
+        }
+
+        if block.index.pixel_position != tile_index * header.max_block_pixel_size() {
+            return Err(Error::invalid("chunk tile index"));
+        }
+
+        self.cached.lock().expect("block cache lock poisoned")
+            .insert(key, block.clone(), self.max_cached_bytes);
+
+        Ok(block)
+    }
+
+    /// Return a single sample of a channel, at a pixel position inside the specified resolution level of a layer.
+    /// The position is relative to the data window of the layer, scaled to the level.
+    /// For subsampled channels, returns the closest sample above and to the left of the position.
+    /// Reads and decompresses the containing block, unless it is already cached.
+    pub fn get_sample(&self, layer_index: usize, level: Vec2<usize>, channel_index: usize, position: Vec2<usize>) -> Result<Sample> {
+        let header = self.meta_data.headers.get(layer_index).ok_or(Error::invalid("layer index"))?;
+        let channel = header.channels.list.get(channel_index).ok_or(Error::invalid("channel index"))?;
+
+        // only pixels with coordinates divisible by the sampling rate contain a sample
+        let sample_position = position / channel.sampling;
+        let position = sample_position * channel.sampling;
+
+        let tile_index = match header.blocks {
+            BlockDescription::Tiles(tiles) => position / tiles.tile_size,
+            BlockDescription::ScanLines => Vec2(0, position.y() / header.compression.scan_lines_per_block()),
+        };
+
+        let block = self.get_tile(layer_index, level, tile_index)?;
+        let block_size = block.index.pixel_size;
+
+        let block_position = position - block.index.pixel_position;
+        if block_position.x() >= block_size.width() || block_position.y() >= block_size.height() {
+            return Err(Error::invalid("pixel position"));
+        }
+
+        let line = block.lines(&header.channels)
+            .find(|line| line.location.channel == channel_index && line.location.position.y() == sample_position.y())
+            .ok_or(Error::invalid("pixel position"))?;
+
+        let sample_size = channel.sample_type.bytes_per_sample();
+        let mut bytes = &line.value[(sample_position.x() - line.location.position.x()) * sample_size ..];
+
+        Ok(match channel.sample_type {
+            SampleType::F16 => Sample::F16(f16::read(&mut bytes)?),
+            SampleType::F32 => Sample::F32(f32::read(&mut bytes)?),
+            SampleType::U32 => Sample::U32(u32::read(&mut bytes)?),
+        })
+    }
+}
+
+impl CachedBlocks {
+
+    /// Return the block and mark it as the most recently used block.
+    fn get(&mut self, key: (usize, TileCoordinates)) -> Option<Arc<UncompressedBlock>> {
+        let next_use = self.next_use;
+        let (block, last_use) = self.blocks.get_mut(&key)?;
+
+        self.last_uses.remove(last_use);
+        self.last_uses.insert(next_use, key);
+        *last_use = next_use;
+        self.next_use += 1;
+
+        Some(block.clone())
+    }
+
+    /// Add the block and remove the least recently used blocks until the cache fits the maximum size.
+    fn insert(&mut self, key: (usize, TileCoordinates), block: Arc<UncompressedBlock>, max_byte_size: usize) {
+        // another thread might have inserted the same block meanwhile
+        if let Some((previous, last_use)) = self.blocks.insert(key, (block.clone(), self.next_use)) {
+            self.last_uses.remove(&last_use);
+            self.byte_size -= previous.data.len();
+        }
+
+        self.last_uses.insert(self.next_use, key);
+        self.byte_size += block.data.len();
+        self.next_use += 1;
+
+        while self.byte_size > max_byte_size {
+            let oldest = match self.last_uses.keys().next() {
+                Some(&oldest) => oldest,
+                None => break,
+            };
+
+            let oldest_key = self.last_uses.remove(&oldest).expect("block cache order bug");
+            let (oldest_block, _) = self.blocks.remove(&oldest_key).expect("block cache order bug");
+            self.byte_size -= oldest_block.data.len();
+        }
+    }
+}
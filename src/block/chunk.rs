@@ -201,9 +201,8 @@ use crate::meta::{MetaData, BlockDescription, calculate_block_size};
 impl CompressedScanLineBlock {
 
     /// Without validation, write this instance to the byte stream.
+    /// The block may be empty, if none of its lines contain samples of a subsampled channel.
     pub fn write<W: Write>(&self, write: &mut W) -> UnitResult {
-        debug_assert_ne!(self.compressed_pixels.len(), 0, "empty blocks should not be put in the file bug");
-
         i32::write(self.y_coordinate, write)?;
         u8::write_i32_sized_slice(write, &self.compressed_pixels)?;
         Ok(())
@@ -221,7 +220,8 @@ impl CompressedTileBlock {
 
     /// Without validation, write this instance to the byte stream.
     pub fn write<W: Write>(&self, write: &mut W) -> UnitResult {
-        debug_assert_ne!(self.compressed_pixels.len(), 0, "empty blocks should not be put in the file bug");
+        // tiles cannot contain subsampled channels, so only empty tiles would contain no samples
+        debug_assert_ne!(self.compressed_pixels.len(), 0, "empty tiles should not be put in the file bug");
 
         self.coordinates.write(write)?;
         u8::write_i32_sized_slice(write, &self.compressed_pixels)?;
@@ -240,8 +240,7 @@ impl CompressedDeepScanLineBlock {
 
     /// Without validation, write this instance to the byte stream.
     pub fn write<W: Write>(&self, write: &mut W) -> UnitResult {
-        debug_assert_ne!(self.compressed_sample_data.len(), 0, "empty blocks should not be put in the file bug");
-
+        // note: deep blocks may be empty, if no pixel in the block contains any sample
         i32::write(self.y_coordinate, write)?;
         u64::write(self.compressed_pixel_offset_table.len() as u64, write)?;
         u64::write(self.compressed_sample_data.len() as u64, write)?; // TODO just guessed
@@ -252,7 +251,8 @@ impl CompressedDeepScanLineBlock {
     }
 
     /// Read the value without validating.
-    pub fn read(read: &mut impl Read, max_block_byte_size: usize) -> Result<Self> {
+    /// The size of the sample data is not limited, as pixels can contain any number of samples.
+    pub fn read(read: &mut impl Read, max_table_byte_size: usize) -> Result<Self> {
         let y_coordinate = i32::read(read)?;
         let compressed_pixel_offset_table_size = u64_to_usize(u64::read(read)?);
         let compressed_sample_data_size = u64_to_usize(u64::read(read)?);
@@ -261,13 +261,14 @@ impl CompressedDeepScanLineBlock {
         // doc said i32, try u8
         let compressed_pixel_offset_table = i8::read_vec(
             read, compressed_pixel_offset_table_size,
-            6 * u16::MAX as usize, Some(max_block_byte_size),
+            6 * u16::MAX as usize, Some(max_table_byte_size),
             "deep scan line block table size"
         )?;
 
+        // compressed data is never larger than the uncompressed data
         let compressed_sample_data = u8::read_vec(
             read, compressed_sample_data_size,
-            6 * u16::MAX as usize, Some(max_block_byte_size),
+            6 * u16::MAX as usize, Some(decompressed_sample_data_size),
             "deep scan line block sample count"
         )?;
 
@@ -285,8 +286,7 @@ impl CompressedDeepTileBlock {
 
     /// Without validation, write this instance to the byte stream.
     pub fn write<W: Write>(&self, write: &mut W) -> UnitResult {
-        debug_assert_ne!(self.compressed_sample_data.len(), 0, "empty blocks should not be put in the file bug");
-
+        // note: deep blocks may be empty, if no pixel in the block contains any sample
         self.coordinates.write(write)?;
         u64::write(self.compressed_pixel_offset_table.len() as u64, write)?;
         u64::write(self.compressed_sample_data.len() as u64, write)?; // TODO just guessed
@@ -297,7 +297,8 @@ impl CompressedDeepTileBlock {
     }
 
     /// Read the value without validating.
-    pub fn read(read: &mut impl Read, hard_max_block_byte_size: usize) -> Result<Self> {
+    /// The size of the sample data is not limited, as pixels can contain any number of samples.
+    pub fn read(read: &mut impl Read, max_table_byte_size: usize) -> Result<Self> {
         let coordinates = TileCoordinates::read(read)?;
         let compressed_pixel_offset_table_size = u64_to_usize(u64::read(read)?);
         let compressed_sample_data_size = u64_to_usize(u64::read(read)?); // TODO u64 just guessed
@@ -305,13 +306,14 @@ impl CompressedDeepTileBlock {
 
         let compressed_pixel_offset_table = i8::read_vec(
             read, compressed_pixel_offset_table_size,
-            6 * u16::MAX as usize, Some(hard_max_block_byte_size),
+            6 * u16::MAX as usize, Some(max_table_byte_size),
             "deep tile block table size"
         )?;
 
+        // compressed data is never larger than the uncompressed data
         let compressed_sample_data = u8::read_vec(
             read, compressed_sample_data_size,
-            6 * u16::MAX as usize, Some(hard_max_block_byte_size),
+            6 * u16::MAX as usize, Some(decompressed_sample_data_size),
             "deep tile block sample count"
         )?;
 
@@ -360,6 +362,9 @@ impl Chunk {
         let header = &meta_data.headers[layer_number];
         let max_block_byte_size = header.max_block_byte_size();
 
+        // the pixel offset table contains one i32 per pixel
+        let max_table_byte_size = header.max_block_pixel_size().area() * i32::BYTE_SIZE;
+
         let chunk = Chunk {
             layer_index: layer_number,
             compressed_block: match header.blocks {
@@ -368,8 +373,8 @@ impl Chunk {
                 BlockDescription::Tiles(_) if !header.deep     => CompressedBlock::Tile(CompressedTileBlock::read(read, max_block_byte_size)?),
 
                 // deep data
-                BlockDescription::ScanLines   => CompressedBlock::DeepScanLine(CompressedDeepScanLineBlock::read(read, max_block_byte_size)?),
-                BlockDescription::Tiles(_)    => CompressedBlock::DeepTile(CompressedDeepTileBlock::read(read, max_block_byte_size)?),
+                BlockDescription::ScanLines   => CompressedBlock::DeepScanLine(CompressedDeepScanLineBlock::read(read, max_table_byte_size)?),
+                BlockDescription::Tiles(_)    => CompressedBlock::DeepTile(CompressedDeepTileBlock::read(read, max_table_byte_size)?),
             },
         };
 
@@ -39,6 +39,36 @@ pub type LineRef<'s> = LineSlice<&'s [u8]>;
 pub type LineRefMut<'s> = LineSlice<&'s mut [u8]>;
 
 
+/// A single line of deep pixels, containing the samples of one channel.
+/// Each pixel in this line can contain any number of samples, including none.
+/// Use [DeepLineRef] or [DeepLineRefMut] for easier type names.
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+pub struct DeepLineSlice<'s, T> {
+
+    /// Where this line is located inside the image.
+    /// The `sample_count` of this location is the number of pixels in the line.
+    pub location: LineIndex,
+
+    /// The number of samples in each pixel of the line, from left to right.
+    pub sample_counts: &'s [u32],
+
+    /// The raw bytes of all samples in the line, pixel after pixel, either `&[u8]` or `&mut [u8]`.
+    /// Must be re-interpreted as slice of f16, f32, or u32,
+    /// according to the channel data type.
+    pub value: T,
+}
+
+/// A reference to a single line of deep pixels.
+///
+/// This line contains an immutable slice that all samples will be read from.
+pub type DeepLineRef<'s> = DeepLineSlice<'s, &'s [u8]>;
+
+/// A reference to a single mutable line of deep pixels.
+///
+/// This line contains a mutable slice that all samples will be written to.
+pub type DeepLineRefMut<'s> = DeepLineSlice<'s, &'s mut [u8]>;
+
+
 /// Specifies where a row of pixels lies inside an image.
 /// This is a globally unique identifier which includes
 /// the layer, channel index, and pixel location.
@@ -55,6 +85,8 @@ pub struct LineIndex {
     pub level: Vec2<usize>,
 
     /// Position of the most left pixel of the row.
+    /// For subsampled channels, this is the position of the sample,
+    /// which is the pixel position divided by the sampling rate.
     pub position: Vec2<usize>,
 
     /// The width of the line; the number of samples in this row,
@@ -69,69 +101,84 @@ impl LineIndex {
     /// For each line in this block, this iterator steps once through each channel.
     /// This is how lines are stored in a pixel data block.
     ///
+    /// Subsampled channels only contain a line where the y coordinate is divisible by the sampling rate,
+    /// and the position of their lines is measured in samples of that channel instead of pixels.
+    ///
     /// Does not check whether `self.layer_index`, `self.level`, `self.size` and `self.position` are valid indices.__
     // TODO be sure this cannot produce incorrect data, as this is not further checked but only handled with panics
     #[inline]
     #[must_use]
     pub fn lines_in_block(block: BlockIndex, channels: &ChannelList) -> impl Iterator<Item=(Range<usize>, LineIndex)> {
         struct LineIter {
-            layer: usize, level: Vec2<usize>, width: usize,
-            end_y: usize, x: usize, channel_sizes: SmallVec<[usize; 8]>,
+            layer: usize, level: Vec2<usize>,
+            end_y: usize, channels: SmallVec<[ChannelLines; 8]>,
             byte: usize, channel: usize, y: usize,
         }
 
-        // FIXME what about sub sampling??
+        /// The horizontal placement of the lines of a single channel inside the block.
+        struct ChannelLines { sampling: Vec2<usize>, x: usize, sample_count: usize, byte_size: usize }
 
         impl Iterator for LineIter {
             type Item = (Range<usize>, LineIndex);
             // TODO size hint?
 
             fn next(&mut self) -> Option<Self::Item> {
-                if self.y < self.end_y {
+                while self.y < self.end_y {
+                    let channel_index = self.channel;
+                    let channel = &self.channels[channel_index];
 
                     // compute return value before incrementing
-                    let byte_len = self.channel_sizes[self.channel];
-                    let return_value = (
-                        (self.byte .. self.byte + byte_len),
-                        LineIndex {
-                            channel: self.channel,
+                    let return_value = if self.y % channel.sampling.y() == 0 {
+                        let byte_range = self.byte .. self.byte + channel.byte_size;
+                        self.byte += channel.byte_size;
+
+                        Some((byte_range, LineIndex {
+                            channel: channel_index,
                             layer: self.layer,
                             level: self.level,
-                            position: Vec2(self.x, self.y),
-                            sample_count: self.width,
-                        }
-                    );
+                            position: Vec2(channel.x, self.y / channel.sampling.y()),
+                            sample_count: channel.sample_count,
+                        }))
+                    }
+                    else { None };
 
                     { // increment indices
-                        self.byte += byte_len;
                         self.channel += 1;
 
-                        if self.channel == self.channel_sizes.len() {
+                        if self.channel == self.channels.len() {
                             self.channel = 0;
                             self.y += 1;
                         }
                     }
 
-                    Some(return_value)
+                    if return_value.is_some() {
+                        return return_value;
+                    }
                 }
 
-                else {
-                    None
-                }
+                None
             }
         }
 
-        let channel_line_sizes: SmallVec<[usize; 8]> = channels.list.iter()
-            .map(move |channel| block.pixel_size.0 * channel.sample_type.bytes_per_sample()) // FIXME is it fewer samples per tile or just fewer tiles for sampled images???
+        let channel_lines: SmallVec<[ChannelLines; 8]> = channels.list.iter()
+            .map(move |channel| {
+                // the first and the end sample index of the line, rounded up to the sampling rate
+                let start_x = (block.pixel_position.x() + channel.sampling.x() - 1) / channel.sampling.x();
+                let end_x = (block.pixel_position.x() + block.pixel_size.width() + channel.sampling.x() - 1) / channel.sampling.x();
+                let sample_count = end_x - start_x;
+
+                ChannelLines {
+                    sampling: channel.sampling, x: start_x, sample_count,
+                    byte_size: sample_count * channel.sample_type.bytes_per_sample()
+                }
+            })
             .collect();
 
         LineIter {
             layer: block.layer,
             level: block.level,
-            width: block.pixel_size.0,
-            x: block.pixel_position.0,
             end_y: block.pixel_position.y() + block.pixel_size.height(),
-            channel_sizes: channel_line_sizes,
+            channels: channel_lines,
 
             byte: 0,
             channel: 0,
@@ -194,4 +241,38 @@ impl LineRef<'_> {
         let mut read = self.value.clone(); // FIXME deep data
         (0..self.location.sample_count).map(move |_| T::read(&mut read))
     }
-}
\ No newline at end of file
+}
+
+impl<T> DeepLineSlice<'_, T> {
+
+    /// The total number of samples in all pixels of this line.
+    pub fn total_sample_count(&self) -> usize {
+        self.sample_counts.iter().map(|&count| count as usize).sum()
+    }
+}
+
+impl DeepLineRefMut<'_> {
+
+    /// Writes all samples (f16, f32, u32 values) of this line, pixel after pixel.
+    /// The slice must contain exactly `total_sample_count()` values.
+    #[inline]
+    #[must_use]
+    pub fn write_samples_from_slice<T: crate::io::Data>(self, slice: &[T]) -> UnitResult {
+        debug_assert_eq!(slice.len(), self.total_sample_count(), "slice size does not match the line sample count");
+        debug_assert_eq!(self.value.len(), slice.len() * T::BYTE_SIZE, "sample type size does not match line byte size");
+
+        T::write_slice(&mut Cursor::new(self.value), slice)
+    }
+}
+
+impl DeepLineRef<'_> {
+
+    /// Read all samples (f16, f32, u32 values) of this line, pixel after pixel.
+    /// The slice must contain exactly `total_sample_count()` values.
+    pub fn read_samples_into_slice<T: crate::io::Data>(self, slice: &mut [T]) -> UnitResult {
+        debug_assert_eq!(slice.len(), self.total_sample_count(), "slice size does not match the line sample count");
+        debug_assert_eq!(self.value.len(), slice.len() * T::BYTE_SIZE, "sample type size does not match line byte size");
+
+        T::read_slice(&mut Cursor::new(self.value), slice)
+    }
+}
@@ -10,6 +10,7 @@
 
 pub mod writer;
 pub mod reader;
+pub mod cache;
 
 pub mod lines;
 pub mod samples;
@@ -17,14 +18,18 @@ pub mod chunk;
 
 
 use std::io::{Read, Seek, Write};
+use std::convert::TryFrom;
 use crate::error::{Result, UnitResult, Error, usize_to_i32};
 use crate::meta::{Headers, MetaData, BlockDescription};
 use crate::math::Vec2;
 use crate::compression::ByteVec;
-use crate::block::chunk::{CompressedBlock, CompressedTileBlock, CompressedScanLineBlock, Chunk, TileCoordinates};
+use crate::block::chunk::{CompressedBlock, CompressedTileBlock, CompressedScanLineBlock, CompressedDeepTileBlock, CompressedDeepScanLineBlock, Chunk, TileCoordinates};
 use crate::meta::header::Header;
-use crate::block::lines::{LineIndex, LineRef, LineSlice, LineRefMut};
-use crate::meta::attribute::ChannelList;
+use crate::block::lines::{LineIndex, LineRef, LineSlice, LineRefMut, DeepLineRef, DeepLineRefMut};
+use std::ops::Range;
+use smallvec::SmallVec;
+use crate::meta::attribute::{ChannelList, IntegerBounds};
+use crate::io::Data;
 
 
 /// Specifies where a block of pixel data should be placed in the actual image.
@@ -62,6 +67,24 @@ pub struct UncompressedBlock {
     pub data: ByteVec,
 }
 
+/// Contains a block of deep pixel data and where that data should be placed in the actual image.
+/// Each pixel of a deep block can contain any number of samples.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct UncompressedDeepBlock {
+
+    /// Location of the data inside the image.
+    pub index: BlockIndex,
+
+    /// The number of samples in each pixel of the block.
+    /// Contains all pixel rows, one after another.
+    pub sample_counts: Vec<u32>,
+
+    /// Uncompressed sample values of the whole block.
+    /// For each line in the block, for each channel, the samples of all pixels in that line are contiguous.
+    /// Stores all samples of the first pixel in the line, then all samples of the second pixel, and so on.
+    pub sample_data: ByteVec,
+}
+
 /// Immediately reads the meta data from the file.
 /// Then, returns a reader that can be used to read all pixel blocks.
 /// From the reader, you can pull each compressed chunk from the file.
@@ -139,7 +162,8 @@ impl UncompressedBlock {
                 })
             },
 
-            _ => return Err(Error::unsupported("deep data not supported yet"))
+            CompressedBlock::DeepTile(_) | CompressedBlock::DeepScanLine(_) =>
+                Err(Error::unsupported("deep data must be decompressed using `UncompressedDeepBlock`"))
         }
     }
 
@@ -153,14 +177,14 @@ impl UncompressedBlock {
         let header: &Header = headers.get(index.layer)
             .expect("block layer index bug");
 
-        let expected_byte_size = header.channels.bytes_per_pixel * self.index.pixel_size.area(); // TODO sampling??
+        let expected_byte_size = header.channels.byte_size_of_section(IntegerBounds::new(index.pixel_position.to_i32(), index.pixel_size));
         if expected_byte_size != data.len() {
             panic!("get_line byte size should be {} but was {}", expected_byte_size, data.len());
         }
 
         let tile_coordinates = TileCoordinates {
             // FIXME this calculation should not be made here but elsewhere instead (in meta::header?)
-            tile_index: index.pixel_position / header.max_block_pixel_size(), // TODO sampling??
+            tile_index: index.pixel_position / header.max_block_pixel_size(),
             level_index: index.level,
         };
 
@@ -187,7 +211,7 @@ impl UncompressedBlock {
                     compressed_pixels: compressed_data,
 
                     // FIXME this calculation should not be made here but elsewhere instead (in meta::header?)
-                    y_coordinate: usize_to_i32(index.pixel_position.y()) + header.own_attributes.layer_position.y(), // TODO sampling??
+                    y_coordinate: usize_to_i32(index.pixel_position.y()) + header.own_attributes.layer_position.y(),
                 }),
 
                 BlockDescription::Tiles(_) => CompressedBlock::Tile(CompressedTileBlock {
@@ -231,11 +255,11 @@ impl UncompressedBlock {
         mut extract_line: impl FnMut(LineRefMut<'_>)
     ) -> Vec<u8>
     {
-        let byte_count = block_index.pixel_size.area() * channels.bytes_per_pixel;
+        let byte_count = channels.byte_size_of_section(IntegerBounds::new(block_index.pixel_position.to_i32(), block_index.pixel_size));
         let mut block_bytes = vec![0_u8; byte_count];
 
         for (byte_range, line_index) in LineIndex::lines_in_block(block_index, channels) {
-            extract_line(LineRefMut { // TODO subsampling
+            extract_line(LineRefMut {
                 value: &mut block_bytes[byte_range],
                 location: line_index,
             });
@@ -254,4 +278,310 @@ impl UncompressedBlock {
             data: Self::collect_block_data_from_lines(channels, block_index, extract_line)
         }
     }
-}
\ No newline at end of file
+
+    /// Create a block that contains only some of the channels of this block, in a possibly different order.
+    /// For each of the selected channels, `channel_indices` contains the index of the channel in the original list.
+    /// The selected channels may be renamed, but must otherwise be equal to the original channels.
+    pub(crate) fn select_channels(&self, channels: &ChannelList, selected_channels: &ChannelList, channel_indices: &[usize]) -> Self {
+        // split the block into lines only once, and then dispatch the lines by their channel index
+        let mut channel_lines: Vec<Vec<&[u8]>> = vec![Vec::new(); channels.list.len()];
+        for line in self.lines(channels) {
+            channel_lines[line.location.channel].push(line.value);
+        }
+
+        // the lines of each channel are in the same order in the selected block
+        let mut selected_lines: SmallVec<[std::slice::Iter<'_, &[u8]>; 8]> = channel_indices.iter()
+            .map(|&index| channel_lines[index].iter())
+            .collect();
+
+        Self::from_lines(selected_channels, self.index, |line| {
+            let original_line = selected_lines[line.location.channel].next().expect("selected line index bug");
+            line.value.copy_from_slice(original_line);
+        })
+    }
+}
+
+impl UncompressedDeepBlock {
+
+    /// Decompress the possibly compressed deep chunk and returns an `UncompressedDeepBlock`.
+    // for uncompressed data, the ByteVec in the chunk is moved all the way
+    #[must_use]
+    pub fn decompress_chunk(chunk: Chunk, meta_data: &MetaData, pedantic: bool) -> Result<Self> {
+        let header: &Header = meta_data.headers.get(chunk.layer_index)
+            .ok_or(Error::invalid("chunk layer index"))?;
+
+        let tile_data_indices = header.get_block_data_indices(&chunk.compressed_block)?;
+        let absolute_indices = header.get_absolute_block_pixel_coordinates(tile_data_indices)?;
+
+        absolute_indices.validate(Some(header.layer_size))?;
+
+        let (compressed_table, compressed_sample_data, sample_data_byte_size) = match chunk.compressed_block {
+            CompressedBlock::DeepTile(CompressedDeepTileBlock {
+                compressed_pixel_offset_table, compressed_sample_data, decompressed_sample_data_size, ..
+            }) |
+            CompressedBlock::DeepScanLine(CompressedDeepScanLineBlock {
+                compressed_pixel_offset_table, compressed_sample_data, decompressed_sample_data_size, ..
+            }) => (compressed_pixel_offset_table, compressed_sample_data, decompressed_sample_data_size),
+
+            CompressedBlock::Tile(_) | CompressedBlock::ScanLine(_) =>
+                return Err(Error::invalid("flat block in deep layer")),
+        };
+
+        let compressed_table = compressed_table.into_iter().map(|byte| byte as u8).collect();
+        let table_byte_size = absolute_indices.size.area() * i32::BYTE_SIZE;
+        let table = header.compression.decompress_deep_section(compressed_table, table_byte_size, pedantic)?;
+
+        // the table contains the cumulative sample count of each pixel, starting anew in each line
+        let line_byte_size = absolute_indices.size.width() * i32::BYTE_SIZE;
+        let mut sample_counts = Vec::with_capacity(absolute_indices.size.area());
+
+        for line in table.chunks_exact(line_byte_size.max(1)) {
+            let mut previous_offset = 0;
+
+            for offset_bytes in line.chunks_exact(i32::BYTE_SIZE) {
+                let offset = i32::from_le_bytes([offset_bytes[0], offset_bytes[1], offset_bytes[2], offset_bytes[3]]);
+                let sample_count = offset.checked_sub(previous_offset)
+                    .and_then(|count| u32::try_from(count).ok())
+                    .ok_or(Error::invalid("deep pixel offset table"))?;
+
+                if pedantic && header.max_samples_per_pixel.map_or(false, |max| sample_count as usize > max) {
+                    return Err(Error::invalid("deep pixel sample count"));
+                }
+
+                sample_counts.push(sample_count);
+                previous_offset = offset;
+            }
+        }
+
+        let total_sample_count: usize = sample_counts.iter().map(|&count| count as usize).sum();
+        if total_sample_count.checked_mul(header.channels.bytes_per_pixel) != Some(sample_data_byte_size) {
+            return Err(Error::invalid("deep sample data size"));
+        }
+
+        Ok(UncompressedDeepBlock {
+            sample_data: header.compression.decompress_deep_section(compressed_sample_data, sample_data_byte_size, pedantic)?,
+            sample_counts,
+            index: BlockIndex {
+                layer: chunk.layer_index,
+                pixel_position: absolute_indices.position.to_usize("data indices start")?,
+                level: tile_data_indices.level_index,
+                pixel_size: absolute_indices.size,
+            }
+        })
+    }
+
+    /// The total number of samples in all pixels of this block.
+    pub fn total_sample_count(&self) -> usize {
+        self.sample_counts.iter().map(|&count| count as usize).sum()
+    }
+
+    /// Consume this block by compressing it, returning a deep `Chunk`.
+    // for uncompressed data, the ByteVec in the chunk is moved all the way
+    #[must_use]
+    pub fn compress_to_chunk(self, headers: &[Header]) -> Result<Chunk> {
+        let UncompressedDeepBlock { index, sample_counts, sample_data } = self;
+
+        let header: &Header = headers.get(index.layer)
+            .expect("block layer index bug");
+
+        debug_assert!(header.deep, "deep block in flat layer bug");
+
+        if sample_counts.len() != index.pixel_size.area() {
+            panic!("deep block should have {} sample counts but had {}", index.pixel_size.area(), sample_counts.len());
+        }
+
+        let expected_byte_size = header.channels.bytes_per_pixel * sample_counts.iter().map(|&count| count as usize).sum::<usize>();
+        if expected_byte_size != sample_data.len() {
+            panic!("deep block byte size should be {} but was {}", expected_byte_size, sample_data.len());
+        }
+
+        let tile_coordinates = TileCoordinates {
+            // FIXME this calculation should not be made here but elsewhere instead (in meta::header?)
+            tile_index: index.pixel_position / header.max_block_pixel_size(),
+            level_index: index.level,
+        };
+
+        let absolute_indices = header.get_absolute_block_pixel_coordinates(tile_coordinates)?;
+        absolute_indices.validate(Some(header.layer_size))?;
+
+        // the table contains the cumulative sample count of each pixel, starting anew in each line
+        let mut table = Vec::with_capacity(sample_counts.len() * i32::BYTE_SIZE);
+        for line in sample_counts.chunks_exact(index.pixel_size.width().max(1)) {
+            let mut offset: i32 = 0;
+
+            for &sample_count in line {
+                offset = i32::try_from(sample_count).ok()
+                    .and_then(|sample_count| offset.checked_add(sample_count))
+                    .ok_or(Error::invalid("deep pixel sample count"))?;
+
+                table.extend_from_slice(&offset.to_le_bytes());
+            }
+        }
+
+        let decompressed_sample_data_size = sample_data.len();
+        let compressed_pixel_offset_table = header.compression.compress_deep_section(table)?
+            .into_iter().map(|byte| byte as i8).collect();
+
+        let compressed_sample_data = header.compression.compress_deep_section(sample_data)?;
+
+        Ok(Chunk {
+            layer_index: index.layer,
+            compressed_block : match header.blocks {
+                BlockDescription::ScanLines => CompressedBlock::DeepScanLine(CompressedDeepScanLineBlock {
+                    // FIXME this calculation should not be made here but elsewhere instead (in meta::header?)
+                    y_coordinate: usize_to_i32(index.pixel_position.y()) + header.own_attributes.layer_position.y(),
+                    decompressed_sample_data_size, compressed_pixel_offset_table, compressed_sample_data,
+                }),
+
+                BlockDescription::Tiles(_) => CompressedBlock::DeepTile(CompressedDeepTileBlock {
+                    coordinates: tile_coordinates,
+                    decompressed_sample_data_size, compressed_pixel_offset_table, compressed_sample_data,
+                }),
+            }
+        })
+    }
+
+    /// Iterate all the lines in this block.
+    /// Each line contains the samples of all pixels for one of the channels.
+    pub fn lines<'s>(&'s self, channels: &'s ChannelList) -> impl 's + Iterator<Item=DeepLineRef<'s>> {
+        Self::lines_in_block(self.index, &self.sample_counts, channels)
+            .map(move |(bytes, location, sample_counts)| DeepLineRef {
+                location, sample_counts, value: &self.sample_data[bytes]
+            })
+    }
+
+    /// Create an uncompressed deep block by requesting one line of samples after another.
+    /// The sample counts contain the number of samples of each pixel in the block, one row after another.
+    pub fn from_lines(
+        channels: &ChannelList, block_index: BlockIndex, sample_counts: Vec<u32>,
+        mut extract_line: impl FnMut(DeepLineRefMut<'_>)
+    ) -> Self {
+        debug_assert_eq!(sample_counts.len(), block_index.pixel_size.area(), "deep block sample count bug");
+
+        let total_sample_count: usize = sample_counts.iter().map(|&count| count as usize).sum();
+        let mut sample_data = vec![0_u8; total_sample_count * channels.bytes_per_pixel];
+
+        for (byte_range, location, line_sample_counts) in Self::lines_in_block(block_index, &sample_counts, channels) {
+            extract_line(DeepLineRefMut {
+                value: &mut sample_data[byte_range],
+                sample_counts: line_sample_counts,
+                location,
+            });
+        }
+
+        UncompressedDeepBlock { index: block_index, sample_counts, sample_data }
+    }
+
+    /// Iterates the lines of a deep block in interleaved fashion:
+    /// For each line in this block, this iterator steps once through each channel.
+    /// Yields the byte range of the line inside the sample data, and the sample counts of the line.
+    fn lines_in_block<'s>(index: BlockIndex, sample_counts: &'s [u32], channels: &'s ChannelList)
+        -> impl 's + Iterator<Item=(Range<usize>, LineIndex, &'s [u32])>
+    {
+        let bytes_per_pixel = channels.bytes_per_pixel;
+
+        sample_counts.chunks_exact(index.pixel_size.width().max(1))
+            .scan(0, move |line_start_byte, line_sample_counts| {
+                let line_sample_count: usize = line_sample_counts.iter().map(|&count| count as usize).sum();
+                let start_byte = *line_start_byte;
+                *line_start_byte += line_sample_count * bytes_per_pixel;
+                Some((start_byte, line_sample_count, line_sample_counts))
+            })
+            .enumerate()
+            .flat_map(move |(y, (line_start_byte, line_sample_count, line_sample_counts))| {
+                channels.list.iter().enumerate().scan(line_start_byte, move |start_byte, (channel_index, channel)| {
+                    let end_byte = *start_byte + line_sample_count * channel.sample_type.bytes_per_sample();
+
+                    let location = LineIndex {
+                        layer: index.layer,
+                        channel: channel_index,
+                        level: index.level,
+                        position: index.pixel_position + Vec2(0, y),
+                        sample_count: index.pixel_size.width(),
+                    };
+
+                    let line = (*start_byte .. end_byte, location, line_sample_counts);
+                    *start_byte = end_byte;
+                    Some(line)
+                })
+            })
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+    use crate::meta::attribute::ChannelDescription;
+
+    fn deep_meta_data(compression: Compression) -> MetaData {
+        let mut header = Header::new(
+            Text::from("deep"), Vec2(3, 1),
+            smallvec![ ChannelDescription::new("Z", SampleType::F32, true) ]
+        ).with_encoding(compression, BlockDescription::ScanLines, LineOrder::Increasing);
+
+        header.deep = true;
+        header.deep_data_version = Some(1);
+        header.max_samples_per_pixel = Some(2);
+
+        let headers: Headers = smallvec![ header ];
+        MetaData { requirements: MetaData::validate(&headers, true).unwrap(), headers }
+    }
+
+    fn deep_chunk(pixel_offsets: &[i32], samples: &[f32]) -> Chunk {
+        Chunk {
+            layer_index: 0,
+            compressed_block: CompressedBlock::DeepScanLine(CompressedDeepScanLineBlock {
+                y_coordinate: 0,
+                decompressed_sample_data_size: samples.len() * f32::BYTE_SIZE,
+                compressed_pixel_offset_table: pixel_offsets.iter().flat_map(|offset| offset.to_le_bytes()).map(|byte| byte as i8).collect(),
+                compressed_sample_data: samples.iter().flat_map(|sample| sample.to_le_bytes()).collect(),
+            })
+        }
+    }
+
+    #[test]
+    fn decompress_deep_scan_line(){
+        let meta_data = deep_meta_data(Compression::Uncompressed);
+        let block = UncompressedDeepBlock::decompress_chunk(deep_chunk(&[1, 1, 3], &[0.5, 1.0, 2.0]), &meta_data, true).unwrap();
+
+        assert_eq!(block.sample_counts, vec![1, 0, 2]);
+        assert_eq!(block.total_sample_count(), 3);
+        assert_eq!(block.sample_data.len(), 3 * f32::BYTE_SIZE);
+        assert_eq!(block.index.pixel_size, Vec2(3, 1));
+
+        assert!(UncompressedBlock::decompress_chunk(deep_chunk(&[1, 1, 3], &[0.5, 1.0, 2.0]), &meta_data, true).is_err());
+    }
+
+    #[test]
+    fn compress_deep_scan_line(){
+        for &compression in &[Compression::Uncompressed, Compression::RLE, Compression::ZIP1(None)] {
+            let meta_data = deep_meta_data(compression);
+            let samples = [0.5_f32, 1.0, 2.0];
+
+            let index = BlockIndex { layer: 0, pixel_position: Vec2(0, 0), pixel_size: Vec2(3, 1), level: Vec2(0, 0) };
+            let block = UncompressedDeepBlock::from_lines(&meta_data.headers[0].channels, index, vec![1, 0, 2], |line| {
+                line.write_samples_from_slice(&samples).unwrap()
+            });
+
+            let chunk = block.clone().compress_to_chunk(&meta_data.headers).unwrap();
+            let decompressed = UncompressedDeepBlock::decompress_chunk(chunk, &meta_data, true).unwrap();
+            assert_eq!(decompressed, block);
+        }
+    }
+
+    #[test]
+    fn decompress_deep_scan_line_invalid(){
+        let meta_data = deep_meta_data(Compression::RLE);
+
+        // decreasing offsets
+        assert!(UncompressedDeepBlock::decompress_chunk(deep_chunk(&[2, 1, 3], &[0.5, 1.0, 2.0]), &meta_data, true).is_err());
+
+        // too many samples in one pixel
+        assert!(UncompressedDeepBlock::decompress_chunk(deep_chunk(&[0, 0, 3], &[0.5, 1.0, 2.0]), &meta_data, true).is_err());
+
+        // sample count not matching the sample data
+        assert!(UncompressedDeepBlock::decompress_chunk(deep_chunk(&[1, 1, 3], &[0.5, 1.0]), &meta_data, true).is_err());
+    }
+}
@@ -9,6 +9,7 @@ use smallvec::alloc::sync::Arc;
 
 use crate::block::{BlockIndex, UncompressedBlock};
 use crate::block::chunk::{Chunk, TileCoordinates};
+use crate::block::cache::BlockCache;
 use crate::compression::Compression;
 use crate::error::{Error, Result, u64_to_usize, UnitResult};
 use crate::io::{PeekRead, Tracking};
@@ -68,6 +69,22 @@ impl<R: Read + Seek> Reader<R> {
         })
     }
 
+    /// Keep the file open to decompress individual blocks on demand, caching the most recently used blocks.
+    /// The cache holds blocks until their total byte size exceeds the specified maximum.
+    /// Reads the offset tables of the file immediately.
+    pub fn into_block_cache(mut self, pedantic: bool, max_cached_bytes: usize) -> Result<BlockCache<R>> {
+        let offset_tables = MetaData::read_offset_tables(&mut self.remaining_reader, &self.meta_data.headers)?;
+
+        if pedantic {
+            validate_offset_tables(
+                self.meta_data.headers.as_slice(), &offset_tables,
+                self.remaining_reader.byte_position()
+            )?;
+        }
+
+        BlockCache::new(self.meta_data, offset_tables, self.remaining_reader, pedantic, max_cached_bytes)
+    }
+
     /// Prepare to read some the chunks from the file.
     /// Does not decode the chunks now, but returns a decoder.
     /// Reading only some chunks may seeking the file, potentially skipping many bytes.
@@ -126,12 +143,16 @@ impl<R: Read + Seek> Reader<R> {
 
 
 fn validate_offset_tables(headers: &[Header], offset_tables: &OffsetTables, chunks_start_byte: usize) -> UnitResult {
+    let has_deep_data = headers.iter().any(|header| header.deep);
+
     let max_pixel_bytes: usize = headers.iter() // when compressed, chunks are smaller, but never larger than max
+        .filter(|header| !header.deep)
         .map(|header| header.max_pixel_file_bytes())
         .sum();
 
     // check that each offset is within the bounds
-    let end_byte = chunks_start_byte + max_pixel_bytes;
+    // (deep data can contain any number of samples, so its end cannot be known in advance)
+    let end_byte = if has_deep_data { usize::MAX } else { chunks_start_byte + max_pixel_bytes };
     let is_invalid = offset_tables.iter().flatten().map(|&u64| u64_to_usize(u64))
         .any(|chunk_start| chunk_start < chunks_start_byte || chunk_start > end_byte);
 
@@ -8,12 +8,13 @@ use std::ops::Not;
 
 use smallvec::alloc::collections::BTreeMap;
 
-use crate::block::UncompressedBlock;
+use crate::block::{UncompressedBlock, UncompressedDeepBlock};
 use crate::block::chunk::{Chunk};
 use crate::compression::Compression;
 use crate::error::{Error, Result, UnitResult, usize_to_u64};
 use crate::io::{Data, Tracking, Write};
 use crate::meta::{Headers, MetaData, OffsetTables};
+use crate::meta::header::Header;
 use crate::meta::attribute::LineOrder;
 
 /// Write an exr file by writing one chunk after another in a closure.
@@ -331,6 +332,14 @@ impl<'w, W> SequentialBlocksCompressor<'w, W> where W: 'w + ChunksWriter {
             block.compress_to_chunk(&self.meta.headers)?
         )
     }
+
+    /// Compress a single deep block immediately. The index of the block must be in increasing line order.
+    pub fn compress_deep_block(&mut self, index_in_header_increasing_y: usize, block: UncompressedDeepBlock) -> UnitResult {
+        self.chunks_writer.write_chunk(
+            index_in_header_increasing_y,
+            block.compress_to_chunk(&self.meta.headers)?
+        )
+    }
 }
 
 /// Compress blocks to a chunk writer with multiple threads.
@@ -414,6 +423,19 @@ impl<'w, W> ParallelBlocksCompressor<'w, W> where W: 'w + ChunksWriter {
     /// This only works when you write as many blocks as the image expects, otherwise you can use `wait_for_all_remaining_chunks`.
     /// Waits for a block from the queue to be written, if the queue already has enough items.
     pub fn add_block_to_compression_queue(&mut self, index_in_header_increasing_y: usize, block: UncompressedBlock) -> UnitResult {
+        self.add_to_compression_queue(index_in_header_increasing_y, move |headers| block.compress_to_chunk(headers))
+    }
+
+    /// Add a single deep block to the compressor queue. The index of the block must be in increasing line order.
+    /// Behaves like `add_block_to_compression_queue`, which can be called for the flat blocks of the same image.
+    pub fn add_deep_block_to_compression_queue(&mut self, index_in_header_increasing_y: usize, block: UncompressedDeepBlock) -> UnitResult {
+        self.add_to_compression_queue(index_in_header_increasing_y, move |headers| block.compress_to_chunk(headers))
+    }
+
+    fn add_to_compression_queue(
+        &mut self, index_in_header_increasing_y: usize,
+        compress: impl 'static + Send + FnOnce(&[Header]) -> Result<Chunk>
+    ) -> UnitResult {
 
         // if pipe is full, block to wait for a slot to free up
         if self.currently_compressing_count >= self.max_threads {
@@ -426,7 +448,7 @@ impl<'w, W> ParallelBlocksCompressor<'w, W> where W: 'w + ChunksWriter {
         let meta = self.meta.clone();
 
         self.pool.execute(move ||{
-            let compressed_or_err = block.compress_to_chunk(&meta.headers);
+            let compressed_or_err = compress(&meta.headers);
 
             // by now, decompressing could have failed in another thread.
             // the error is then already handled, so we simply
@@ -234,7 +234,6 @@ struct ChannelData {
     y_sampling: usize,
     sample_type: SampleType,
     quantize_linearly: bool,
-    samples_per_pixel: usize,
 }
 
 // TODO: Unsafe seems to be required to efficiently copy whole slice of u16 ot u8. For now, we use
@@ -265,7 +264,7 @@ pub fn decompress(
 ) -> Result<ByteVec> {
     debug_assert_eq!(
         expected_byte_size,
-        rectangle.size.area() * channels.bytes_per_pixel,
+        channels.byte_size_of_section(rectangle),
         "expected byte size does not match header" // TODO compute instead of passing argument?
     );
 
@@ -283,15 +282,13 @@ pub fn decompress(
         let channel = ChannelData {
             tmp_start_index: tmp_read_index,
             tmp_end_index: tmp_read_index,
-            resolution: channel.subsampled_resolution(rectangle.size),
+            resolution: channel.subsampled_section_resolution(rectangle),
             y_sampling: channel.sampling.y(),
             sample_type: channel.sample_type,
             quantize_linearly: channel.quantize_linearly,
-            samples_per_pixel: channel.sampling.area(),
         };
 
         tmp_read_index += channel.resolution.area()
-            * channel.samples_per_pixel
             * channel.sample_type.bytes_per_sample();
 
         channel_data.push(channel);
@@ -311,7 +308,7 @@ pub fn decompress(
         debug_assert_eq!(remaining, compressed.len()-in_i);
 
         // Compute information for current channel.
-        let sample_count = channel.resolution.area() * channel.samples_per_pixel;
+        let sample_count = channel.resolution.area();
         let byte_count = sample_count * channel.sample_type.bytes_per_sample();
 
         // Sample types that does not support B44 compression (u32 and f32) are raw copied.
@@ -340,8 +337,8 @@ pub fn decompress(
         // Increase buffer to get new uncompressed datas.
         tmp.resize(tmp.len() + byte_count, 0);
 
-        let x_sample_count = channel.resolution.x() * channel.samples_per_pixel;
-        let y_sample_count = channel.resolution.y() * channel.samples_per_pixel;
+        let x_sample_count = channel.resolution.x();
+        let y_sample_count = channel.resolution.y();
 
         let bytes_per_sample = size_of::<u16>();
 
@@ -441,7 +438,7 @@ pub fn decompress(
             }
 
             // Find data location in temporary buffer.
-            let x_sample_count = channel.resolution.x() * channel.samples_per_pixel;
+            let x_sample_count = channel.resolution.x();
             let bytes_per_line = x_sample_count * channel.sample_type.bytes_per_sample();
             let next_tmp_end_index = channel.tmp_end_index + bytes_per_line;
             let channel_bytes = &tmp[channel.tmp_end_index..next_tmp_end_index];
@@ -502,9 +499,9 @@ pub fn compress(
 
     let mut tmp_end_index = 0;
     for channel in &channels.list {
-        let number_samples = channel.subsampled_resolution(rectangle.size);
+        let number_samples = channel.subsampled_section_resolution(rectangle);
 
-        let sample_count = channel.subsampled_resolution(rectangle.size).area();
+        let sample_count = channel.subsampled_section_resolution(rectangle).area();
         let byte_count = sample_count * channel.sample_type.bytes_per_sample();
 
         let channel = ChannelData {
@@ -514,7 +511,6 @@ pub fn compress(
             resolution: number_samples,
             sample_type: channel.sample_type,
             quantize_linearly: channel.quantize_linearly,
-            samples_per_pixel: channel.sampling.area(),
         };
 
         tmp_end_index += byte_count;
@@ -533,7 +529,7 @@ pub fn compress(
                 continue;
             }
 
-            let x_sample_count = channel.resolution.x() * channel.samples_per_pixel;
+            let x_sample_count = channel.resolution.x();
             let bytes_per_line = x_sample_count * channel.sample_type.bytes_per_sample();
             let next_tmp_end_index = channel.tmp_end_index + bytes_per_line;
             let target = &mut tmp[channel.tmp_end_index..next_tmp_end_index];
@@ -563,8 +559,18 @@ pub fn compress(
         }
     }
 
+    // Every 4x4 block of a half channel packs into at most 14 bytes, which can be more
+    // than the raw bytes for narrow or subsampled channels, so reserve the worst case.
+    let max_compressed_byte_size: usize = channel_data.iter().map(|channel| {
+        if channel.sample_type != SampleType::F16 { channel.tmp_end_index - channel.tmp_start_index }
+        else {
+            let block_count = |samples: usize| (samples + BLOCK_SAMPLE_COUNT - 1) / BLOCK_SAMPLE_COUNT;
+            block_count(channel.resolution.x()) * block_count(channel.resolution.y()) * 14
+        }
+    }).sum();
+
     // Generate a whole buffer that we will crop to proper size once compression is done.
-    let mut b44_compressed = vec![0; std::cmp::max(2048, uncompressed.len())];
+    let mut b44_compressed = vec![0; std::cmp::max(2048, max_compressed_byte_size)];
     let mut b44_end = 0; // Buffer byte index for storing next compressed values.
 
     for channel in &channel_data {
@@ -587,8 +593,8 @@ pub fn compress(
         debug_assert_eq!(channel.sample_type, SampleType::F16);
         debug_assert_eq!(channel.sample_type.bytes_per_sample(), size_of::<u16>());
 
-        let x_sample_count = channel.resolution.x() * channel.samples_per_pixel;
-        let y_sample_count = channel.resolution.y() * channel.samples_per_pixel;
+        let x_sample_count = channel.resolution.x();
+        let y_sample_count = channel.resolution.y();
 
         let x_byte_count = x_sample_count * size_of::<u16>();
         let cd_start = channel.tmp_start_index;
@@ -709,7 +715,7 @@ mod test {
             .list
             .iter()
             .map(|c| {
-                c.subsampled_resolution(rectangle.size).area() * c.sample_type.bytes_per_sample()
+                c.subsampled_section_resolution(rectangle).area() * c.sample_type.bytes_per_sample()
             })
             .sum();
 
@@ -0,0 +1,217 @@
+//! The discrete cosine transform and color space conversion
+//! used by the lossy channels of DWA compression.
+// see https://github.com/AcademySoftwareFoundation/openexr/blob/main/src/lib/OpenEXR/ImfDwaCompressorSimd.h
+
+
+/// The zig zag index of the coefficient at each position of an 8x8 block.
+/// Coefficients are stored in this order such that
+/// the high frequencies, which are mostly zero, are stored last.
+pub const ZIG_ZAG_INDICES: [usize; 64] = [
+    0,  1,  5,  6,  14, 15, 27, 28,
+    2,  4,  7,  13, 16, 26, 29, 42,
+    3,  8,  12, 17, 25, 30, 41, 43,
+    9,  11, 18, 24, 31, 40, 44, 53,
+    10, 19, 23, 32, 39, 45, 52, 54,
+    20, 22, 33, 38, 46, 51, 55, 60,
+    21, 34, 37, 47, 50, 56, 59, 61,
+    35, 36, 48, 49, 57, 58, 62, 63,
+];
+
+/// The first zig zag index of each row, starting at the second row.
+/// If the last non-zero coefficient comes before one of these,
+/// the rows from there on are known to contain only zeroes.
+const ROW_START_ZIG_ZAG_INDICES: [usize; 7] = [ 2, 3, 9, 10, 20, 21, 35 ];
+
+
+/// Compute the number of trailing block rows that are skipped in the row-wise
+/// inverse transform, given the zig zag index of the last non-zero coefficient.
+// The reference implementation transforms the rows in pairs, and always skips
+// a pair which contains only a single non-zero row (except for the first row).
+// This must be replicated to obtain the exact same pixel values.
+pub fn zeroed_rows(last_non_zero_index: usize) -> usize {
+    let non_zero_rows = 1 + ROW_START_ZIG_ZAG_INDICES.iter()
+        .take_while(|&&row_start| last_non_zero_index >= row_start)
+        .count();
+
+    let zeroed_rows = 8 - non_zero_rows;
+    if zeroed_rows % 2 == 1 && zeroed_rows < 7 { zeroed_rows + 1 }
+    else { zeroed_rows }
+}
+
+/// Inverse DCT for a block where all coefficients except the first are zero.
+pub fn inverse_dc_only(block: &mut [f32; 64]) {
+    let value = block[0] * 3.535536e-01_f32 * 3.535536e-01_f32;
+    *block = [value; 64];
+}
+
+/// Inverse DCT for a block of 8x8 coefficients, in-place.
+/// The last `zeroed_rows` rows are assumed to contain only zero coefficients,
+/// and will be skipped in the row-wise pass.
+// this needs to be reproduced exactly, as the rounding of the original implementation
+// determines the resulting pixel values.
+pub fn inverse_8x8(block: &mut [f32; 64], zeroed_rows: usize) {
+    let [a, b, c, d, e, f, g] = cosines();
+
+    let transform = |value: [f32; 8]| -> [f32; 8] {
+        let alpha = [ c * value[2], f * value[2], c * value[6], f * value[6] ];
+
+        let beta = [
+            b * value[1] + d * value[3] + e * value[5] + g * value[7],
+            d * value[1] - g * value[3] - b * value[5] - e * value[7],
+            e * value[1] - b * value[3] + g * value[5] + d * value[7],
+            g * value[1] - e * value[3] + d * value[5] - b * value[7],
+        ];
+
+        let theta = [
+            a * (value[0] + value[4]),
+            alpha[0] + alpha[3],
+            alpha[1] - alpha[2],
+            a * (value[0] - value[4]),
+        ];
+
+        let gamma = [
+            theta[0] + theta[1],
+            theta[3] + theta[2],
+            theta[3] - theta[2],
+            theta[0] - theta[1],
+        ];
+
+        [
+            gamma[0] + beta[0], gamma[1] + beta[1], gamma[2] + beta[2], gamma[3] + beta[3],
+            gamma[3] - beta[3], gamma[2] - beta[2], gamma[1] - beta[1], gamma[0] - beta[0],
+        ]
+    };
+
+    transform_rows_then_columns(block, 8 - zeroed_rows, transform);
+}
+
+/// Forward DCT for a block of 8x8 pixels, in-place.
+/// This is the transposed version of the inverse transform.
+pub fn forward_8x8(block: &mut [f32; 64]) {
+    let [a, b, c, d, e, f, g] = cosines();
+
+    let transform = |value: [f32; 8]| -> [f32; 8] {
+        let sums = [ value[0] + value[7], value[1] + value[6], value[2] + value[5], value[3] + value[4] ];
+        let differences = [ value[0] - value[7], value[1] - value[6], value[2] - value[5], value[3] - value[4] ];
+
+        [
+            a * (sums[0] + sums[1] + sums[2] + sums[3]),
+            b * differences[0] + d * differences[1] + e * differences[2] + g * differences[3],
+            c * (sums[0] - sums[3]) + f * (sums[1] - sums[2]),
+            d * differences[0] - g * differences[1] - b * differences[2] - e * differences[3],
+            a * (sums[0] - sums[1] - sums[2] + sums[3]),
+            e * differences[0] - b * differences[1] + g * differences[2] + d * differences[3],
+            f * (sums[0] - sums[3]) - c * (sums[1] - sums[2]),
+            g * differences[0] - e * differences[1] + d * differences[2] - b * differences[3],
+        ]
+    };
+
+    transform_rows_then_columns(block, 8, transform);
+}
+
+/// The scaled cosines used by both the forward and the inverse transform.
+fn cosines() -> [f32; 7] {
+    [
+        0.5_f32 * (3.14159_f32 / 4.0).cos(),
+        0.5_f32 * (3.14159_f32 / 16.0).cos(),
+        0.5_f32 * (3.14159_f32 / 8.0).cos(),
+        0.5_f32 * (3.0 * 3.14159_f32 / 16.0).cos(),
+        0.5_f32 * (5.0 * 3.14159_f32 / 16.0).cos(),
+        0.5_f32 * (3.0 * 3.14159_f32 / 8.0).cos(),
+        0.5_f32 * (7.0 * 3.14159_f32 / 16.0).cos(),
+    ]
+}
+
+/// Apply the one-dimensional transform to the first `row_count` rows, and then to all columns.
+fn transform_rows_then_columns(block: &mut [f32; 64], row_count: usize, transform: impl Fn([f32; 8]) -> [f32; 8]) {
+    for row in block.chunks_exact_mut(8).take(row_count) {
+        let mut values = [0.0; 8];
+        values.copy_from_slice(row);
+        row.copy_from_slice(&transform(values));
+    }
+
+    for column in 0..8 {
+        let mut values = [0.0; 8];
+        for (row, value) in values.iter_mut().enumerate() {
+            *value = block[row * 8 + column];
+        }
+
+        for (row, value) in transform(values).iter().enumerate() {
+            block[row * 8 + column] = *value;
+        }
+    }
+}
+
+/// Convert a pixel from RGB to the Rec.709 Y'CbCr color space.
+pub fn rgb_to_ycbcr(red: f32, green: f32, blue: f32) -> (f32, f32, f32) {
+    (
+        0.2126 * red + 0.7152 * green + 0.0722 * blue,
+        -0.1146 * red - 0.3854 * green + 0.5 * blue,
+        0.5 * red - 0.4542 * green - 0.0458 * blue,
+    )
+}
+
+/// Convert a pixel from the Rec.709 Y'CbCr color space to RGB.
+pub fn ycbcr_to_rgb(y: f32, cb: f32, cr: f32) -> (f32, f32, f32) {
+    (
+        y + 1.5747 * cr,
+        y - 0.1873 * cb - 0.4682 * cr,
+        y + 1.8556 * cb,
+    )
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn zeroed_rows_from_last_index(){
+        assert_eq!(zeroed_rows(0), 7);
+        assert_eq!(zeroed_rows(1), 7);
+        assert_eq!(zeroed_rows(2), 6);
+        assert_eq!(zeroed_rows(20), 2);
+        assert_eq!(zeroed_rows(21), 2);
+        assert_eq!(zeroed_rows(34), 2);
+        assert_eq!(zeroed_rows(35), 0);
+        assert_eq!(zeroed_rows(63), 0);
+    }
+
+    #[test]
+    fn dc_only_equals_full_transform(){
+        let mut dc_only = [0.0; 64];
+        dc_only[0] = 3.0;
+
+        let mut full = dc_only;
+        inverse_dc_only(&mut dc_only);
+        inverse_8x8(&mut full, 0);
+
+        for (expected, actual) in dc_only.iter().zip(full.iter()) {
+            assert!((expected - actual).abs() < 0.0001, "{} != {}", expected, actual);
+        }
+    }
+
+    #[test]
+    fn inverse_reverts_forward(){
+        let mut original = [0.0; 64];
+        for (index, value) in original.iter_mut().enumerate() {
+            *value = ((index * 37) % 17) as f32 * 0.25 - 1.0;
+        }
+
+        let mut block = original;
+        forward_8x8(&mut block);
+        inverse_8x8(&mut block, 0);
+
+        for (expected, actual) in original.iter().zip(block.iter()) {
+            assert!((expected - actual).abs() < 0.001, "{} != {}", expected, actual);
+        }
+    }
+
+    #[test]
+    fn ycbcr_reverts_rgb(){
+        let (y, cb, cr) = rgb_to_ycbcr(0.25, 0.5, 0.75);
+        let (red, green, blue) = ycbcr_to_rgb(y, cb, cr);
+
+        assert!((red - 0.25).abs() < 0.001 && (green - 0.5).abs() < 0.001 && (blue - 0.75).abs() < 0.001);
+    }
+}
@@ -0,0 +1,1090 @@
+//! The DWA compression method, developed by DreamWorks Animation.
+//! Color channels are compressed lossy, similar to JPEG, using a discrete cosine transform.
+//! All other channels are compressed losslessly, using RLE or zlib.
+// inspired by https://github.com/AcademySoftwareFoundation/openexr/blob/main/src/lib/OpenEXR/ImfDwaCompressor.cpp
+
+mod dct;
+mod table;
+
+use crate::compression::{ByteVec, Bytes, mod_p};
+use crate::compression::optimize_bytes::*;
+use crate::compression::piz::huffman;
+use crate::error::{usize_to_i32, UnitResult};
+use crate::io::Data;
+use crate::math::RoundingMode;
+use crate::meta::attribute::ChannelList;
+use crate::prelude::*;
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+use std::io::Write;
+
+
+/// The number of `u64` values at the start of the compressed data.
+const HEADER_VALUE_COUNT: usize = 11;
+
+/// Marks the end of the coefficients of a block in the AC stream.
+const END_OF_BLOCK: u16 = 0xff00;
+
+/// In the AC stream, a value with this high byte marks a run of zeroes.
+const ZERO_RUN_MARKER: u16 = 0xff;
+
+/// The compression level used if the header does not specify any.
+pub const DEFAULT_COMPRESSION_LEVEL: f32 = 45.0;
+
+/// The version of the compressed data written by this implementation, which includes the channel rules.
+const VERSION: u64 = 2;
+
+/// The JPEG quantization table for the luminance or single channel coefficients.
+/// Divided by its smallest value, this scales the error tolerated for each coefficient.
+const QUANTIZATION_TABLE_Y: [u8; 64] = [
+    16,  11,  10,  16,  24,  40,  51,  61,
+    12,  12,  14,  19,  26,  58,  60,  55,
+    14,  13,  16,  24,  40,  57,  69,  56,
+    14,  17,  22,  29,  51,  87,  80,  62,
+    18,  22,  37,  56,  68,  109, 103, 77,
+    24,  35,  55,  64,  81,  104, 113, 92,
+    49,  64,  78,  87,  103, 121, 120, 101,
+    72,  92,  95,  98,  112, 100, 103, 99,
+];
+
+/// The JPEG quantization table for the chroma coefficients.
+/// Divided by its smallest value, this scales the error tolerated for each coefficient.
+const QUANTIZATION_TABLE_CBCR: [u8; 64] = [
+    17,  18,  24,  47,  99,  99,  99,  99,
+    18,  21,  26,  66,  99,  99,  99,  99,
+    24,  26,  56,  99,  99,  99,  99,  99,
+    47,  66,  99,  99,  99,  99,  99,  99,
+    99,  99,  99,  99,  99,  99,  99,  99,
+    99,  99,  99,  99,  99,  99,  99,  99,
+    99,  99,  99,  99,  99,  99,  99,  99,
+    99,  99,  99,  99,  99,  99,  99,  99,
+];
+
+/// The compression sub-method of a single channel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Scheme {
+
+    /// Compressed losslessly with zlib.
+    Unknown,
+
+    /// Compressed lossy with a discrete cosine transform.
+    LossyDct,
+
+    /// Compressed losslessly with run length encoding and zlib.
+    Rle,
+}
+
+/// How the AC coefficients are compressed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum AcCompression {
+    StaticHuffman,
+    Deflate,
+}
+
+/// The sizes of all the compressed sections, as stored at the start of the compressed data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct StreamHeader {
+    version: u64,
+    unknown_uncompressed_size: usize,
+    unknown_compressed_size: usize,
+    ac_compressed_size: usize,
+    dc_compressed_size: usize,
+    rle_compressed_size: usize,
+    rle_uncompressed_size: usize,
+    rle_raw_size: usize,
+    ac_uncompressed_count: usize,
+    dc_uncompressed_count: usize,
+    ac_compression: AcCompression,
+}
+
+/// Decides which channels are compressed with which scheme, based on the channel name suffix.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ChannelRule {
+    suffix: Text,
+    scheme: Scheme,
+    sample_type: SampleType,
+
+    /// Channels with a color index are grouped, if all three (red, green, blue) are present.
+    /// The group is then converted to Y'CbCr before compression.
+    color_index: Option<usize>,
+    case_insensitive: bool,
+}
+
+#[derive(Debug)]
+struct ChannelData {
+    scheme: Scheme,
+    sample_type: SampleType,
+    quantize_linearly: bool,
+    resolution: Vec2<usize>,
+
+    /// The byte index of each line of this channel in the uncompressed block.
+    line_starts: Vec<usize>,
+}
+
+
+pub fn decompress(
+    channels: &ChannelList,
+    compressed: ByteVec,
+    rectangle: IntegerBounds,
+    expected_byte_size: usize,
+    pedantic: bool
+) -> Result<ByteVec>
+{
+    let mut remaining = compressed.as_slice();
+    let header = StreamHeader::read(&mut remaining)?;
+
+    let rules = match header.version {
+        0 | 1 => ChannelRule::legacy_rules(),
+        2 => ChannelRule::read_all(&mut remaining)?,
+        _ => return Err(Error::unsupported("dwa version")),
+    };
+
+    let unknown_compressed = take_n(&mut remaining, header.unknown_compressed_size)?;
+    let ac_compressed = take_n(&mut remaining, header.ac_compressed_size)?;
+    let dc_compressed = take_n(&mut remaining, header.dc_compressed_size)?;
+    let rle_compressed = take_n(&mut remaining, header.rle_compressed_size)?;
+
+    if pedantic && !remaining.is_empty() {
+        return Err(Error::invalid("data amount"));
+    }
+
+    let (channel_data, color_groups) = classify_channels(channels, rectangle, &rules);
+    let mut out = vec![0_u8; expected_byte_size];
+
+    // compute the size limits of all sections, as the header values cannot be trusted
+    let mut max_unknown_byte_size = 0;
+    let mut max_rle_byte_size = 0;
+    let mut max_dct_block_count = 0;
+
+    for channel in &channel_data {
+        let byte_size = channel.resolution.area() * channel.sample_type.bytes_per_sample();
+
+        match channel.scheme {
+            Scheme::Unknown => max_unknown_byte_size += byte_size,
+            Scheme::Rle => max_rle_byte_size += byte_size,
+            Scheme::LossyDct => max_dct_block_count += block_count(channel.resolution).area(),
+        }
+    }
+
+    validate_line_starts(&channel_data, out.len())?;
+
+    if header.unknown_uncompressed_size > max_unknown_byte_size
+        || header.rle_raw_size > max_rle_byte_size
+        || header.ac_uncompressed_count > max_dct_block_count * 63
+        || header.dc_uncompressed_count > max_dct_block_count
+    {
+        return Err(Error::invalid("dwa section size"));
+    }
+
+    let unknown_bytes = if header.unknown_compressed_size == 0 { Vec::new() }
+        else { decompress_zlib(unknown_compressed, header.unknown_uncompressed_size)? };
+
+    let ac_values = if header.ac_compressed_size == 0 { Vec::new() } else {
+        match header.ac_compression {
+            AcCompression::StaticHuffman => huffman::decompress(ac_compressed, header.ac_uncompressed_count)?,
+            AcCompression::Deflate => u16s_from_little_endian(&decompress_zlib(ac_compressed, header.ac_uncompressed_count * 2)?),
+        }
+    };
+
+    let dc_values = if header.dc_compressed_size == 0 { Vec::new() } else {
+        let mut dc_bytes = decompress_zlib(dc_compressed, header.dc_uncompressed_count * 2)?;
+        differences_to_samples(&mut dc_bytes);
+        interleave_byte_blocks(&mut dc_bytes);
+        u16s_from_little_endian(&dc_bytes)
+    };
+
+    let rle_bytes = if header.rle_raw_size == 0 { Vec::new() } else {
+        let rle_compressed = decompress_zlib(rle_compressed, header.rle_uncompressed_size)?;
+        decompress_rle(&rle_compressed, header.rle_raw_size)?
+    };
+
+    let mut remaining_ac = ac_values.as_slice();
+    let mut remaining_dc = dc_values.as_slice();
+
+    // the color channels are decoded first, in groups of three
+    for group in &color_groups {
+        let [red, green, blue] = group.map(|index| &channel_data[index]);
+
+        // the color conversion always assumes the channels to be non-linear
+        decode_lossy_dct(
+            &[
+                DctComponent { sample_type: red.sample_type, line_starts: &red.line_starts, nonlinear: true },
+                DctComponent { sample_type: green.sample_type, line_starts: &green.line_starts, nonlinear: true },
+                DctComponent { sample_type: blue.sample_type, line_starts: &blue.line_starts, nonlinear: true },
+            ],
+            red.resolution, &mut remaining_ac, &mut remaining_dc, &mut out
+        )?;
+    }
+
+    let mut remaining_unknown_bytes = unknown_bytes.as_slice();
+    let mut remaining_rle_bytes = rle_bytes.as_slice();
+
+    for (channel_index, channel) in channel_data.iter().enumerate() {
+        let is_color_group_member = color_groups.iter().any(|group| group.contains(&channel_index));
+        if is_color_group_member { continue; }
+
+        let line_byte_size = channel.resolution.x() * channel.sample_type.bytes_per_sample();
+
+        match channel.scheme {
+            Scheme::LossyDct => decode_lossy_dct(
+                &[ DctComponent {
+                    sample_type: channel.sample_type,
+                    line_starts: &channel.line_starts,
+                    nonlinear: !channel.quantize_linearly,
+                } ],
+                channel.resolution, &mut remaining_ac, &mut remaining_dc, &mut out
+            )?,
+
+            // the bytes of each sample have been separated into planes before compression
+            Scheme::Rle => {
+                let bytes_per_sample = channel.sample_type.bytes_per_sample();
+                let plane_size = channel.resolution.area();
+                let planes = take_n(&mut remaining_rle_bytes, plane_size * bytes_per_sample)?;
+
+                for (line_index, &line_start) in channel.line_starts.iter().enumerate() {
+                    let line = &mut out[line_start .. line_start + line_byte_size];
+
+                    for (sample_index, sample) in line.chunks_exact_mut(bytes_per_sample).enumerate() {
+                        let plane_index = line_index * channel.resolution.x() + sample_index;
+
+                        for (byte_index, byte) in sample.iter_mut().enumerate() {
+                            *byte = planes[byte_index * plane_size + plane_index];
+                        }
+                    }
+                }
+            },
+
+            Scheme::Unknown => {
+                for &line_start in &channel.line_starts {
+                    let line = take_n(&mut remaining_unknown_bytes, line_byte_size)?;
+                    out[line_start .. line_start + line_byte_size].copy_from_slice(line);
+                }
+            },
+        }
+    }
+
+    if pedantic && !(remaining_ac.is_empty() && remaining_dc.is_empty()) {
+        return Err(Error::invalid("data amount"));
+    }
+
+    Ok(super::convert_little_endian_to_current(&out, channels, rectangle))
+}
+
+
+pub fn compress(
+    channels: &ChannelList,
+    uncompressed: Bytes<'_>,
+    rectangle: IntegerBounds,
+    compression_level: f32
+) -> Result<ByteVec>
+{
+    let uncompressed = super::convert_current_to_little_endian(uncompressed, channels, rectangle);
+
+    let rules = ChannelRule::default_rules();
+    let (channel_data, color_groups) = classify_channels(channels, rectangle, &rules);
+    validate_line_starts(&channel_data, uncompressed.len())?;
+
+    // the error that is tolerated when quantizing the lowest frequency
+    let base_error = compression_level / 100_000.0;
+
+    let mut ac_values = Vec::new();
+    let mut dc_values = Vec::new();
+    let mut unknown_bytes = Vec::new();
+    let mut rle_bytes = Vec::new();
+
+    for group in &color_groups {
+        let [red, green, blue] = group.map(|index| &channel_data[index]);
+
+        encode_lossy_dct(
+            &[
+                DctComponent { sample_type: red.sample_type, line_starts: &red.line_starts, nonlinear: true },
+                DctComponent { sample_type: green.sample_type, line_starts: &green.line_starts, nonlinear: true },
+                DctComponent { sample_type: blue.sample_type, line_starts: &blue.line_starts, nonlinear: true },
+            ],
+            red.resolution, base_error, &uncompressed, &mut ac_values, &mut dc_values
+        )?;
+    }
+
+    for (channel_index, channel) in channel_data.iter().enumerate() {
+        let is_color_group_member = color_groups.iter().any(|group| group.contains(&channel_index));
+        if is_color_group_member { continue; }
+
+        let line_byte_size = channel.resolution.x() * channel.sample_type.bytes_per_sample();
+
+        match channel.scheme {
+            Scheme::LossyDct => encode_lossy_dct(
+                &[ DctComponent {
+                    sample_type: channel.sample_type,
+                    line_starts: &channel.line_starts,
+                    nonlinear: !channel.quantize_linearly,
+                } ],
+                channel.resolution, base_error, &uncompressed, &mut ac_values, &mut dc_values
+            )?,
+
+            // separate the bytes of each sample into planes, which are more likely to contain runs
+            Scheme::Rle => {
+                let bytes_per_sample = channel.sample_type.bytes_per_sample();
+                let plane_size = channel.resolution.area();
+
+                let planes_start = rle_bytes.len();
+                rle_bytes.resize(planes_start + plane_size * bytes_per_sample, 0);
+                let planes = &mut rle_bytes[planes_start ..];
+
+                for (line_index, &line_start) in channel.line_starts.iter().enumerate() {
+                    let line = &uncompressed[line_start .. line_start + line_byte_size];
+
+                    for (sample_index, sample) in line.chunks_exact(bytes_per_sample).enumerate() {
+                        let plane_index = line_index * channel.resolution.x() + sample_index;
+
+                        for (byte_index, &byte) in sample.iter().enumerate() {
+                            *planes.get_mut(byte_index * plane_size + plane_index)
+                                .ok_or(Error::invalid("dwa channel size"))? = byte;
+                        }
+                    }
+                }
+            },
+
+            Scheme::Unknown => {
+                for &line_start in &channel.line_starts {
+                    unknown_bytes.extend_from_slice(&uncompressed[line_start .. line_start + line_byte_size]);
+                }
+            },
+        }
+    }
+
+    let unknown_compressed = if unknown_bytes.is_empty() { Vec::new() } else { compress_zlib(&unknown_bytes) };
+    let ac_compressed = huffman::compress(&ac_values)?;
+
+    let dc_compressed = if dc_values.is_empty() { Vec::new() } else {
+        let mut dc_bytes = u16s_to_little_endian(&dc_values);
+        separate_bytes_fragments(&mut dc_bytes);
+        samples_to_differences(&mut dc_bytes);
+        compress_zlib(&dc_bytes)
+    };
+
+    let rle_uncompressed = super::rle::compress_runs(&rle_bytes);
+    let rle_compressed = if rle_uncompressed.is_empty() { Vec::new() } else { compress_zlib(&rle_uncompressed) };
+
+    let header = StreamHeader {
+        version: VERSION,
+        unknown_uncompressed_size: unknown_bytes.len(),
+        unknown_compressed_size: unknown_compressed.len(),
+        ac_compressed_size: ac_compressed.len(),
+        dc_compressed_size: dc_compressed.len(),
+        rle_compressed_size: rle_compressed.len(),
+        rle_uncompressed_size: rle_uncompressed.len(),
+        rle_raw_size: rle_bytes.len(),
+        ac_uncompressed_count: ac_values.len(),
+        dc_uncompressed_count: dc_values.len(),
+        ac_compression: AcCompression::StaticHuffman,
+    };
+
+    let mut compressed = Vec::with_capacity(uncompressed.len() / 2);
+    header.write(&mut compressed)?;
+    ChannelRule::write_all(&rules, &mut compressed)?;
+
+    compressed.extend_from_slice(&unknown_compressed);
+    compressed.extend_from_slice(&ac_compressed);
+    compressed.extend_from_slice(&dc_compressed);
+    compressed.extend_from_slice(&rle_compressed);
+
+    Ok(compressed)
+}
+
+
+impl StreamHeader {
+    fn read(read: &mut &[u8]) -> Result<Self> {
+        let mut values = [0_u64; HEADER_VALUE_COUNT];
+        u64::read_slice(read, &mut values)?;
+
+        let size = |value: u64| usize::try_from(value).map_err(|_| Error::invalid("dwa section size"));
+
+        Ok(StreamHeader {
+            version: values[0],
+            unknown_uncompressed_size: size(values[1])?,
+            unknown_compressed_size: size(values[2])?,
+            ac_compressed_size: size(values[3])?,
+            dc_compressed_size: size(values[4])?,
+            rle_compressed_size: size(values[5])?,
+            rle_uncompressed_size: size(values[6])?,
+            rle_raw_size: size(values[7])?,
+            ac_uncompressed_count: size(values[8])?,
+            dc_uncompressed_count: size(values[9])?,
+
+            ac_compression: match values[10] {
+                0 => AcCompression::StaticHuffman,
+                1 => AcCompression::Deflate,
+                _ => return Err(Error::invalid("dwa ac compression")),
+            },
+        })
+    }
+
+    fn write(&self, write: &mut impl Write) -> UnitResult {
+        let size = |value: usize| value as u64;
+
+        let values: [u64; HEADER_VALUE_COUNT] = [
+            self.version,
+            size(self.unknown_uncompressed_size),
+            size(self.unknown_compressed_size),
+            size(self.ac_compressed_size),
+            size(self.dc_compressed_size),
+            size(self.rle_compressed_size),
+            size(self.rle_uncompressed_size),
+            size(self.rle_raw_size),
+            size(self.ac_uncompressed_count),
+            size(self.dc_uncompressed_count),
+
+            match self.ac_compression {
+                AcCompression::StaticHuffman => 0,
+                AcCompression::Deflate => 1,
+            },
+        ];
+
+        u64::write_slice(write, &values)
+    }
+}
+
+impl ChannelRule {
+    fn new(suffix: &str, scheme: Scheme, sample_type: SampleType, color_index: Option<usize>, case_insensitive: bool) -> Self {
+        ChannelRule { suffix: Text::new_or_panic(suffix), scheme, sample_type, color_index, case_insensitive }
+    }
+
+    /// The rules assumed for files that do not contain any rules.
+    fn legacy_rules() -> Vec<Self> {
+        use Scheme::*;
+        use SampleType::F16;
+
+        vec![
+            ChannelRule::new("r", LossyDct, F16, Some(0), true),
+            ChannelRule::new("red", LossyDct, F16, Some(0), true),
+            ChannelRule::new("g", LossyDct, F16, Some(1), true),
+            ChannelRule::new("grn", LossyDct, F16, Some(1), true),
+            ChannelRule::new("green", LossyDct, F16, Some(1), true),
+            ChannelRule::new("b", LossyDct, F16, Some(2), true),
+            ChannelRule::new("blu", LossyDct, F16, Some(2), true),
+            ChannelRule::new("blue", LossyDct, F16, Some(2), true),
+            ChannelRule::new("y", LossyDct, F16, None, true),
+            ChannelRule::new("by", LossyDct, F16, None, true),
+            ChannelRule::new("ry", LossyDct, F16, None, true),
+            ChannelRule::new("a", Rle, F16, None, true),
+        ]
+    }
+
+    /// The rules used when writing a file, the same as in the reference implementation.
+    /// Red, green, blue, and luminance channels are compressed lossy, alpha channels using RLE.
+    fn default_rules() -> Vec<Self> {
+        use Scheme::*;
+        use SampleType::*;
+
+        let mut rules = Vec::new();
+
+        for &sample_type in &[F16, F32] {
+            rules.push(ChannelRule::new("R", LossyDct, sample_type, Some(0), false));
+            rules.push(ChannelRule::new("G", LossyDct, sample_type, Some(1), false));
+            rules.push(ChannelRule::new("B", LossyDct, sample_type, Some(2), false));
+            rules.push(ChannelRule::new("Y", LossyDct, sample_type, None, false));
+            rules.push(ChannelRule::new("BY", LossyDct, sample_type, None, false));
+            rules.push(ChannelRule::new("RY", LossyDct, sample_type, None, false));
+        }
+
+        for &sample_type in &[U32, F16, F32] {
+            rules.push(ChannelRule::new("A", Rle, sample_type, None, false));
+        }
+
+        rules
+    }
+
+    /// Read the rules stored in the compressed data, prefixed by their total byte size.
+    fn read_all(read: &mut &[u8]) -> Result<Vec<Self>> {
+        let byte_size = u16::read(read)? as usize;
+        let byte_size = byte_size.checked_sub(u16::BYTE_SIZE)
+            .ok_or(Error::invalid("dwa channel rules size"))?;
+
+        let mut rule_bytes = take_n(read, byte_size)?;
+        let mut rules = Vec::new();
+
+        while !rule_bytes.is_empty() {
+            rules.push(ChannelRule::read(&mut rule_bytes)?);
+        }
+
+        Ok(rules)
+    }
+
+    fn read(read: &mut &[u8]) -> Result<Self> {
+        let suffix_length = read.iter().position(|&byte| byte == 0)
+            .ok_or(Error::invalid("dwa channel rule suffix"))?;
+
+        let suffix = Text::from_slice_unchecked(take_n(read, suffix_length)?);
+        take_n(read, 1)?; // null terminator
+
+        let flags = u8::read(read)?;
+
+        let color_index = match flags >> 4 {
+            0 => None,
+            index @ 1 ..= 3 => Some(index as usize - 1),
+            _ => return Err(Error::invalid("dwa channel rule color index")),
+        };
+
+        let scheme = match (flags >> 2) & 3 {
+            0 => Scheme::Unknown,
+            1 => Scheme::LossyDct,
+            2 => Scheme::Rle,
+            _ => return Err(Error::invalid("dwa channel rule scheme")),
+        };
+
+        let sample_type = match u8::read(read)? {
+            0 => SampleType::U32,
+            1 => SampleType::F16,
+            2 => SampleType::F32,
+            _ => return Err(Error::invalid("dwa channel rule sample type")),
+        };
+
+        Ok(ChannelRule { suffix, scheme, sample_type, color_index, case_insensitive: flags & 1 != 0 })
+    }
+
+    /// Write the rules, prefixed by their total byte size.
+    fn write_all(rules: &[Self], write: &mut impl Write) -> UnitResult {
+        let rules_byte_size: usize = rules.iter().map(|rule| rule.suffix.bytes().len() + 3).sum();
+        u16::try_from(u16::BYTE_SIZE + rules_byte_size)?.write(write)?;
+
+        for rule in rules {
+            rule.write(write)?;
+        }
+
+        Ok(())
+    }
+
+    fn write(&self, write: &mut impl Write) -> UnitResult {
+        u8::write_slice(write, self.suffix.bytes())?;
+        0_u8.write(write)?; // null terminator
+
+        let color_index = self.color_index.map_or(0, |index| index as u8 + 1);
+
+        let scheme = match self.scheme {
+            Scheme::Unknown => 0,
+            Scheme::LossyDct => 1,
+            Scheme::Rle => 2,
+        };
+
+        let flags = color_index << 4 | scheme << 2 | self.case_insensitive as u8;
+        flags.write(write)?;
+
+        let sample_type: u8 = match self.sample_type {
+            SampleType::U32 => 0,
+            SampleType::F16 => 1,
+            SampleType::F32 => 2,
+        };
+
+        sample_type.write(write)
+    }
+
+    fn matches(&self, suffix: &[u8], sample_type: SampleType) -> bool {
+        sample_type == self.sample_type && (
+            if self.case_insensitive { self.suffix.bytes().eq_ignore_ascii_case(suffix) }
+            else { self.suffix.bytes() == suffix }
+        )
+    }
+}
+
+/// Find the compression scheme of each channel, and find the channel groups that contain red, green, and blue.
+/// Uses the part of the channel name after the last dot to classify the channel.
+fn classify_channels(channels: &ChannelList, rectangle: IntegerBounds, rules: &[ChannelRule]) -> (Vec<ChannelData>, Vec<[usize; 3]>) {
+    let mut channel_data: Vec<ChannelData> = channels.list.iter()
+        .map(|channel| ChannelData {
+            scheme: Scheme::Unknown,
+            sample_type: channel.sample_type,
+            quantize_linearly: channel.quantize_linearly,
+            resolution: channel.subsampled_section_resolution(rectangle),
+            line_starts: Vec::new(),
+        })
+        .collect();
+
+    // the sorted map ensures the same group order as the reference implementation
+    let mut color_groups_by_prefix: BTreeMap<&[u8], [Option<usize>; 3]> = BTreeMap::new();
+
+    for (channel_index, channel) in channels.list.iter().enumerate() {
+        let name = channel.name.bytes();
+
+        let (prefix, suffix) = match name.iter().rposition(|&byte| byte == b'.') {
+            Some(dot_index) => (&name[.. dot_index], &name[dot_index + 1 ..]),
+            None => (&name[.. 0], name),
+        };
+
+        let group = color_groups_by_prefix.entry(prefix).or_insert([None; 3]);
+
+        for rule in rules.iter().filter(|rule| rule.matches(suffix, channel.sample_type)) {
+            channel_data[channel_index].scheme = rule.scheme;
+
+            if let Some(color_index) = rule.color_index {
+                group[color_index] = Some(channel_index);
+            }
+        }
+    }
+
+    let color_groups = color_groups_by_prefix.values()
+        .filter_map(|group| match *group {
+            [Some(red), Some(green), Some(blue)] => Some([red, green, blue]),
+            _ => None,
+        })
+        .filter(|group| group.iter().all(|&index| channel_data[index].scheme == Scheme::LossyDct))
+        .collect();
+
+    // compute where the lines of each channel start in the uncompressed block
+    let mut byte_index = 0;
+    for y in rectangle.position.y() .. rectangle.end().y() {
+        for (channel, data) in channels.list.iter().zip(&mut channel_data) {
+            if mod_p(y, usize_to_i32(channel.sampling.y())) != 0 { continue; }
+
+            data.line_starts.push(byte_index);
+            byte_index += data.resolution.x() * data.sample_type.bytes_per_sample();
+        }
+    }
+
+    (channel_data, color_groups)
+}
+
+
+/// Check that all lines of all channels are inside the uncompressed block.
+fn validate_line_starts(channel_data: &[ChannelData], byte_size: usize) -> UnitResult {
+    for channel in channel_data {
+        if let Some(&last_line_start) = channel.line_starts.last() {
+            let line_byte_size = channel.resolution.x() * channel.sample_type.bytes_per_sample();
+            if last_line_start + line_byte_size > byte_size {
+                return Err(Error::invalid("dwa channel size"));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+
+/// One of the channels that are encoded together.
+#[derive(Debug)]
+struct DctComponent<'s> {
+    sample_type: SampleType,
+    line_starts: &'s [usize],
+
+    /// Whether the samples are transformed to a perceptually uniform space before the transform.
+    nonlinear: bool,
+}
+
+/// The number of 8x8 blocks required to cover the resolution.
+fn block_count(resolution: Vec2<usize>) -> Vec2<usize> {
+    Vec2(RoundingMode::Up.divide(resolution.x(), 8), RoundingMode::Up.divide(resolution.y(), 8))
+}
+
+/// Decode either a single channel or a group of three color channels.
+/// The DC values of all components are stored one after another,
+/// while the AC values of the components are stored interleaved per block.
+fn decode_lossy_dct(
+    components: &[DctComponent<'_>], resolution: Vec2<usize>,
+    remaining_ac: &mut &[u16], remaining_dc: &mut &[u16], out: &mut [u8]
+) -> UnitResult
+{
+    let block_count = block_count(resolution);
+    let dc_values = take_n(remaining_dc, block_count.area() * components.len())?;
+
+    // convert the pixels of one row of blocks at a time
+    let mut row_blocks = vec![vec![0_u16; block_count.x() * 64]; components.len()];
+    let mut blocks = vec![[0.0_f32; 64]; components.len()];
+
+    for block_y in 0 .. block_count.y() {
+        for block_x in 0 .. block_count.x() {
+            let block_index = block_y * block_count.x() + block_x;
+            let mut is_constant = true;
+
+            for (component_index, block) in blocks.iter_mut().enumerate() {
+                let mut zig_zag = [0_u16; 64];
+                zig_zag[0] = dc_values[component_index * block_count.area() + block_index];
+
+                let last_non_zero_index = decode_ac_run_lengths(remaining_ac, &mut zig_zag)?;
+
+                if last_non_zero_index == 0 {
+                    block[0] = f16::from_bits(zig_zag[0]).to_f32();
+                    dct::inverse_dc_only(block);
+                }
+                else {
+                    is_constant = false;
+
+                    for (value, &zig_zag_index) in block.iter_mut().zip(dct::ZIG_ZAG_INDICES.iter()) {
+                        *value = f16::from_bits(zig_zag[zig_zag_index]).to_f32();
+                    }
+
+                    dct::inverse_8x8(block, dct::zeroed_rows(last_non_zero_index));
+                }
+            }
+
+            if let [y, cb, cr] = blocks.as_mut_slice() {
+                // a constant block has the same value in each pixel
+                let pixel_count = if is_constant { 1 } else { 64 };
+
+                for index in 0 .. pixel_count {
+                    let (red, green, blue) = dct::ycbcr_to_rgb(y[index], cb[index], cr[index]);
+                    y[index] = red; cb[index] = green; cr[index] = blue;
+                }
+            }
+
+            for (row_block, block) in row_blocks.iter_mut().zip(&blocks) {
+                let target = &mut row_block[block_x * 64 .. (block_x + 1) * 64];
+
+                if is_constant { target.fill(f16::from_f32(block[0]).to_bits()); }
+                else {
+                    for (target, &value) in target.iter_mut().zip(block.iter()) {
+                        *target = f16::from_f32(value).to_bits();
+                    }
+                }
+            }
+        }
+
+        // copy the pixels of the blocks to the lines of the uncompressed block
+        for (component, row_block) in components.iter().zip(&row_blocks) {
+            let block_start_y = block_y * 8;
+            let block_end_y = (block_start_y + 8).min(resolution.y());
+
+            for y in block_start_y .. block_end_y {
+                let line_start = *component.line_starts.get(y).ok_or(Error::invalid("dwa line count"))?;
+                let bytes_per_sample = component.sample_type.bytes_per_sample();
+                let line = &mut out[line_start .. line_start + resolution.x() * bytes_per_sample];
+
+                for (x, sample_bytes) in line.chunks_exact_mut(bytes_per_sample).enumerate() {
+                    let mut bits = row_block[(x / 8) * 64 + (y % 8) * 8 + x % 8];
+                    if component.nonlinear { bits = table::TO_LINEAR[bits as usize]; }
+
+                    match component.sample_type {
+                        SampleType::F16 => sample_bytes.copy_from_slice(&bits.to_le_bytes()),
+                        SampleType::F32 => sample_bytes.copy_from_slice(&f16::from_bits(bits).to_f32().to_le_bytes()),
+                        SampleType::U32 => return Err(Error::invalid("dwa lossy channel sample type")),
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Fill the zig zag ordered block with the run length encoded AC values.
+/// Returns the index of the last non-zero value.
+fn decode_ac_run_lengths(remaining_ac: &mut &[u16], zig_zag: &mut [u16; 64]) -> Result<usize> {
+    let mut last_non_zero_index = 0;
+    let mut index = 1;
+
+    while index < 64 {
+        let value = *take_n(remaining_ac, 1)?.first().expect("take_n bug");
+
+        if value == END_OF_BLOCK { index = 64; }
+        else if value >> 8 == ZERO_RUN_MARKER { index += (value & 0xff) as usize; }
+        else {
+            zig_zag[index] = value;
+            last_non_zero_index = index;
+            index += 1;
+        }
+    }
+
+    Ok(last_non_zero_index)
+}
+
+/// Encode either a single channel or a group of three color channels.
+/// Blocks at the border are filled up by repeating the last pixel of the rows and columns.
+fn encode_lossy_dct(
+    components: &[DctComponent<'_>], resolution: Vec2<usize>, base_error: f32,
+    uncompressed: Bytes<'_>, ac_values: &mut Vec<u16>, dc_values: &mut Vec<u16>
+) -> UnitResult
+{
+    let block_count = block_count(resolution);
+    let mut component_dc_values = vec![Vec::with_capacity(block_count.area()); components.len()];
+    let mut blocks = vec![[0.0_f32; 64]; components.len()];
+
+    for block_y in 0 .. block_count.y() {
+        for block_x in 0 .. block_count.x() {
+            for (component, block) in components.iter().zip(&mut blocks) {
+                let bytes_per_sample = component.sample_type.bytes_per_sample();
+
+                for (index, value) in block.iter_mut().enumerate() {
+                    let x = (block_x * 8 + index % 8).min(resolution.x() - 1);
+                    let y = (block_y * 8 + index / 8).min(resolution.y() - 1);
+
+                    let line_start = *component.line_starts.get(y).ok_or(Error::invalid("dwa line count"))?;
+                    let sample_bytes = &uncompressed[line_start + x * bytes_per_sample ..][.. bytes_per_sample];
+
+                    let sample = match component.sample_type {
+                        SampleType::F16 => f16::from_bits(u16::from_le_bytes([sample_bytes[0], sample_bytes[1]])),
+                        SampleType::F32 => f16::from_f32(f32::from_le_bytes([sample_bytes[0], sample_bytes[1], sample_bytes[2], sample_bytes[3]])),
+                        SampleType::U32 => return Err(Error::invalid("dwa lossy channel sample type")),
+                    };
+
+                    *value = if component.nonlinear { to_nonlinear(sample) } else { sample.to_f32() };
+                }
+            }
+
+            if let [y, cb, cr] = blocks.as_mut_slice() {
+                for index in 0 .. 64 {
+                    let (luma, blue_chroma, red_chroma) = dct::rgb_to_ycbcr(y[index], cb[index], cr[index]);
+                    y[index] = luma; cb[index] = blue_chroma; cr[index] = red_chroma;
+                }
+            }
+
+            for (component_index, block) in blocks.iter_mut().enumerate() {
+                dct::forward_8x8(block);
+
+                let (table, table_min) =
+                    if component_index == 0 { (&QUANTIZATION_TABLE_Y, 10.0) }
+                    else { (&QUANTIZATION_TABLE_CBCR, 17.0) };
+
+                let mut zig_zag = [0_u16; 64];
+                for (index, (&value, &zig_zag_index)) in block.iter().zip(dct::ZIG_ZAG_INDICES.iter()).enumerate() {
+                    let tolerance = base_error * f32::from(table[index]) / table_min;
+                    zig_zag[zig_zag_index] = quantize(value, tolerance);
+                }
+
+                component_dc_values[component_index].push(zig_zag[0]);
+                encode_ac_run_lengths(&zig_zag, ac_values);
+            }
+        }
+    }
+
+    for values in component_dc_values {
+        dc_values.extend_from_slice(&values);
+    }
+
+    Ok(())
+}
+
+/// Append the AC values of the zig zag ordered block, replacing runs of zeroes with a marker.
+fn encode_ac_run_lengths(zig_zag: &[u16; 64], ac_values: &mut Vec<u16>) {
+    let mut index = 1;
+
+    while index < 64 {
+        if zig_zag[index] != 0 {
+            ac_values.push(zig_zag[index]);
+            index += 1;
+            continue;
+        }
+
+        let run_length = zig_zag[index ..].iter().take_while(|&&value| value == 0).count();
+
+        if index + run_length == 64 { ac_values.push(END_OF_BLOCK); }
+        else if run_length == 1 { ac_values.push(0); }
+        else { ac_values.push(ZERO_RUN_MARKER << 8 | run_length as u16); }
+
+        index += run_length;
+    }
+}
+
+/// Find the `f16` within the error tolerance that has the most trailing zero bits,
+/// as these values compress best.
+fn quantize(value: f32, tolerance: f32) -> u16 {
+    let bits = f16::from_f32(value).to_bits();
+    if !f16::from_bits(bits).is_finite() { return bits; }
+    if value.abs() <= tolerance { return 0; }
+
+    let error = |bits: u16| (f16::from_bits(bits).to_f32() - value).abs();
+
+    // the bit patterns of positive and of negative floats are both ordered by magnitude
+    for zero_bits in (1 .. 16).rev() {
+        let rounded_down = bits & !((1_u16 << zero_bits) - 1);
+        let rounded_up = rounded_down.wrapping_add(1 << zero_bits);
+
+        let closest = [rounded_down, rounded_up].iter().copied()
+            .filter(|&candidate| candidate & 0x7fff <= 0x7bff && (candidate ^ bits) & 0x8000 == 0)
+            .filter(|&candidate| error(candidate) <= tolerance)
+            .min_by(|&a, &b| error(a).partial_cmp(&error(b)).expect("finite error"));
+
+        if let Some(closest) = closest {
+            return closest;
+        }
+    }
+
+    bits
+}
+
+/// Transform a linear value to a perceptually more uniform space,
+/// reverting `table::TO_LINEAR`. Infinity and NaN are mapped to zero.
+fn to_nonlinear(value: f16) -> f32 {
+    if !value.is_finite() { return 0.0; }
+
+    let value = value.to_f32();
+    let magnitude = value.abs();
+
+    let nonlinear =
+        if magnitude <= 1.0 { magnitude.powf(1.0 / 2.2) }
+        else { magnitude.ln() / 2.2 + 1.0 };
+
+    nonlinear.copysign(value)
+}
+
+/// Compress zlib data, without any further byte reordering.
+fn compress_zlib(uncompressed: Bytes<'_>) -> ByteVec {
+    miniz_oxide::deflate::compress_to_vec_zlib(uncompressed, 4)
+}
+
+/// Decompress zlib data, without any further byte reordering.
+fn decompress_zlib(compressed: Bytes<'_>, expected_byte_size: usize) -> Result<ByteVec> {
+    let options = zune_inflate::DeflateOptions::default().set_limit(expected_byte_size).set_size_hint(expected_byte_size);
+    let mut decoder = zune_inflate::DeflateDecoder::new_with_options(compressed, options);
+
+    let decompressed = decoder.decode_zlib()
+        .map_err(|_| Error::invalid("zlib-compressed data malformed"))?;
+
+    if decompressed.len() != expected_byte_size {
+        return Err(Error::invalid("decompressed data"));
+    }
+
+    Ok(decompressed)
+}
+
+/// Run length decoding, without any further byte reordering.
+fn decompress_rle(compressed: Bytes<'_>, expected_byte_size: usize) -> Result<ByteVec> {
+    let mut remaining = compressed;
+    let mut decompressed = Vec::with_capacity(expected_byte_size);
+
+    while !remaining.is_empty() && decompressed.len() < expected_byte_size {
+        let count = *take_n(&mut remaining, 1)?.first().expect("take_n bug") as i8 as i32;
+
+        if count < 0 {
+            // take the next '-count' bytes as-is
+            decompressed.extend_from_slice(take_n(&mut remaining, (-count) as usize)?);
+        }
+        else {
+            // repeat the next value 'count + 1' times
+            let value = *take_n(&mut remaining, 1)?.first().expect("take_n bug");
+            decompressed.resize(decompressed.len() + count as usize + 1, value);
+        }
+    }
+
+    if decompressed.len() != expected_byte_size {
+        return Err(Error::invalid("decompressed data"));
+    }
+
+    Ok(decompressed)
+}
+
+fn u16s_from_little_endian(bytes: Bytes<'_>) -> Vec<u16> {
+    bytes.chunks_exact(2).map(|pair| u16::from_le_bytes([pair[0], pair[1]])).collect()
+}
+
+fn u16s_to_little_endian(values: &[u16]) -> ByteVec {
+    values.iter().flat_map(|value| value.to_le_bytes()).collect()
+}
+
+fn take_n<'s, T>(slice: &mut &'s [T], n: usize) -> Result<&'s [T]> {
+    if n <= slice.len() {
+        let (front, back) = slice.split_at(n);
+        *slice = back;
+        Ok(front)
+    }
+    else {
+        Err(Error::invalid("compressed data"))
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::meta::attribute::ChannelDescription;
+
+    fn channel(name: &str, sample_type: SampleType) -> ChannelDescription {
+        ChannelDescription::new(name, sample_type, false)
+    }
+
+    /// Compress and decompress smooth gradients, returning the samples of each channel.
+    fn roundtrip_gradients(channels: &ChannelList, rectangle: IntegerBounds) -> (Vec<Vec<f32>>, Vec<Vec<f32>>) {
+        let mut original = vec![Vec::new(); channels.list.len()];
+        let mut bytes = Vec::new();
+
+        for y in 0 .. rectangle.size.height() {
+            for (channel_index, channel) in channels.list.iter().enumerate() {
+                for x in 0 .. rectangle.size.width() {
+                    let value = (x + 2 * y + channel_index) as f32 / 40.0;
+                    original[channel_index].push(value);
+
+                    match channel.sample_type {
+                        SampleType::F16 => bytes.extend_from_slice(&f16::from_f32(value).to_ne_bytes()),
+                        SampleType::F32 => bytes.extend_from_slice(&value.to_ne_bytes()),
+                        SampleType::U32 => bytes.extend_from_slice(&(value as u32).to_ne_bytes()),
+                    }
+                }
+            }
+        }
+
+        let compressed = compress(channels, &bytes, rectangle, DEFAULT_COMPRESSION_LEVEL).unwrap();
+        assert!(compressed.len() < bytes.len());
+
+        let decompressed = decompress(channels, compressed, rectangle, bytes.len(), true).unwrap();
+        let mut remaining = decompressed.as_slice();
+        let mut decoded = vec![Vec::new(); channels.list.len()];
+
+        for _y in 0 .. rectangle.size.height() {
+            for (channel_index, channel) in channels.list.iter().enumerate() {
+                for _x in 0 .. rectangle.size.width() {
+                    let value = match channel.sample_type {
+                        SampleType::F16 => f16::from_bits(u16::from_ne_bytes([remaining[0], remaining[1]])).to_f32(),
+                        SampleType::F32 => f32::from_ne_bytes([remaining[0], remaining[1], remaining[2], remaining[3]]),
+                        SampleType::U32 => u32::from_ne_bytes([remaining[0], remaining[1], remaining[2], remaining[3]]) as f32,
+                    };
+
+                    decoded[channel_index].push(value);
+                    remaining = &remaining[channel.sample_type.bytes_per_sample() ..];
+                }
+            }
+        }
+
+        (original, decoded)
+    }
+
+    #[test]
+    fn roundtrip_lossless_channels(){
+        let channels = ChannelList::new(smallvec![
+            channel("A", SampleType::F16),
+            channel("Z", SampleType::F32),
+            channel("id", SampleType::U32),
+            channel("layer.A", SampleType::F32),
+        ]);
+
+        let rectangle = IntegerBounds::new(Vec2(-3, 7), Vec2(37, 19));
+        let (original, decoded) = roundtrip_gradients(&channels, rectangle);
+
+        assert_eq!(original[0].iter().map(|&value| f16::from_f32(value).to_f32()).collect::<Vec<f32>>(), decoded[0]);
+        assert_eq!(original[1], decoded[1]);
+        assert_eq!(original[2].iter().map(|&value| value as u32 as f32).collect::<Vec<f32>>(), decoded[2]);
+        assert_eq!(original[3], decoded[3]);
+    }
+
+    #[test]
+    fn roundtrip_lossy_channels(){
+        let channels = ChannelList::new(smallvec![
+            channel("B", SampleType::F16),
+            channel("G", SampleType::F32),
+            channel("R", SampleType::F16),
+            channel("Y", SampleType::F16),
+        ]);
+
+        let rectangle = IntegerBounds::new(Vec2(0, 0), Vec2(35, 32));
+        let (original, decoded) = roundtrip_gradients(&channels, rectangle);
+
+        for (original, decoded) in original.iter().zip(&decoded) {
+            for (&original, &decoded) in original.iter().zip(decoded) {
+                assert!((original - decoded).abs() <= 0.02 * original.abs().max(1.0), "{} != {}", original, decoded);
+            }
+        }
+    }
+
+    #[test]
+    fn quantize_keeps_values_within_tolerance(){
+        for &(value, tolerance) in &[(0.3_f32, 0.001_f32), (-12.5, 0.1), (1000.0, 0.0), (0.0001, 0.001)] {
+            let quantized = f16::from_bits(quantize(value, tolerance)).to_f32();
+            assert!((quantized - value).abs() <= tolerance, "{} != {}", value, quantized);
+        }
+    }
+
+    #[test]
+    fn to_nonlinear_reverts_to_linear(){
+        for &value in &[0.0_f32, 0.18, 1.0, -0.5, 3.0, 100.0] {
+            let nonlinear = f16::from_f32(to_nonlinear(f16::from_f32(value)));
+            let linear = f16::from_bits(table::TO_LINEAR[nonlinear.to_bits() as usize]).to_f32();
+            assert!((linear - value).abs() <= 0.01 * value.abs().max(1.0), "{} != {}", value, linear);
+        }
+    }
+}
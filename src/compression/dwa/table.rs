@@ -0,0 +1,5471 @@
+// Generated with the same algorithm as `dwaLookups.cpp`:
+// https://github.com/AcademySoftwareFoundation/openexr/blob/main/src/lib/OpenEXR/dwaLookups.cpp
+// When Rust support it, we should use `const fn` to compute the array at compile time.
+
+const TABLE_SIZE: usize = 1 << 16;
+
+/// Maps the bits of a non-linear `f16` to the bits of the linear `f16`.
+pub const TO_LINEAR: [u16; TABLE_SIZE] = [
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001,
+    0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001,
+    0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001,
+    0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001,
+    0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001,
+    0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001,
+    0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001,
+    0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001,
+    0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001,
+    0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001,
+    0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001,
+    0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001,
+    0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001,
+    0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001,
+    0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001,
+    0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001,
+    0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001,
+    0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001,
+    0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001,
+    0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001,
+    0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001,
+    0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001,
+    0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001,
+    0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001,
+    0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001,
+    0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001,
+    0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001,
+    0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001,
+    0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001,
+    0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001,
+    0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001,
+    0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001,
+    0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001,
+    0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001,
+    0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001,
+    0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001,
+    0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001,
+    0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001,
+    0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001,
+    0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001,
+    0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001,
+    0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001,
+    0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001,
+    0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001,
+    0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001,
+    0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001,
+    0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001,
+    0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001,
+    0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001,
+    0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001,
+    0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001,
+    0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001,
+    0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001,
+    0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001,
+    0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001,
+    0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001,
+    0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001,
+    0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001,
+    0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001,
+    0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001,
+    0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001,
+    0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001,
+    0x0001, 0x0001, 0x0001, 0x0001, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
+    0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
+    0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
+    0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
+    0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
+    0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
+    0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
+    0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
+    0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
+    0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
+    0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
+    0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
+    0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
+    0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
+    0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
+    0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
+    0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
+    0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
+    0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
+    0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
+    0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
+    0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
+    0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
+    0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
+    0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
+    0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
+    0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
+    0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
+    0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0003,
+    0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003,
+    0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003,
+    0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003,
+    0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003,
+    0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003,
+    0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003,
+    0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003,
+    0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003,
+    0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003,
+    0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003,
+    0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003,
+    0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003,
+    0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003,
+    0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003,
+    0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003,
+    0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003,
+    0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003,
+    0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003,
+    0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003,
+    0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003,
+    0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003,
+    0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003,
+    0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005,
+    0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005,
+    0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005,
+    0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005,
+    0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005,
+    0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005,
+    0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005,
+    0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005,
+    0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005,
+    0x0006, 0x0006, 0x0006, 0x0006, 0x0006, 0x0006, 0x0006, 0x0006, 0x0006, 0x0006, 0x0006, 0x0006,
+    0x0006, 0x0006, 0x0006, 0x0006, 0x0006, 0x0006, 0x0006, 0x0006, 0x0006, 0x0006, 0x0006, 0x0006,
+    0x0006, 0x0006, 0x0006, 0x0006, 0x0006, 0x0006, 0x0006, 0x0006, 0x0006, 0x0006, 0x0006, 0x0006,
+    0x0006, 0x0006, 0x0006, 0x0006, 0x0006, 0x0006, 0x0006, 0x0006, 0x0006, 0x0006, 0x0006, 0x0006,
+    0x0006, 0x0006, 0x0006, 0x0006, 0x0006, 0x0006, 0x0006, 0x0006, 0x0006, 0x0006, 0x0006, 0x0006,
+    0x0006, 0x0006, 0x0006, 0x0006, 0x0006, 0x0006, 0x0006, 0x0006, 0x0006, 0x0006, 0x0006, 0x0006,
+    0x0006, 0x0006, 0x0006, 0x0006, 0x0006, 0x0006, 0x0006, 0x0006, 0x0006, 0x0006, 0x0006, 0x0006,
+    0x0006, 0x0006, 0x0006, 0x0006, 0x0006, 0x0006, 0x0006, 0x0006, 0x0006, 0x0007, 0x0007, 0x0007,
+    0x0007, 0x0007, 0x0007, 0x0007, 0x0007, 0x0007, 0x0007, 0x0007, 0x0007, 0x0007, 0x0007, 0x0007,
+    0x0007, 0x0007, 0x0007, 0x0007, 0x0007, 0x0007, 0x0007, 0x0007, 0x0007, 0x0007, 0x0007, 0x0007,
+    0x0007, 0x0007, 0x0007, 0x0007, 0x0007, 0x0007, 0x0007, 0x0007, 0x0007, 0x0007, 0x0007, 0x0007,
+    0x0007, 0x0007, 0x0007, 0x0007, 0x0007, 0x0007, 0x0007, 0x0007, 0x0007, 0x0007, 0x0007, 0x0007,
+    0x0007, 0x0007, 0x0007, 0x0007, 0x0007, 0x0007, 0x0007, 0x0007, 0x0007, 0x0007, 0x0007, 0x0007,
+    0x0007, 0x0007, 0x0007, 0x0007, 0x0007, 0x0007, 0x0007, 0x0007, 0x0007, 0x0007, 0x0007, 0x0007,
+    0x0007, 0x0007, 0x0007, 0x0007, 0x0007, 0x0007, 0x0007, 0x0007, 0x0007, 0x0007, 0x0007, 0x0008,
+    0x0008, 0x0008, 0x0008, 0x0008, 0x0008, 0x0008, 0x0008, 0x0008, 0x0008, 0x0008, 0x0008, 0x0008,
+    0x0008, 0x0008, 0x0008, 0x0008, 0x0008, 0x0008, 0x0008, 0x0008, 0x0008, 0x0008, 0x0008, 0x0008,
+    0x0008, 0x0008, 0x0008, 0x0008, 0x0008, 0x0008, 0x0008, 0x0008, 0x0008, 0x0008, 0x0008, 0x0008,
+    0x0008, 0x0008, 0x0008, 0x0008, 0x0008, 0x0008, 0x0008, 0x0008, 0x0008, 0x0008, 0x0008, 0x0008,
+    0x0008, 0x0008, 0x0008, 0x0008, 0x0008, 0x0008, 0x0008, 0x0008, 0x0008, 0x0008, 0x0008, 0x0008,
+    0x0008, 0x0008, 0x0008, 0x0008, 0x0008, 0x0008, 0x0008, 0x0008, 0x0008, 0x0008, 0x0008, 0x0008,
+    0x0008, 0x0008, 0x0008, 0x0008, 0x0008, 0x0008, 0x0008, 0x0009, 0x0009, 0x0009, 0x0009, 0x0009,
+    0x0009, 0x0009, 0x0009, 0x0009, 0x0009, 0x0009, 0x0009, 0x0009, 0x0009, 0x0009, 0x0009, 0x0009,
+    0x0009, 0x0009, 0x0009, 0x0009, 0x0009, 0x0009, 0x0009, 0x0009, 0x0009, 0x0009, 0x0009, 0x0009,
+    0x0009, 0x0009, 0x0009, 0x0009, 0x0009, 0x0009, 0x0009, 0x0009, 0x0009, 0x0009, 0x0009, 0x0009,
+    0x0009, 0x0009, 0x0009, 0x0009, 0x0009, 0x0009, 0x0009, 0x0009, 0x0009, 0x0009, 0x0009, 0x0009,
+    0x0009, 0x0009, 0x0009, 0x0009, 0x0009, 0x0009, 0x0009, 0x0009, 0x0009, 0x0009, 0x0009, 0x0009,
+    0x0009, 0x0009, 0x0009, 0x0009, 0x0009, 0x0009, 0x0009, 0x0009, 0x0009, 0x0009, 0x000a, 0x000a,
+    0x000a, 0x000a, 0x000a, 0x000a, 0x000a, 0x000a, 0x000a, 0x000a, 0x000a, 0x000a, 0x000a, 0x000a,
+    0x000a, 0x000a, 0x000a, 0x000a, 0x000a, 0x000a, 0x000a, 0x000a, 0x000a, 0x000a, 0x000a, 0x000a,
+    0x000a, 0x000a, 0x000a, 0x000a, 0x000a, 0x000a, 0x000a, 0x000a, 0x000a, 0x000a, 0x000a, 0x000a,
+    0x000a, 0x000a, 0x000a, 0x000a, 0x000a, 0x000a, 0x000a, 0x000a, 0x000a, 0x000a, 0x000a, 0x000a,
+    0x000a, 0x000a, 0x000a, 0x000a, 0x000a, 0x000a, 0x000a, 0x000a, 0x000a, 0x000a, 0x000a, 0x000a,
+    0x000a, 0x000a, 0x000a, 0x000a, 0x000a, 0x000a, 0x000a, 0x000a, 0x000b, 0x000b, 0x000b, 0x000b,
+    0x000b, 0x000b, 0x000b, 0x000b, 0x000b, 0x000b, 0x000b, 0x000b, 0x000b, 0x000b, 0x000b, 0x000b,
+    0x000b, 0x000b, 0x000b, 0x000b, 0x000b, 0x000b, 0x000b, 0x000b, 0x000b, 0x000b, 0x000b, 0x000b,
+    0x000b, 0x000b, 0x000b, 0x000b, 0x000b, 0x000b, 0x000b, 0x000b, 0x000b, 0x000b, 0x000b, 0x000b,
+    0x000b, 0x000b, 0x000b, 0x000b, 0x000b, 0x000b, 0x000b, 0x000b, 0x000b, 0x000b, 0x000b, 0x000b,
+    0x000b, 0x000b, 0x000b, 0x000b, 0x000b, 0x000b, 0x000b, 0x000b, 0x000b, 0x000b, 0x000b, 0x000b,
+    0x000b, 0x000b, 0x000b, 0x000c, 0x000c, 0x000c, 0x000c, 0x000c, 0x000c, 0x000c, 0x000c, 0x000c,
+    0x000c, 0x000c, 0x000c, 0x000c, 0x000c, 0x000c, 0x000c, 0x000c, 0x000c, 0x000c, 0x000c, 0x000c,
+    0x000c, 0x000c, 0x000c, 0x000c, 0x000c, 0x000c, 0x000c, 0x000c, 0x000c, 0x000c, 0x000c, 0x000c,
+    0x000c, 0x000c, 0x000c, 0x000c, 0x000c, 0x000c, 0x000c, 0x000c, 0x000c, 0x000c, 0x000c, 0x000c,
+    0x000c, 0x000c, 0x000c, 0x000c, 0x000c, 0x000c, 0x000c, 0x000c, 0x000c, 0x000c, 0x000c, 0x000c,
+    0x000c, 0x000c, 0x000c, 0x000c, 0x000c, 0x000c, 0x000c, 0x000d, 0x000d, 0x000d, 0x000d, 0x000d,
+    0x000d, 0x000d, 0x000d, 0x000d, 0x000d, 0x000d, 0x000d, 0x000d, 0x000d, 0x000d, 0x000d, 0x000d,
+    0x000d, 0x000d, 0x000d, 0x000d, 0x000d, 0x000d, 0x000d, 0x000d, 0x000d, 0x000d, 0x000d, 0x000d,
+    0x000d, 0x000d, 0x000d, 0x000d, 0x000d, 0x000d, 0x000d, 0x000d, 0x000d, 0x000d, 0x000d, 0x000d,
+    0x000d, 0x000d, 0x000d, 0x000d, 0x000d, 0x000d, 0x000d, 0x000d, 0x000d, 0x000d, 0x000d, 0x000d,
+    0x000d, 0x000d, 0x000d, 0x000d, 0x000d, 0x000d, 0x000d, 0x000d, 0x000d, 0x000e, 0x000e, 0x000e,
+    0x000e, 0x000e, 0x000e, 0x000e, 0x000e, 0x000e, 0x000e, 0x000e, 0x000e, 0x000e, 0x000e, 0x000e,
+    0x000e, 0x000e, 0x000e, 0x000e, 0x000e, 0x000e, 0x000e, 0x000e, 0x000e, 0x000e, 0x000e, 0x000e,
+    0x000e, 0x000e, 0x000e, 0x000e, 0x000e, 0x000e, 0x000e, 0x000e, 0x000e, 0x000e, 0x000e, 0x000e,
+    0x000e, 0x000e, 0x000e, 0x000e, 0x000e, 0x000e, 0x000e, 0x000e, 0x000e, 0x000e, 0x000e, 0x000e,
+    0x000e, 0x000e, 0x000e, 0x000e, 0x000e, 0x000e, 0x000e, 0x000f, 0x000f, 0x000f, 0x000f, 0x000f,
+    0x000f, 0x000f, 0x000f, 0x000f, 0x000f, 0x000f, 0x000f, 0x000f, 0x000f, 0x000f, 0x000f, 0x000f,
+    0x000f, 0x000f, 0x000f, 0x000f, 0x000f, 0x000f, 0x000f, 0x000f, 0x000f, 0x000f, 0x000f, 0x000f,
+    0x000f, 0x000f, 0x000f, 0x000f, 0x000f, 0x000f, 0x000f, 0x000f, 0x000f, 0x000f, 0x000f, 0x000f,
+    0x000f, 0x000f, 0x000f, 0x000f, 0x000f, 0x000f, 0x000f, 0x000f, 0x000f, 0x000f, 0x000f, 0x000f,
+    0x000f, 0x000f, 0x000f, 0x000f, 0x0010, 0x0010, 0x0010, 0x0010, 0x0010, 0x0010, 0x0010, 0x0010,
+    0x0010, 0x0010, 0x0010, 0x0010, 0x0010, 0x0010, 0x0010, 0x0010, 0x0010, 0x0010, 0x0010, 0x0010,
+    0x0010, 0x0010, 0x0010, 0x0010, 0x0010, 0x0010, 0x0010, 0x0010, 0x0010, 0x0010, 0x0010, 0x0010,
+    0x0010, 0x0010, 0x0010, 0x0010, 0x0010, 0x0010, 0x0010, 0x0010, 0x0010, 0x0010, 0x0010, 0x0010,
+    0x0010, 0x0010, 0x0010, 0x0010, 0x0010, 0x0010, 0x0010, 0x0010, 0x0010, 0x0010, 0x0010, 0x0011,
+    0x0011, 0x0011, 0x0011, 0x0011, 0x0011, 0x0011, 0x0011, 0x0011, 0x0011, 0x0011, 0x0011, 0x0011,
+    0x0011, 0x0011, 0x0011, 0x0011, 0x0011, 0x0011, 0x0011, 0x0011, 0x0011, 0x0011, 0x0011, 0x0011,
+    0x0011, 0x0011, 0x0011, 0x0011, 0x0011, 0x0011, 0x0011, 0x0011, 0x0011, 0x0011, 0x0011, 0x0011,
+    0x0011, 0x0011, 0x0011, 0x0011, 0x0011, 0x0011, 0x0011, 0x0011, 0x0011, 0x0011, 0x0011, 0x0011,
+    0x0011, 0x0011, 0x0011, 0x0012, 0x0012, 0x0012, 0x0012, 0x0012, 0x0012, 0x0012, 0x0012, 0x0012,
+    0x0012, 0x0012, 0x0012, 0x0012, 0x0012, 0x0012, 0x0012, 0x0012, 0x0012, 0x0012, 0x0012, 0x0012,
+    0x0012, 0x0012, 0x0012, 0x0012, 0x0012, 0x0012, 0x0012, 0x0012, 0x0012, 0x0012, 0x0012, 0x0012,
+    0x0012, 0x0012, 0x0012, 0x0012, 0x0012, 0x0012, 0x0012, 0x0012, 0x0012, 0x0012, 0x0012, 0x0012,
+    0x0012, 0x0012, 0x0012, 0x0012, 0x0013, 0x0013, 0x0013, 0x0013, 0x0013, 0x0013, 0x0013, 0x0013,
+    0x0013, 0x0013, 0x0013, 0x0013, 0x0013, 0x0013, 0x0013, 0x0013, 0x0013, 0x0013, 0x0013, 0x0013,
+    0x0013, 0x0013, 0x0013, 0x0013, 0x0014, 0x0014, 0x0014, 0x0014, 0x0014, 0x0014, 0x0014, 0x0014,
+    0x0014, 0x0014, 0x0014, 0x0014, 0x0014, 0x0014, 0x0014, 0x0014, 0x0014, 0x0014, 0x0014, 0x0014,
+    0x0014, 0x0014, 0x0014, 0x0014, 0x0014, 0x0015, 0x0015, 0x0015, 0x0015, 0x0015, 0x0015, 0x0015,
+    0x0015, 0x0015, 0x0015, 0x0015, 0x0015, 0x0015, 0x0015, 0x0015, 0x0015, 0x0015, 0x0015, 0x0015,
+    0x0015, 0x0015, 0x0015, 0x0015, 0x0016, 0x0016, 0x0016, 0x0016, 0x0016, 0x0016, 0x0016, 0x0016,
+    0x0016, 0x0016, 0x0016, 0x0016, 0x0016, 0x0016, 0x0016, 0x0016, 0x0016, 0x0016, 0x0016, 0x0016,
+    0x0016, 0x0016, 0x0016, 0x0017, 0x0017, 0x0017, 0x0017, 0x0017, 0x0017, 0x0017, 0x0017, 0x0017,
+    0x0017, 0x0017, 0x0017, 0x0017, 0x0017, 0x0017, 0x0017, 0x0017, 0x0017, 0x0017, 0x0017, 0x0017,
+    0x0017, 0x0017, 0x0018, 0x0018, 0x0018, 0x0018, 0x0018, 0x0018, 0x0018, 0x0018, 0x0018, 0x0018,
+    0x0018, 0x0018, 0x0018, 0x0018, 0x0018, 0x0018, 0x0018, 0x0018, 0x0018, 0x0018, 0x0018, 0x0019,
+    0x0019, 0x0019, 0x0019, 0x0019, 0x0019, 0x0019, 0x0019, 0x0019, 0x0019, 0x0019, 0x0019, 0x0019,
+    0x0019, 0x0019, 0x0019, 0x0019, 0x0019, 0x0019, 0x0019, 0x0019, 0x0019, 0x001a, 0x001a, 0x001a,
+    0x001a, 0x001a, 0x001a, 0x001a, 0x001a, 0x001a, 0x001a, 0x001a, 0x001a, 0x001a, 0x001a, 0x001a,
+    0x001a, 0x001a, 0x001a, 0x001a, 0x001a, 0x001a, 0x001b, 0x001b, 0x001b, 0x001b, 0x001b, 0x001b,
+    0x001b, 0x001b, 0x001b, 0x001b, 0x001b, 0x001b, 0x001b, 0x001b, 0x001b, 0x001b, 0x001b, 0x001b,
+    0x001b, 0x001b, 0x001c, 0x001c, 0x001c, 0x001c, 0x001c, 0x001c, 0x001c, 0x001c, 0x001c, 0x001c,
+    0x001c, 0x001c, 0x001c, 0x001c, 0x001c, 0x001c, 0x001c, 0x001c, 0x001c, 0x001c, 0x001d, 0x001d,
+    0x001d, 0x001d, 0x001d, 0x001d, 0x001d, 0x001d, 0x001d, 0x001d, 0x001d, 0x001d, 0x001d, 0x001d,
+    0x001d, 0x001d, 0x001d, 0x001d, 0x001d, 0x001d, 0x001e, 0x001e, 0x001e, 0x001e, 0x001e, 0x001e,
+    0x001e, 0x001e, 0x001e, 0x001e, 0x001e, 0x001e, 0x001e, 0x001e, 0x001e, 0x001e, 0x001e, 0x001e,
+    0x001e, 0x001e, 0x001f, 0x001f, 0x001f, 0x001f, 0x001f, 0x001f, 0x001f, 0x001f, 0x001f, 0x001f,
+    0x001f, 0x001f, 0x001f, 0x001f, 0x001f, 0x001f, 0x001f, 0x001f, 0x001f, 0x0020, 0x0020, 0x0020,
+    0x0020, 0x0020, 0x0020, 0x0020, 0x0020, 0x0020, 0x0020, 0x0020, 0x0020, 0x0020, 0x0020, 0x0020,
+    0x0020, 0x0020, 0x0020, 0x0021, 0x0021, 0x0021, 0x0021, 0x0021, 0x0021, 0x0021, 0x0021, 0x0021,
+    0x0021, 0x0021, 0x0021, 0x0021, 0x0021, 0x0021, 0x0021, 0x0021, 0x0021, 0x0021, 0x0022, 0x0022,
+    0x0022, 0x0022, 0x0022, 0x0022, 0x0022, 0x0022, 0x0022, 0x0022, 0x0022, 0x0022, 0x0022, 0x0022,
+    0x0022, 0x0022, 0x0022, 0x0022, 0x0023, 0x0023, 0x0023, 0x0023, 0x0023, 0x0023, 0x0023, 0x0023,
+    0x0023, 0x0023, 0x0023, 0x0023, 0x0023, 0x0023, 0x0023, 0x0023, 0x0023, 0x0023, 0x0024, 0x0024,
+    0x0024, 0x0024, 0x0024, 0x0024, 0x0024, 0x0024, 0x0024, 0x0024, 0x0024, 0x0024, 0x0024, 0x0024,
+    0x0024, 0x0024, 0x0024, 0x0025, 0x0025, 0x0025, 0x0025, 0x0025, 0x0025, 0x0025, 0x0025, 0x0025,
+    0x0025, 0x0025, 0x0025, 0x0025, 0x0025, 0x0025, 0x0025, 0x0025, 0x0025, 0x0026, 0x0026, 0x0026,
+    0x0026, 0x0026, 0x0026, 0x0026, 0x0026, 0x0026, 0x0026, 0x0026, 0x0026, 0x0026, 0x0026, 0x0026,
+    0x0026, 0x0026, 0x0027, 0x0027, 0x0027, 0x0027, 0x0027, 0x0027, 0x0027, 0x0027, 0x0027, 0x0027,
+    0x0027, 0x0027, 0x0027, 0x0027, 0x0027, 0x0027, 0x0028, 0x0028, 0x0028, 0x0028, 0x0028, 0x0028,
+    0x0028, 0x0028, 0x0028, 0x0028, 0x0028, 0x0028, 0x0028, 0x0028, 0x0028, 0x0028, 0x0028, 0x0029,
+    0x0029, 0x0029, 0x0029, 0x0029, 0x0029, 0x0029, 0x0029, 0x0029, 0x0029, 0x0029, 0x0029, 0x0029,
+    0x0029, 0x0029, 0x0029, 0x002a, 0x002a, 0x002a, 0x002a, 0x002a, 0x002a, 0x002a, 0x002a, 0x002a,
+    0x002a, 0x002a, 0x002a, 0x002a, 0x002a, 0x002a, 0x002a, 0x002b, 0x002b, 0x002b, 0x002b, 0x002b,
+    0x002b, 0x002b, 0x002b, 0x002b, 0x002b, 0x002b, 0x002b, 0x002b, 0x002b, 0x002b, 0x002b, 0x002c,
+    0x002c, 0x002c, 0x002c, 0x002c, 0x002c, 0x002c, 0x002c, 0x002c, 0x002c, 0x002c, 0x002c, 0x002c,
+    0x002c, 0x002c, 0x002c, 0x002d, 0x002d, 0x002d, 0x002d, 0x002d, 0x002d, 0x002d, 0x002d, 0x002d,
+    0x002d, 0x002d, 0x002d, 0x002d, 0x002d, 0x002d, 0x002d, 0x002e, 0x002e, 0x002e, 0x002e, 0x002e,
+    0x002e, 0x002e, 0x002e, 0x002e, 0x002e, 0x002e, 0x002e, 0x002e, 0x002e, 0x002e, 0x002f, 0x002f,
+    0x002f, 0x002f, 0x002f, 0x002f, 0x002f, 0x002f, 0x002f, 0x002f, 0x002f, 0x002f, 0x002f, 0x002f,
+    0x002f, 0x0030, 0x0030, 0x0030, 0x0030, 0x0030, 0x0030, 0x0030, 0x0030, 0x0030, 0x0030, 0x0030,
+    0x0030, 0x0030, 0x0030, 0x0030, 0x0031, 0x0031, 0x0031, 0x0031, 0x0031, 0x0031, 0x0031, 0x0031,
+    0x0031, 0x0031, 0x0031, 0x0031, 0x0031, 0x0031, 0x0031, 0x0032, 0x0032, 0x0032, 0x0032, 0x0032,
+    0x0032, 0x0032, 0x0032, 0x0032, 0x0032, 0x0032, 0x0032, 0x0032, 0x0032, 0x0032, 0x0033, 0x0033,
+    0x0033, 0x0033, 0x0033, 0x0033, 0x0033, 0x0033, 0x0033, 0x0033, 0x0033, 0x0033, 0x0033, 0x0033,
+    0x0034, 0x0034, 0x0034, 0x0034, 0x0034, 0x0034, 0x0034, 0x0034, 0x0034, 0x0034, 0x0034, 0x0034,
+    0x0034, 0x0034, 0x0034, 0x0035, 0x0035, 0x0035, 0x0035, 0x0035, 0x0035, 0x0035, 0x0035, 0x0035,
+    0x0035, 0x0035, 0x0035, 0x0035, 0x0035, 0x0036, 0x0036, 0x0036, 0x0036, 0x0036, 0x0036, 0x0036,
+    0x0036, 0x0036, 0x0036, 0x0036, 0x0036, 0x0036, 0x0036, 0x0037, 0x0037, 0x0037, 0x0037, 0x0037,
+    0x0037, 0x0037, 0x0037, 0x0037, 0x0037, 0x0037, 0x0037, 0x0037, 0x0037, 0x0038, 0x0038, 0x0038,
+    0x0038, 0x0038, 0x0038, 0x0038, 0x0038, 0x0038, 0x0038, 0x0038, 0x0038, 0x0038, 0x0038, 0x0039,
+    0x0039, 0x0039, 0x0039, 0x0039, 0x0039, 0x0039, 0x0039, 0x0039, 0x0039, 0x0039, 0x0039, 0x0039,
+    0x003a, 0x003a, 0x003a, 0x003a, 0x003a, 0x003a, 0x003a, 0x003a, 0x003a, 0x003a, 0x003a, 0x003a,
+    0x003a, 0x003a, 0x003b, 0x003b, 0x003b, 0x003b, 0x003b, 0x003b, 0x003b, 0x003b, 0x003b, 0x003b,
+    0x003b, 0x003b, 0x003b, 0x003c, 0x003c, 0x003c, 0x003c, 0x003c, 0x003c, 0x003c, 0x003c, 0x003c,
+    0x003c, 0x003c, 0x003c, 0x003c, 0x003d, 0x003d, 0x003d, 0x003d, 0x003d, 0x003d, 0x003d, 0x003d,
+    0x003d, 0x003d, 0x003d, 0x003d, 0x003d, 0x003d, 0x003e, 0x003e, 0x003e, 0x003e, 0x003e, 0x003e,
+    0x003e, 0x003e, 0x003e, 0x003e, 0x003e, 0x003e, 0x003e, 0x003f, 0x003f, 0x003f, 0x003f, 0x003f,
+    0x003f, 0x003f, 0x003f, 0x003f, 0x003f, 0x003f, 0x003f, 0x003f, 0x0040, 0x0040, 0x0040, 0x0040,
+    0x0040, 0x0040, 0x0040, 0x0040, 0x0040, 0x0040, 0x0040, 0x0040, 0x0041, 0x0041, 0x0041, 0x0041,
+    0x0041, 0x0041, 0x0041, 0x0041, 0x0041, 0x0041, 0x0041, 0x0041, 0x0041, 0x0042, 0x0042, 0x0042,
+    0x0042, 0x0042, 0x0042, 0x0042, 0x0042, 0x0042, 0x0042, 0x0042, 0x0042, 0x0042, 0x0043, 0x0043,
+    0x0043, 0x0043, 0x0043, 0x0043, 0x0043, 0x0043, 0x0043, 0x0043, 0x0043, 0x0043, 0x0044, 0x0044,
+    0x0044, 0x0044, 0x0044, 0x0044, 0x0044, 0x0044, 0x0044, 0x0044, 0x0044, 0x0044, 0x0044, 0x0045,
+    0x0045, 0x0045, 0x0045, 0x0045, 0x0045, 0x0045, 0x0045, 0x0045, 0x0045, 0x0045, 0x0045, 0x0046,
+    0x0046, 0x0046, 0x0046, 0x0046, 0x0046, 0x0046, 0x0046, 0x0046, 0x0046, 0x0046, 0x0046, 0x0047,
+    0x0047, 0x0047, 0x0047, 0x0047, 0x0047, 0x0047, 0x0047, 0x0047, 0x0047, 0x0047, 0x0047, 0x0048,
+    0x0048, 0x0048, 0x0048, 0x0048, 0x0048, 0x0048, 0x0048, 0x0048, 0x0048, 0x0048, 0x0048, 0x0049,
+    0x0049, 0x0049, 0x0049, 0x0049, 0x0049, 0x0049, 0x0049, 0x0049, 0x0049, 0x0049, 0x0049, 0x004a,
+    0x004a, 0x004a, 0x004a, 0x004a, 0x004a, 0x004a, 0x004a, 0x004a, 0x004a, 0x004a, 0x004a, 0x004b,
+    0x004b, 0x004b, 0x004b, 0x004b, 0x004b, 0x004b, 0x004b, 0x004b, 0x004b, 0x004b, 0x004b, 0x004c,
+    0x004c, 0x004c, 0x004c, 0x004c, 0x004c, 0x004c, 0x004c, 0x004c, 0x004c, 0x004c, 0x004c, 0x004d,
+    0x004d, 0x004d, 0x004d, 0x004d, 0x004d, 0x004d, 0x004d, 0x004d, 0x004d, 0x004d, 0x004e, 0x004e,
+    0x004e, 0x004e, 0x004e, 0x004e, 0x004e, 0x004e, 0x004e, 0x004e, 0x004e, 0x004e, 0x004f, 0x004f,
+    0x004f, 0x004f, 0x004f, 0x004f, 0x004f, 0x004f, 0x004f, 0x004f, 0x004f, 0x0050, 0x0050, 0x0050,
+    0x0050, 0x0050, 0x0050, 0x0050, 0x0050, 0x0050, 0x0050, 0x0050, 0x0051, 0x0051, 0x0051, 0x0051,
+    0x0051, 0x0051, 0x0051, 0x0051, 0x0051, 0x0051, 0x0051, 0x0051, 0x0052, 0x0052, 0x0052, 0x0052,
+    0x0052, 0x0052, 0x0052, 0x0052, 0x0052, 0x0052, 0x0052, 0x0053, 0x0053, 0x0053, 0x0053, 0x0053,
+    0x0053, 0x0053, 0x0053, 0x0053, 0x0053, 0x0053, 0x0054, 0x0054, 0x0054, 0x0054, 0x0054, 0x0054,
+    0x0054, 0x0054, 0x0054, 0x0054, 0x0054, 0x0055, 0x0055, 0x0055, 0x0055, 0x0055, 0x0056, 0x0056,
+    0x0056, 0x0056, 0x0056, 0x0056, 0x0057, 0x0057, 0x0057, 0x0057, 0x0057, 0x0058, 0x0058, 0x0058,
+    0x0058, 0x0058, 0x0058, 0x0059, 0x0059, 0x0059, 0x0059, 0x0059, 0x005a, 0x005a, 0x005a, 0x005a,
+    0x005a, 0x005b, 0x005b, 0x005b, 0x005b, 0x005b, 0x005b, 0x005c, 0x005c, 0x005c, 0x005c, 0x005c,
+    0x005d, 0x005d, 0x005d, 0x005d, 0x005d, 0x005e, 0x005e, 0x005e, 0x005e, 0x005e, 0x005f, 0x005f,
+    0x005f, 0x005f, 0x005f, 0x0060, 0x0060, 0x0060, 0x0060, 0x0060, 0x0060, 0x0061, 0x0061, 0x0061,
+    0x0061, 0x0061, 0x0062, 0x0062, 0x0062, 0x0062, 0x0062, 0x0063, 0x0063, 0x0063, 0x0063, 0x0063,
+    0x0064, 0x0064, 0x0064, 0x0064, 0x0064, 0x0065, 0x0065, 0x0065, 0x0065, 0x0065, 0x0066, 0x0066,
+    0x0066, 0x0066, 0x0066, 0x0067, 0x0067, 0x0067, 0x0067, 0x0067, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0069, 0x0069, 0x0069, 0x0069, 0x0069, 0x006a, 0x006a, 0x006a, 0x006a, 0x006b, 0x006b,
+    0x006b, 0x006b, 0x006b, 0x006c, 0x006c, 0x006c, 0x006c, 0x006c, 0x006d, 0x006d, 0x006d, 0x006d,
+    0x006d, 0x006e, 0x006e, 0x006e, 0x006e, 0x006e, 0x006f, 0x006f, 0x006f, 0x006f, 0x0070, 0x0070,
+    0x0070, 0x0070, 0x0070, 0x0071, 0x0071, 0x0071, 0x0071, 0x0071, 0x0072, 0x0072, 0x0072, 0x0072,
+    0x0073, 0x0073, 0x0073, 0x0073, 0x0073, 0x0074, 0x0074, 0x0074, 0x0074, 0x0074, 0x0075, 0x0075,
+    0x0075, 0x0075, 0x0076, 0x0076, 0x0076, 0x0076, 0x0076, 0x0077, 0x0077, 0x0077, 0x0077, 0x0077,
+    0x0078, 0x0078, 0x0078, 0x0078, 0x0079, 0x0079, 0x0079, 0x0079, 0x0079, 0x007a, 0x007a, 0x007a,
+    0x007a, 0x007b, 0x007b, 0x007b, 0x007b, 0x007b, 0x007c, 0x007c, 0x007c, 0x007c, 0x007d, 0x007d,
+    0x007d, 0x007d, 0x007d, 0x007e, 0x007e, 0x007e, 0x007e, 0x007f, 0x007f, 0x007f, 0x007f, 0x0080,
+    0x0080, 0x0080, 0x0080, 0x0080, 0x0081, 0x0081, 0x0081, 0x0081, 0x0082, 0x0082, 0x0082, 0x0082,
+    0x0082, 0x0083, 0x0083, 0x0083, 0x0083, 0x0084, 0x0084, 0x0084, 0x0084, 0x0085, 0x0085, 0x0085,
+    0x0085, 0x0086, 0x0086, 0x0086, 0x0086, 0x0086, 0x0087, 0x0087, 0x0087, 0x0087, 0x0088, 0x0088,
+    0x0088, 0x0088, 0x0089, 0x0089, 0x0089, 0x0089, 0x0089, 0x008a, 0x008a, 0x008a, 0x008a, 0x008b,
+    0x008b, 0x008b, 0x008b, 0x008c, 0x008c, 0x008c, 0x008c, 0x008d, 0x008d, 0x008d, 0x008d, 0x008e,
+    0x008e, 0x008e, 0x008e, 0x008f, 0x008f, 0x008f, 0x008f, 0x008f, 0x0090, 0x0090, 0x0090, 0x0090,
+    0x0091, 0x0091, 0x0091, 0x0091, 0x0092, 0x0092, 0x0092, 0x0092, 0x0093, 0x0093, 0x0093, 0x0093,
+    0x0094, 0x0094, 0x0094, 0x0094, 0x0095, 0x0095, 0x0095, 0x0095, 0x0096, 0x0096, 0x0096, 0x0096,
+    0x0097, 0x0097, 0x0097, 0x0097, 0x0098, 0x0098, 0x0098, 0x0098, 0x0099, 0x0099, 0x0099, 0x0099,
+    0x009a, 0x009a, 0x009a, 0x009a, 0x009b, 0x009b, 0x009b, 0x009b, 0x009c, 0x009c, 0x009c, 0x009c,
+    0x009d, 0x009d, 0x009d, 0x009d, 0x009e, 0x009e, 0x009e, 0x009e, 0x009f, 0x009f, 0x009f, 0x009f,
+    0x00a0, 0x00a0, 0x00a0, 0x00a0, 0x00a1, 0x00a1, 0x00a1, 0x00a2, 0x00a2, 0x00a2, 0x00a2, 0x00a3,
+    0x00a3, 0x00a3, 0x00a3, 0x00a4, 0x00a4, 0x00a4, 0x00a4, 0x00a5, 0x00a5, 0x00a5, 0x00a5, 0x00a6,
+    0x00a6, 0x00a6, 0x00a6, 0x00a7, 0x00a7, 0x00a7, 0x00a8, 0x00a8, 0x00a8, 0x00a8, 0x00a9, 0x00a9,
+    0x00a9, 0x00a9, 0x00aa, 0x00aa, 0x00aa, 0x00aa, 0x00ab, 0x00ab, 0x00ab, 0x00ab, 0x00ac, 0x00ac,
+    0x00ac, 0x00ad, 0x00ad, 0x00ad, 0x00ad, 0x00ae, 0x00ae, 0x00ae, 0x00ae, 0x00af, 0x00af, 0x00af,
+    0x00b0, 0x00b0, 0x00b0, 0x00b0, 0x00b1, 0x00b1, 0x00b1, 0x00b1, 0x00b2, 0x00b2, 0x00b2, 0x00b3,
+    0x00b3, 0x00b3, 0x00b3, 0x00b4, 0x00b4, 0x00b4, 0x00b4, 0x00b5, 0x00b5, 0x00b5, 0x00b6, 0x00b6,
+    0x00b6, 0x00b6, 0x00b7, 0x00b7, 0x00b7, 0x00b7, 0x00b8, 0x00b8, 0x00b8, 0x00b9, 0x00b9, 0x00b9,
+    0x00b9, 0x00ba, 0x00ba, 0x00ba, 0x00bb, 0x00bb, 0x00bb, 0x00bb, 0x00bc, 0x00bc, 0x00bc, 0x00bc,
+    0x00bd, 0x00bd, 0x00bd, 0x00be, 0x00be, 0x00be, 0x00be, 0x00bf, 0x00bf, 0x00bf, 0x00c0, 0x00c0,
+    0x00c0, 0x00c0, 0x00c1, 0x00c1, 0x00c1, 0x00c2, 0x00c2, 0x00c2, 0x00c2, 0x00c3, 0x00c3, 0x00c3,
+    0x00c4, 0x00c4, 0x00c4, 0x00c4, 0x00c5, 0x00c5, 0x00c5, 0x00c6, 0x00c6, 0x00c6, 0x00c6, 0x00c7,
+    0x00c7, 0x00c7, 0x00c8, 0x00c8, 0x00c8, 0x00c8, 0x00c9, 0x00c9, 0x00c9, 0x00ca, 0x00ca, 0x00ca,
+    0x00cb, 0x00cb, 0x00cb, 0x00cb, 0x00cc, 0x00cc, 0x00cc, 0x00cd, 0x00cd, 0x00cd, 0x00cd, 0x00ce,
+    0x00ce, 0x00ce, 0x00cf, 0x00cf, 0x00cf, 0x00d0, 0x00d0, 0x00d0, 0x00d0, 0x00d1, 0x00d1, 0x00d1,
+    0x00d2, 0x00d2, 0x00d2, 0x00d3, 0x00d3, 0x00d3, 0x00d3, 0x00d4, 0x00d4, 0x00d4, 0x00d5, 0x00d5,
+    0x00d5, 0x00d6, 0x00d6, 0x00d6, 0x00d6, 0x00d7, 0x00d7, 0x00d7, 0x00d8, 0x00d8, 0x00d8, 0x00d9,
+    0x00d9, 0x00d9, 0x00d9, 0x00da, 0x00da, 0x00da, 0x00db, 0x00db, 0x00db, 0x00dc, 0x00dc, 0x00dc,
+    0x00dc, 0x00dd, 0x00dd, 0x00dd, 0x00de, 0x00de, 0x00de, 0x00df, 0x00df, 0x00df, 0x00e0, 0x00e0,
+    0x00e0, 0x00e0, 0x00e1, 0x00e1, 0x00e1, 0x00e2, 0x00e2, 0x00e2, 0x00e3, 0x00e3, 0x00e3, 0x00e4,
+    0x00e4, 0x00e4, 0x00e5, 0x00e5, 0x00e5, 0x00e5, 0x00e6, 0x00e6, 0x00e6, 0x00e7, 0x00e7, 0x00e7,
+    0x00e8, 0x00e8, 0x00e8, 0x00e9, 0x00e9, 0x00e9, 0x00ea, 0x00ea, 0x00ea, 0x00eb, 0x00eb, 0x00eb,
+    0x00eb, 0x00ec, 0x00ec, 0x00ec, 0x00ed, 0x00ed, 0x00ed, 0x00ee, 0x00ee, 0x00ee, 0x00ef, 0x00ef,
+    0x00ef, 0x00f0, 0x00f0, 0x00f0, 0x00f1, 0x00f1, 0x00f1, 0x00f2, 0x00f2, 0x00f2, 0x00f2, 0x00f3,
+    0x00f3, 0x00f3, 0x00f4, 0x00f4, 0x00f4, 0x00f5, 0x00f5, 0x00f5, 0x00f6, 0x00f6, 0x00f6, 0x00f7,
+    0x00f7, 0x00f7, 0x00f8, 0x00f8, 0x00f8, 0x00f9, 0x00f9, 0x00f9, 0x00fa, 0x00fa, 0x00fa, 0x00fb,
+    0x00fb, 0x00fb, 0x00fc, 0x00fc, 0x00fc, 0x00fd, 0x00fd, 0x00fd, 0x00fe, 0x00fe, 0x00fe, 0x00ff,
+    0x00ff, 0x00ff, 0x0100, 0x0100, 0x0100, 0x0101, 0x0101, 0x0101, 0x0102, 0x0102, 0x0102, 0x0103,
+    0x0103, 0x0103, 0x0104, 0x0104, 0x0104, 0x0105, 0x0105, 0x0105, 0x0106, 0x0106, 0x0106, 0x0107,
+    0x0107, 0x0107, 0x0108, 0x0108, 0x0108, 0x0109, 0x0109, 0x0109, 0x010a, 0x010a, 0x010a, 0x010b,
+    0x010b, 0x010b, 0x010c, 0x010c, 0x010c, 0x010d, 0x010d, 0x010d, 0x010e, 0x010e, 0x010e, 0x010f,
+    0x010f, 0x010f, 0x0110, 0x0110, 0x0110, 0x0111, 0x0111, 0x0112, 0x0112, 0x0112, 0x0113, 0x0113,
+    0x0113, 0x0114, 0x0114, 0x0114, 0x0115, 0x0115, 0x0115, 0x0116, 0x0116, 0x0116, 0x0117, 0x0117,
+    0x0117, 0x0118, 0x0118, 0x0118, 0x0119, 0x0119, 0x0119, 0x011a, 0x011a, 0x011b, 0x011b, 0x011b,
+    0x011c, 0x011c, 0x011c, 0x011d, 0x011d, 0x011d, 0x011e, 0x011e, 0x011e, 0x011f, 0x011f, 0x011f,
+    0x0120, 0x0120, 0x0121, 0x0121, 0x0121, 0x0122, 0x0122, 0x0122, 0x0123, 0x0123, 0x0123, 0x0124,
+    0x0124, 0x0124, 0x0125, 0x0125, 0x0126, 0x0126, 0x0126, 0x0127, 0x0127, 0x0127, 0x0128, 0x0128,
+    0x0128, 0x0129, 0x0129, 0x0129, 0x012a, 0x012a, 0x012b, 0x012b, 0x012b, 0x012c, 0x012c, 0x012c,
+    0x012d, 0x012d, 0x012d, 0x012e, 0x012e, 0x012f, 0x012f, 0x012f, 0x0130, 0x0130, 0x0130, 0x0131,
+    0x0131, 0x0131, 0x0132, 0x0132, 0x0133, 0x0133, 0x0133, 0x0134, 0x0134, 0x0134, 0x0135, 0x0135,
+    0x0136, 0x0136, 0x0136, 0x0137, 0x0137, 0x0137, 0x0138, 0x0138, 0x0138, 0x0139, 0x0139, 0x013a,
+    0x013a, 0x013a, 0x013b, 0x013b, 0x013b, 0x013c, 0x013c, 0x013d, 0x013d, 0x013d, 0x013e, 0x013e,
+    0x013e, 0x013f, 0x013f, 0x0140, 0x0140, 0x0140, 0x0141, 0x0141, 0x0141, 0x0142, 0x0142, 0x0143,
+    0x0143, 0x0143, 0x0144, 0x0144, 0x0144, 0x0145, 0x0145, 0x0146, 0x0146, 0x0146, 0x0147, 0x0147,
+    0x0147, 0x0148, 0x0148, 0x0149, 0x0149, 0x0149, 0x014a, 0x014a, 0x014b, 0x014b, 0x014b, 0x014c,
+    0x014c, 0x014c, 0x014d, 0x014d, 0x014e, 0x014e, 0x014e, 0x014f, 0x014f, 0x0150, 0x0150, 0x0150,
+    0x0151, 0x0151, 0x0151, 0x0152, 0x0152, 0x0153, 0x0153, 0x0153, 0x0154, 0x0154, 0x0155, 0x0155,
+    0x0155, 0x0156, 0x0156, 0x0156, 0x0157, 0x0157, 0x0158, 0x0158, 0x0158, 0x0159, 0x0159, 0x015a,
+    0x015a, 0x015a, 0x015b, 0x015b, 0x015c, 0x015c, 0x015c, 0x015d, 0x015d, 0x015e, 0x015e, 0x015e,
+    0x015f, 0x015f, 0x015f, 0x0160, 0x0160, 0x0161, 0x0161, 0x0161, 0x0162, 0x0162, 0x0163, 0x0163,
+    0x0163, 0x0164, 0x0164, 0x0165, 0x0165, 0x0165, 0x0166, 0x0166, 0x0167, 0x0167, 0x0167, 0x0168,
+    0x0168, 0x0169, 0x0169, 0x0169, 0x016a, 0x016a, 0x016b, 0x016b, 0x016b, 0x016c, 0x016c, 0x016d,
+    0x016d, 0x016d, 0x016e, 0x016e, 0x016f, 0x016f, 0x016f, 0x0170, 0x0170, 0x0171, 0x0171, 0x0172,
+    0x0172, 0x0172, 0x0173, 0x0173, 0x0174, 0x0174, 0x0174, 0x0175, 0x0175, 0x0176, 0x0176, 0x0176,
+    0x0177, 0x0177, 0x0178, 0x0178, 0x0178, 0x0179, 0x0179, 0x017a, 0x017a, 0x017b, 0x017b, 0x017b,
+    0x017c, 0x017c, 0x017d, 0x017d, 0x017d, 0x017e, 0x017e, 0x017f, 0x017f, 0x017f, 0x0180, 0x0180,
+    0x0181, 0x0181, 0x0182, 0x0182, 0x0182, 0x0183, 0x0183, 0x0184, 0x0184, 0x0185, 0x0186, 0x0187,
+    0x0187, 0x0188, 0x0189, 0x018a, 0x018b, 0x018c, 0x018c, 0x018d, 0x018e, 0x018f, 0x0190, 0x0191,
+    0x0191, 0x0192, 0x0193, 0x0194, 0x0195, 0x0196, 0x0197, 0x0197, 0x0198, 0x0199, 0x019a, 0x019b,
+    0x019c, 0x019d, 0x019d, 0x019e, 0x019f, 0x01a0, 0x01a1, 0x01a2, 0x01a3, 0x01a4, 0x01a4, 0x01a5,
+    0x01a6, 0x01a7, 0x01a8, 0x01a9, 0x01aa, 0x01ab, 0x01ab, 0x01ac, 0x01ad, 0x01ae, 0x01af, 0x01b0,
+    0x01b1, 0x01b2, 0x01b2, 0x01b3, 0x01b4, 0x01b5, 0x01b6, 0x01b7, 0x01b8, 0x01b9, 0x01ba, 0x01ba,
+    0x01bb, 0x01bc, 0x01bd, 0x01be, 0x01bf, 0x01c0, 0x01c1, 0x01c2, 0x01c3, 0x01c3, 0x01c4, 0x01c5,
+    0x01c6, 0x01c7, 0x01c8, 0x01c9, 0x01ca, 0x01cb, 0x01cc, 0x01cd, 0x01ce, 0x01ce, 0x01cf, 0x01d0,
+    0x01d1, 0x01d2, 0x01d3, 0x01d4, 0x01d5, 0x01d6, 0x01d7, 0x01d8, 0x01d9, 0x01da, 0x01da, 0x01db,
+    0x01dc, 0x01dd, 0x01de, 0x01df, 0x01e0, 0x01e1, 0x01e2, 0x01e3, 0x01e4, 0x01e5, 0x01e6, 0x01e7,
+    0x01e8, 0x01e9, 0x01e9, 0x01ea, 0x01eb, 0x01ec, 0x01ed, 0x01ee, 0x01ef, 0x01f0, 0x01f1, 0x01f2,
+    0x01f3, 0x01f4, 0x01f5, 0x01f6, 0x01f7, 0x01f8, 0x01f9, 0x01fa, 0x01fb, 0x01fc, 0x01fd, 0x01fe,
+    0x01ff, 0x01ff, 0x0200, 0x0201, 0x0202, 0x0203, 0x0204, 0x0205, 0x0206, 0x0207, 0x0208, 0x0209,
+    0x020a, 0x020b, 0x020c, 0x020d, 0x020e, 0x020f, 0x0210, 0x0211, 0x0212, 0x0213, 0x0214, 0x0215,
+    0x0216, 0x0217, 0x0218, 0x0219, 0x021a, 0x021b, 0x021c, 0x021d, 0x021e, 0x021f, 0x0220, 0x0221,
+    0x0222, 0x0223, 0x0224, 0x0225, 0x0226, 0x0227, 0x0228, 0x0229, 0x022a, 0x022b, 0x022c, 0x022d,
+    0x022e, 0x022f, 0x0230, 0x0231, 0x0232, 0x0233, 0x0234, 0x0235, 0x0236, 0x0237, 0x0238, 0x0239,
+    0x023a, 0x023b, 0x023c, 0x023e, 0x023f, 0x0240, 0x0241, 0x0242, 0x0243, 0x0244, 0x0245, 0x0246,
+    0x0247, 0x0248, 0x0249, 0x024a, 0x024b, 0x024c, 0x024d, 0x024e, 0x024f, 0x0250, 0x0251, 0x0252,
+    0x0253, 0x0254, 0x0255, 0x0257, 0x0258, 0x0259, 0x025a, 0x025b, 0x025c, 0x025d, 0x025e, 0x025f,
+    0x0260, 0x0261, 0x0262, 0x0263, 0x0264, 0x0265, 0x0267, 0x0268, 0x0269, 0x026a, 0x026b, 0x026c,
+    0x026d, 0x026e, 0x026f, 0x0270, 0x0271, 0x0272, 0x0273, 0x0275, 0x0276, 0x0277, 0x0278, 0x0279,
+    0x027a, 0x027b, 0x027c, 0x027d, 0x027e, 0x027f, 0x0281, 0x0282, 0x0283, 0x0284, 0x0285, 0x0286,
+    0x0287, 0x0288, 0x0289, 0x028a, 0x028c, 0x028d, 0x028e, 0x028f, 0x0290, 0x0291, 0x0292, 0x0293,
+    0x0294, 0x0296, 0x0297, 0x0298, 0x0299, 0x029a, 0x029b, 0x029c, 0x029d, 0x029e, 0x02a0, 0x02a1,
+    0x02a2, 0x02a3, 0x02a4, 0x02a5, 0x02a6, 0x02a7, 0x02a9, 0x02aa, 0x02ab, 0x02ac, 0x02ad, 0x02ae,
+    0x02af, 0x02b1, 0x02b2, 0x02b3, 0x02b4, 0x02b5, 0x02b6, 0x02b7, 0x02b9, 0x02ba, 0x02bb, 0x02bc,
+    0x02bd, 0x02be, 0x02bf, 0x02c1, 0x02c2, 0x02c3, 0x02c4, 0x02c5, 0x02c6, 0x02c8, 0x02c9, 0x02ca,
+    0x02cb, 0x02cc, 0x02cd, 0x02cf, 0x02d0, 0x02d1, 0x02d2, 0x02d3, 0x02d4, 0x02d6, 0x02d7, 0x02d8,
+    0x02d9, 0x02da, 0x02db, 0x02dd, 0x02de, 0x02df, 0x02e0, 0x02e1, 0x02e3, 0x02e4, 0x02e5, 0x02e6,
+    0x02e7, 0x02e8, 0x02ea, 0x02eb, 0x02ec, 0x02ed, 0x02ee, 0x02f0, 0x02f1, 0x02f2, 0x02f3, 0x02f4,
+    0x02f6, 0x02f7, 0x02f8, 0x02f9, 0x02fa, 0x02fc, 0x02fd, 0x02fe, 0x02ff, 0x0300, 0x0302, 0x0303,
+    0x0304, 0x0305, 0x0307, 0x0308, 0x0309, 0x030a, 0x030b, 0x030d, 0x030e, 0x030f, 0x0310, 0x0312,
+    0x0313, 0x0314, 0x0315, 0x0316, 0x0318, 0x0319, 0x031a, 0x031b, 0x031d, 0x031e, 0x031f, 0x0320,
+    0x0322, 0x0323, 0x0324, 0x0325, 0x0326, 0x0328, 0x0329, 0x032a, 0x032b, 0x032d, 0x032e, 0x032f,
+    0x0330, 0x0332, 0x0333, 0x0334, 0x0335, 0x0337, 0x0338, 0x0339, 0x033b, 0x033c, 0x033d, 0x033e,
+    0x0340, 0x0341, 0x0342, 0x0343, 0x0345, 0x0346, 0x0347, 0x0348, 0x034a, 0x034b, 0x034c, 0x034e,
+    0x034f, 0x0350, 0x0351, 0x0353, 0x0354, 0x0355, 0x0356, 0x0358, 0x0359, 0x035a, 0x035c, 0x035d,
+    0x035e, 0x035f, 0x0361, 0x0362, 0x0363, 0x0365, 0x0366, 0x0367, 0x0369, 0x036a, 0x036b, 0x036c,
+    0x036e, 0x036f, 0x0370, 0x0372, 0x0373, 0x0374, 0x0376, 0x0377, 0x0378, 0x0379, 0x037b, 0x037c,
+    0x037d, 0x037f, 0x0380, 0x0381, 0x0383, 0x0384, 0x0385, 0x0387, 0x0388, 0x0389, 0x038b, 0x038c,
+    0x038d, 0x038f, 0x0390, 0x0391, 0x0393, 0x0394, 0x0395, 0x0397, 0x0398, 0x0399, 0x039b, 0x039c,
+    0x039d, 0x039f, 0x03a0, 0x03a1, 0x03a3, 0x03a4, 0x03a5, 0x03a7, 0x03a8, 0x03a9, 0x03ab, 0x03ac,
+    0x03ad, 0x03af, 0x03b0, 0x03b1, 0x03b3, 0x03b4, 0x03b6, 0x03b7, 0x03b8, 0x03ba, 0x03bb, 0x03bc,
+    0x03be, 0x03bf, 0x03c0, 0x03c2, 0x03c3, 0x03c5, 0x03c6, 0x03c7, 0x03c9, 0x03ca, 0x03cb, 0x03cd,
+    0x03ce, 0x03d0, 0x03d1, 0x03d2, 0x03d4, 0x03d5, 0x03d6, 0x03d8, 0x03d9, 0x03db, 0x03dc, 0x03dd,
+    0x03df, 0x03e0, 0x03e2, 0x03e3, 0x03e4, 0x03e6, 0x03e7, 0x03e8, 0x03ea, 0x03eb, 0x03ed, 0x03ee,
+    0x03ef, 0x03f1, 0x03f2, 0x03f4, 0x03f5, 0x03f7, 0x03f8, 0x03f9, 0x03fb, 0x03fc, 0x03fe, 0x03ff,
+    0x0400, 0x0402, 0x0403, 0x0405, 0x0406, 0x0407, 0x0409, 0x040a, 0x040c, 0x040d, 0x040f, 0x0410,
+    0x0411, 0x0413, 0x0414, 0x0416, 0x0417, 0x0419, 0x041a, 0x041b, 0x041d, 0x041e, 0x0420, 0x0421,
+    0x0423, 0x0424, 0x0426, 0x0427, 0x0428, 0x042a, 0x042b, 0x042d, 0x042e, 0x0430, 0x0431, 0x0433,
+    0x0434, 0x0436, 0x0437, 0x0438, 0x043a, 0x043b, 0x043d, 0x043e, 0x0440, 0x0441, 0x0443, 0x0444,
+    0x0446, 0x0447, 0x0449, 0x044a, 0x044b, 0x044d, 0x044e, 0x0450, 0x0451, 0x0453, 0x0454, 0x0456,
+    0x0457, 0x0459, 0x045a, 0x045c, 0x045d, 0x045f, 0x0460, 0x0462, 0x0463, 0x0465, 0x0466, 0x0468,
+    0x0469, 0x046b, 0x046c, 0x046e, 0x046f, 0x0471, 0x0472, 0x0474, 0x0475, 0x0477, 0x0478, 0x047a,
+    0x047b, 0x047d, 0x047e, 0x0480, 0x0481, 0x0483, 0x0484, 0x0486, 0x0487, 0x0489, 0x048a, 0x048c,
+    0x048d, 0x048f, 0x0490, 0x0492, 0x0493, 0x0495, 0x0496, 0x0498, 0x0499, 0x049b, 0x049c, 0x049e,
+    0x04a0, 0x04a1, 0x04a3, 0x04a4, 0x04a6, 0x04a7, 0x04a9, 0x04aa, 0x04ac, 0x04ad, 0x04af, 0x04b0,
+    0x04b2, 0x04b4, 0x04b5, 0x04b7, 0x04b8, 0x04ba, 0x04bb, 0x04bd, 0x04be, 0x04c0, 0x04c2, 0x04c3,
+    0x04c5, 0x04c6, 0x04c8, 0x04c9, 0x04cb, 0x04cc, 0x04ce, 0x04d0, 0x04d1, 0x04d3, 0x04d4, 0x04d6,
+    0x04d7, 0x04d9, 0x04db, 0x04dc, 0x04de, 0x04df, 0x04e1, 0x04e2, 0x04e4, 0x04e6, 0x04e7, 0x04e9,
+    0x04ea, 0x04ec, 0x04ed, 0x04ef, 0x04f1, 0x04f2, 0x04f4, 0x04f5, 0x04f7, 0x04f9, 0x04fa, 0x04fc,
+    0x04fd, 0x04ff, 0x0501, 0x0502, 0x0504, 0x0505, 0x0507, 0x0509, 0x050a, 0x050c, 0x050d, 0x050f,
+    0x0511, 0x0512, 0x0514, 0x0515, 0x0517, 0x0519, 0x051a, 0x051c, 0x051e, 0x051f, 0x0521, 0x0522,
+    0x0524, 0x0526, 0x0527, 0x0529, 0x052b, 0x052c, 0x052e, 0x052f, 0x0531, 0x0533, 0x0534, 0x0536,
+    0x0538, 0x0539, 0x053b, 0x053c, 0x053e, 0x0540, 0x0541, 0x0543, 0x0545, 0x0546, 0x0548, 0x054a,
+    0x054b, 0x054d, 0x054f, 0x0550, 0x0552, 0x0554, 0x0555, 0x0557, 0x0559, 0x055a, 0x055c, 0x055e,
+    0x055f, 0x0561, 0x0562, 0x0564, 0x0566, 0x0567, 0x0569, 0x056b, 0x056c, 0x056e, 0x0570, 0x0572,
+    0x0573, 0x0575, 0x0577, 0x0578, 0x057a, 0x057c, 0x057d, 0x057f, 0x0581, 0x0582, 0x0584, 0x0586,
+    0x0587, 0x0589, 0x058b, 0x058c, 0x058e, 0x0590, 0x0592, 0x0593, 0x0595, 0x0597, 0x0598, 0x059a,
+    0x059c, 0x059d, 0x059f, 0x05a1, 0x05a3, 0x05a4, 0x05a6, 0x05a8, 0x05a9, 0x05ab, 0x05ad, 0x05af,
+    0x05b0, 0x05b2, 0x05b4, 0x05b5, 0x05b7, 0x05b9, 0x05bb, 0x05bc, 0x05be, 0x05c0, 0x05c1, 0x05c3,
+    0x05c5, 0x05c7, 0x05c8, 0x05ca, 0x05cc, 0x05ce, 0x05cf, 0x05d1, 0x05d3, 0x05d4, 0x05d6, 0x05d8,
+    0x05da, 0x05db, 0x05dd, 0x05df, 0x05e1, 0x05e2, 0x05e4, 0x05e6, 0x05e8, 0x05e9, 0x05eb, 0x05ed,
+    0x05ef, 0x05f0, 0x05f2, 0x05f4, 0x05f6, 0x05f7, 0x05f9, 0x05fb, 0x05fd, 0x05ff, 0x0600, 0x0602,
+    0x0604, 0x0606, 0x0607, 0x0609, 0x060b, 0x060d, 0x060e, 0x0610, 0x0612, 0x0614, 0x0616, 0x0617,
+    0x0619, 0x061b, 0x061d, 0x061e, 0x0620, 0x0622, 0x0624, 0x0626, 0x0627, 0x0629, 0x062b, 0x062d,
+    0x062f, 0x0630, 0x0632, 0x0634, 0x0636, 0x0638, 0x0639, 0x063b, 0x063d, 0x063f, 0x0641, 0x0642,
+    0x0644, 0x0646, 0x0648, 0x064a, 0x064b, 0x064d, 0x064f, 0x0651, 0x0653, 0x0654, 0x0656, 0x0658,
+    0x065a, 0x065c, 0x065e, 0x065f, 0x0661, 0x0663, 0x0665, 0x0667, 0x0669, 0x066a, 0x066c, 0x066e,
+    0x0670, 0x0672, 0x0674, 0x0675, 0x0677, 0x0679, 0x067b, 0x067d, 0x067f, 0x0680, 0x0682, 0x0684,
+    0x0686, 0x0688, 0x068a, 0x068c, 0x068d, 0x068f, 0x0691, 0x0693, 0x0695, 0x0697, 0x0699, 0x069a,
+    0x069c, 0x069e, 0x06a0, 0x06a2, 0x06a4, 0x06a6, 0x06a7, 0x06a9, 0x06ab, 0x06ad, 0x06af, 0x06b1,
+    0x06b3, 0x06b5, 0x06b6, 0x06b8, 0x06ba, 0x06bc, 0x06be, 0x06c0, 0x06c2, 0x06c4, 0x06c5, 0x06c7,
+    0x06c9, 0x06cb, 0x06cd, 0x06cf, 0x06d1, 0x06d3, 0x06d5, 0x06d6, 0x06d8, 0x06da, 0x06dc, 0x06de,
+    0x06e0, 0x06e2, 0x06e4, 0x06e6, 0x06e8, 0x06ea, 0x06eb, 0x06ed, 0x06ef, 0x06f1, 0x06f3, 0x06f5,
+    0x06f7, 0x06fb, 0x06ff, 0x0702, 0x0706, 0x070a, 0x070e, 0x0712, 0x0716, 0x071a, 0x071d, 0x0721,
+    0x0725, 0x0729, 0x072d, 0x0731, 0x0735, 0x0739, 0x073d, 0x0740, 0x0744, 0x0748, 0x074c, 0x0750,
+    0x0754, 0x0758, 0x075c, 0x0760, 0x0764, 0x0768, 0x076c, 0x0770, 0x0774, 0x0778, 0x077c, 0x0780,
+    0x0784, 0x0788, 0x078c, 0x0790, 0x0794, 0x0798, 0x079c, 0x07a0, 0x07a4, 0x07a8, 0x07ac, 0x07b0,
+    0x07b4, 0x07b8, 0x07bc, 0x07c0, 0x07c4, 0x07c8, 0x07cc, 0x07d0, 0x07d4, 0x07d9, 0x07dd, 0x07e1,
+    0x07e5, 0x07e9, 0x07ed, 0x07f1, 0x07f5, 0x07f9, 0x07fe, 0x0801, 0x0803, 0x0805, 0x0807, 0x0809,
+    0x080b, 0x080d, 0x080f, 0x0811, 0x0814, 0x0816, 0x0818, 0x081a, 0x081c, 0x081e, 0x0820, 0x0822,
+    0x0824, 0x0826, 0x0828, 0x082b, 0x082d, 0x082f, 0x0831, 0x0833, 0x0835, 0x0837, 0x0839, 0x083c,
+    0x083e, 0x0840, 0x0842, 0x0844, 0x0846, 0x0848, 0x084b, 0x084d, 0x084f, 0x0851, 0x0853, 0x0855,
+    0x0857, 0x085a, 0x085c, 0x085e, 0x0860, 0x0862, 0x0864, 0x0867, 0x0869, 0x086b, 0x086d, 0x086f,
+    0x0872, 0x0874, 0x0876, 0x0878, 0x087a, 0x087d, 0x087f, 0x0881, 0x0883, 0x0885, 0x0888, 0x088a,
+    0x088c, 0x088e, 0x0890, 0x0893, 0x0895, 0x0897, 0x0899, 0x089c, 0x089e, 0x08a0, 0x08a2, 0x08a4,
+    0x08a7, 0x08a9, 0x08ab, 0x08ad, 0x08b0, 0x08b2, 0x08b4, 0x08b6, 0x08b9, 0x08bb, 0x08bd, 0x08c0,
+    0x08c2, 0x08c4, 0x08c6, 0x08c9, 0x08cb, 0x08cd, 0x08cf, 0x08d2, 0x08d4, 0x08d6, 0x08d9, 0x08db,
+    0x08dd, 0x08e0, 0x08e2, 0x08e4, 0x08e6, 0x08e9, 0x08eb, 0x08ed, 0x08f0, 0x08f2, 0x08f4, 0x08f7,
+    0x08f9, 0x08fb, 0x08fe, 0x0900, 0x0902, 0x0905, 0x0907, 0x0909, 0x090c, 0x090e, 0x0910, 0x0913,
+    0x0915, 0x0917, 0x091a, 0x091c, 0x091e, 0x0921, 0x0923, 0x0926, 0x0928, 0x092a, 0x092d, 0x092f,
+    0x0931, 0x0934, 0x0936, 0x0939, 0x093b, 0x093d, 0x0940, 0x0942, 0x0945, 0x0947, 0x0949, 0x094c,
+    0x094e, 0x0951, 0x0953, 0x0955, 0x0958, 0x095a, 0x095d, 0x095f, 0x0962, 0x0964, 0x0966, 0x0969,
+    0x096b, 0x096e, 0x0970, 0x0973, 0x0975, 0x0977, 0x097a, 0x097c, 0x097f, 0x0981, 0x0984, 0x0986,
+    0x0989, 0x098b, 0x098e, 0x0990, 0x0993, 0x0995, 0x0998, 0x099a, 0x099c, 0x099f, 0x09a1, 0x09a4,
+    0x09a6, 0x09a9, 0x09ab, 0x09ae, 0x09b0, 0x09b3, 0x09b5, 0x09b8, 0x09ba, 0x09bd, 0x09c0, 0x09c2,
+    0x09c5, 0x09c7, 0x09ca, 0x09cc, 0x09cf, 0x09d1, 0x09d4, 0x09d6, 0x09d9, 0x09db, 0x09de, 0x09e0,
+    0x09e3, 0x09e6, 0x09e8, 0x09eb, 0x09ed, 0x09f0, 0x09f2, 0x09f5, 0x09f7, 0x09fa, 0x09fd, 0x09ff,
+    0x0a02, 0x0a04, 0x0a07, 0x0a0a, 0x0a0c, 0x0a0f, 0x0a11, 0x0a14, 0x0a16, 0x0a19, 0x0a1c, 0x0a1e,
+    0x0a21, 0x0a23, 0x0a26, 0x0a29, 0x0a2b, 0x0a2e, 0x0a31, 0x0a33, 0x0a36, 0x0a38, 0x0a3b, 0x0a3e,
+    0x0a40, 0x0a43, 0x0a46, 0x0a48, 0x0a4b, 0x0a4e, 0x0a50, 0x0a53, 0x0a55, 0x0a58, 0x0a5b, 0x0a5d,
+    0x0a60, 0x0a63, 0x0a65, 0x0a68, 0x0a6b, 0x0a6d, 0x0a70, 0x0a73, 0x0a76, 0x0a78, 0x0a7b, 0x0a7e,
+    0x0a80, 0x0a83, 0x0a86, 0x0a88, 0x0a8b, 0x0a8e, 0x0a90, 0x0a93, 0x0a96, 0x0a99, 0x0a9b, 0x0a9e,
+    0x0aa1, 0x0aa3, 0x0aa6, 0x0aa9, 0x0aac, 0x0aae, 0x0ab1, 0x0ab4, 0x0ab7, 0x0ab9, 0x0abc, 0x0abf,
+    0x0ac2, 0x0ac4, 0x0ac7, 0x0aca, 0x0acd, 0x0acf, 0x0ad2, 0x0ad5, 0x0ad8, 0x0ada, 0x0add, 0x0ae0,
+    0x0ae3, 0x0ae5, 0x0ae8, 0x0aeb, 0x0aee, 0x0af1, 0x0af3, 0x0af6, 0x0af9, 0x0afc, 0x0aff, 0x0b01,
+    0x0b04, 0x0b07, 0x0b0a, 0x0b0d, 0x0b0f, 0x0b12, 0x0b15, 0x0b18, 0x0b1b, 0x0b1e, 0x0b20, 0x0b23,
+    0x0b26, 0x0b29, 0x0b2c, 0x0b2f, 0x0b31, 0x0b34, 0x0b37, 0x0b3a, 0x0b3d, 0x0b40, 0x0b43, 0x0b45,
+    0x0b48, 0x0b4b, 0x0b4e, 0x0b51, 0x0b54, 0x0b57, 0x0b59, 0x0b5c, 0x0b5f, 0x0b62, 0x0b65, 0x0b68,
+    0x0b6b, 0x0b6e, 0x0b71, 0x0b74, 0x0b76, 0x0b79, 0x0b7c, 0x0b7f, 0x0b82, 0x0b85, 0x0b88, 0x0b8b,
+    0x0b8e, 0x0b91, 0x0b94, 0x0b96, 0x0b99, 0x0b9c, 0x0b9f, 0x0ba2, 0x0ba5, 0x0ba8, 0x0bab, 0x0bae,
+    0x0bb1, 0x0bb4, 0x0bb7, 0x0bba, 0x0bbd, 0x0bc0, 0x0bc3, 0x0bc6, 0x0bc9, 0x0bcc, 0x0bcf, 0x0bd2,
+    0x0bd5, 0x0bd7, 0x0bda, 0x0bdd, 0x0be0, 0x0be3, 0x0be6, 0x0be9, 0x0bec, 0x0bef, 0x0bf2, 0x0bf5,
+    0x0bf8, 0x0bfb, 0x0bfe, 0x0c01, 0x0c02, 0x0c04, 0x0c05, 0x0c07, 0x0c08, 0x0c0a, 0x0c0b, 0x0c0d,
+    0x0c0e, 0x0c10, 0x0c11, 0x0c13, 0x0c14, 0x0c16, 0x0c17, 0x0c19, 0x0c1b, 0x0c1c, 0x0c1e, 0x0c1f,
+    0x0c21, 0x0c22, 0x0c24, 0x0c25, 0x0c27, 0x0c28, 0x0c2a, 0x0c2b, 0x0c2d, 0x0c2f, 0x0c30, 0x0c32,
+    0x0c33, 0x0c35, 0x0c36, 0x0c38, 0x0c39, 0x0c3b, 0x0c3c, 0x0c3e, 0x0c40, 0x0c41, 0x0c43, 0x0c44,
+    0x0c46, 0x0c47, 0x0c49, 0x0c4b, 0x0c4c, 0x0c4e, 0x0c4f, 0x0c51, 0x0c52, 0x0c54, 0x0c56, 0x0c57,
+    0x0c59, 0x0c5a, 0x0c5c, 0x0c5d, 0x0c5f, 0x0c61, 0x0c62, 0x0c64, 0x0c65, 0x0c67, 0x0c69, 0x0c6a,
+    0x0c6c, 0x0c6d, 0x0c6f, 0x0c70, 0x0c72, 0x0c74, 0x0c75, 0x0c77, 0x0c78, 0x0c7a, 0x0c7c, 0x0c7d,
+    0x0c7f, 0x0c80, 0x0c82, 0x0c84, 0x0c85, 0x0c87, 0x0c89, 0x0c8a, 0x0c8c, 0x0c8d, 0x0c8f, 0x0c91,
+    0x0c92, 0x0c94, 0x0c95, 0x0c97, 0x0c99, 0x0c9a, 0x0c9c, 0x0c9e, 0x0c9f, 0x0ca1, 0x0ca3, 0x0ca4,
+    0x0ca6, 0x0ca7, 0x0ca9, 0x0cab, 0x0cac, 0x0cae, 0x0cb0, 0x0cb1, 0x0cb3, 0x0cb5, 0x0cb6, 0x0cb8,
+    0x0cba, 0x0cbb, 0x0cbd, 0x0cbe, 0x0cc0, 0x0cc2, 0x0cc3, 0x0cc5, 0x0cc7, 0x0cc8, 0x0cca, 0x0ccc,
+    0x0ccd, 0x0ccf, 0x0cd1, 0x0cd2, 0x0cd4, 0x0cd6, 0x0cd7, 0x0cd9, 0x0cdb, 0x0cdc, 0x0cde, 0x0ce0,
+    0x0ce1, 0x0ce3, 0x0ce5, 0x0ce7, 0x0ce8, 0x0cea, 0x0cec, 0x0ced, 0x0cef, 0x0cf1, 0x0cf2, 0x0cf4,
+    0x0cf6, 0x0cf7, 0x0cf9, 0x0cfb, 0x0cfd, 0x0cfe, 0x0d00, 0x0d02, 0x0d03, 0x0d05, 0x0d07, 0x0d08,
+    0x0d0a, 0x0d0c, 0x0d0e, 0x0d0f, 0x0d11, 0x0d13, 0x0d14, 0x0d16, 0x0d18, 0x0d1a, 0x0d1b, 0x0d1d,
+    0x0d1f, 0x0d20, 0x0d22, 0x0d24, 0x0d26, 0x0d27, 0x0d29, 0x0d2b, 0x0d2d, 0x0d2e, 0x0d30, 0x0d32,
+    0x0d34, 0x0d35, 0x0d37, 0x0d39, 0x0d3b, 0x0d3c, 0x0d3e, 0x0d40, 0x0d42, 0x0d43, 0x0d45, 0x0d47,
+    0x0d49, 0x0d4a, 0x0d4c, 0x0d4e, 0x0d50, 0x0d51, 0x0d53, 0x0d55, 0x0d57, 0x0d58, 0x0d5a, 0x0d5c,
+    0x0d5e, 0x0d5f, 0x0d61, 0x0d63, 0x0d65, 0x0d67, 0x0d68, 0x0d6a, 0x0d6c, 0x0d6e, 0x0d6f, 0x0d71,
+    0x0d73, 0x0d75, 0x0d77, 0x0d78, 0x0d7a, 0x0d7c, 0x0d7e, 0x0d7f, 0x0d81, 0x0d83, 0x0d85, 0x0d87,
+    0x0d88, 0x0d8a, 0x0d8c, 0x0d8e, 0x0d90, 0x0d91, 0x0d93, 0x0d95, 0x0d97, 0x0d99, 0x0d9b, 0x0d9c,
+    0x0d9e, 0x0da0, 0x0da2, 0x0da4, 0x0da5, 0x0da7, 0x0da9, 0x0dab, 0x0dad, 0x0daf, 0x0db0, 0x0db2,
+    0x0db4, 0x0db6, 0x0db8, 0x0dba, 0x0dbb, 0x0dbd, 0x0dbf, 0x0dc1, 0x0dc3, 0x0dc5, 0x0dc6, 0x0dc8,
+    0x0dca, 0x0dcc, 0x0dce, 0x0dd0, 0x0dd1, 0x0dd3, 0x0dd5, 0x0dd7, 0x0dd9, 0x0ddb, 0x0ddd, 0x0dde,
+    0x0de0, 0x0de2, 0x0de4, 0x0de6, 0x0de8, 0x0dea, 0x0deb, 0x0ded, 0x0def, 0x0df1, 0x0df3, 0x0df5,
+    0x0df7, 0x0df9, 0x0dfa, 0x0dfc, 0x0dfe, 0x0e00, 0x0e02, 0x0e04, 0x0e06, 0x0e08, 0x0e09, 0x0e0b,
+    0x0e0d, 0x0e0f, 0x0e11, 0x0e13, 0x0e15, 0x0e17, 0x0e19, 0x0e1b, 0x0e1c, 0x0e1e, 0x0e20, 0x0e22,
+    0x0e24, 0x0e26, 0x0e28, 0x0e2a, 0x0e2c, 0x0e2e, 0x0e2f, 0x0e31, 0x0e33, 0x0e35, 0x0e37, 0x0e39,
+    0x0e3b, 0x0e3d, 0x0e3f, 0x0e41, 0x0e43, 0x0e45, 0x0e47, 0x0e48, 0x0e4a, 0x0e4c, 0x0e4e, 0x0e50,
+    0x0e52, 0x0e54, 0x0e56, 0x0e58, 0x0e5a, 0x0e5c, 0x0e5e, 0x0e60, 0x0e62, 0x0e64, 0x0e66, 0x0e67,
+    0x0e69, 0x0e6b, 0x0e6d, 0x0e6f, 0x0e71, 0x0e73, 0x0e75, 0x0e77, 0x0e79, 0x0e7b, 0x0e7d, 0x0e7f,
+    0x0e81, 0x0e83, 0x0e85, 0x0e87, 0x0e89, 0x0e8b, 0x0e8d, 0x0e8f, 0x0e91, 0x0e93, 0x0e95, 0x0e97,
+    0x0e99, 0x0e9b, 0x0e9d, 0x0e9f, 0x0ea0, 0x0ea2, 0x0ea4, 0x0ea6, 0x0ea8, 0x0eaa, 0x0eac, 0x0eae,
+    0x0eb0, 0x0eb2, 0x0eb4, 0x0eb6, 0x0eb8, 0x0eba, 0x0ebc, 0x0ebe, 0x0ec0, 0x0ec2, 0x0ec4, 0x0ec6,
+    0x0ec8, 0x0eca, 0x0ecc, 0x0ece, 0x0ed1, 0x0ed3, 0x0ed5, 0x0ed7, 0x0ed9, 0x0edb, 0x0edd, 0x0edf,
+    0x0ee1, 0x0ee3, 0x0ee5, 0x0ee7, 0x0ee9, 0x0eeb, 0x0eed, 0x0eef, 0x0ef1, 0x0ef3, 0x0ef5, 0x0ef7,
+    0x0ef9, 0x0efb, 0x0efd, 0x0eff, 0x0f01, 0x0f03, 0x0f05, 0x0f07, 0x0f09, 0x0f0b, 0x0f0e, 0x0f10,
+    0x0f12, 0x0f14, 0x0f16, 0x0f18, 0x0f1a, 0x0f1c, 0x0f1e, 0x0f20, 0x0f22, 0x0f24, 0x0f26, 0x0f28,
+    0x0f2a, 0x0f2c, 0x0f2f, 0x0f31, 0x0f33, 0x0f35, 0x0f37, 0x0f39, 0x0f3b, 0x0f3d, 0x0f3f, 0x0f41,
+    0x0f43, 0x0f45, 0x0f48, 0x0f4a, 0x0f4c, 0x0f4e, 0x0f50, 0x0f52, 0x0f54, 0x0f56, 0x0f58, 0x0f5a,
+    0x0f5d, 0x0f5f, 0x0f61, 0x0f63, 0x0f65, 0x0f67, 0x0f69, 0x0f6b, 0x0f6d, 0x0f6f, 0x0f72, 0x0f74,
+    0x0f76, 0x0f78, 0x0f7a, 0x0f7c, 0x0f7e, 0x0f80, 0x0f83, 0x0f85, 0x0f87, 0x0f89, 0x0f8b, 0x0f8d,
+    0x0f8f, 0x0f91, 0x0f94, 0x0f96, 0x0f98, 0x0f9a, 0x0f9c, 0x0f9e, 0x0fa0, 0x0fa3, 0x0fa5, 0x0fa7,
+    0x0fa9, 0x0fab, 0x0fad, 0x0faf, 0x0fb2, 0x0fb4, 0x0fb6, 0x0fb8, 0x0fba, 0x0fbc, 0x0fbf, 0x0fc1,
+    0x0fc3, 0x0fc5, 0x0fc7, 0x0fc9, 0x0fcc, 0x0fce, 0x0fd0, 0x0fd2, 0x0fd4, 0x0fd6, 0x0fd9, 0x0fdb,
+    0x0fdd, 0x0fdf, 0x0fe1, 0x0fe4, 0x0fe6, 0x0fe8, 0x0fea, 0x0fec, 0x0fee, 0x0ff1, 0x0ff3, 0x0ff5,
+    0x0ff7, 0x0ff9, 0x0ffc, 0x0ffe, 0x1000, 0x1002, 0x1004, 0x1007, 0x1009, 0x100b, 0x100d, 0x100f,
+    0x1012, 0x1014, 0x1016, 0x1018, 0x101b, 0x101d, 0x101f, 0x1021, 0x1024, 0x1026, 0x1028, 0x102a,
+    0x102d, 0x102f, 0x1031, 0x1033, 0x1036, 0x1038, 0x103a, 0x103c, 0x103f, 0x1041, 0x1043, 0x1045,
+    0x1048, 0x104a, 0x104c, 0x104f, 0x1051, 0x1053, 0x1055, 0x1058, 0x105a, 0x105c, 0x105f, 0x1061,
+    0x1063, 0x1066, 0x1068, 0x106a, 0x106d, 0x106f, 0x1071, 0x1074, 0x1076, 0x1078, 0x107b, 0x107d,
+    0x107f, 0x1082, 0x1084, 0x1086, 0x1089, 0x108b, 0x108d, 0x1090, 0x1092, 0x1094, 0x1097, 0x1099,
+    0x109c, 0x109e, 0x10a0, 0x10a3, 0x10a5, 0x10a8, 0x10aa, 0x10ac, 0x10af, 0x10b1, 0x10b3, 0x10b6,
+    0x10b8, 0x10bb, 0x10bd, 0x10c0, 0x10c2, 0x10c4, 0x10c7, 0x10c9, 0x10cc, 0x10ce, 0x10d1, 0x10d3,
+    0x10d5, 0x10d8, 0x10da, 0x10dd, 0x10df, 0x10e2, 0x10e4, 0x10e7, 0x10e9, 0x10eb, 0x10ee, 0x10f0,
+    0x10f3, 0x10f5, 0x10f8, 0x10fa, 0x10fd, 0x10ff, 0x1102, 0x1104, 0x1107, 0x1109, 0x110c, 0x110e,
+    0x1111, 0x1113, 0x1116, 0x1118, 0x111b, 0x111d, 0x1120, 0x1122, 0x1125, 0x1127, 0x112a, 0x112c,
+    0x112f, 0x1131, 0x1134, 0x1137, 0x1139, 0x113c, 0x113e, 0x1141, 0x1143, 0x1146, 0x1148, 0x114b,
+    0x114d, 0x1150, 0x1153, 0x1155, 0x1158, 0x115a, 0x115d, 0x1160, 0x1162, 0x1165, 0x1167, 0x116a,
+    0x116c, 0x116f, 0x1172, 0x1174, 0x1177, 0x1179, 0x117c, 0x117f, 0x1181, 0x1184, 0x1187, 0x1189,
+    0x118c, 0x118e, 0x1191, 0x1194, 0x1196, 0x1199, 0x119c, 0x119e, 0x11a1, 0x11a4, 0x11a6, 0x11a9,
+    0x11ac, 0x11ae, 0x11b1, 0x11b4, 0x11b6, 0x11b9, 0x11bc, 0x11be, 0x11c1, 0x11c4, 0x11c6, 0x11c9,
+    0x11cc, 0x11ce, 0x11d1, 0x11d4, 0x11d6, 0x11d9, 0x11dc, 0x11df, 0x11e1, 0x11e4, 0x11e7, 0x11e9,
+    0x11ec, 0x11ef, 0x11f2, 0x11f4, 0x11f7, 0x11fa, 0x11fd, 0x11ff, 0x1202, 0x1205, 0x1208, 0x120a,
+    0x120d, 0x1210, 0x1213, 0x1215, 0x1218, 0x121b, 0x121e, 0x1220, 0x1223, 0x1226, 0x1229, 0x122c,
+    0x122e, 0x1231, 0x1234, 0x1237, 0x123a, 0x123c, 0x123f, 0x1242, 0x1245, 0x1248, 0x124a, 0x124d,
+    0x1250, 0x1253, 0x1256, 0x1259, 0x125b, 0x125e, 0x1261, 0x1264, 0x1267, 0x126a, 0x126c, 0x126f,
+    0x1272, 0x1275, 0x1278, 0x127b, 0x127e, 0x1280, 0x1283, 0x1286, 0x1289, 0x128c, 0x128f, 0x1292,
+    0x1295, 0x1297, 0x129a, 0x129d, 0x12a0, 0x12a3, 0x12a6, 0x12a9, 0x12ac, 0x12af, 0x12b2, 0x12b4,
+    0x12b7, 0x12ba, 0x12bd, 0x12c0, 0x12c3, 0x12c6, 0x12c9, 0x12cc, 0x12cf, 0x12d2, 0x12d5, 0x12d8,
+    0x12db, 0x12de, 0x12e1, 0x12e3, 0x12e6, 0x12e9, 0x12ec, 0x12ef, 0x12f2, 0x12f5, 0x12f8, 0x12fb,
+    0x12fe, 0x1301, 0x1304, 0x1307, 0x130a, 0x130d, 0x1310, 0x1313, 0x1316, 0x1319, 0x131c, 0x131f,
+    0x1322, 0x1325, 0x1328, 0x132b, 0x132e, 0x1331, 0x1334, 0x1337, 0x133a, 0x133d, 0x1341, 0x1344,
+    0x1347, 0x134a, 0x134d, 0x1350, 0x1353, 0x1356, 0x1359, 0x135c, 0x135f, 0x1362, 0x1365, 0x1368,
+    0x136b, 0x136e, 0x1372, 0x1375, 0x1378, 0x137b, 0x137e, 0x1381, 0x1384, 0x1387, 0x138a, 0x138d,
+    0x1391, 0x1394, 0x1397, 0x139a, 0x139d, 0x13a0, 0x13a3, 0x13a6, 0x13aa, 0x13ad, 0x13b0, 0x13b3,
+    0x13b6, 0x13b9, 0x13bc, 0x13c0, 0x13c3, 0x13c6, 0x13c9, 0x13cc, 0x13cf, 0x13d3, 0x13d6, 0x13d9,
+    0x13dc, 0x13df, 0x13e2, 0x13e6, 0x13e9, 0x13ec, 0x13ef, 0x13f2, 0x13f6, 0x13f9, 0x13fc, 0x13ff,
+    0x1401, 0x1403, 0x1404, 0x1406, 0x1408, 0x1409, 0x140b, 0x140d, 0x140e, 0x1410, 0x1411, 0x1413,
+    0x1415, 0x1416, 0x1418, 0x1419, 0x141b, 0x141d, 0x141e, 0x1420, 0x1422, 0x1423, 0x1425, 0x1427,
+    0x1428, 0x142a, 0x142b, 0x142d, 0x142f, 0x1430, 0x1432, 0x1434, 0x1435, 0x1437, 0x1439, 0x143a,
+    0x143c, 0x143e, 0x143f, 0x1441, 0x1443, 0x1444, 0x1446, 0x1448, 0x1449, 0x144b, 0x144d, 0x144e,
+    0x1450, 0x1452, 0x1453, 0x1455, 0x1457, 0x1458, 0x145a, 0x145c, 0x145d, 0x145f, 0x1461, 0x1462,
+    0x1464, 0x1466, 0x1467, 0x1469, 0x146b, 0x146d, 0x146e, 0x1470, 0x1472, 0x1473, 0x1475, 0x1477,
+    0x1478, 0x147a, 0x147c, 0x147e, 0x147f, 0x1481, 0x1483, 0x1484, 0x1486, 0x1488, 0x148a, 0x148b,
+    0x148d, 0x148f, 0x1490, 0x1492, 0x1494, 0x1496, 0x1497, 0x1499, 0x149b, 0x149d, 0x149e, 0x14a0,
+    0x14a2, 0x14a4, 0x14a5, 0x14a7, 0x14a9, 0x14ab, 0x14ac, 0x14ae, 0x14b0, 0x14b2, 0x14b3, 0x14b5,
+    0x14b7, 0x14b9, 0x14ba, 0x14bc, 0x14be, 0x14c0, 0x14c1, 0x14c3, 0x14c5, 0x14c7, 0x14c8, 0x14ca,
+    0x14cc, 0x14ce, 0x14cf, 0x14d1, 0x14d3, 0x14d5, 0x14d7, 0x14d8, 0x14da, 0x14dc, 0x14de, 0x14e0,
+    0x14e1, 0x14e3, 0x14e5, 0x14e7, 0x14e8, 0x14ea, 0x14ec, 0x14ee, 0x14f0, 0x14f1, 0x14f3, 0x14f5,
+    0x14f7, 0x14f9, 0x14fa, 0x14fc, 0x14fe, 0x1500, 0x1502, 0x1504, 0x1505, 0x1507, 0x1509, 0x150b,
+    0x150d, 0x150e, 0x1510, 0x1512, 0x1514, 0x1516, 0x1518, 0x1519, 0x151b, 0x151d, 0x151f, 0x1521,
+    0x1523, 0x1524, 0x1526, 0x1528, 0x152a, 0x152c, 0x152e, 0x1530, 0x1531, 0x1533, 0x1535, 0x1537,
+    0x1539, 0x153b, 0x153d, 0x153e, 0x1540, 0x1542, 0x1544, 0x1546, 0x1548, 0x154a, 0x154b, 0x154d,
+    0x154f, 0x1551, 0x1553, 0x1555, 0x1557, 0x1559, 0x155a, 0x155c, 0x155e, 0x1560, 0x1562, 0x1564,
+    0x1566, 0x1568, 0x156a, 0x156b, 0x156d, 0x156f, 0x1571, 0x1573, 0x1575, 0x1577, 0x1579, 0x157b,
+    0x157d, 0x157e, 0x1580, 0x1582, 0x1584, 0x1586, 0x1588, 0x158a, 0x158c, 0x158e, 0x1590, 0x1592,
+    0x1594, 0x1595, 0x1597, 0x1599, 0x159b, 0x159d, 0x159f, 0x15a1, 0x15a3, 0x15a5, 0x15a7, 0x15a9,
+    0x15ab, 0x15ad, 0x15af, 0x15b1, 0x15b3, 0x15b4, 0x15b6, 0x15b8, 0x15ba, 0x15bc, 0x15be, 0x15c0,
+    0x15c2, 0x15c4, 0x15c6, 0x15c8, 0x15ca, 0x15cc, 0x15ce, 0x15d0, 0x15d2, 0x15d4, 0x15d6, 0x15d8,
+    0x15da, 0x15dc, 0x15de, 0x15e0, 0x15e2, 0x15e4, 0x15e6, 0x15e8, 0x15ea, 0x15ec, 0x15ee, 0x15f0,
+    0x15f2, 0x15f4, 0x15f6, 0x15f8, 0x15fa, 0x15fc, 0x15fe, 0x1600, 0x1602, 0x1604, 0x1606, 0x1608,
+    0x160a, 0x160c, 0x160e, 0x1610, 0x1612, 0x1614, 0x1616, 0x1618, 0x161a, 0x161c, 0x161e, 0x1620,
+    0x1622, 0x1624, 0x1626, 0x1628, 0x162a, 0x162c, 0x162e, 0x1630, 0x1632, 0x1634, 0x1636, 0x1638,
+    0x163a, 0x163c, 0x163e, 0x1640, 0x1642, 0x1644, 0x1647, 0x1649, 0x164b, 0x164d, 0x164f, 0x1651,
+    0x1653, 0x1655, 0x1657, 0x1659, 0x165b, 0x165d, 0x165f, 0x1661, 0x1663, 0x1665, 0x1668, 0x166a,
+    0x166c, 0x166e, 0x1670, 0x1672, 0x1674, 0x1676, 0x1678, 0x167a, 0x167c, 0x167e, 0x1681, 0x1683,
+    0x1685, 0x1687, 0x1689, 0x168b, 0x168d, 0x168f, 0x1691, 0x1693, 0x1696, 0x1698, 0x169a, 0x169c,
+    0x169e, 0x16a0, 0x16a2, 0x16a4, 0x16a6, 0x16a9, 0x16ab, 0x16ad, 0x16af, 0x16b1, 0x16b3, 0x16b5,
+    0x16b7, 0x16ba, 0x16bc, 0x16be, 0x16c0, 0x16c2, 0x16c4, 0x16c6, 0x16c9, 0x16cb, 0x16cd, 0x16cf,
+    0x16d1, 0x16d3, 0x16d5, 0x16d8, 0x16da, 0x16dc, 0x16de, 0x16e0, 0x16e2, 0x16e4, 0x16e7, 0x16e9,
+    0x16eb, 0x16ed, 0x16ef, 0x16f1, 0x16f4, 0x16f6, 0x16f8, 0x16fa, 0x16fc, 0x16ff, 0x1701, 0x1703,
+    0x1705, 0x1707, 0x1709, 0x170c, 0x170e, 0x1710, 0x1712, 0x1714, 0x1717, 0x1719, 0x171b, 0x171d,
+    0x171f, 0x1722, 0x1724, 0x1726, 0x1728, 0x172a, 0x172d, 0x172f, 0x1731, 0x1733, 0x1735, 0x1738,
+    0x173a, 0x173c, 0x173e, 0x1740, 0x1743, 0x1745, 0x1747, 0x1749, 0x174c, 0x174e, 0x1750, 0x1752,
+    0x1755, 0x1757, 0x1759, 0x175b, 0x175d, 0x1760, 0x1762, 0x1764, 0x1766, 0x1769, 0x176b, 0x176d,
+    0x176f, 0x1772, 0x1774, 0x1776, 0x1778, 0x177b, 0x177d, 0x177f, 0x1781, 0x1784, 0x1786, 0x1788,
+    0x178b, 0x178d, 0x178f, 0x1791, 0x1794, 0x1796, 0x1798, 0x179a, 0x179d, 0x179f, 0x17a1, 0x17a4,
+    0x17a6, 0x17a8, 0x17aa, 0x17ad, 0x17af, 0x17b1, 0x17b4, 0x17b6, 0x17b8, 0x17bb, 0x17bd, 0x17bf,
+    0x17c1, 0x17c4, 0x17c6, 0x17c8, 0x17cb, 0x17cd, 0x17cf, 0x17d2, 0x17d4, 0x17d6, 0x17d9, 0x17db,
+    0x17dd, 0x17e0, 0x17e2, 0x17e4, 0x17e6, 0x17e9, 0x17eb, 0x17ed, 0x17f0, 0x17f2, 0x17f4, 0x17f7,
+    0x17f9, 0x17fb, 0x17fe, 0x1800, 0x1801, 0x1802, 0x1804, 0x1805, 0x1806, 0x1807, 0x1808, 0x1809,
+    0x180b, 0x180c, 0x180d, 0x180e, 0x180f, 0x1811, 0x1812, 0x1813, 0x1814, 0x1815, 0x1816, 0x1818,
+    0x1819, 0x181a, 0x181b, 0x181c, 0x181e, 0x181f, 0x1820, 0x1821, 0x1822, 0x1824, 0x1825, 0x1826,
+    0x1827, 0x1828, 0x182a, 0x182b, 0x182c, 0x182d, 0x182e, 0x1830, 0x1831, 0x1832, 0x1833, 0x1834,
+    0x1836, 0x1837, 0x1838, 0x1839, 0x183a, 0x183c, 0x183d, 0x183e, 0x183f, 0x1840, 0x1842, 0x1843,
+    0x1844, 0x1845, 0x1846, 0x1848, 0x1849, 0x184a, 0x184b, 0x184d, 0x184e, 0x184f, 0x1850, 0x1851,
+    0x1853, 0x1854, 0x1855, 0x1856, 0x1858, 0x1859, 0x185a, 0x185b, 0x185c, 0x185e, 0x185f, 0x1860,
+    0x1861, 0x1863, 0x1864, 0x1865, 0x1866, 0x1868, 0x1869, 0x186a, 0x186b, 0x186c, 0x186e, 0x186f,
+    0x1870, 0x1871, 0x1873, 0x1874, 0x1875, 0x1876, 0x1878, 0x1879, 0x187a, 0x187b, 0x187d, 0x187e,
+    0x187f, 0x1880, 0x1882, 0x1883, 0x1884, 0x1885, 0x1887, 0x1888, 0x1889, 0x188a, 0x188c, 0x188d,
+    0x188e, 0x188f, 0x1891, 0x1892, 0x1893, 0x1894, 0x1896, 0x1897, 0x1898, 0x189b, 0x189d, 0x18a0,
+    0x18a2, 0x18a5, 0x18a7, 0x18aa, 0x18ad, 0x18af, 0x18b2, 0x18b4, 0x18b7, 0x18b9, 0x18bc, 0x18bf,
+    0x18c1, 0x18c4, 0x18c6, 0x18c9, 0x18cb, 0x18ce, 0x18d1, 0x18d3, 0x18d6, 0x18d8, 0x18db, 0x18de,
+    0x18e0, 0x18e3, 0x18e5, 0x18e8, 0x18eb, 0x18ed, 0x18f0, 0x18f3, 0x18f5, 0x18f8, 0x18fa, 0x18fd,
+    0x1900, 0x1902, 0x1905, 0x1908, 0x190a, 0x190d, 0x1910, 0x1912, 0x1915, 0x1918, 0x191a, 0x191d,
+    0x1920, 0x1922, 0x1925, 0x1928, 0x192a, 0x192d, 0x1930, 0x1933, 0x1935, 0x1938, 0x193b, 0x193d,
+    0x1940, 0x1943, 0x1946, 0x1948, 0x194b, 0x194e, 0x1950, 0x1953, 0x1956, 0x1959, 0x195b, 0x195e,
+    0x1961, 0x1964, 0x1966, 0x1969, 0x196c, 0x196f, 0x1972, 0x1974, 0x1977, 0x197a, 0x197d, 0x197f,
+    0x1982, 0x1985, 0x1988, 0x198b, 0x198d, 0x1990, 0x1993, 0x1996, 0x1999, 0x199b, 0x199e, 0x19a1,
+    0x19a4, 0x19a7, 0x19aa, 0x19ac, 0x19af, 0x19b2, 0x19b5, 0x19b8, 0x19bb, 0x19bd, 0x19c0, 0x19c3,
+    0x19c6, 0x19c9, 0x19cc, 0x19cf, 0x19d1, 0x19d4, 0x19d7, 0x19da, 0x19dd, 0x19e0, 0x19e3, 0x19e6,
+    0x19e9, 0x19eb, 0x19ee, 0x19f1, 0x19f4, 0x19f7, 0x19fa, 0x19fd, 0x1a00, 0x1a03, 0x1a06, 0x1a09,
+    0x1a0c, 0x1a0f, 0x1a11, 0x1a14, 0x1a17, 0x1a1a, 0x1a1d, 0x1a20, 0x1a23, 0x1a26, 0x1a29, 0x1a2c,
+    0x1a2f, 0x1a32, 0x1a35, 0x1a38, 0x1a3b, 0x1a3e, 0x1a41, 0x1a44, 0x1a47, 0x1a4a, 0x1a4d, 0x1a50,
+    0x1a53, 0x1a56, 0x1a59, 0x1a5c, 0x1a5f, 0x1a62, 0x1a65, 0x1a68, 0x1a6b, 0x1a6e, 0x1a71, 0x1a74,
+    0x1a77, 0x1a7a, 0x1a7d, 0x1a80, 0x1a83, 0x1a86, 0x1a8a, 0x1a8d, 0x1a90, 0x1a93, 0x1a96, 0x1a99,
+    0x1a9c, 0x1a9f, 0x1aa2, 0x1aa5, 0x1aa8, 0x1aab, 0x1aaf, 0x1ab2, 0x1ab5, 0x1ab8, 0x1abb, 0x1abe,
+    0x1ac1, 0x1ac4, 0x1ac7, 0x1acb, 0x1ace, 0x1ad1, 0x1ad4, 0x1ad7, 0x1ada, 0x1add, 0x1ae1, 0x1ae4,
+    0x1ae7, 0x1aea, 0x1aed, 0x1af0, 0x1af3, 0x1af7, 0x1afa, 0x1afd, 0x1b00, 0x1b03, 0x1b07, 0x1b0a,
+    0x1b0d, 0x1b10, 0x1b13, 0x1b16, 0x1b1a, 0x1b1d, 0x1b20, 0x1b23, 0x1b27, 0x1b2a, 0x1b2d, 0x1b30,
+    0x1b33, 0x1b37, 0x1b3a, 0x1b3d, 0x1b40, 0x1b44, 0x1b47, 0x1b4a, 0x1b4d, 0x1b51, 0x1b54, 0x1b57,
+    0x1b5a, 0x1b5e, 0x1b61, 0x1b64, 0x1b67, 0x1b6b, 0x1b6e, 0x1b71, 0x1b75, 0x1b78, 0x1b7b, 0x1b7e,
+    0x1b82, 0x1b85, 0x1b88, 0x1b8c, 0x1b8f, 0x1b92, 0x1b96, 0x1b99, 0x1b9c, 0x1ba0, 0x1ba3, 0x1ba6,
+    0x1baa, 0x1bad, 0x1bb0, 0x1bb4, 0x1bb7, 0x1bba, 0x1bbe, 0x1bc1, 0x1bc4, 0x1bc8, 0x1bcb, 0x1bcf,
+    0x1bd2, 0x1bd5, 0x1bd9, 0x1bdc, 0x1be0, 0x1be3, 0x1be6, 0x1bea, 0x1bed, 0x1bf0, 0x1bf4, 0x1bf7,
+    0x1bfb, 0x1bfe, 0x1c01, 0x1c02, 0x1c04, 0x1c06, 0x1c08, 0x1c09, 0x1c0b, 0x1c0d, 0x1c0f, 0x1c10,
+    0x1c12, 0x1c14, 0x1c15, 0x1c17, 0x1c19, 0x1c1b, 0x1c1c, 0x1c1e, 0x1c20, 0x1c22, 0x1c23, 0x1c25,
+    0x1c27, 0x1c29, 0x1c2a, 0x1c2c, 0x1c2e, 0x1c30, 0x1c31, 0x1c33, 0x1c35, 0x1c37, 0x1c38, 0x1c3a,
+    0x1c3c, 0x1c3e, 0x1c3f, 0x1c41, 0x1c43, 0x1c45, 0x1c46, 0x1c48, 0x1c4a, 0x1c4c, 0x1c4e, 0x1c4f,
+    0x1c51, 0x1c53, 0x1c55, 0x1c56, 0x1c58, 0x1c5a, 0x1c5c, 0x1c5e, 0x1c5f, 0x1c61, 0x1c63, 0x1c65,
+    0x1c67, 0x1c68, 0x1c6a, 0x1c6c, 0x1c6e, 0x1c70, 0x1c71, 0x1c73, 0x1c75, 0x1c77, 0x1c79, 0x1c7b,
+    0x1c7c, 0x1c7e, 0x1c80, 0x1c82, 0x1c84, 0x1c85, 0x1c87, 0x1c89, 0x1c8b, 0x1c8d, 0x1c8f, 0x1c90,
+    0x1c92, 0x1c94, 0x1c96, 0x1c98, 0x1c9a, 0x1c9c, 0x1c9d, 0x1c9f, 0x1ca1, 0x1ca3, 0x1ca5, 0x1ca7,
+    0x1ca8, 0x1caa, 0x1cac, 0x1cae, 0x1cb0, 0x1cb2, 0x1cb4, 0x1cb6, 0x1cb7, 0x1cb9, 0x1cbb, 0x1cbd,
+    0x1cbf, 0x1cc1, 0x1cc3, 0x1cc5, 0x1cc6, 0x1cc8, 0x1cca, 0x1ccc, 0x1cce, 0x1cd0, 0x1cd2, 0x1cd4,
+    0x1cd6, 0x1cd7, 0x1cd9, 0x1cdb, 0x1cdd, 0x1cdf, 0x1ce1, 0x1ce3, 0x1ce5, 0x1ce7, 0x1ce9, 0x1cea,
+    0x1cec, 0x1cee, 0x1cf0, 0x1cf2, 0x1cf4, 0x1cf6, 0x1cf8, 0x1cfa, 0x1cfc, 0x1cfe, 0x1d00, 0x1d02,
+    0x1d03, 0x1d05, 0x1d07, 0x1d09, 0x1d0b, 0x1d0d, 0x1d0f, 0x1d11, 0x1d13, 0x1d15, 0x1d17, 0x1d19,
+    0x1d1b, 0x1d1d, 0x1d1f, 0x1d21, 0x1d23, 0x1d25, 0x1d27, 0x1d29, 0x1d2a, 0x1d2c, 0x1d2e, 0x1d30,
+    0x1d32, 0x1d34, 0x1d36, 0x1d38, 0x1d3a, 0x1d3c, 0x1d3e, 0x1d40, 0x1d42, 0x1d44, 0x1d46, 0x1d48,
+    0x1d4a, 0x1d4c, 0x1d4e, 0x1d50, 0x1d52, 0x1d54, 0x1d56, 0x1d58, 0x1d5a, 0x1d5c, 0x1d5e, 0x1d60,
+    0x1d62, 0x1d64, 0x1d66, 0x1d68, 0x1d6a, 0x1d6c, 0x1d6e, 0x1d70, 0x1d72, 0x1d74, 0x1d76, 0x1d78,
+    0x1d7a, 0x1d7c, 0x1d7e, 0x1d80, 0x1d83, 0x1d85, 0x1d87, 0x1d89, 0x1d8b, 0x1d8d, 0x1d8f, 0x1d91,
+    0x1d93, 0x1d95, 0x1d97, 0x1d99, 0x1d9b, 0x1d9d, 0x1d9f, 0x1da1, 0x1da3, 0x1da5, 0x1da7, 0x1daa,
+    0x1dac, 0x1dae, 0x1db0, 0x1db2, 0x1db4, 0x1db6, 0x1db8, 0x1dba, 0x1dbc, 0x1dbe, 0x1dc0, 0x1dc2,
+    0x1dc5, 0x1dc7, 0x1dc9, 0x1dcb, 0x1dcd, 0x1dcf, 0x1dd1, 0x1dd3, 0x1dd5, 0x1dd7, 0x1dd9, 0x1ddc,
+    0x1dde, 0x1de0, 0x1de2, 0x1de4, 0x1de6, 0x1de8, 0x1dea, 0x1dec, 0x1def, 0x1df1, 0x1df3, 0x1df5,
+    0x1df7, 0x1df9, 0x1dfb, 0x1dfd, 0x1e00, 0x1e02, 0x1e04, 0x1e06, 0x1e08, 0x1e0a, 0x1e0c, 0x1e0f,
+    0x1e11, 0x1e13, 0x1e15, 0x1e17, 0x1e19, 0x1e1b, 0x1e1e, 0x1e20, 0x1e22, 0x1e24, 0x1e26, 0x1e28,
+    0x1e2b, 0x1e2d, 0x1e2f, 0x1e31, 0x1e33, 0x1e35, 0x1e38, 0x1e3a, 0x1e3c, 0x1e3e, 0x1e40, 0x1e42,
+    0x1e45, 0x1e47, 0x1e49, 0x1e4b, 0x1e4d, 0x1e50, 0x1e52, 0x1e54, 0x1e56, 0x1e58, 0x1e5b, 0x1e5d,
+    0x1e5f, 0x1e61, 0x1e63, 0x1e66, 0x1e68, 0x1e6a, 0x1e6c, 0x1e6e, 0x1e71, 0x1e73, 0x1e75, 0x1e77,
+    0x1e7a, 0x1e7c, 0x1e7e, 0x1e80, 0x1e82, 0x1e85, 0x1e87, 0x1e89, 0x1e8b, 0x1e8e, 0x1e90, 0x1e92,
+    0x1e94, 0x1e97, 0x1e99, 0x1e9b, 0x1e9d, 0x1ea0, 0x1ea2, 0x1ea4, 0x1ea6, 0x1ea9, 0x1eab, 0x1ead,
+    0x1eaf, 0x1eb2, 0x1eb4, 0x1eb6, 0x1eb8, 0x1ebb, 0x1ebd, 0x1ebf, 0x1ec2, 0x1ec4, 0x1ec6, 0x1ec8,
+    0x1ecb, 0x1ecd, 0x1ecf, 0x1ed2, 0x1ed4, 0x1ed6, 0x1ed8, 0x1edb, 0x1edd, 0x1edf, 0x1ee2, 0x1ee4,
+    0x1ee6, 0x1ee8, 0x1eeb, 0x1eed, 0x1eef, 0x1ef2, 0x1ef4, 0x1ef6, 0x1ef9, 0x1efb, 0x1efd, 0x1f00,
+    0x1f02, 0x1f04, 0x1f07, 0x1f09, 0x1f0b, 0x1f0e, 0x1f10, 0x1f12, 0x1f15, 0x1f17, 0x1f19, 0x1f1c,
+    0x1f1e, 0x1f20, 0x1f23, 0x1f25, 0x1f27, 0x1f2a, 0x1f2c, 0x1f2e, 0x1f31, 0x1f33, 0x1f35, 0x1f38,
+    0x1f3a, 0x1f3c, 0x1f3f, 0x1f41, 0x1f44, 0x1f46, 0x1f48, 0x1f4b, 0x1f4d, 0x1f4f, 0x1f52, 0x1f54,
+    0x1f57, 0x1f59, 0x1f5b, 0x1f5e, 0x1f60, 0x1f62, 0x1f65, 0x1f67, 0x1f6a, 0x1f6c, 0x1f6e, 0x1f71,
+    0x1f73, 0x1f76, 0x1f78, 0x1f7a, 0x1f7d, 0x1f7f, 0x1f82, 0x1f84, 0x1f87, 0x1f89, 0x1f8b, 0x1f8e,
+    0x1f90, 0x1f93, 0x1f95, 0x1f97, 0x1f9a, 0x1f9c, 0x1f9f, 0x1fa1, 0x1fa4, 0x1fa6, 0x1fa8, 0x1fab,
+    0x1fad, 0x1fb0, 0x1fb2, 0x1fb5, 0x1fb7, 0x1fba, 0x1fbc, 0x1fbe, 0x1fc1, 0x1fc3, 0x1fc6, 0x1fc8,
+    0x1fcb, 0x1fcd, 0x1fd0, 0x1fd2, 0x1fd5, 0x1fd7, 0x1fda, 0x1fdc, 0x1fde, 0x1fe1, 0x1fe3, 0x1fe6,
+    0x1fe8, 0x1feb, 0x1fed, 0x1ff0, 0x1ff2, 0x1ff5, 0x1ff7, 0x1ffa, 0x1ffc, 0x1fff, 0x2001, 0x2002,
+    0x2003, 0x2004, 0x2006, 0x2007, 0x2008, 0x2009, 0x200b, 0x200c, 0x200d, 0x200e, 0x2010, 0x2011,
+    0x2012, 0x2013, 0x2015, 0x2016, 0x2017, 0x2018, 0x201a, 0x201b, 0x201c, 0x201e, 0x201f, 0x2020,
+    0x2021, 0x2023, 0x2024, 0x2025, 0x2026, 0x2028, 0x2029, 0x202a, 0x202c, 0x202d, 0x202e, 0x202f,
+    0x2031, 0x2032, 0x2033, 0x2034, 0x2036, 0x2037, 0x2038, 0x203a, 0x203b, 0x203c, 0x203d, 0x203f,
+    0x2040, 0x2041, 0x2043, 0x2044, 0x2045, 0x2047, 0x2048, 0x2049, 0x204a, 0x204c, 0x204d, 0x204e,
+    0x2050, 0x2051, 0x2052, 0x2054, 0x2055, 0x2056, 0x2057, 0x2059, 0x205a, 0x205b, 0x205d, 0x205e,
+    0x205f, 0x2061, 0x2062, 0x2063, 0x2065, 0x2066, 0x2067, 0x2068, 0x206a, 0x206b, 0x206c, 0x206e,
+    0x206f, 0x2070, 0x2072, 0x2073, 0x2074, 0x2076, 0x2077, 0x2078, 0x207a, 0x207b, 0x207c, 0x207e,
+    0x207f, 0x2080, 0x2082, 0x2083, 0x2084, 0x2086, 0x2087, 0x2088, 0x208a, 0x208b, 0x208c, 0x208e,
+    0x208f, 0x2090, 0x2092, 0x2093, 0x2094, 0x2096, 0x2097, 0x2098, 0x209a, 0x209b, 0x209c, 0x209e,
+    0x209f, 0x20a0, 0x20a2, 0x20a3, 0x20a5, 0x20a6, 0x20a7, 0x20a9, 0x20aa, 0x20ab, 0x20ad, 0x20ae,
+    0x20af, 0x20b1, 0x20b2, 0x20b3, 0x20b5, 0x20b6, 0x20b8, 0x20b9, 0x20ba, 0x20bc, 0x20bd, 0x20be,
+    0x20c0, 0x20c1, 0x20c2, 0x20c4, 0x20c5, 0x20c7, 0x20c8, 0x20c9, 0x20cb, 0x20cc, 0x20cd, 0x20cf,
+    0x20d0, 0x20d2, 0x20d3, 0x20d4, 0x20d6, 0x20d7, 0x20d9, 0x20da, 0x20db, 0x20dd, 0x20de, 0x20df,
+    0x20e1, 0x20e2, 0x20e4, 0x20e5, 0x20e6, 0x20e8, 0x20e9, 0x20eb, 0x20ec, 0x20ed, 0x20ef, 0x20f0,
+    0x20f2, 0x20f3, 0x20f4, 0x20f6, 0x20f7, 0x20f9, 0x20fa, 0x20fb, 0x20fd, 0x20fe, 0x2100, 0x2101,
+    0x2102, 0x2104, 0x2105, 0x2107, 0x2108, 0x210a, 0x210b, 0x210c, 0x210e, 0x210f, 0x2111, 0x2112,
+    0x2113, 0x2115, 0x2116, 0x2118, 0x2119, 0x211b, 0x211c, 0x211d, 0x211f, 0x2120, 0x2122, 0x2123,
+    0x2125, 0x2126, 0x2127, 0x2129, 0x212a, 0x212c, 0x212d, 0x212f, 0x2130, 0x2131, 0x2133, 0x2134,
+    0x2136, 0x2137, 0x2139, 0x213a, 0x213c, 0x213d, 0x213e, 0x2140, 0x2141, 0x2143, 0x2144, 0x2146,
+    0x2147, 0x214a, 0x214d, 0x2150, 0x2153, 0x2156, 0x2159, 0x215c, 0x215f, 0x2161, 0x2164, 0x2167,
+    0x216a, 0x216d, 0x2170, 0x2173, 0x2176, 0x2179, 0x217c, 0x217f, 0x2182, 0x2185, 0x2188, 0x218b,
+    0x218e, 0x2191, 0x2194, 0x2197, 0x219a, 0x219d, 0x21a0, 0x21a3, 0x21a6, 0x21a9, 0x21ac, 0x21af,
+    0x21b2, 0x21b5, 0x21b8, 0x21bb, 0x21be, 0x21c1, 0x21c4, 0x21c7, 0x21ca, 0x21cd, 0x21d0, 0x21d3,
+    0x21d6, 0x21da, 0x21dd, 0x21e0, 0x21e3, 0x21e6, 0x21e9, 0x21ec, 0x21ef, 0x21f2, 0x21f5, 0x21f8,
+    0x21fb, 0x21ff, 0x2202, 0x2205, 0x2208, 0x220b, 0x220e, 0x2211, 0x2214, 0x2218, 0x221b, 0x221e,
+    0x2221, 0x2224, 0x2227, 0x222b, 0x222e, 0x2231, 0x2234, 0x2237, 0x223a, 0x223e, 0x2241, 0x2244,
+    0x2247, 0x224a, 0x224d, 0x2251, 0x2254, 0x2257, 0x225a, 0x225e, 0x2261, 0x2264, 0x2267, 0x226a,
+    0x226e, 0x2271, 0x2274, 0x2277, 0x227b, 0x227e, 0x2281, 0x2284, 0x2288, 0x228b, 0x228e, 0x2291,
+    0x2295, 0x2298, 0x229b, 0x229f, 0x22a2, 0x22a5, 0x22a8, 0x22ac, 0x22af, 0x22b2, 0x22b6, 0x22b9,
+    0x22bc, 0x22c0, 0x22c3, 0x22c6, 0x22c9, 0x22cd, 0x22d0, 0x22d4, 0x22d7, 0x22da, 0x22de, 0x22e1,
+    0x22e4, 0x22e8, 0x22eb, 0x22ee, 0x22f2, 0x22f5, 0x22f8, 0x22fc, 0x22ff, 0x2303, 0x2306, 0x2309,
+    0x230d, 0x2310, 0x2314, 0x2317, 0x231a, 0x231e, 0x2321, 0x2325, 0x2328, 0x232c, 0x232f, 0x2332,
+    0x2336, 0x2339, 0x233d, 0x2340, 0x2344, 0x2347, 0x234b, 0x234e, 0x2351, 0x2355, 0x2358, 0x235c,
+    0x235f, 0x2363, 0x2366, 0x236a, 0x236d, 0x2371, 0x2374, 0x2378, 0x237b, 0x237f, 0x2382, 0x2386,
+    0x2389, 0x238d, 0x2390, 0x2394, 0x2398, 0x239b, 0x239f, 0x23a2, 0x23a6, 0x23a9, 0x23ad, 0x23b0,
+    0x23b4, 0x23b8, 0x23bb, 0x23bf, 0x23c2, 0x23c6, 0x23c9, 0x23cd, 0x23d1, 0x23d4, 0x23d8, 0x23db,
+    0x23df, 0x23e3, 0x23e6, 0x23ea, 0x23ee, 0x23f1, 0x23f5, 0x23f8, 0x23fc, 0x2400, 0x2402, 0x2403,
+    0x2405, 0x2407, 0x2409, 0x240b, 0x240d, 0x240e, 0x2410, 0x2412, 0x2414, 0x2416, 0x2418, 0x241a,
+    0x241b, 0x241d, 0x241f, 0x2421, 0x2423, 0x2425, 0x2426, 0x2428, 0x242a, 0x242c, 0x242e, 0x2430,
+    0x2432, 0x2434, 0x2435, 0x2437, 0x2439, 0x243b, 0x243d, 0x243f, 0x2441, 0x2443, 0x2444, 0x2446,
+    0x2448, 0x244a, 0x244c, 0x244e, 0x2450, 0x2452, 0x2454, 0x2455, 0x2457, 0x2459, 0x245b, 0x245d,
+    0x245f, 0x2461, 0x2463, 0x2465, 0x2467, 0x2469, 0x246b, 0x246c, 0x246e, 0x2470, 0x2472, 0x2474,
+    0x2476, 0x2478, 0x247a, 0x247c, 0x247e, 0x2480, 0x2482, 0x2484, 0x2486, 0x2488, 0x2489, 0x248b,
+    0x248d, 0x248f, 0x2491, 0x2493, 0x2495, 0x2497, 0x2499, 0x249b, 0x249d, 0x249f, 0x24a1, 0x24a3,
+    0x24a5, 0x24a7, 0x24a9, 0x24ab, 0x24ad, 0x24af, 0x24b1, 0x24b3, 0x24b5, 0x24b7, 0x24b9, 0x24bb,
+    0x24bd, 0x24bf, 0x24c1, 0x24c3, 0x24c5, 0x24c7, 0x24c9, 0x24cb, 0x24cd, 0x24cf, 0x24d1, 0x24d3,
+    0x24d5, 0x24d7, 0x24d9, 0x24db, 0x24dd, 0x24df, 0x24e1, 0x24e3, 0x24e5, 0x24e7, 0x24e9, 0x24eb,
+    0x24ed, 0x24ef, 0x24f1, 0x24f3, 0x24f5, 0x24f7, 0x24fa, 0x24fc, 0x24fe, 0x2500, 0x2502, 0x2504,
+    0x2506, 0x2508, 0x250a, 0x250c, 0x250e, 0x2510, 0x2512, 0x2514, 0x2516, 0x2519, 0x251b, 0x251d,
+    0x251f, 0x2521, 0x2523, 0x2525, 0x2527, 0x2529, 0x252b, 0x252d, 0x252f, 0x2532, 0x2534, 0x2536,
+    0x2538, 0x253a, 0x253c, 0x253e, 0x2540, 0x2542, 0x2545, 0x2547, 0x2549, 0x254b, 0x254d, 0x254f,
+    0x2551, 0x2553, 0x2556, 0x2558, 0x255a, 0x255c, 0x255e, 0x2560, 0x2562, 0x2564, 0x2567, 0x2569,
+    0x256b, 0x256d, 0x256f, 0x2571, 0x2574, 0x2576, 0x2578, 0x257a, 0x257c, 0x257e, 0x2581, 0x2583,
+    0x2585, 0x2587, 0x2589, 0x258b, 0x258e, 0x2590, 0x2592, 0x2594, 0x2596, 0x2598, 0x259b, 0x259d,
+    0x259f, 0x25a1, 0x25a3, 0x25a6, 0x25a8, 0x25aa, 0x25ac, 0x25ae, 0x25b1, 0x25b3, 0x25b5, 0x25b7,
+    0x25b9, 0x25bc, 0x25be, 0x25c0, 0x25c2, 0x25c5, 0x25c7, 0x25c9, 0x25cb, 0x25cd, 0x25d0, 0x25d2,
+    0x25d4, 0x25d6, 0x25d9, 0x25db, 0x25dd, 0x25df, 0x25e2, 0x25e4, 0x25e6, 0x25e8, 0x25eb, 0x25ed,
+    0x25ef, 0x25f1, 0x25f4, 0x25f6, 0x25f8, 0x25fa, 0x25fd, 0x25ff, 0x2601, 0x2604, 0x2606, 0x2608,
+    0x260a, 0x260d, 0x260f, 0x2611, 0x2614, 0x2616, 0x2618, 0x261a, 0x261d, 0x261f, 0x2621, 0x2624,
+    0x2626, 0x2628, 0x262a, 0x262d, 0x262f, 0x2631, 0x2634, 0x2636, 0x2638, 0x263b, 0x263d, 0x263f,
+    0x2642, 0x2644, 0x2646, 0x2649, 0x264b, 0x264d, 0x2650, 0x2652, 0x2654, 0x2657, 0x2659, 0x265b,
+    0x265e, 0x2660, 0x2662, 0x2665, 0x2667, 0x2669, 0x266c, 0x266e, 0x2670, 0x2673, 0x2675, 0x2678,
+    0x267a, 0x267c, 0x267f, 0x2681, 0x2683, 0x2686, 0x2688, 0x268b, 0x268d, 0x268f, 0x2692, 0x2694,
+    0x2696, 0x2699, 0x269b, 0x269e, 0x26a0, 0x26a2, 0x26a5, 0x26a7, 0x26aa, 0x26ac, 0x26ae, 0x26b1,
+    0x26b3, 0x26b6, 0x26b8, 0x26bb, 0x26bd, 0x26bf, 0x26c2, 0x26c4, 0x26c7, 0x26c9, 0x26cc, 0x26ce,
+    0x26d0, 0x26d3, 0x26d5, 0x26d8, 0x26da, 0x26dd, 0x26df, 0x26e1, 0x26e4, 0x26e6, 0x26e9, 0x26eb,
+    0x26ee, 0x26f0, 0x26f3, 0x26f5, 0x26f8, 0x26fa, 0x26fd, 0x26ff, 0x2701, 0x2704, 0x2706, 0x2709,
+    0x270b, 0x270e, 0x2710, 0x2713, 0x2715, 0x2718, 0x271a, 0x271d, 0x271f, 0x2722, 0x2724, 0x2727,
+    0x2729, 0x272c, 0x272e, 0x2731, 0x2733, 0x2736, 0x2738, 0x273b, 0x273d, 0x2740, 0x2742, 0x2745,
+    0x2747, 0x274a, 0x274c, 0x274f, 0x2752, 0x2754, 0x2757, 0x2759, 0x275c, 0x275e, 0x2761, 0x2763,
+    0x2766, 0x2768, 0x276b, 0x276d, 0x2770, 0x2773, 0x2775, 0x2778, 0x277a, 0x277d, 0x277f, 0x2782,
+    0x2785, 0x2787, 0x278a, 0x278c, 0x278f, 0x2791, 0x2794, 0x2797, 0x2799, 0x279c, 0x279e, 0x27a1,
+    0x27a4, 0x27a6, 0x27a9, 0x27ab, 0x27ae, 0x27b0, 0x27b3, 0x27b6, 0x27b8, 0x27bb, 0x27be, 0x27c0,
+    0x27c3, 0x27c5, 0x27c8, 0x27cb, 0x27cd, 0x27d0, 0x27d2, 0x27d5, 0x27d8, 0x27da, 0x27dd, 0x27e0,
+    0x27e2, 0x27e5, 0x27e8, 0x27ea, 0x27ed, 0x27ef, 0x27f2, 0x27f5, 0x27f7, 0x27fa, 0x27fd, 0x27ff,
+    0x2801, 0x2802, 0x2804, 0x2805, 0x2806, 0x2808, 0x2809, 0x280a, 0x280c, 0x280d, 0x280e, 0x2810,
+    0x2811, 0x2812, 0x2814, 0x2815, 0x2816, 0x2818, 0x2819, 0x281a, 0x281c, 0x281d, 0x281e, 0x2820,
+    0x2821, 0x2823, 0x2824, 0x2825, 0x2827, 0x2828, 0x2829, 0x282b, 0x282c, 0x282d, 0x282f, 0x2830,
+    0x2831, 0x2833, 0x2834, 0x2836, 0x2837, 0x2838, 0x283a, 0x283b, 0x283c, 0x283e, 0x283f, 0x2841,
+    0x2842, 0x2843, 0x2845, 0x2846, 0x2847, 0x2849, 0x284a, 0x284c, 0x284d, 0x284e, 0x2850, 0x2851,
+    0x2852, 0x2854, 0x2855, 0x2857, 0x2858, 0x2859, 0x285b, 0x285c, 0x285e, 0x285f, 0x2860, 0x2862,
+    0x2863, 0x2865, 0x2866, 0x2867, 0x2869, 0x286a, 0x286c, 0x286d, 0x286e, 0x2870, 0x2871, 0x2873,
+    0x2874, 0x2875, 0x2877, 0x2878, 0x287a, 0x287b, 0x287d, 0x287e, 0x287f, 0x2881, 0x2882, 0x2884,
+    0x2885, 0x2886, 0x2888, 0x2889, 0x288b, 0x288c, 0x288e, 0x288f, 0x2890, 0x2892, 0x2893, 0x2895,
+    0x2896, 0x2898, 0x2899, 0x289a, 0x289c, 0x289d, 0x289f, 0x28a0, 0x28a2, 0x28a3, 0x28a4, 0x28a6,
+    0x28a7, 0x28a9, 0x28aa, 0x28ac, 0x28ad, 0x28af, 0x28b0, 0x28b1, 0x28b3, 0x28b4, 0x28b6, 0x28b7,
+    0x28b9, 0x28ba, 0x28bc, 0x28bd, 0x28bf, 0x28c0, 0x28c2, 0x28c3, 0x28c4, 0x28c6, 0x28c7, 0x28c9,
+    0x28ca, 0x28cc, 0x28cd, 0x28cf, 0x28d0, 0x28d2, 0x28d3, 0x28d5, 0x28d6, 0x28d8, 0x28d9, 0x28da,
+    0x28dc, 0x28dd, 0x28df, 0x28e0, 0x28e2, 0x28e3, 0x28e5, 0x28e6, 0x28e8, 0x28e9, 0x28eb, 0x28ec,
+    0x28ee, 0x28ef, 0x28f1, 0x28f2, 0x28f4, 0x28f5, 0x28f7, 0x28f8, 0x28fa, 0x28fb, 0x28fd, 0x28fe,
+    0x2900, 0x2901, 0x2903, 0x2904, 0x2906, 0x2907, 0x2909, 0x290a, 0x290c, 0x290d, 0x290f, 0x2910,
+    0x2912, 0x2913, 0x2915, 0x2916, 0x2918, 0x2919, 0x291b, 0x291c, 0x291e, 0x291f, 0x2921, 0x2922,
+    0x2924, 0x2926, 0x2927, 0x2929, 0x292a, 0x292c, 0x292d, 0x292f, 0x2930, 0x2932, 0x2933, 0x2935,
+    0x2936, 0x2938, 0x2939, 0x293b, 0x293c, 0x293e, 0x2940, 0x2941, 0x2943, 0x2944, 0x2946, 0x2947,
+    0x2949, 0x294a, 0x294c, 0x294d, 0x294f, 0x2951, 0x2952, 0x2954, 0x2955, 0x2957, 0x2958, 0x295a,
+    0x295b, 0x295d, 0x295f, 0x2960, 0x2962, 0x2963, 0x2965, 0x2966, 0x2968, 0x296a, 0x296b, 0x296d,
+    0x296e, 0x2970, 0x2971, 0x2973, 0x2975, 0x2976, 0x2978, 0x2979, 0x297b, 0x297c, 0x297e, 0x2980,
+    0x2981, 0x2983, 0x2984, 0x2986, 0x2987, 0x2989, 0x298b, 0x298c, 0x298e, 0x298f, 0x2991, 0x2993,
+    0x2994, 0x2996, 0x2997, 0x2999, 0x299b, 0x299c, 0x299e, 0x299f, 0x29a1, 0x29a3, 0x29a4, 0x29a6,
+    0x29a7, 0x29a9, 0x29ab, 0x29ac, 0x29ae, 0x29af, 0x29b1, 0x29b3, 0x29b4, 0x29b6, 0x29b7, 0x29b9,
+    0x29bb, 0x29bc, 0x29be, 0x29c0, 0x29c1, 0x29c3, 0x29c4, 0x29c6, 0x29c8, 0x29c9, 0x29cb, 0x29cd,
+    0x29ce, 0x29d0, 0x29d1, 0x29d3, 0x29d5, 0x29d6, 0x29d8, 0x29da, 0x29db, 0x29dd, 0x29df, 0x29e0,
+    0x29e2, 0x29e3, 0x29e5, 0x29e7, 0x29e8, 0x29ea, 0x29ec, 0x29ed, 0x29ef, 0x29f1, 0x29f2, 0x29f4,
+    0x29f6, 0x29f7, 0x29f9, 0x29fb, 0x29fc, 0x29fe, 0x29ff, 0x2a01, 0x2a03, 0x2a04, 0x2a06, 0x2a08,
+    0x2a09, 0x2a0b, 0x2a0d, 0x2a0e, 0x2a10, 0x2a13, 0x2a17, 0x2a1a, 0x2a1d, 0x2a21, 0x2a24, 0x2a28,
+    0x2a2b, 0x2a2e, 0x2a32, 0x2a35, 0x2a38, 0x2a3c, 0x2a3f, 0x2a43, 0x2a46, 0x2a49, 0x2a4d, 0x2a50,
+    0x2a54, 0x2a57, 0x2a5a, 0x2a5e, 0x2a61, 0x2a65, 0x2a68, 0x2a6c, 0x2a6f, 0x2a72, 0x2a76, 0x2a79,
+    0x2a7d, 0x2a80, 0x2a84, 0x2a87, 0x2a8b, 0x2a8e, 0x2a92, 0x2a95, 0x2a99, 0x2a9c, 0x2aa0, 0x2aa3,
+    0x2aa7, 0x2aaa, 0x2aae, 0x2ab1, 0x2ab5, 0x2ab8, 0x2abc, 0x2abf, 0x2ac3, 0x2ac6, 0x2aca, 0x2acd,
+    0x2ad1, 0x2ad5, 0x2ad8, 0x2adc, 0x2adf, 0x2ae3, 0x2ae6, 0x2aea, 0x2aee, 0x2af1, 0x2af5, 0x2af8,
+    0x2afc, 0x2b00, 0x2b03, 0x2b07, 0x2b0a, 0x2b0e, 0x2b12, 0x2b15, 0x2b19, 0x2b1c, 0x2b20, 0x2b24,
+    0x2b27, 0x2b2b, 0x2b2f, 0x2b32, 0x2b36, 0x2b3a, 0x2b3d, 0x2b41, 0x2b45, 0x2b48, 0x2b4c, 0x2b50,
+    0x2b54, 0x2b57, 0x2b5b, 0x2b5f, 0x2b62, 0x2b66, 0x2b6a, 0x2b6d, 0x2b71, 0x2b75, 0x2b79, 0x2b7c,
+    0x2b80, 0x2b84, 0x2b88, 0x2b8b, 0x2b8f, 0x2b93, 0x2b97, 0x2b9a, 0x2b9e, 0x2ba2, 0x2ba6, 0x2baa,
+    0x2bad, 0x2bb1, 0x2bb5, 0x2bb9, 0x2bbd, 0x2bc0, 0x2bc4, 0x2bc8, 0x2bcc, 0x2bd0, 0x2bd4, 0x2bd7,
+    0x2bdb, 0x2bdf, 0x2be3, 0x2be7, 0x2beb, 0x2bee, 0x2bf2, 0x2bf6, 0x2bfa, 0x2bfe, 0x2c01, 0x2c03,
+    0x2c05, 0x2c07, 0x2c09, 0x2c0b, 0x2c0d, 0x2c0f, 0x2c10, 0x2c12, 0x2c14, 0x2c16, 0x2c18, 0x2c1a,
+    0x2c1c, 0x2c1e, 0x2c20, 0x2c22, 0x2c24, 0x2c26, 0x2c28, 0x2c2a, 0x2c2c, 0x2c2e, 0x2c30, 0x2c32,
+    0x2c34, 0x2c36, 0x2c38, 0x2c3a, 0x2c3c, 0x2c3e, 0x2c40, 0x2c42, 0x2c44, 0x2c46, 0x2c48, 0x2c4a,
+    0x2c4c, 0x2c4e, 0x2c50, 0x2c52, 0x2c54, 0x2c56, 0x2c58, 0x2c5a, 0x2c5c, 0x2c5e, 0x2c60, 0x2c62,
+    0x2c64, 0x2c66, 0x2c69, 0x2c6b, 0x2c6d, 0x2c6f, 0x2c71, 0x2c73, 0x2c75, 0x2c77, 0x2c79, 0x2c7b,
+    0x2c7d, 0x2c7f, 0x2c81, 0x2c83, 0x2c85, 0x2c87, 0x2c8a, 0x2c8c, 0x2c8e, 0x2c90, 0x2c92, 0x2c94,
+    0x2c96, 0x2c98, 0x2c9a, 0x2c9c, 0x2c9e, 0x2ca0, 0x2ca3, 0x2ca5, 0x2ca7, 0x2ca9, 0x2cab, 0x2cad,
+    0x2caf, 0x2cb1, 0x2cb3, 0x2cb6, 0x2cb8, 0x2cba, 0x2cbc, 0x2cbe, 0x2cc0, 0x2cc2, 0x2cc4, 0x2cc7,
+    0x2cc9, 0x2ccb, 0x2ccd, 0x2ccf, 0x2cd1, 0x2cd3, 0x2cd6, 0x2cd8, 0x2cda, 0x2cdc, 0x2cde, 0x2ce0,
+    0x2ce3, 0x2ce5, 0x2ce7, 0x2ce9, 0x2ceb, 0x2ced, 0x2cf0, 0x2cf2, 0x2cf4, 0x2cf6, 0x2cf8, 0x2cfa,
+    0x2cfd, 0x2cff, 0x2d01, 0x2d03, 0x2d05, 0x2d08, 0x2d0a, 0x2d0c, 0x2d0e, 0x2d10, 0x2d13, 0x2d15,
+    0x2d17, 0x2d19, 0x2d1b, 0x2d1e, 0x2d20, 0x2d22, 0x2d24, 0x2d27, 0x2d29, 0x2d2b, 0x2d2d, 0x2d2f,
+    0x2d32, 0x2d34, 0x2d36, 0x2d38, 0x2d3b, 0x2d3d, 0x2d3f, 0x2d41, 0x2d44, 0x2d46, 0x2d48, 0x2d4a,
+    0x2d4d, 0x2d4f, 0x2d51, 0x2d54, 0x2d56, 0x2d58, 0x2d5a, 0x2d5d, 0x2d5f, 0x2d61, 0x2d63, 0x2d66,
+    0x2d68, 0x2d6a, 0x2d6d, 0x2d6f, 0x2d71, 0x2d73, 0x2d76, 0x2d78, 0x2d7a, 0x2d7d, 0x2d7f, 0x2d81,
+    0x2d84, 0x2d86, 0x2d88, 0x2d8b, 0x2d8d, 0x2d8f, 0x2d91, 0x2d94, 0x2d96, 0x2d98, 0x2d9b, 0x2d9d,
+    0x2d9f, 0x2da2, 0x2da4, 0x2da6, 0x2da9, 0x2dab, 0x2dae, 0x2db0, 0x2db2, 0x2db5, 0x2db7, 0x2db9,
+    0x2dbc, 0x2dbe, 0x2dc0, 0x2dc3, 0x2dc5, 0x2dc7, 0x2dca, 0x2dcc, 0x2dcf, 0x2dd1, 0x2dd3, 0x2dd6,
+    0x2dd8, 0x2dda, 0x2ddd, 0x2ddf, 0x2de2, 0x2de4, 0x2de6, 0x2de9, 0x2deb, 0x2dee, 0x2df0, 0x2df2,
+    0x2df5, 0x2df7, 0x2dfa, 0x2dfc, 0x2dff, 0x2e01, 0x2e03, 0x2e06, 0x2e08, 0x2e0b, 0x2e0d, 0x2e10,
+    0x2e12, 0x2e14, 0x2e17, 0x2e19, 0x2e1c, 0x2e1e, 0x2e21, 0x2e23, 0x2e25, 0x2e28, 0x2e2a, 0x2e2d,
+    0x2e2f, 0x2e32, 0x2e34, 0x2e37, 0x2e39, 0x2e3c, 0x2e3e, 0x2e41, 0x2e43, 0x2e46, 0x2e48, 0x2e4b,
+    0x2e4d, 0x2e4f, 0x2e52, 0x2e54, 0x2e57, 0x2e59, 0x2e5c, 0x2e5e, 0x2e61, 0x2e63, 0x2e66, 0x2e68,
+    0x2e6b, 0x2e6d, 0x2e70, 0x2e72, 0x2e75, 0x2e78, 0x2e7a, 0x2e7d, 0x2e7f, 0x2e82, 0x2e84, 0x2e87,
+    0x2e89, 0x2e8c, 0x2e8e, 0x2e91, 0x2e93, 0x2e96, 0x2e98, 0x2e9b, 0x2e9e, 0x2ea0, 0x2ea3, 0x2ea5,
+    0x2ea8, 0x2eaa, 0x2ead, 0x2eaf, 0x2eb2, 0x2eb5, 0x2eb7, 0x2eba, 0x2ebc, 0x2ebf, 0x2ec2, 0x2ec4,
+    0x2ec7, 0x2ec9, 0x2ecc, 0x2ece, 0x2ed1, 0x2ed4, 0x2ed6, 0x2ed9, 0x2edb, 0x2ede, 0x2ee1, 0x2ee3,
+    0x2ee6, 0x2ee8, 0x2eeb, 0x2eee, 0x2ef0, 0x2ef3, 0x2ef6, 0x2ef8, 0x2efb, 0x2efd, 0x2f00, 0x2f03,
+    0x2f05, 0x2f08, 0x2f0b, 0x2f0d, 0x2f10, 0x2f13, 0x2f15, 0x2f18, 0x2f1a, 0x2f1d, 0x2f20, 0x2f22,
+    0x2f25, 0x2f28, 0x2f2a, 0x2f2d, 0x2f30, 0x2f32, 0x2f35, 0x2f38, 0x2f3a, 0x2f3d, 0x2f40, 0x2f43,
+    0x2f45, 0x2f48, 0x2f4b, 0x2f4d, 0x2f50, 0x2f53, 0x2f55, 0x2f58, 0x2f5b, 0x2f5d, 0x2f60, 0x2f63,
+    0x2f66, 0x2f68, 0x2f6b, 0x2f6e, 0x2f70, 0x2f73, 0x2f76, 0x2f79, 0x2f7b, 0x2f7e, 0x2f81, 0x2f84,
+    0x2f86, 0x2f89, 0x2f8c, 0x2f8f, 0x2f91, 0x2f94, 0x2f97, 0x2f9a, 0x2f9c, 0x2f9f, 0x2fa2, 0x2fa5,
+    0x2fa7, 0x2faa, 0x2fad, 0x2fb0, 0x2fb2, 0x2fb5, 0x2fb8, 0x2fbb, 0x2fbd, 0x2fc0, 0x2fc3, 0x2fc6,
+    0x2fc9, 0x2fcb, 0x2fce, 0x2fd1, 0x2fd4, 0x2fd7, 0x2fd9, 0x2fdc, 0x2fdf, 0x2fe2, 0x2fe5, 0x2fe7,
+    0x2fea, 0x2fed, 0x2ff0, 0x2ff3, 0x2ff6, 0x2ff8, 0x2ffb, 0x2ffe, 0x3000, 0x3002, 0x3003, 0x3005,
+    0x3006, 0x3007, 0x3009, 0x300a, 0x300c, 0x300d, 0x300f, 0x3010, 0x3011, 0x3013, 0x3014, 0x3016,
+    0x3017, 0x3019, 0x301a, 0x301b, 0x301d, 0x301e, 0x3020, 0x3021, 0x3023, 0x3024, 0x3026, 0x3027,
+    0x3028, 0x302a, 0x302b, 0x302d, 0x302e, 0x3030, 0x3031, 0x3033, 0x3034, 0x3036, 0x3037, 0x3038,
+    0x303a, 0x303b, 0x303d, 0x303e, 0x3040, 0x3041, 0x3043, 0x3044, 0x3046, 0x3047, 0x3049, 0x304a,
+    0x304b, 0x304d, 0x304e, 0x3050, 0x3051, 0x3053, 0x3054, 0x3056, 0x3057, 0x3059, 0x305a, 0x305c,
+    0x305d, 0x305f, 0x3060, 0x3062, 0x3063, 0x3065, 0x3066, 0x3068, 0x3069, 0x306b, 0x306c, 0x306e,
+    0x306f, 0x3071, 0x3072, 0x3074, 0x3075, 0x3077, 0x3078, 0x307a, 0x307b, 0x307d, 0x307e, 0x3080,
+    0x3081, 0x3083, 0x3084, 0x3086, 0x3087, 0x3089, 0x308a, 0x308c, 0x308d, 0x308f, 0x3090, 0x3092,
+    0x3093, 0x3095, 0x3096, 0x3098, 0x3099, 0x309b, 0x309c, 0x309e, 0x30a0, 0x30a1, 0x30a3, 0x30a4,
+    0x30a6, 0x30a7, 0x30a9, 0x30aa, 0x30ac, 0x30ad, 0x30af, 0x30b0, 0x30b2, 0x30b4, 0x30b5, 0x30b7,
+    0x30b8, 0x30ba, 0x30bb, 0x30bd, 0x30be, 0x30c0, 0x30c2, 0x30c3, 0x30c5, 0x30c6, 0x30c8, 0x30c9,
+    0x30cb, 0x30cc, 0x30ce, 0x30d0, 0x30d1, 0x30d3, 0x30d4, 0x30d6, 0x30d7, 0x30d9, 0x30db, 0x30dc,
+    0x30de, 0x30df, 0x30e1, 0x30e2, 0x30e4, 0x30e6, 0x30e7, 0x30e9, 0x30ea, 0x30ec, 0x30ed, 0x30ef,
+    0x30f1, 0x30f2, 0x30f4, 0x30f5, 0x30f7, 0x30f9, 0x30fa, 0x30fc, 0x30fd, 0x30ff, 0x3101, 0x3102,
+    0x3104, 0x3105, 0x3107, 0x3109, 0x310a, 0x310c, 0x310d, 0x310f, 0x3111, 0x3112, 0x3114, 0x3115,
+    0x3117, 0x3119, 0x311a, 0x311c, 0x311e, 0x311f, 0x3121, 0x3122, 0x3124, 0x3126, 0x3127, 0x3129,
+    0x312b, 0x312c, 0x312e, 0x312f, 0x3131, 0x3133, 0x3134, 0x3136, 0x3138, 0x3139, 0x313b, 0x313c,
+    0x313e, 0x3140, 0x3141, 0x3143, 0x3145, 0x3146, 0x3148, 0x314a, 0x314b, 0x314d, 0x314f, 0x3150,
+    0x3152, 0x3154, 0x3155, 0x3157, 0x3159, 0x315a, 0x315c, 0x315e, 0x315f, 0x3161, 0x3162, 0x3164,
+    0x3166, 0x3167, 0x3169, 0x316b, 0x316c, 0x316e, 0x3170, 0x3172, 0x3173, 0x3175, 0x3177, 0x3178,
+    0x317a, 0x317c, 0x317d, 0x317f, 0x3181, 0x3182, 0x3184, 0x3186, 0x3187, 0x3189, 0x318b, 0x318c,
+    0x318e, 0x3190, 0x3192, 0x3193, 0x3195, 0x3197, 0x3198, 0x319a, 0x319c, 0x319d, 0x319f, 0x31a1,
+    0x31a3, 0x31a4, 0x31a6, 0x31a8, 0x31a9, 0x31ab, 0x31ad, 0x31af, 0x31b0, 0x31b2, 0x31b4, 0x31b5,
+    0x31b7, 0x31b9, 0x31bb, 0x31bc, 0x31be, 0x31c0, 0x31c1, 0x31c3, 0x31c5, 0x31c7, 0x31c8, 0x31ca,
+    0x31cc, 0x31ce, 0x31cf, 0x31d1, 0x31d3, 0x31d4, 0x31d6, 0x31d8, 0x31da, 0x31db, 0x31dd, 0x31df,
+    0x31e1, 0x31e2, 0x31e4, 0x31e6, 0x31e8, 0x31e9, 0x31eb, 0x31ed, 0x31ef, 0x31f0, 0x31f2, 0x31f4,
+    0x31f6, 0x31f7, 0x31f9, 0x31fb, 0x31fd, 0x31ff, 0x3200, 0x3202, 0x3204, 0x3206, 0x3207, 0x3209,
+    0x320b, 0x320d, 0x320e, 0x3210, 0x3212, 0x3214, 0x3216, 0x3217, 0x3219, 0x321b, 0x321d, 0x321e,
+    0x3220, 0x3222, 0x3224, 0x3226, 0x3227, 0x3229, 0x322b, 0x322d, 0x322f, 0x3230, 0x3232, 0x3234,
+    0x3236, 0x3238, 0x3239, 0x323b, 0x323d, 0x323f, 0x3241, 0x3242, 0x3244, 0x3246, 0x3248, 0x324a,
+    0x324b, 0x324d, 0x324f, 0x3251, 0x3253, 0x3254, 0x3256, 0x3258, 0x325a, 0x325c, 0x325e, 0x325f,
+    0x3261, 0x3263, 0x3265, 0x3267, 0x3269, 0x326a, 0x326c, 0x326e, 0x3270, 0x3272, 0x3274, 0x3275,
+    0x3277, 0x3279, 0x327b, 0x327d, 0x327f, 0x3280, 0x3282, 0x3284, 0x3286, 0x3288, 0x328a, 0x328c,
+    0x328d, 0x328f, 0x3291, 0x3293, 0x3295, 0x3297, 0x3299, 0x329a, 0x329c, 0x329e, 0x32a0, 0x32a2,
+    0x32a4, 0x32a6, 0x32a7, 0x32a9, 0x32ab, 0x32ad, 0x32af, 0x32b1, 0x32b3, 0x32b5, 0x32b6, 0x32b8,
+    0x32ba, 0x32bc, 0x32be, 0x32c0, 0x32c2, 0x32c4, 0x32c5, 0x32c7, 0x32c9, 0x32cb, 0x32cd, 0x32cf,
+    0x32d1, 0x32d3, 0x32d5, 0x32d6, 0x32d8, 0x32da, 0x32dc, 0x32de, 0x32e0, 0x32e2, 0x32e4, 0x32e6,
+    0x32e8, 0x32ea, 0x32eb, 0x32ed, 0x32ef, 0x32f1, 0x32f3, 0x32f5, 0x32f7, 0x32fb, 0x32ff, 0x3302,
+    0x3306, 0x330a, 0x330e, 0x3312, 0x3316, 0x331a, 0x331d, 0x3321, 0x3325, 0x3329, 0x332d, 0x3331,
+    0x3335, 0x3339, 0x333d, 0x3340, 0x3344, 0x3348, 0x334c, 0x3350, 0x3354, 0x3358, 0x335c, 0x3360,
+    0x3364, 0x3368, 0x336c, 0x3370, 0x3374, 0x3378, 0x337c, 0x3380, 0x3384, 0x3388, 0x338c, 0x3390,
+    0x3394, 0x3398, 0x339c, 0x33a0, 0x33a4, 0x33a8, 0x33ac, 0x33b0, 0x33b4, 0x33b8, 0x33bc, 0x33c0,
+    0x33c4, 0x33c8, 0x33cc, 0x33d0, 0x33d4, 0x33d9, 0x33dd, 0x33e1, 0x33e5, 0x33e9, 0x33ed, 0x33f1,
+    0x33f5, 0x33f9, 0x33fe, 0x3401, 0x3403, 0x3405, 0x3407, 0x3409, 0x340b, 0x340d, 0x340f, 0x3411,
+    0x3414, 0x3416, 0x3418, 0x341a, 0x341c, 0x341e, 0x3420, 0x3422, 0x3424, 0x3426, 0x3428, 0x342b,
+    0x342d, 0x342f, 0x3431, 0x3433, 0x3435, 0x3437, 0x3439, 0x343c, 0x343e, 0x3440, 0x3442, 0x3444,
+    0x3446, 0x3448, 0x344b, 0x344d, 0x344f, 0x3451, 0x3453, 0x3455, 0x3457, 0x345a, 0x345c, 0x345e,
+    0x3460, 0x3462, 0x3464, 0x3467, 0x3469, 0x346b, 0x346d, 0x346f, 0x3472, 0x3474, 0x3476, 0x3478,
+    0x347a, 0x347d, 0x347f, 0x3481, 0x3483, 0x3485, 0x3488, 0x348a, 0x348c, 0x348e, 0x3490, 0x3493,
+    0x3495, 0x3497, 0x3499, 0x349c, 0x349e, 0x34a0, 0x34a2, 0x34a4, 0x34a7, 0x34a9, 0x34ab, 0x34ad,
+    0x34b0, 0x34b2, 0x34b4, 0x34b6, 0x34b9, 0x34bb, 0x34bd, 0x34c0, 0x34c2, 0x34c4, 0x34c6, 0x34c9,
+    0x34cb, 0x34cd, 0x34cf, 0x34d2, 0x34d4, 0x34d6, 0x34d9, 0x34db, 0x34dd, 0x34e0, 0x34e2, 0x34e4,
+    0x34e6, 0x34e9, 0x34eb, 0x34ed, 0x34f0, 0x34f2, 0x34f4, 0x34f7, 0x34f9, 0x34fb, 0x34fe, 0x3500,
+    0x3502, 0x3505, 0x3507, 0x3509, 0x350c, 0x350e, 0x3510, 0x3513, 0x3515, 0x3517, 0x351a, 0x351c,
+    0x351e, 0x3521, 0x3523, 0x3526, 0x3528, 0x352a, 0x352d, 0x352f, 0x3531, 0x3534, 0x3536, 0x3539,
+    0x353b, 0x353d, 0x3540, 0x3542, 0x3545, 0x3547, 0x3549, 0x354c, 0x354e, 0x3551, 0x3553, 0x3555,
+    0x3558, 0x355a, 0x355d, 0x355f, 0x3562, 0x3564, 0x3566, 0x3569, 0x356b, 0x356e, 0x3570, 0x3573,
+    0x3575, 0x3577, 0x357a, 0x357c, 0x357f, 0x3581, 0x3584, 0x3586, 0x3589, 0x358b, 0x358e, 0x3590,
+    0x3593, 0x3595, 0x3598, 0x359a, 0x359d, 0x359f, 0x35a1, 0x35a4, 0x35a6, 0x35a9, 0x35ab, 0x35ae,
+    0x35b0, 0x35b3, 0x35b5, 0x35b8, 0x35ba, 0x35bd, 0x35c0, 0x35c2, 0x35c5, 0x35c7, 0x35ca, 0x35cc,
+    0x35cf, 0x35d1, 0x35d4, 0x35d6, 0x35d9, 0x35db, 0x35de, 0x35e0, 0x35e3, 0x35e6, 0x35e8, 0x35eb,
+    0x35ed, 0x35f0, 0x35f2, 0x35f5, 0x35f7, 0x35fa, 0x35fd, 0x35ff, 0x3602, 0x3604, 0x3607, 0x360a,
+    0x360c, 0x360f, 0x3611, 0x3614, 0x3616, 0x3619, 0x361c, 0x361e, 0x3621, 0x3623, 0x3626, 0x3629,
+    0x362b, 0x362e, 0x3631, 0x3633, 0x3636, 0x3638, 0x363b, 0x363e, 0x3640, 0x3643, 0x3646, 0x3648,
+    0x364b, 0x364e, 0x3650, 0x3653, 0x3655, 0x3658, 0x365b, 0x365d, 0x3660, 0x3663, 0x3665, 0x3668,
+    0x366b, 0x366d, 0x3670, 0x3673, 0x3676, 0x3678, 0x367b, 0x367e, 0x3680, 0x3683, 0x3686, 0x3688,
+    0x368b, 0x368e, 0x3690, 0x3693, 0x3696, 0x3699, 0x369b, 0x369e, 0x36a1, 0x36a3, 0x36a6, 0x36a9,
+    0x36ac, 0x36ae, 0x36b1, 0x36b4, 0x36b7, 0x36b9, 0x36bc, 0x36bf, 0x36c2, 0x36c4, 0x36c7, 0x36ca,
+    0x36cd, 0x36cf, 0x36d2, 0x36d5, 0x36d8, 0x36da, 0x36dd, 0x36e0, 0x36e3, 0x36e5, 0x36e8, 0x36eb,
+    0x36ee, 0x36f1, 0x36f3, 0x36f6, 0x36f9, 0x36fc, 0x36ff, 0x3701, 0x3704, 0x3707, 0x370a, 0x370d,
+    0x370f, 0x3712, 0x3715, 0x3718, 0x371b, 0x371e, 0x3720, 0x3723, 0x3726, 0x3729, 0x372c, 0x372f,
+    0x3731, 0x3734, 0x3737, 0x373a, 0x373d, 0x3740, 0x3743, 0x3745, 0x3748, 0x374b, 0x374e, 0x3751,
+    0x3754, 0x3757, 0x3759, 0x375c, 0x375f, 0x3762, 0x3765, 0x3768, 0x376b, 0x376e, 0x3771, 0x3774,
+    0x3776, 0x3779, 0x377c, 0x377f, 0x3782, 0x3785, 0x3788, 0x378b, 0x378e, 0x3791, 0x3794, 0x3796,
+    0x3799, 0x379c, 0x379f, 0x37a2, 0x37a5, 0x37a8, 0x37ab, 0x37ae, 0x37b1, 0x37b4, 0x37b7, 0x37ba,
+    0x37bd, 0x37c0, 0x37c3, 0x37c6, 0x37c9, 0x37cc, 0x37cf, 0x37d2, 0x37d5, 0x37d7, 0x37da, 0x37dd,
+    0x37e0, 0x37e3, 0x37e6, 0x37e9, 0x37ec, 0x37ef, 0x37f2, 0x37f5, 0x37f8, 0x37fb, 0x37fe, 0x3801,
+    0x3802, 0x3804, 0x3805, 0x3807, 0x3808, 0x380a, 0x380b, 0x380d, 0x380e, 0x3810, 0x3811, 0x3813,
+    0x3814, 0x3816, 0x3817, 0x3819, 0x381b, 0x381c, 0x381e, 0x381f, 0x3821, 0x3822, 0x3824, 0x3825,
+    0x3827, 0x3828, 0x382a, 0x382b, 0x382d, 0x382f, 0x3830, 0x3832, 0x3833, 0x3835, 0x3836, 0x3838,
+    0x3839, 0x383b, 0x383c, 0x383e, 0x3840, 0x3841, 0x3843, 0x3844, 0x3846, 0x3847, 0x3849, 0x384b,
+    0x384c, 0x384e, 0x384f, 0x3851, 0x3852, 0x3854, 0x3856, 0x3857, 0x3859, 0x385a, 0x385c, 0x385d,
+    0x385f, 0x3861, 0x3862, 0x3864, 0x3865, 0x3867, 0x3869, 0x386a, 0x386c, 0x386d, 0x386f, 0x3870,
+    0x3872, 0x3874, 0x3875, 0x3877, 0x3878, 0x387a, 0x387c, 0x387d, 0x387f, 0x3880, 0x3882, 0x3884,
+    0x3885, 0x3887, 0x3889, 0x388a, 0x388c, 0x388d, 0x388f, 0x3891, 0x3892, 0x3894, 0x3895, 0x3897,
+    0x3899, 0x389a, 0x389c, 0x389e, 0x389f, 0x38a1, 0x38a3, 0x38a4, 0x38a6, 0x38a7, 0x38a9, 0x38ab,
+    0x38ac, 0x38ae, 0x38b0, 0x38b1, 0x38b3, 0x38b5, 0x38b6, 0x38b8, 0x38ba, 0x38bb, 0x38bd, 0x38be,
+    0x38c0, 0x38c2, 0x38c3, 0x38c5, 0x38c7, 0x38c8, 0x38ca, 0x38cc, 0x38cd, 0x38cf, 0x38d1, 0x38d2,
+    0x38d4, 0x38d6, 0x38d7, 0x38d9, 0x38db, 0x38dc, 0x38de, 0x38e0, 0x38e1, 0x38e3, 0x38e5, 0x38e7,
+    0x38e8, 0x38ea, 0x38ec, 0x38ed, 0x38ef, 0x38f1, 0x38f2, 0x38f4, 0x38f6, 0x38f7, 0x38f9, 0x38fb,
+    0x38fd, 0x38fe, 0x3900, 0x3902, 0x3903, 0x3905, 0x3907, 0x3908, 0x390a, 0x390c, 0x390e, 0x390f,
+    0x3911, 0x3913, 0x3914, 0x3916, 0x3918, 0x391a, 0x391b, 0x391d, 0x391f, 0x3920, 0x3922, 0x3924,
+    0x3926, 0x3927, 0x3929, 0x392b, 0x392d, 0x392e, 0x3930, 0x3932, 0x3934, 0x3935, 0x3937, 0x3939,
+    0x393b, 0x393c, 0x393e, 0x3940, 0x3942, 0x3943, 0x3945, 0x3947, 0x3949, 0x394a, 0x394c, 0x394e,
+    0x3950, 0x3951, 0x3953, 0x3955, 0x3957, 0x3958, 0x395a, 0x395c, 0x395e, 0x395f, 0x3961, 0x3963,
+    0x3965, 0x3967, 0x3968, 0x396a, 0x396c, 0x396e, 0x396f, 0x3971, 0x3973, 0x3975, 0x3977, 0x3978,
+    0x397a, 0x397c, 0x397e, 0x397f, 0x3981, 0x3983, 0x3985, 0x3987, 0x3988, 0x398a, 0x398c, 0x398e,
+    0x3990, 0x3991, 0x3993, 0x3995, 0x3997, 0x3999, 0x399b, 0x399c, 0x399e, 0x39a0, 0x39a2, 0x39a4,
+    0x39a5, 0x39a7, 0x39a9, 0x39ab, 0x39ad, 0x39af, 0x39b0, 0x39b2, 0x39b4, 0x39b6, 0x39b8, 0x39ba,
+    0x39bb, 0x39bd, 0x39bf, 0x39c1, 0x39c3, 0x39c5, 0x39c6, 0x39c8, 0x39ca, 0x39cc, 0x39ce, 0x39d0,
+    0x39d1, 0x39d3, 0x39d5, 0x39d7, 0x39d9, 0x39db, 0x39dd, 0x39de, 0x39e0, 0x39e2, 0x39e4, 0x39e6,
+    0x39e8, 0x39ea, 0x39eb, 0x39ed, 0x39ef, 0x39f1, 0x39f3, 0x39f5, 0x39f7, 0x39f9, 0x39fa, 0x39fc,
+    0x39fe, 0x3a00, 0x3a02, 0x3a04, 0x3a06, 0x3a08, 0x3a09, 0x3a0b, 0x3a0d, 0x3a0f, 0x3a11, 0x3a13,
+    0x3a15, 0x3a17, 0x3a19, 0x3a1b, 0x3a1c, 0x3a1e, 0x3a20, 0x3a22, 0x3a24, 0x3a26, 0x3a28, 0x3a2a,
+    0x3a2c, 0x3a2e, 0x3a2f, 0x3a31, 0x3a33, 0x3a35, 0x3a37, 0x3a39, 0x3a3b, 0x3a3d, 0x3a3f, 0x3a41,
+    0x3a43, 0x3a45, 0x3a47, 0x3a48, 0x3a4a, 0x3a4c, 0x3a4e, 0x3a50, 0x3a52, 0x3a54, 0x3a56, 0x3a58,
+    0x3a5a, 0x3a5c, 0x3a5e, 0x3a60, 0x3a62, 0x3a64, 0x3a66, 0x3a67, 0x3a69, 0x3a6b, 0x3a6d, 0x3a6f,
+    0x3a71, 0x3a73, 0x3a75, 0x3a77, 0x3a79, 0x3a7b, 0x3a7d, 0x3a7f, 0x3a81, 0x3a83, 0x3a85, 0x3a87,
+    0x3a89, 0x3a8b, 0x3a8d, 0x3a8f, 0x3a91, 0x3a93, 0x3a95, 0x3a97, 0x3a99, 0x3a9b, 0x3a9d, 0x3a9f,
+    0x3aa0, 0x3aa2, 0x3aa4, 0x3aa6, 0x3aa8, 0x3aaa, 0x3aac, 0x3aae, 0x3ab0, 0x3ab2, 0x3ab4, 0x3ab6,
+    0x3ab8, 0x3aba, 0x3abc, 0x3abe, 0x3ac0, 0x3ac2, 0x3ac4, 0x3ac6, 0x3ac8, 0x3aca, 0x3acc, 0x3ace,
+    0x3ad1, 0x3ad3, 0x3ad5, 0x3ad7, 0x3ad9, 0x3adb, 0x3add, 0x3adf, 0x3ae1, 0x3ae3, 0x3ae5, 0x3ae7,
+    0x3ae9, 0x3aeb, 0x3aed, 0x3aef, 0x3af1, 0x3af3, 0x3af5, 0x3af7, 0x3af9, 0x3afb, 0x3afd, 0x3aff,
+    0x3b01, 0x3b03, 0x3b05, 0x3b07, 0x3b09, 0x3b0b, 0x3b0e, 0x3b10, 0x3b12, 0x3b14, 0x3b16, 0x3b18,
+    0x3b1a, 0x3b1c, 0x3b1e, 0x3b20, 0x3b22, 0x3b24, 0x3b26, 0x3b28, 0x3b2a, 0x3b2c, 0x3b2f, 0x3b31,
+    0x3b33, 0x3b35, 0x3b37, 0x3b39, 0x3b3b, 0x3b3d, 0x3b3f, 0x3b41, 0x3b43, 0x3b45, 0x3b48, 0x3b4a,
+    0x3b4c, 0x3b4e, 0x3b50, 0x3b52, 0x3b54, 0x3b56, 0x3b58, 0x3b5a, 0x3b5d, 0x3b5f, 0x3b61, 0x3b63,
+    0x3b65, 0x3b67, 0x3b69, 0x3b6b, 0x3b6d, 0x3b6f, 0x3b72, 0x3b74, 0x3b76, 0x3b78, 0x3b7a, 0x3b7c,
+    0x3b7e, 0x3b80, 0x3b83, 0x3b85, 0x3b87, 0x3b89, 0x3b8b, 0x3b8d, 0x3b8f, 0x3b91, 0x3b94, 0x3b96,
+    0x3b98, 0x3b9a, 0x3b9c, 0x3b9e, 0x3ba0, 0x3ba3, 0x3ba5, 0x3ba7, 0x3ba9, 0x3bab, 0x3bad, 0x3baf,
+    0x3bb2, 0x3bb4, 0x3bb6, 0x3bb8, 0x3bba, 0x3bbc, 0x3bbf, 0x3bc1, 0x3bc3, 0x3bc5, 0x3bc7, 0x3bc9,
+    0x3bcc, 0x3bce, 0x3bd0, 0x3bd2, 0x3bd4, 0x3bd6, 0x3bd9, 0x3bdb, 0x3bdd, 0x3bdf, 0x3be1, 0x3be4,
+    0x3be6, 0x3be8, 0x3bea, 0x3bec, 0x3bee, 0x3bf1, 0x3bf3, 0x3bf5, 0x3bf7, 0x3bf9, 0x3bfc, 0x3bfe,
+    0x3c00, 0x3c02, 0x3c04, 0x3c07, 0x3c09, 0x3c0b, 0x3c0d, 0x3c10, 0x3c12, 0x3c14, 0x3c16, 0x3c18,
+    0x3c1b, 0x3c1d, 0x3c1f, 0x3c22, 0x3c24, 0x3c26, 0x3c28, 0x3c2b, 0x3c2d, 0x3c2f, 0x3c32, 0x3c34,
+    0x3c36, 0x3c39, 0x3c3b, 0x3c3d, 0x3c3f, 0x3c42, 0x3c44, 0x3c47, 0x3c49, 0x3c4b, 0x3c4e, 0x3c50,
+    0x3c52, 0x3c55, 0x3c57, 0x3c59, 0x3c5c, 0x3c5e, 0x3c61, 0x3c63, 0x3c66, 0x3c68, 0x3c6a, 0x3c6d,
+    0x3c6f, 0x3c72, 0x3c74, 0x3c77, 0x3c79, 0x3c7b, 0x3c7e, 0x3c80, 0x3c83, 0x3c85, 0x3c88, 0x3c8a,
+    0x3c8d, 0x3c8f, 0x3c92, 0x3c94, 0x3c97, 0x3c99, 0x3c9c, 0x3c9f, 0x3ca1, 0x3ca4, 0x3ca6, 0x3ca9,
+    0x3cab, 0x3cae, 0x3cb0, 0x3cb3, 0x3cb6, 0x3cb8, 0x3cbb, 0x3cbd, 0x3cc0, 0x3cc3, 0x3cc5, 0x3cc8,
+    0x3ccb, 0x3ccd, 0x3cd0, 0x3cd2, 0x3cd5, 0x3cd8, 0x3cda, 0x3cdd, 0x3ce0, 0x3ce2, 0x3ce5, 0x3ce8,
+    0x3ceb, 0x3ced, 0x3cf0, 0x3cf3, 0x3cf5, 0x3cf8, 0x3cfb, 0x3cfe, 0x3d00, 0x3d03, 0x3d06, 0x3d09,
+    0x3d0b, 0x3d0e, 0x3d11, 0x3d14, 0x3d17, 0x3d19, 0x3d1c, 0x3d1f, 0x3d22, 0x3d25, 0x3d27, 0x3d2a,
+    0x3d2d, 0x3d30, 0x3d33, 0x3d36, 0x3d39, 0x3d3b, 0x3d3e, 0x3d41, 0x3d44, 0x3d47, 0x3d4a, 0x3d4d,
+    0x3d50, 0x3d53, 0x3d56, 0x3d59, 0x3d5b, 0x3d5e, 0x3d61, 0x3d64, 0x3d67, 0x3d6a, 0x3d6d, 0x3d70,
+    0x3d73, 0x3d76, 0x3d79, 0x3d7c, 0x3d7f, 0x3d82, 0x3d85, 0x3d88, 0x3d8b, 0x3d8f, 0x3d92, 0x3d95,
+    0x3d98, 0x3d9b, 0x3d9e, 0x3da1, 0x3da4, 0x3da7, 0x3daa, 0x3dad, 0x3db1, 0x3db4, 0x3db7, 0x3dba,
+    0x3dbd, 0x3dc0, 0x3dc3, 0x3dc7, 0x3dca, 0x3dcd, 0x3dd0, 0x3dd3, 0x3dd7, 0x3dda, 0x3ddd, 0x3de0,
+    0x3de3, 0x3de7, 0x3dea, 0x3ded, 0x3df0, 0x3df4, 0x3df7, 0x3dfa, 0x3dfe, 0x3e01, 0x3e04, 0x3e08,
+    0x3e0b, 0x3e0e, 0x3e12, 0x3e15, 0x3e18, 0x3e1c, 0x3e1f, 0x3e22, 0x3e26, 0x3e29, 0x3e2c, 0x3e30,
+    0x3e33, 0x3e37, 0x3e3a, 0x3e3e, 0x3e41, 0x3e44, 0x3e48, 0x3e4b, 0x3e4f, 0x3e52, 0x3e56, 0x3e59,
+    0x3e5d, 0x3e60, 0x3e64, 0x3e67, 0x3e6b, 0x3e6e, 0x3e72, 0x3e75, 0x3e79, 0x3e7c, 0x3e80, 0x3e84,
+    0x3e87, 0x3e8b, 0x3e8e, 0x3e92, 0x3e96, 0x3e99, 0x3e9d, 0x3ea1, 0x3ea4, 0x3ea8, 0x3eac, 0x3eaf,
+    0x3eb3, 0x3eb7, 0x3eba, 0x3ebe, 0x3ec2, 0x3ec5, 0x3ec9, 0x3ecd, 0x3ed1, 0x3ed4, 0x3ed8, 0x3edc,
+    0x3ee0, 0x3ee3, 0x3ee7, 0x3eeb, 0x3eef, 0x3ef3, 0x3ef6, 0x3efa, 0x3efe, 0x3f02, 0x3f06, 0x3f0a,
+    0x3f0e, 0x3f12, 0x3f15, 0x3f19, 0x3f1d, 0x3f21, 0x3f25, 0x3f29, 0x3f2d, 0x3f31, 0x3f35, 0x3f39,
+    0x3f3d, 0x3f41, 0x3f45, 0x3f49, 0x3f4d, 0x3f51, 0x3f55, 0x3f59, 0x3f5d, 0x3f61, 0x3f65, 0x3f69,
+    0x3f6d, 0x3f71, 0x3f75, 0x3f79, 0x3f7e, 0x3f82, 0x3f86, 0x3f8a, 0x3f8e, 0x3f92, 0x3f96, 0x3f9b,
+    0x3f9f, 0x3fa3, 0x3fa7, 0x3fab, 0x3fb0, 0x3fb4, 0x3fb8, 0x3fbc, 0x3fc1, 0x3fc5, 0x3fc9, 0x3fcd,
+    0x3fd2, 0x3fd6, 0x3fda, 0x3fdf, 0x3fe3, 0x3fe7, 0x3fec, 0x3ff0, 0x3ff4, 0x3ff9, 0x3ffd, 0x4001,
+    0x4003, 0x4005, 0x4007, 0x400a, 0x400c, 0x400e, 0x4010, 0x4013, 0x4015, 0x4017, 0x4019, 0x401c,
+    0x401e, 0x4020, 0x4022, 0x4025, 0x4027, 0x4029, 0x402c, 0x402e, 0x4030, 0x4032, 0x4035, 0x4037,
+    0x4039, 0x403c, 0x403e, 0x4040, 0x4043, 0x4045, 0x4047, 0x404a, 0x404c, 0x404e, 0x4051, 0x4053,
+    0x4056, 0x4058, 0x405a, 0x405d, 0x405f, 0x4062, 0x4064, 0x4066, 0x4069, 0x406b, 0x406e, 0x4070,
+    0x4073, 0x4075, 0x4077, 0x407a, 0x407c, 0x407f, 0x4081, 0x4084, 0x4086, 0x4089, 0x408b, 0x408e,
+    0x4090, 0x4093, 0x4095, 0x4098, 0x409a, 0x409d, 0x409f, 0x40a2, 0x40a5, 0x40a7, 0x40aa, 0x40ac,
+    0x40af, 0x40b1, 0x40b4, 0x40b7, 0x40b9, 0x40bc, 0x40be, 0x40c1, 0x40c4, 0x40c6, 0x40c9, 0x40cc,
+    0x40ce, 0x40d1, 0x40d3, 0x40d6, 0x40d9, 0x40db, 0x40de, 0x40e1, 0x40e3, 0x40e6, 0x40e9, 0x40ec,
+    0x40ee, 0x40f1, 0x40f4, 0x40f6, 0x40f9, 0x40fc, 0x40ff, 0x4101, 0x4104, 0x4107, 0x410a, 0x410c,
+    0x410f, 0x4112, 0x4115, 0x4118, 0x411a, 0x411d, 0x4120, 0x4123, 0x4126, 0x4129, 0x412b, 0x412e,
+    0x4131, 0x4134, 0x4137, 0x413a, 0x413d, 0x413f, 0x4142, 0x4145, 0x4148, 0x414b, 0x414e, 0x4151,
+    0x4154, 0x4157, 0x415a, 0x415d, 0x4160, 0x4163, 0x4165, 0x4168, 0x416b, 0x416e, 0x4171, 0x4174,
+    0x4177, 0x417a, 0x417d, 0x4180, 0x4183, 0x4187, 0x418a, 0x418d, 0x4190, 0x4193, 0x4196, 0x4199,
+    0x419c, 0x419f, 0x41a2, 0x41a5, 0x41a8, 0x41ab, 0x41af, 0x41b2, 0x41b5, 0x41b8, 0x41bb, 0x41be,
+    0x41c1, 0x41c5, 0x41c8, 0x41cb, 0x41ce, 0x41d1, 0x41d5, 0x41d8, 0x41db, 0x41de, 0x41e1, 0x41e5,
+    0x41e8, 0x41eb, 0x41ee, 0x41f2, 0x41f5, 0x41f8, 0x41fc, 0x41ff, 0x4202, 0x4205, 0x4209, 0x420c,
+    0x420f, 0x4213, 0x4216, 0x4219, 0x421d, 0x4220, 0x4224, 0x4227, 0x422a, 0x422e, 0x4231, 0x4235,
+    0x4238, 0x423b, 0x423f, 0x4242, 0x4246, 0x4249, 0x424d, 0x4250, 0x4254, 0x4257, 0x425a, 0x425e,
+    0x4262, 0x4265, 0x4269, 0x426c, 0x4270, 0x4273, 0x4277, 0x427a, 0x427e, 0x4281, 0x4285, 0x4289,
+    0x428c, 0x4290, 0x4293, 0x4297, 0x429b, 0x429e, 0x42a2, 0x42a6, 0x42a9, 0x42ad, 0x42b1, 0x42b4,
+    0x42b8, 0x42bc, 0x42bf, 0x42c3, 0x42c7, 0x42cb, 0x42ce, 0x42d2, 0x42d6, 0x42da, 0x42dd, 0x42e1,
+    0x42e5, 0x42e9, 0x42ec, 0x42f0, 0x42f4, 0x42f8, 0x42fc, 0x4300, 0x4303, 0x4307, 0x430b, 0x430f,
+    0x4313, 0x4317, 0x431b, 0x431f, 0x4323, 0x4327, 0x432a, 0x432e, 0x4332, 0x4336, 0x433a, 0x433e,
+    0x4342, 0x4346, 0x434a, 0x434e, 0x4352, 0x4356, 0x435a, 0x435e, 0x4362, 0x4367, 0x436b, 0x436f,
+    0x4373, 0x4377, 0x437b, 0x437f, 0x4383, 0x4387, 0x438c, 0x4390, 0x4394, 0x4398, 0x439c, 0x43a0,
+    0x43a5, 0x43a9, 0x43ad, 0x43b1, 0x43b5, 0x43ba, 0x43be, 0x43c2, 0x43c6, 0x43cb, 0x43cf, 0x43d3,
+    0x43d8, 0x43dc, 0x43e0, 0x43e5, 0x43e9, 0x43ed, 0x43f2, 0x43f6, 0x43fa, 0x43ff, 0x4402, 0x4404,
+    0x4406, 0x4408, 0x440a, 0x440d, 0x440f, 0x4411, 0x4413, 0x4416, 0x4418, 0x441a, 0x441c, 0x441f,
+    0x4421, 0x4423, 0x4426, 0x4428, 0x442a, 0x442c, 0x442f, 0x4431, 0x4433, 0x4436, 0x4438, 0x443a,
+    0x443d, 0x443f, 0x4441, 0x4444, 0x4446, 0x4448, 0x444b, 0x444d, 0x444f, 0x4452, 0x4454, 0x4456,
+    0x4459, 0x445b, 0x445e, 0x4460, 0x4462, 0x4465, 0x4467, 0x446a, 0x446c, 0x446f, 0x4471, 0x4473,
+    0x4476, 0x4478, 0x447b, 0x447d, 0x4480, 0x4482, 0x4485, 0x4487, 0x448a, 0x448c, 0x448f, 0x4491,
+    0x4494, 0x4496, 0x4499, 0x449b, 0x449e, 0x44a0, 0x44a3, 0x44a6, 0x44a8, 0x44ab, 0x44ad, 0x44b0,
+    0x44b2, 0x44b5, 0x44b8, 0x44ba, 0x44bd, 0x44bf, 0x44c2, 0x44c5, 0x44c7, 0x44ca, 0x44cc, 0x44cf,
+    0x44d2, 0x44d4, 0x44d7, 0x44da, 0x44dc, 0x44df, 0x44e2, 0x44e4, 0x44e7, 0x44ea, 0x44ed, 0x44ef,
+    0x44f2, 0x44f5, 0x44f7, 0x44fa, 0x44fd, 0x4500, 0x4502, 0x4505, 0x4508, 0x450b, 0x450d, 0x4510,
+    0x4513, 0x4516, 0x4519, 0x451b, 0x451e, 0x4521, 0x4524, 0x4527, 0x452a, 0x452c, 0x452f, 0x4532,
+    0x4535, 0x4538, 0x453b, 0x453e, 0x4540, 0x4543, 0x4546, 0x4549, 0x454c, 0x454f, 0x4552, 0x4555,
+    0x4558, 0x455b, 0x455e, 0x4561, 0x4564, 0x4567, 0x456a, 0x456d, 0x4570, 0x4573, 0x4576, 0x4579,
+    0x457c, 0x457f, 0x4582, 0x4585, 0x4588, 0x458b, 0x458e, 0x4591, 0x4594, 0x4597, 0x459a, 0x459d,
+    0x45a0, 0x45a3, 0x45a6, 0x45a9, 0x45ad, 0x45b0, 0x45b3, 0x45b6, 0x45b9, 0x45bc, 0x45bf, 0x45c3,
+    0x45c6, 0x45c9, 0x45cc, 0x45cf, 0x45d3, 0x45d6, 0x45d9, 0x45dc, 0x45df, 0x45e3, 0x45e6, 0x45e9,
+    0x45ec, 0x45f0, 0x45f3, 0x45f6, 0x45f9, 0x45fd, 0x4600, 0x4603, 0x4607, 0x460a, 0x460d, 0x4611,
+    0x4614, 0x4617, 0x461b, 0x461e, 0x4621, 0x4625, 0x4628, 0x462c, 0x462f, 0x4632, 0x4636, 0x4639,
+    0x463d, 0x4640, 0x4643, 0x4647, 0x464a, 0x464e, 0x4651, 0x4655, 0x4658, 0x465c, 0x465f, 0x4663,
+    0x4666, 0x466a, 0x466d, 0x4671, 0x4674, 0x4678, 0x467c, 0x467f, 0x4683, 0x4686, 0x468a, 0x468e,
+    0x4691, 0x4695, 0x4698, 0x469c, 0x46a0, 0x46a3, 0x46a7, 0x46ab, 0x46ae, 0x46b2, 0x46b6, 0x46b9,
+    0x46bd, 0x46c1, 0x46c4, 0x46c8, 0x46cc, 0x46d0, 0x46d3, 0x46d7, 0x46db, 0x46df, 0x46e2, 0x46e6,
+    0x46ea, 0x46ee, 0x46f2, 0x46f6, 0x46f9, 0x46fd, 0x4701, 0x4705, 0x4709, 0x470d, 0x4711, 0x4714,
+    0x4718, 0x471c, 0x4720, 0x4724, 0x4728, 0x472c, 0x4730, 0x4734, 0x4738, 0x473c, 0x4740, 0x4744,
+    0x4748, 0x474c, 0x4750, 0x4754, 0x4758, 0x475c, 0x4760, 0x4764, 0x4768, 0x476c, 0x4770, 0x4774,
+    0x4778, 0x477d, 0x4781, 0x4785, 0x4789, 0x478d, 0x4791, 0x4795, 0x479a, 0x479e, 0x47a2, 0x47a6,
+    0x47aa, 0x47af, 0x47b3, 0x47b7, 0x47bb, 0x47c0, 0x47c4, 0x47c8, 0x47cc, 0x47d1, 0x47d5, 0x47d9,
+    0x47de, 0x47e2, 0x47e6, 0x47eb, 0x47ef, 0x47f3, 0x47f8, 0x47fc, 0x4800, 0x4802, 0x4805, 0x4807,
+    0x4809, 0x480b, 0x480e, 0x4810, 0x4812, 0x4814, 0x4816, 0x4819, 0x481b, 0x481d, 0x4820, 0x4822,
+    0x4824, 0x4826, 0x4829, 0x482b, 0x482d, 0x4830, 0x4832, 0x4834, 0x4836, 0x4839, 0x483b, 0x483d,
+    0x4840, 0x4842, 0x4844, 0x4847, 0x4849, 0x484c, 0x484e, 0x4850, 0x4853, 0x4855, 0x4857, 0x485a,
+    0x485c, 0x485f, 0x4861, 0x4863, 0x4866, 0x4868, 0x486b, 0x486d, 0x4870, 0x4872, 0x4874, 0x4877,
+    0x4879, 0x487c, 0x487e, 0x4881, 0x4883, 0x4888, 0x488d, 0x4892, 0x4897, 0x489c, 0x48a1, 0x48a6,
+    0x48ac, 0x48b1, 0x48b6, 0x48bb, 0x48c0, 0x48c6, 0x48cb, 0x48d0, 0x48d5, 0x48db, 0x48e0, 0x48e5,
+    0x48eb, 0x48f0, 0x48f6, 0x48fb, 0x4901, 0x4906, 0x490c, 0x4911, 0x4917, 0x491d, 0x4922, 0x4928,
+    0x492d, 0x4933, 0x4939, 0x493f, 0x4944, 0x494a, 0x4950, 0x4956, 0x495c, 0x4962, 0x4968, 0x496e,
+    0x4974, 0x497a, 0x4980, 0x4986, 0x498c, 0x4992, 0x4998, 0x499e, 0x49a4, 0x49ab, 0x49b1, 0x49b7,
+    0x49bd, 0x49c4, 0x49ca, 0x49d1, 0x49d7, 0x49dd, 0x49e4, 0x49ea, 0x49f1, 0x49f7, 0x49fe, 0x4a05,
+    0x4a0b, 0x4a12, 0x4a19, 0x4a1f, 0x4a26, 0x4a2d, 0x4a34, 0x4a3a, 0x4a41, 0x4a48, 0x4a4f, 0x4a56,
+    0x4a5d, 0x4a64, 0x4a6b, 0x4a72, 0x4a79, 0x4a80, 0x4a88, 0x4a8f, 0x4a96, 0x4a9d, 0x4aa5, 0x4aac,
+    0x4ab3, 0x4abb, 0x4ac2, 0x4aca, 0x4ad1, 0x4ad9, 0x4ae0, 0x4ae8, 0x4aef, 0x4af7, 0x4aff, 0x4b06,
+    0x4b0e, 0x4b16, 0x4b1e, 0x4b25, 0x4b2d, 0x4b35, 0x4b3d, 0x4b45, 0x4b4d, 0x4b55, 0x4b5d, 0x4b65,
+    0x4b6e, 0x4b76, 0x4b7e, 0x4b86, 0x4b8f, 0x4b97, 0x4b9f, 0x4ba8, 0x4bb0, 0x4bb9, 0x4bc1, 0x4bca,
+    0x4bd2, 0x4bdb, 0x4be4, 0x4bec, 0x4bf5, 0x4bfe, 0x4c03, 0x4c08, 0x4c0c, 0x4c11, 0x4c15, 0x4c1a,
+    0x4c1e, 0x4c23, 0x4c27, 0x4c2c, 0x4c30, 0x4c35, 0x4c3a, 0x4c3e, 0x4c43, 0x4c48, 0x4c4c, 0x4c51,
+    0x4c56, 0x4c5b, 0x4c5f, 0x4c64, 0x4c69, 0x4c6e, 0x4c73, 0x4c78, 0x4c7d, 0x4c82, 0x4c87, 0x4c8c,
+    0x4c91, 0x4c96, 0x4c9b, 0x4ca0, 0x4ca5, 0x4caa, 0x4caf, 0x4cb4, 0x4cb9, 0x4cbf, 0x4cc4, 0x4cc9,
+    0x4cce, 0x4cd4, 0x4cd9, 0x4cde, 0x4ce4, 0x4ce9, 0x4cef, 0x4cf4, 0x4cf9, 0x4cff, 0x4d04, 0x4d0a,
+    0x4d10, 0x4d15, 0x4d1b, 0x4d20, 0x4d26, 0x4d2c, 0x4d31, 0x4d37, 0x4d3d, 0x4d43, 0x4d48, 0x4d4e,
+    0x4d54, 0x4d5a, 0x4d60, 0x4d66, 0x4d6c, 0x4d72, 0x4d78, 0x4d7e, 0x4d84, 0x4d8a, 0x4d90, 0x4d96,
+    0x4d9c, 0x4da2, 0x4da9, 0x4daf, 0x4db5, 0x4dbb, 0x4dc2, 0x4dc8, 0x4dcf, 0x4dd5, 0x4ddb, 0x4de2,
+    0x4de8, 0x4def, 0x4df5, 0x4dfc, 0x4e03, 0x4e09, 0x4e10, 0x4e16, 0x4e1d, 0x4e24, 0x4e2b, 0x4e31,
+    0x4e38, 0x4e3f, 0x4e46, 0x4e4d, 0x4e54, 0x4e5b, 0x4e62, 0x4e69, 0x4e70, 0x4e77, 0x4e7e, 0x4e85,
+    0x4e8d, 0x4e94, 0x4e9b, 0x4ea2, 0x4eaa, 0x4eb1, 0x4eb8, 0x4ec0, 0x4ec7, 0x4ecf, 0x4ed6, 0x4ede,
+    0x4ee5, 0x4eed, 0x4ef5, 0x4efc, 0x4f04, 0x4f0c, 0x4f13, 0x4f1b, 0x4f23, 0x4f2b, 0x4f33, 0x4f3b,
+    0x4f43, 0x4f4b, 0x4f53, 0x4f5b, 0x4f63, 0x4f6b, 0x4f73, 0x4f7b, 0x4f84, 0x4f8c, 0x4f94, 0x4f9d,
+    0x4fa5, 0x4fad, 0x4fb6, 0x4fbe, 0x4fc7, 0x4fd0, 0x4fd8, 0x4fe1, 0x4fe9, 0x4ff2, 0x4ffb, 0x5002,
+    0x5006, 0x500b, 0x500f, 0x5014, 0x5018, 0x501d, 0x5021, 0x5026, 0x502a, 0x502f, 0x5034, 0x5038,
+    0x503d, 0x5041, 0x5046, 0x504b, 0x5050, 0x5054, 0x5059, 0x505e, 0x5063, 0x5068, 0x506c, 0x5071,
+    0x5076, 0x507b, 0x5080, 0x5085, 0x508a, 0x508f, 0x5094, 0x5099, 0x509e, 0x50a3, 0x50a8, 0x50ae,
+    0x50b3, 0x50b8, 0x50bd, 0x50c2, 0x50c8, 0x50cd, 0x50d2, 0x50d7, 0x50dd, 0x50e2, 0x50e7, 0x50ed,
+    0x50f2, 0x50f8, 0x50fd, 0x5103, 0x5108, 0x510e, 0x5113, 0x5119, 0x511f, 0x5124, 0x512a, 0x5130,
+    0x5135, 0x513b, 0x5141, 0x5147, 0x514c, 0x5152, 0x5158, 0x515e, 0x5164, 0x516a, 0x5170, 0x5176,
+    0x517c, 0x5182, 0x5188, 0x518e, 0x5194, 0x519a, 0x51a1, 0x51a7, 0x51ad, 0x51b3, 0x51ba, 0x51c0,
+    0x51c6, 0x51cd, 0x51d3, 0x51d9, 0x51e0, 0x51e6, 0x51ed, 0x51f3, 0x51fa, 0x5200, 0x5207, 0x520e,
+    0x5214, 0x521b, 0x5222, 0x5229, 0x522f, 0x5236, 0x523d, 0x5244, 0x524b, 0x5252, 0x5259, 0x5260,
+    0x5267, 0x526e, 0x5275, 0x527c, 0x5283, 0x528a, 0x5292, 0x5299, 0x52a0, 0x52a7, 0x52af, 0x52b6,
+    0x52bd, 0x52c5, 0x52cc, 0x52d4, 0x52db, 0x52e3, 0x52ea, 0x52f2, 0x52fa, 0x5301, 0x5309, 0x5311,
+    0x5319, 0x5321, 0x5328, 0x5330, 0x5338, 0x5340, 0x5348, 0x5350, 0x5358, 0x5360, 0x5369, 0x5371,
+    0x5379, 0x5381, 0x5389, 0x5392, 0x539a, 0x53a2, 0x53ab, 0x53b3, 0x53bc, 0x53c4, 0x53cd, 0x53d5,
+    0x53de, 0x53e7, 0x53ef, 0x53f8, 0x5401, 0x5405, 0x5409, 0x540e, 0x5412, 0x5417, 0x541b, 0x5420,
+    0x5424, 0x5429, 0x542d, 0x5432, 0x5437, 0x543b, 0x5440, 0x5445, 0x5449, 0x544e, 0x5453, 0x5458,
+    0x545c, 0x5461, 0x5466, 0x546b, 0x5470, 0x5475, 0x547a, 0x547f, 0x5483, 0x5488, 0x548d, 0x5492,
+    0x5498, 0x549d, 0x54a2, 0x54a7, 0x54ac, 0x54b1, 0x54b6, 0x54bb, 0x54c1, 0x54c6, 0x54cb, 0x54d0,
+    0x54d6, 0x54db, 0x54e0, 0x54e6, 0x54eb, 0x54f1, 0x54f6, 0x54fc, 0x5501, 0x5507, 0x550c, 0x5512,
+    0x5517, 0x551d, 0x5522, 0x5528, 0x552e, 0x5534, 0x5539, 0x553f, 0x5545, 0x554b, 0x5550, 0x5556,
+    0x555c, 0x5562, 0x5568, 0x556e, 0x5574, 0x557a, 0x5580, 0x5586, 0x558c, 0x5592, 0x5598, 0x559f,
+    0x55a5, 0x55ab, 0x55b1, 0x55b8, 0x55be, 0x55c4, 0x55cb, 0x55d1, 0x55d7, 0x55de, 0x55e4, 0x55eb,
+    0x55f1, 0x55f8, 0x55fe, 0x5605, 0x560c, 0x5612, 0x5619, 0x5620, 0x5626, 0x562d, 0x5634, 0x563b,
+    0x5642, 0x5649, 0x5650, 0x5657, 0x565d, 0x5665, 0x566c, 0x5673, 0x567a, 0x5681, 0x5688, 0x568f,
+    0x5696, 0x569e, 0x56a5, 0x56ac, 0x56b4, 0x56bb, 0x56c3, 0x56ca, 0x56d1, 0x56d9, 0x56e1, 0x56e8,
+    0x56f0, 0x56f7, 0x56ff, 0x5707, 0x570f, 0x5716, 0x571e, 0x5726, 0x572e, 0x5736, 0x573e, 0x5746,
+    0x574e, 0x5756, 0x575e, 0x5766, 0x576e, 0x5776, 0x577f, 0x5787, 0x578f, 0x5797, 0x57a0, 0x57a8,
+    0x57b1, 0x57b9, 0x57c2, 0x57ca, 0x57d3, 0x57db, 0x57e4, 0x57ed, 0x57f5, 0x57fe, 0x5804, 0x5808,
+    0x580c, 0x5811, 0x5815, 0x581a, 0x581e, 0x5823, 0x5827, 0x582c, 0x5831, 0x5835, 0x583a, 0x583f,
+    0x5843, 0x5848, 0x584d, 0x5851, 0x5856, 0x585b, 0x5860, 0x5865, 0x5869, 0x586e, 0x5873, 0x5878,
+    0x587d, 0x5882, 0x5887, 0x588c, 0x5891, 0x5896, 0x589b, 0x58a0, 0x58a5, 0x58aa, 0x58af, 0x58b5,
+    0x58ba, 0x58bf, 0x58c4, 0x58c9, 0x58cf, 0x58d4, 0x58d9, 0x58df, 0x58e4, 0x58e9, 0x58ef, 0x58f4,
+    0x58fa, 0x58ff, 0x5905, 0x590a, 0x5910, 0x5915, 0x591b, 0x5921, 0x5926, 0x592c, 0x5932, 0x5937,
+    0x593d, 0x5943, 0x5949, 0x594f, 0x5954, 0x595a, 0x5960, 0x5966, 0x596c, 0x5972, 0x5978, 0x597e,
+    0x5984, 0x598a, 0x5990, 0x5996, 0x599d, 0x59a3, 0x59a9, 0x59af, 0x59b6, 0x59bc, 0x59c2, 0x59c9,
+    0x59cf, 0x59d5, 0x59dc, 0x59e2, 0x59e9, 0x59ef, 0x59f6, 0x59fc, 0x5a03, 0x5a0a, 0x5a10, 0x5a17,
+    0x5a1e, 0x5a24, 0x5a2b, 0x5a32, 0x5a39, 0x5a40, 0x5a46, 0x5a4d, 0x5a54, 0x5a5b, 0x5a62, 0x5a69,
+    0x5a70, 0x5a78, 0x5a7f, 0x5a86, 0x5a8d, 0x5a94, 0x5a9b, 0x5aa3, 0x5aaa, 0x5ab1, 0x5ab9, 0x5ac0,
+    0x5ac8, 0x5acf, 0x5ad7, 0x5ade, 0x5ae6, 0x5aed, 0x5af5, 0x5afd, 0x5b04, 0x5b0c, 0x5b14, 0x5b1c,
+    0x5b23, 0x5b2b, 0x5b33, 0x5b3b, 0x5b43, 0x5b4b, 0x5b53, 0x5b5b, 0x5b63, 0x5b6c, 0x5b74, 0x5b7c,
+    0x5b84, 0x5b8c, 0x5b95, 0x5b9d, 0x5ba6, 0x5bae, 0x5bb6, 0x5bbf, 0x5bc7, 0x5bd0, 0x5bd9, 0x5be1,
+    0x5bea, 0x5bf3, 0x5bfb, 0x5c02, 0x5c07, 0x5c0b, 0x5c0f, 0x5c14, 0x5c18, 0x5c1d, 0x5c21, 0x5c26,
+    0x5c2b, 0x5c2f, 0x5c34, 0x5c38, 0x5c3d, 0x5c42, 0x5c46, 0x5c4b, 0x5c50, 0x5c55, 0x5c59, 0x5c5e,
+    0x5c63, 0x5c68, 0x5c6d, 0x5c72, 0x5c77, 0x5c7b, 0x5c80, 0x5c85, 0x5c8a, 0x5c8f, 0x5c94, 0x5c99,
+    0x5c9e, 0x5ca4, 0x5ca9, 0x5cae, 0x5cb3, 0x5cb8, 0x5cbd, 0x5cc3, 0x5cc8, 0x5ccd, 0x5cd2, 0x5cd8,
+    0x5cdd, 0x5ce2, 0x5ce8, 0x5ced, 0x5cf3, 0x5cf8, 0x5cfe, 0x5d03, 0x5d09, 0x5d0e, 0x5d14, 0x5d19,
+    0x5d1f, 0x5d25, 0x5d2a, 0x5d30, 0x5d36, 0x5d3b, 0x5d41, 0x5d47, 0x5d4d, 0x5d53, 0x5d58, 0x5d5e,
+    0x5d64, 0x5d6a, 0x5d70, 0x5d76, 0x5d7c, 0x5d82, 0x5d88, 0x5d8e, 0x5d95, 0x5d9b, 0x5da1, 0x5da7,
+    0x5dad, 0x5db4, 0x5dba, 0x5dc0, 0x5dc7, 0x5dcd, 0x5dd3, 0x5dda, 0x5de0, 0x5de7, 0x5ded, 0x5df4,
+    0x5dfa, 0x5e01, 0x5e07, 0x5e0e, 0x5e15, 0x5e1b, 0x5e22, 0x5e29, 0x5e30, 0x5e37, 0x5e3d, 0x5e44,
+    0x5e4b, 0x5e52, 0x5e59, 0x5e60, 0x5e67, 0x5e6e, 0x5e75, 0x5e7c, 0x5e84, 0x5e8b, 0x5e92, 0x5e99,
+    0x5ea0, 0x5ea8, 0x5eaf, 0x5eb6, 0x5ebe, 0x5ec5, 0x5ecd, 0x5ed4, 0x5edc, 0x5ee3, 0x5eeb, 0x5ef3,
+    0x5efa, 0x5f02, 0x5f0a, 0x5f11, 0x5f19, 0x5f21, 0x5f29, 0x5f31, 0x5f39, 0x5f41, 0x5f49, 0x5f51,
+    0x5f59, 0x5f61, 0x5f69, 0x5f71, 0x5f79, 0x5f82, 0x5f8a, 0x5f92, 0x5f9b, 0x5fa3, 0x5fab, 0x5fb4,
+    0x5fbc, 0x5fc5, 0x5fcd, 0x5fd6, 0x5fdf, 0x5fe7, 0x5ff0, 0x5ff9, 0x6001, 0x6005, 0x600a, 0x600e,
+    0x6013, 0x6017, 0x601c, 0x6020, 0x6025, 0x6029, 0x602e, 0x6032, 0x6037, 0x603c, 0x6040, 0x6045,
+    0x604a, 0x604e, 0x6053, 0x6058, 0x605d, 0x6062, 0x6066, 0x606b, 0x6070, 0x6075, 0x607a, 0x607f,
+    0x6084, 0x6089, 0x608e, 0x6093, 0x6098, 0x609d, 0x60a2, 0x60a7, 0x60ac, 0x60b1, 0x60b7, 0x60bc,
+    0x60c1, 0x60c6, 0x60cb, 0x60d1, 0x60d6, 0x60db, 0x60e1, 0x60e6, 0x60eb, 0x60f1, 0x60f6, 0x60fc,
+    0x6101, 0x6107, 0x610c, 0x6112, 0x6118, 0x611d, 0x6123, 0x6128, 0x612e, 0x6134, 0x613a, 0x613f,
+    0x6145, 0x614b, 0x6151, 0x6157, 0x615d, 0x6162, 0x6168, 0x616e, 0x6174, 0x617a, 0x6180, 0x6186,
+    0x618d, 0x6193, 0x6199, 0x619f, 0x61a5, 0x61ab, 0x61b2, 0x61b8, 0x61be, 0x61cb, 0x61d8, 0x61e5,
+    0x61f2, 0x61ff, 0x620c, 0x6219, 0x6227, 0x6234, 0x6242, 0x6250, 0x625e, 0x626c, 0x627a, 0x6288,
+    0x6297, 0x62a5, 0x62b4, 0x62c3, 0x62d2, 0x62e1, 0x62f0, 0x62ff, 0x630f, 0x631f, 0x632e, 0x633e,
+    0x634e, 0x635e, 0x636f, 0x637f, 0x6390, 0x63a0, 0x63b1, 0x63c2, 0x63d3, 0x63e5, 0x63f6, 0x6404,
+    0x640d, 0x6416, 0x641f, 0x6428, 0x6431, 0x643a, 0x6444, 0x644d, 0x6456, 0x6460, 0x646a, 0x6473,
+    0x647d, 0x6487, 0x6491, 0x649b, 0x64a5, 0x64b0, 0x64ba, 0x64c5, 0x64cf, 0x64da, 0x64e4, 0x64ef,
+    0x64fa, 0x6505, 0x6510, 0x651b, 0x6527, 0x6532, 0x653e, 0x6549, 0x6555, 0x6561, 0x656c, 0x6578,
+    0x6585, 0x6591, 0x659d, 0x65a9, 0x65b6, 0x65c3, 0x65cf, 0x65dc, 0x65e9, 0x65f6, 0x6603, 0x6611,
+    0x661e, 0x662b, 0x6639, 0x6647, 0x6655, 0x6663, 0x6671, 0x667f, 0x668d, 0x669c, 0x66aa, 0x66b9,
+    0x66c8, 0x66d7, 0x66e6, 0x66f5, 0x6705, 0x6714, 0x6724, 0x6734, 0x6744, 0x6754, 0x6764, 0x6774,
+    0x6785, 0x6795, 0x67a6, 0x67b7, 0x67c8, 0x67d9, 0x67ea, 0x67fc, 0x6807, 0x6810, 0x6819, 0x6822,
+    0x682b, 0x6834, 0x683d, 0x6847, 0x6850, 0x685a, 0x6863, 0x686d, 0x6877, 0x6881, 0x688b, 0x6895,
+    0x689f, 0x68a9, 0x68b3, 0x68be, 0x68c8, 0x68d3, 0x68dd, 0x68e8, 0x68f3, 0x68fe, 0x6909, 0x6914,
+    0x691f, 0x692b, 0x6936, 0x6941, 0x694d, 0x6959, 0x6965, 0x6971, 0x697d, 0x6989, 0x6995, 0x69a1,
+    0x69ae, 0x69ba, 0x69c7, 0x69d4, 0x69e1, 0x69ee, 0x69fb, 0x6a08, 0x6a15, 0x6a23, 0x6a30, 0x6a3e,
+    0x6a4c, 0x6a5a, 0x6a68, 0x6a76, 0x6a84, 0x6a92, 0x6aa1, 0x6ab0, 0x6abe, 0x6acd, 0x6adc, 0x6aeb,
+    0x6afb, 0x6b0a, 0x6b1a, 0x6b29, 0x6b39, 0x6b49, 0x6b59, 0x6b69, 0x6b7a, 0x6b8a, 0x6b9b, 0x6bac,
+    0x6bbd, 0x6bce, 0x6bdf, 0x6bf0, 0x6c01, 0x6c0a, 0x6c13, 0x6c1c, 0x6c25, 0x6c2e, 0x6c37, 0x6c41,
+    0x6c4a, 0x6c53, 0x6c5d, 0x6c67, 0x6c70, 0x6c7a, 0x6c84, 0x6c8e, 0x6c98, 0x6ca2, 0x6cac, 0x6cb7,
+    0x6cc1, 0x6ccc, 0x6cd6, 0x6ce1, 0x6cec, 0x6cf7, 0x6d02, 0x6d0d, 0x6d18, 0x6d23, 0x6d2e, 0x6d3a,
+    0x6d45, 0x6d51, 0x6d5d, 0x6d69, 0x6d75, 0x6d81, 0x6d8d, 0x6d99, 0x6da5, 0x6db2, 0x6dbf, 0x6dcb,
+    0x6dd8, 0x6de5, 0x6df2, 0x6dff, 0x6e0c, 0x6e1a, 0x6e27, 0x6e35, 0x6e43, 0x6e50, 0x6e5e, 0x6e6c,
+    0x6e7b, 0x6e89, 0x6e97, 0x6ea6, 0x6eb5, 0x6ec3, 0x6ed2, 0x6ee1, 0x6ef1, 0x6f00, 0x6f0f, 0x6f1f,
+    0x6f2f, 0x6f3f, 0x6f4f, 0x6f5f, 0x6f6f, 0x6f7f, 0x6f90, 0x6fa1, 0x6fb2, 0x6fc3, 0x6fd4, 0x6fe5,
+    0x6ff6, 0x7004, 0x700d, 0x7016, 0x701f, 0x7028, 0x7031, 0x703a, 0x7044, 0x704d, 0x7057, 0x7060,
+    0x706a, 0x7074, 0x707e, 0x7087, 0x7091, 0x709c, 0x70a6, 0x70b0, 0x70ba, 0x70c5, 0x70cf, 0x70da,
+    0x70e5, 0x70f0, 0x70fa, 0x7105, 0x7111, 0x711c, 0x7127, 0x7132, 0x713e, 0x7149, 0x7155, 0x7161,
+    0x716d, 0x7179, 0x7185, 0x7191, 0x719d, 0x71aa, 0x71b6, 0x71c3, 0x71d0, 0x71dc, 0x71e9, 0x71f6,
+    0x7204, 0x7211, 0x721e, 0x722c, 0x7239, 0x7247, 0x7255, 0x7263, 0x7271, 0x727f, 0x728e, 0x729c,
+    0x72ab, 0x72ba, 0x72c8, 0x72d7, 0x72e7, 0x72f6, 0x7305, 0x7315, 0x7324, 0x7334, 0x7344, 0x7354,
+    0x7364, 0x7375, 0x7385, 0x7396, 0x73a6, 0x73b7, 0x73c8, 0x73da, 0x73eb, 0x73fc, 0x7407, 0x7410,
+    0x7419, 0x7422, 0x742b, 0x7434, 0x743e, 0x7447, 0x7450, 0x745a, 0x7464, 0x746d, 0x7477, 0x7481,
+    0x748b, 0x7495, 0x749f, 0x74a9, 0x74b4, 0x74be, 0x74c8, 0x74d3, 0x74de, 0x74e8, 0x74f3, 0x74fe,
+    0x7509, 0x7514, 0x7520, 0x752b, 0x7536, 0x7542, 0x754d, 0x7559, 0x7565, 0x7571, 0x757d, 0x7589,
+    0x7595, 0x75a2, 0x75ae, 0x75bb, 0x75c7, 0x75d4, 0x75e1, 0x75ee, 0x75fb, 0x7608, 0x7616, 0x7623,
+    0x7631, 0x763e, 0x764c, 0x765a, 0x7668, 0x7676, 0x7684, 0x7693, 0x76a1, 0x76b0, 0x76bf, 0x76ce,
+    0x76dd, 0x76ec, 0x76fb, 0x770b, 0x771a, 0x772a, 0x773a, 0x774a, 0x775a, 0x776a, 0x777a, 0x778b,
+    0x779b, 0x77ac, 0x77bd, 0x77ce, 0x77e0, 0x77f1, 0x7801, 0x780a, 0x7813, 0x781c, 0x7825, 0x782e,
+    0x7838, 0x7841, 0x784a, 0x7854, 0x785d, 0x7867, 0x7871, 0x787a, 0x7884, 0x788e, 0x7898, 0x78a3,
+    0x78ad, 0x78b7, 0x78c2, 0x78cc, 0x78d7, 0x78e1, 0x78ec, 0x78f7, 0x7902, 0x790d, 0x7918, 0x7923,
+    0x792f, 0x793a, 0x7946, 0x7951, 0x795d, 0x7969, 0x7975, 0x7981, 0x798d, 0x7999, 0x79a6, 0x79b2,
+    0x79bf, 0x79cc, 0x79d8, 0x79e5, 0x79f2, 0x79ff, 0x7a0d, 0x7a1a, 0x7a28, 0x7a35, 0x7a43, 0x7a51,
+    0x7a5f, 0x7a6d, 0x7a7b, 0x7a89, 0x7a98, 0x7aa6, 0x7ab5, 0x7ac4, 0x7ad3, 0x7ae2, 0x7af1, 0x7b00,
+    0x7b10, 0x7b1f, 0x7b2f, 0x7b3f, 0x7b4f, 0x7b5f, 0x7b70, 0x7b80, 0x7b91, 0x7ba1, 0x7bb2, 0x7bc3,
+    0x7bd4, 0x7be6, 0x7bf7, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x7c00,
+    0x7c00, 0x7c00, 0x7c00, 0x7c00, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001,
+    0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001,
+    0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001,
+    0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001,
+    0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001,
+    0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001,
+    0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001,
+    0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001,
+    0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001,
+    0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001,
+    0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001,
+    0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001,
+    0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001,
+    0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001,
+    0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001,
+    0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001,
+    0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001,
+    0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001,
+    0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001,
+    0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001,
+    0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001,
+    0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001,
+    0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001,
+    0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001,
+    0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001,
+    0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001,
+    0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001,
+    0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001,
+    0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001,
+    0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001,
+    0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001,
+    0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001,
+    0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001,
+    0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001,
+    0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001,
+    0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001,
+    0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001,
+    0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001,
+    0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001,
+    0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001,
+    0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001,
+    0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001,
+    0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001,
+    0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001,
+    0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001,
+    0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001,
+    0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001,
+    0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001,
+    0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001,
+    0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001,
+    0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001,
+    0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001,
+    0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001,
+    0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001,
+    0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001,
+    0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001,
+    0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001,
+    0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001,
+    0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001,
+    0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001,
+    0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001,
+    0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001, 0x8001,
+    0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002,
+    0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002,
+    0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002,
+    0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002,
+    0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002,
+    0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002,
+    0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002,
+    0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002,
+    0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002,
+    0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002,
+    0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002,
+    0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002,
+    0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002,
+    0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002,
+    0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002,
+    0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002,
+    0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002,
+    0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002,
+    0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002,
+    0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002,
+    0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002,
+    0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002,
+    0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002,
+    0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002,
+    0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002,
+    0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002,
+    0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002,
+    0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002,
+    0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8002, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003,
+    0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003,
+    0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003,
+    0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003,
+    0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003,
+    0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003,
+    0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003,
+    0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003,
+    0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003,
+    0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003,
+    0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003,
+    0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003,
+    0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003,
+    0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003,
+    0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003,
+    0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003,
+    0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003,
+    0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003,
+    0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003,
+    0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003,
+    0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003,
+    0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003,
+    0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003, 0x8003,
+    0x8003, 0x8003, 0x8003, 0x8003, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004,
+    0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004,
+    0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004,
+    0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004,
+    0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004,
+    0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004,
+    0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004,
+    0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004,
+    0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004,
+    0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004,
+    0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004,
+    0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004,
+    0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004,
+    0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004,
+    0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004, 0x8004,
+    0x8004, 0x8005, 0x8005, 0x8005, 0x8005, 0x8005, 0x8005, 0x8005, 0x8005, 0x8005, 0x8005, 0x8005,
+    0x8005, 0x8005, 0x8005, 0x8005, 0x8005, 0x8005, 0x8005, 0x8005, 0x8005, 0x8005, 0x8005, 0x8005,
+    0x8005, 0x8005, 0x8005, 0x8005, 0x8005, 0x8005, 0x8005, 0x8005, 0x8005, 0x8005, 0x8005, 0x8005,
+    0x8005, 0x8005, 0x8005, 0x8005, 0x8005, 0x8005, 0x8005, 0x8005, 0x8005, 0x8005, 0x8005, 0x8005,
+    0x8005, 0x8005, 0x8005, 0x8005, 0x8005, 0x8005, 0x8005, 0x8005, 0x8005, 0x8005, 0x8005, 0x8005,
+    0x8005, 0x8005, 0x8005, 0x8005, 0x8005, 0x8005, 0x8005, 0x8005, 0x8005, 0x8005, 0x8005, 0x8005,
+    0x8005, 0x8005, 0x8005, 0x8005, 0x8005, 0x8005, 0x8005, 0x8005, 0x8005, 0x8005, 0x8005, 0x8005,
+    0x8005, 0x8005, 0x8005, 0x8005, 0x8005, 0x8005, 0x8005, 0x8005, 0x8005, 0x8005, 0x8005, 0x8005,
+    0x8005, 0x8005, 0x8005, 0x8005, 0x8005, 0x8005, 0x8005, 0x8005, 0x8006, 0x8006, 0x8006, 0x8006,
+    0x8006, 0x8006, 0x8006, 0x8006, 0x8006, 0x8006, 0x8006, 0x8006, 0x8006, 0x8006, 0x8006, 0x8006,
+    0x8006, 0x8006, 0x8006, 0x8006, 0x8006, 0x8006, 0x8006, 0x8006, 0x8006, 0x8006, 0x8006, 0x8006,
+    0x8006, 0x8006, 0x8006, 0x8006, 0x8006, 0x8006, 0x8006, 0x8006, 0x8006, 0x8006, 0x8006, 0x8006,
+    0x8006, 0x8006, 0x8006, 0x8006, 0x8006, 0x8006, 0x8006, 0x8006, 0x8006, 0x8006, 0x8006, 0x8006,
+    0x8006, 0x8006, 0x8006, 0x8006, 0x8006, 0x8006, 0x8006, 0x8006, 0x8006, 0x8006, 0x8006, 0x8006,
+    0x8006, 0x8006, 0x8006, 0x8006, 0x8006, 0x8006, 0x8006, 0x8006, 0x8006, 0x8006, 0x8006, 0x8006,
+    0x8006, 0x8006, 0x8006, 0x8006, 0x8006, 0x8006, 0x8006, 0x8006, 0x8006, 0x8006, 0x8006, 0x8006,
+    0x8006, 0x8006, 0x8006, 0x8006, 0x8006, 0x8007, 0x8007, 0x8007, 0x8007, 0x8007, 0x8007, 0x8007,
+    0x8007, 0x8007, 0x8007, 0x8007, 0x8007, 0x8007, 0x8007, 0x8007, 0x8007, 0x8007, 0x8007, 0x8007,
+    0x8007, 0x8007, 0x8007, 0x8007, 0x8007, 0x8007, 0x8007, 0x8007, 0x8007, 0x8007, 0x8007, 0x8007,
+    0x8007, 0x8007, 0x8007, 0x8007, 0x8007, 0x8007, 0x8007, 0x8007, 0x8007, 0x8007, 0x8007, 0x8007,
+    0x8007, 0x8007, 0x8007, 0x8007, 0x8007, 0x8007, 0x8007, 0x8007, 0x8007, 0x8007, 0x8007, 0x8007,
+    0x8007, 0x8007, 0x8007, 0x8007, 0x8007, 0x8007, 0x8007, 0x8007, 0x8007, 0x8007, 0x8007, 0x8007,
+    0x8007, 0x8007, 0x8007, 0x8007, 0x8007, 0x8007, 0x8007, 0x8007, 0x8007, 0x8007, 0x8007, 0x8007,
+    0x8007, 0x8007, 0x8007, 0x8007, 0x8007, 0x8007, 0x8007, 0x8008, 0x8008, 0x8008, 0x8008, 0x8008,
+    0x8008, 0x8008, 0x8008, 0x8008, 0x8008, 0x8008, 0x8008, 0x8008, 0x8008, 0x8008, 0x8008, 0x8008,
+    0x8008, 0x8008, 0x8008, 0x8008, 0x8008, 0x8008, 0x8008, 0x8008, 0x8008, 0x8008, 0x8008, 0x8008,
+    0x8008, 0x8008, 0x8008, 0x8008, 0x8008, 0x8008, 0x8008, 0x8008, 0x8008, 0x8008, 0x8008, 0x8008,
+    0x8008, 0x8008, 0x8008, 0x8008, 0x8008, 0x8008, 0x8008, 0x8008, 0x8008, 0x8008, 0x8008, 0x8008,
+    0x8008, 0x8008, 0x8008, 0x8008, 0x8008, 0x8008, 0x8008, 0x8008, 0x8008, 0x8008, 0x8008, 0x8008,
+    0x8008, 0x8008, 0x8008, 0x8008, 0x8008, 0x8008, 0x8008, 0x8008, 0x8008, 0x8008, 0x8008, 0x8008,
+    0x8008, 0x8008, 0x8008, 0x8009, 0x8009, 0x8009, 0x8009, 0x8009, 0x8009, 0x8009, 0x8009, 0x8009,
+    0x8009, 0x8009, 0x8009, 0x8009, 0x8009, 0x8009, 0x8009, 0x8009, 0x8009, 0x8009, 0x8009, 0x8009,
+    0x8009, 0x8009, 0x8009, 0x8009, 0x8009, 0x8009, 0x8009, 0x8009, 0x8009, 0x8009, 0x8009, 0x8009,
+    0x8009, 0x8009, 0x8009, 0x8009, 0x8009, 0x8009, 0x8009, 0x8009, 0x8009, 0x8009, 0x8009, 0x8009,
+    0x8009, 0x8009, 0x8009, 0x8009, 0x8009, 0x8009, 0x8009, 0x8009, 0x8009, 0x8009, 0x8009, 0x8009,
+    0x8009, 0x8009, 0x8009, 0x8009, 0x8009, 0x8009, 0x8009, 0x8009, 0x8009, 0x8009, 0x8009, 0x8009,
+    0x8009, 0x8009, 0x8009, 0x8009, 0x8009, 0x8009, 0x800a, 0x800a, 0x800a, 0x800a, 0x800a, 0x800a,
+    0x800a, 0x800a, 0x800a, 0x800a, 0x800a, 0x800a, 0x800a, 0x800a, 0x800a, 0x800a, 0x800a, 0x800a,
+    0x800a, 0x800a, 0x800a, 0x800a, 0x800a, 0x800a, 0x800a, 0x800a, 0x800a, 0x800a, 0x800a, 0x800a,
+    0x800a, 0x800a, 0x800a, 0x800a, 0x800a, 0x800a, 0x800a, 0x800a, 0x800a, 0x800a, 0x800a, 0x800a,
+    0x800a, 0x800a, 0x800a, 0x800a, 0x800a, 0x800a, 0x800a, 0x800a, 0x800a, 0x800a, 0x800a, 0x800a,
+    0x800a, 0x800a, 0x800a, 0x800a, 0x800a, 0x800a, 0x800a, 0x800a, 0x800a, 0x800a, 0x800a, 0x800a,
+    0x800a, 0x800a, 0x800a, 0x800a, 0x800b, 0x800b, 0x800b, 0x800b, 0x800b, 0x800b, 0x800b, 0x800b,
+    0x800b, 0x800b, 0x800b, 0x800b, 0x800b, 0x800b, 0x800b, 0x800b, 0x800b, 0x800b, 0x800b, 0x800b,
+    0x800b, 0x800b, 0x800b, 0x800b, 0x800b, 0x800b, 0x800b, 0x800b, 0x800b, 0x800b, 0x800b, 0x800b,
+    0x800b, 0x800b, 0x800b, 0x800b, 0x800b, 0x800b, 0x800b, 0x800b, 0x800b, 0x800b, 0x800b, 0x800b,
+    0x800b, 0x800b, 0x800b, 0x800b, 0x800b, 0x800b, 0x800b, 0x800b, 0x800b, 0x800b, 0x800b, 0x800b,
+    0x800b, 0x800b, 0x800b, 0x800b, 0x800b, 0x800b, 0x800b, 0x800b, 0x800b, 0x800b, 0x800b, 0x800c,
+    0x800c, 0x800c, 0x800c, 0x800c, 0x800c, 0x800c, 0x800c, 0x800c, 0x800c, 0x800c, 0x800c, 0x800c,
+    0x800c, 0x800c, 0x800c, 0x800c, 0x800c, 0x800c, 0x800c, 0x800c, 0x800c, 0x800c, 0x800c, 0x800c,
+    0x800c, 0x800c, 0x800c, 0x800c, 0x800c, 0x800c, 0x800c, 0x800c, 0x800c, 0x800c, 0x800c, 0x800c,
+    0x800c, 0x800c, 0x800c, 0x800c, 0x800c, 0x800c, 0x800c, 0x800c, 0x800c, 0x800c, 0x800c, 0x800c,
+    0x800c, 0x800c, 0x800c, 0x800c, 0x800c, 0x800c, 0x800c, 0x800c, 0x800c, 0x800c, 0x800c, 0x800c,
+    0x800c, 0x800c, 0x800c, 0x800d, 0x800d, 0x800d, 0x800d, 0x800d, 0x800d, 0x800d, 0x800d, 0x800d,
+    0x800d, 0x800d, 0x800d, 0x800d, 0x800d, 0x800d, 0x800d, 0x800d, 0x800d, 0x800d, 0x800d, 0x800d,
+    0x800d, 0x800d, 0x800d, 0x800d, 0x800d, 0x800d, 0x800d, 0x800d, 0x800d, 0x800d, 0x800d, 0x800d,
+    0x800d, 0x800d, 0x800d, 0x800d, 0x800d, 0x800d, 0x800d, 0x800d, 0x800d, 0x800d, 0x800d, 0x800d,
+    0x800d, 0x800d, 0x800d, 0x800d, 0x800d, 0x800d, 0x800d, 0x800d, 0x800d, 0x800d, 0x800d, 0x800d,
+    0x800d, 0x800d, 0x800d, 0x800d, 0x800d, 0x800e, 0x800e, 0x800e, 0x800e, 0x800e, 0x800e, 0x800e,
+    0x800e, 0x800e, 0x800e, 0x800e, 0x800e, 0x800e, 0x800e, 0x800e, 0x800e, 0x800e, 0x800e, 0x800e,
+    0x800e, 0x800e, 0x800e, 0x800e, 0x800e, 0x800e, 0x800e, 0x800e, 0x800e, 0x800e, 0x800e, 0x800e,
+    0x800e, 0x800e, 0x800e, 0x800e, 0x800e, 0x800e, 0x800e, 0x800e, 0x800e, 0x800e, 0x800e, 0x800e,
+    0x800e, 0x800e, 0x800e, 0x800e, 0x800e, 0x800e, 0x800e, 0x800e, 0x800e, 0x800e, 0x800e, 0x800e,
+    0x800e, 0x800e, 0x800e, 0x800f, 0x800f, 0x800f, 0x800f, 0x800f, 0x800f, 0x800f, 0x800f, 0x800f,
+    0x800f, 0x800f, 0x800f, 0x800f, 0x800f, 0x800f, 0x800f, 0x800f, 0x800f, 0x800f, 0x800f, 0x800f,
+    0x800f, 0x800f, 0x800f, 0x800f, 0x800f, 0x800f, 0x800f, 0x800f, 0x800f, 0x800f, 0x800f, 0x800f,
+    0x800f, 0x800f, 0x800f, 0x800f, 0x800f, 0x800f, 0x800f, 0x800f, 0x800f, 0x800f, 0x800f, 0x800f,
+    0x800f, 0x800f, 0x800f, 0x800f, 0x800f, 0x800f, 0x800f, 0x800f, 0x800f, 0x800f, 0x800f, 0x800f,
+    0x8010, 0x8010, 0x8010, 0x8010, 0x8010, 0x8010, 0x8010, 0x8010, 0x8010, 0x8010, 0x8010, 0x8010,
+    0x8010, 0x8010, 0x8010, 0x8010, 0x8010, 0x8010, 0x8010, 0x8010, 0x8010, 0x8010, 0x8010, 0x8010,
+    0x8010, 0x8010, 0x8010, 0x8010, 0x8010, 0x8010, 0x8010, 0x8010, 0x8010, 0x8010, 0x8010, 0x8010,
+    0x8010, 0x8010, 0x8010, 0x8010, 0x8010, 0x8010, 0x8010, 0x8010, 0x8010, 0x8010, 0x8010, 0x8010,
+    0x8010, 0x8010, 0x8010, 0x8010, 0x8010, 0x8010, 0x8010, 0x8011, 0x8011, 0x8011, 0x8011, 0x8011,
+    0x8011, 0x8011, 0x8011, 0x8011, 0x8011, 0x8011, 0x8011, 0x8011, 0x8011, 0x8011, 0x8011, 0x8011,
+    0x8011, 0x8011, 0x8011, 0x8011, 0x8011, 0x8011, 0x8011, 0x8011, 0x8011, 0x8011, 0x8011, 0x8011,
+    0x8011, 0x8011, 0x8011, 0x8011, 0x8011, 0x8011, 0x8011, 0x8011, 0x8011, 0x8011, 0x8011, 0x8011,
+    0x8011, 0x8011, 0x8011, 0x8011, 0x8011, 0x8011, 0x8011, 0x8011, 0x8011, 0x8011, 0x8011, 0x8012,
+    0x8012, 0x8012, 0x8012, 0x8012, 0x8012, 0x8012, 0x8012, 0x8012, 0x8012, 0x8012, 0x8012, 0x8012,
+    0x8012, 0x8012, 0x8012, 0x8012, 0x8012, 0x8012, 0x8012, 0x8012, 0x8012, 0x8012, 0x8012, 0x8012,
+    0x8012, 0x8012, 0x8012, 0x8012, 0x8012, 0x8012, 0x8012, 0x8012, 0x8012, 0x8012, 0x8012, 0x8012,
+    0x8012, 0x8012, 0x8012, 0x8012, 0x8012, 0x8012, 0x8012, 0x8012, 0x8012, 0x8012, 0x8012, 0x8012,
+    0x8013, 0x8013, 0x8013, 0x8013, 0x8013, 0x8013, 0x8013, 0x8013, 0x8013, 0x8013, 0x8013, 0x8013,
+    0x8013, 0x8013, 0x8013, 0x8013, 0x8013, 0x8013, 0x8013, 0x8013, 0x8013, 0x8013, 0x8013, 0x8013,
+    0x8014, 0x8014, 0x8014, 0x8014, 0x8014, 0x8014, 0x8014, 0x8014, 0x8014, 0x8014, 0x8014, 0x8014,
+    0x8014, 0x8014, 0x8014, 0x8014, 0x8014, 0x8014, 0x8014, 0x8014, 0x8014, 0x8014, 0x8014, 0x8014,
+    0x8014, 0x8015, 0x8015, 0x8015, 0x8015, 0x8015, 0x8015, 0x8015, 0x8015, 0x8015, 0x8015, 0x8015,
+    0x8015, 0x8015, 0x8015, 0x8015, 0x8015, 0x8015, 0x8015, 0x8015, 0x8015, 0x8015, 0x8015, 0x8015,
+    0x8016, 0x8016, 0x8016, 0x8016, 0x8016, 0x8016, 0x8016, 0x8016, 0x8016, 0x8016, 0x8016, 0x8016,
+    0x8016, 0x8016, 0x8016, 0x8016, 0x8016, 0x8016, 0x8016, 0x8016, 0x8016, 0x8016, 0x8016, 0x8017,
+    0x8017, 0x8017, 0x8017, 0x8017, 0x8017, 0x8017, 0x8017, 0x8017, 0x8017, 0x8017, 0x8017, 0x8017,
+    0x8017, 0x8017, 0x8017, 0x8017, 0x8017, 0x8017, 0x8017, 0x8017, 0x8017, 0x8017, 0x8018, 0x8018,
+    0x8018, 0x8018, 0x8018, 0x8018, 0x8018, 0x8018, 0x8018, 0x8018, 0x8018, 0x8018, 0x8018, 0x8018,
+    0x8018, 0x8018, 0x8018, 0x8018, 0x8018, 0x8018, 0x8018, 0x8019, 0x8019, 0x8019, 0x8019, 0x8019,
+    0x8019, 0x8019, 0x8019, 0x8019, 0x8019, 0x8019, 0x8019, 0x8019, 0x8019, 0x8019, 0x8019, 0x8019,
+    0x8019, 0x8019, 0x8019, 0x8019, 0x8019, 0x801a, 0x801a, 0x801a, 0x801a, 0x801a, 0x801a, 0x801a,
+    0x801a, 0x801a, 0x801a, 0x801a, 0x801a, 0x801a, 0x801a, 0x801a, 0x801a, 0x801a, 0x801a, 0x801a,
+    0x801a, 0x801a, 0x801b, 0x801b, 0x801b, 0x801b, 0x801b, 0x801b, 0x801b, 0x801b, 0x801b, 0x801b,
+    0x801b, 0x801b, 0x801b, 0x801b, 0x801b, 0x801b, 0x801b, 0x801b, 0x801b, 0x801b, 0x801c, 0x801c,
+    0x801c, 0x801c, 0x801c, 0x801c, 0x801c, 0x801c, 0x801c, 0x801c, 0x801c, 0x801c, 0x801c, 0x801c,
+    0x801c, 0x801c, 0x801c, 0x801c, 0x801c, 0x801c, 0x801d, 0x801d, 0x801d, 0x801d, 0x801d, 0x801d,
+    0x801d, 0x801d, 0x801d, 0x801d, 0x801d, 0x801d, 0x801d, 0x801d, 0x801d, 0x801d, 0x801d, 0x801d,
+    0x801d, 0x801d, 0x801e, 0x801e, 0x801e, 0x801e, 0x801e, 0x801e, 0x801e, 0x801e, 0x801e, 0x801e,
+    0x801e, 0x801e, 0x801e, 0x801e, 0x801e, 0x801e, 0x801e, 0x801e, 0x801e, 0x801e, 0x801f, 0x801f,
+    0x801f, 0x801f, 0x801f, 0x801f, 0x801f, 0x801f, 0x801f, 0x801f, 0x801f, 0x801f, 0x801f, 0x801f,
+    0x801f, 0x801f, 0x801f, 0x801f, 0x801f, 0x8020, 0x8020, 0x8020, 0x8020, 0x8020, 0x8020, 0x8020,
+    0x8020, 0x8020, 0x8020, 0x8020, 0x8020, 0x8020, 0x8020, 0x8020, 0x8020, 0x8020, 0x8020, 0x8021,
+    0x8021, 0x8021, 0x8021, 0x8021, 0x8021, 0x8021, 0x8021, 0x8021, 0x8021, 0x8021, 0x8021, 0x8021,
+    0x8021, 0x8021, 0x8021, 0x8021, 0x8021, 0x8021, 0x8022, 0x8022, 0x8022, 0x8022, 0x8022, 0x8022,
+    0x8022, 0x8022, 0x8022, 0x8022, 0x8022, 0x8022, 0x8022, 0x8022, 0x8022, 0x8022, 0x8022, 0x8022,
+    0x8023, 0x8023, 0x8023, 0x8023, 0x8023, 0x8023, 0x8023, 0x8023, 0x8023, 0x8023, 0x8023, 0x8023,
+    0x8023, 0x8023, 0x8023, 0x8023, 0x8023, 0x8023, 0x8024, 0x8024, 0x8024, 0x8024, 0x8024, 0x8024,
+    0x8024, 0x8024, 0x8024, 0x8024, 0x8024, 0x8024, 0x8024, 0x8024, 0x8024, 0x8024, 0x8024, 0x8025,
+    0x8025, 0x8025, 0x8025, 0x8025, 0x8025, 0x8025, 0x8025, 0x8025, 0x8025, 0x8025, 0x8025, 0x8025,
+    0x8025, 0x8025, 0x8025, 0x8025, 0x8025, 0x8026, 0x8026, 0x8026, 0x8026, 0x8026, 0x8026, 0x8026,
+    0x8026, 0x8026, 0x8026, 0x8026, 0x8026, 0x8026, 0x8026, 0x8026, 0x8026, 0x8026, 0x8027, 0x8027,
+    0x8027, 0x8027, 0x8027, 0x8027, 0x8027, 0x8027, 0x8027, 0x8027, 0x8027, 0x8027, 0x8027, 0x8027,
+    0x8027, 0x8027, 0x8028, 0x8028, 0x8028, 0x8028, 0x8028, 0x8028, 0x8028, 0x8028, 0x8028, 0x8028,
+    0x8028, 0x8028, 0x8028, 0x8028, 0x8028, 0x8028, 0x8028, 0x8029, 0x8029, 0x8029, 0x8029, 0x8029,
+    0x8029, 0x8029, 0x8029, 0x8029, 0x8029, 0x8029, 0x8029, 0x8029, 0x8029, 0x8029, 0x8029, 0x802a,
+    0x802a, 0x802a, 0x802a, 0x802a, 0x802a, 0x802a, 0x802a, 0x802a, 0x802a, 0x802a, 0x802a, 0x802a,
+    0x802a, 0x802a, 0x802a, 0x802b, 0x802b, 0x802b, 0x802b, 0x802b, 0x802b, 0x802b, 0x802b, 0x802b,
+    0x802b, 0x802b, 0x802b, 0x802b, 0x802b, 0x802b, 0x802b, 0x802c, 0x802c, 0x802c, 0x802c, 0x802c,
+    0x802c, 0x802c, 0x802c, 0x802c, 0x802c, 0x802c, 0x802c, 0x802c, 0x802c, 0x802c, 0x802c, 0x802d,
+    0x802d, 0x802d, 0x802d, 0x802d, 0x802d, 0x802d, 0x802d, 0x802d, 0x802d, 0x802d, 0x802d, 0x802d,
+    0x802d, 0x802d, 0x802d, 0x802e, 0x802e, 0x802e, 0x802e, 0x802e, 0x802e, 0x802e, 0x802e, 0x802e,
+    0x802e, 0x802e, 0x802e, 0x802e, 0x802e, 0x802e, 0x802f, 0x802f, 0x802f, 0x802f, 0x802f, 0x802f,
+    0x802f, 0x802f, 0x802f, 0x802f, 0x802f, 0x802f, 0x802f, 0x802f, 0x802f, 0x8030, 0x8030, 0x8030,
+    0x8030, 0x8030, 0x8030, 0x8030, 0x8030, 0x8030, 0x8030, 0x8030, 0x8030, 0x8030, 0x8030, 0x8030,
+    0x8031, 0x8031, 0x8031, 0x8031, 0x8031, 0x8031, 0x8031, 0x8031, 0x8031, 0x8031, 0x8031, 0x8031,
+    0x8031, 0x8031, 0x8031, 0x8032, 0x8032, 0x8032, 0x8032, 0x8032, 0x8032, 0x8032, 0x8032, 0x8032,
+    0x8032, 0x8032, 0x8032, 0x8032, 0x8032, 0x8032, 0x8033, 0x8033, 0x8033, 0x8033, 0x8033, 0x8033,
+    0x8033, 0x8033, 0x8033, 0x8033, 0x8033, 0x8033, 0x8033, 0x8033, 0x8034, 0x8034, 0x8034, 0x8034,
+    0x8034, 0x8034, 0x8034, 0x8034, 0x8034, 0x8034, 0x8034, 0x8034, 0x8034, 0x8034, 0x8034, 0x8035,
+    0x8035, 0x8035, 0x8035, 0x8035, 0x8035, 0x8035, 0x8035, 0x8035, 0x8035, 0x8035, 0x8035, 0x8035,
+    0x8035, 0x8036, 0x8036, 0x8036, 0x8036, 0x8036, 0x8036, 0x8036, 0x8036, 0x8036, 0x8036, 0x8036,
+    0x8036, 0x8036, 0x8036, 0x8037, 0x8037, 0x8037, 0x8037, 0x8037, 0x8037, 0x8037, 0x8037, 0x8037,
+    0x8037, 0x8037, 0x8037, 0x8037, 0x8037, 0x8038, 0x8038, 0x8038, 0x8038, 0x8038, 0x8038, 0x8038,
+    0x8038, 0x8038, 0x8038, 0x8038, 0x8038, 0x8038, 0x8038, 0x8039, 0x8039, 0x8039, 0x8039, 0x8039,
+    0x8039, 0x8039, 0x8039, 0x8039, 0x8039, 0x8039, 0x8039, 0x8039, 0x803a, 0x803a, 0x803a, 0x803a,
+    0x803a, 0x803a, 0x803a, 0x803a, 0x803a, 0x803a, 0x803a, 0x803a, 0x803a, 0x803a, 0x803b, 0x803b,
+    0x803b, 0x803b, 0x803b, 0x803b, 0x803b, 0x803b, 0x803b, 0x803b, 0x803b, 0x803b, 0x803b, 0x803c,
+    0x803c, 0x803c, 0x803c, 0x803c, 0x803c, 0x803c, 0x803c, 0x803c, 0x803c, 0x803c, 0x803c, 0x803c,
+    0x803d, 0x803d, 0x803d, 0x803d, 0x803d, 0x803d, 0x803d, 0x803d, 0x803d, 0x803d, 0x803d, 0x803d,
+    0x803d, 0x803d, 0x803e, 0x803e, 0x803e, 0x803e, 0x803e, 0x803e, 0x803e, 0x803e, 0x803e, 0x803e,
+    0x803e, 0x803e, 0x803e, 0x803f, 0x803f, 0x803f, 0x803f, 0x803f, 0x803f, 0x803f, 0x803f, 0x803f,
+    0x803f, 0x803f, 0x803f, 0x803f, 0x8040, 0x8040, 0x8040, 0x8040, 0x8040, 0x8040, 0x8040, 0x8040,
+    0x8040, 0x8040, 0x8040, 0x8040, 0x8041, 0x8041, 0x8041, 0x8041, 0x8041, 0x8041, 0x8041, 0x8041,
+    0x8041, 0x8041, 0x8041, 0x8041, 0x8041, 0x8042, 0x8042, 0x8042, 0x8042, 0x8042, 0x8042, 0x8042,
+    0x8042, 0x8042, 0x8042, 0x8042, 0x8042, 0x8042, 0x8043, 0x8043, 0x8043, 0x8043, 0x8043, 0x8043,
+    0x8043, 0x8043, 0x8043, 0x8043, 0x8043, 0x8043, 0x8044, 0x8044, 0x8044, 0x8044, 0x8044, 0x8044,
+    0x8044, 0x8044, 0x8044, 0x8044, 0x8044, 0x8044, 0x8044, 0x8045, 0x8045, 0x8045, 0x8045, 0x8045,
+    0x8045, 0x8045, 0x8045, 0x8045, 0x8045, 0x8045, 0x8045, 0x8046, 0x8046, 0x8046, 0x8046, 0x8046,
+    0x8046, 0x8046, 0x8046, 0x8046, 0x8046, 0x8046, 0x8046, 0x8047, 0x8047, 0x8047, 0x8047, 0x8047,
+    0x8047, 0x8047, 0x8047, 0x8047, 0x8047, 0x8047, 0x8047, 0x8048, 0x8048, 0x8048, 0x8048, 0x8048,
+    0x8048, 0x8048, 0x8048, 0x8048, 0x8048, 0x8048, 0x8048, 0x8049, 0x8049, 0x8049, 0x8049, 0x8049,
+    0x8049, 0x8049, 0x8049, 0x8049, 0x8049, 0x8049, 0x8049, 0x804a, 0x804a, 0x804a, 0x804a, 0x804a,
+    0x804a, 0x804a, 0x804a, 0x804a, 0x804a, 0x804a, 0x804a, 0x804b, 0x804b, 0x804b, 0x804b, 0x804b,
+    0x804b, 0x804b, 0x804b, 0x804b, 0x804b, 0x804b, 0x804b, 0x804c, 0x804c, 0x804c, 0x804c, 0x804c,
+    0x804c, 0x804c, 0x804c, 0x804c, 0x804c, 0x804c, 0x804c, 0x804d, 0x804d, 0x804d, 0x804d, 0x804d,
+    0x804d, 0x804d, 0x804d, 0x804d, 0x804d, 0x804d, 0x804e, 0x804e, 0x804e, 0x804e, 0x804e, 0x804e,
+    0x804e, 0x804e, 0x804e, 0x804e, 0x804e, 0x804e, 0x804f, 0x804f, 0x804f, 0x804f, 0x804f, 0x804f,
+    0x804f, 0x804f, 0x804f, 0x804f, 0x804f, 0x8050, 0x8050, 0x8050, 0x8050, 0x8050, 0x8050, 0x8050,
+    0x8050, 0x8050, 0x8050, 0x8050, 0x8051, 0x8051, 0x8051, 0x8051, 0x8051, 0x8051, 0x8051, 0x8051,
+    0x8051, 0x8051, 0x8051, 0x8051, 0x8052, 0x8052, 0x8052, 0x8052, 0x8052, 0x8052, 0x8052, 0x8052,
+    0x8052, 0x8052, 0x8052, 0x8053, 0x8053, 0x8053, 0x8053, 0x8053, 0x8053, 0x8053, 0x8053, 0x8053,
+    0x8053, 0x8053, 0x8054, 0x8054, 0x8054, 0x8054, 0x8054, 0x8054, 0x8054, 0x8054, 0x8054, 0x8054,
+    0x8054, 0x8055, 0x8055, 0x8055, 0x8055, 0x8055, 0x8056, 0x8056, 0x8056, 0x8056, 0x8056, 0x8056,
+    0x8057, 0x8057, 0x8057, 0x8057, 0x8057, 0x8058, 0x8058, 0x8058, 0x8058, 0x8058, 0x8058, 0x8059,
+    0x8059, 0x8059, 0x8059, 0x8059, 0x805a, 0x805a, 0x805a, 0x805a, 0x805a, 0x805b, 0x805b, 0x805b,
+    0x805b, 0x805b, 0x805b, 0x805c, 0x805c, 0x805c, 0x805c, 0x805c, 0x805d, 0x805d, 0x805d, 0x805d,
+    0x805d, 0x805e, 0x805e, 0x805e, 0x805e, 0x805e, 0x805f, 0x805f, 0x805f, 0x805f, 0x805f, 0x8060,
+    0x8060, 0x8060, 0x8060, 0x8060, 0x8060, 0x8061, 0x8061, 0x8061, 0x8061, 0x8061, 0x8062, 0x8062,
+    0x8062, 0x8062, 0x8062, 0x8063, 0x8063, 0x8063, 0x8063, 0x8063, 0x8064, 0x8064, 0x8064, 0x8064,
+    0x8064, 0x8065, 0x8065, 0x8065, 0x8065, 0x8065, 0x8066, 0x8066, 0x8066, 0x8066, 0x8066, 0x8067,
+    0x8067, 0x8067, 0x8067, 0x8067, 0x8068, 0x8068, 0x8068, 0x8068, 0x8068, 0x8069, 0x8069, 0x8069,
+    0x8069, 0x8069, 0x806a, 0x806a, 0x806a, 0x806a, 0x806b, 0x806b, 0x806b, 0x806b, 0x806b, 0x806c,
+    0x806c, 0x806c, 0x806c, 0x806c, 0x806d, 0x806d, 0x806d, 0x806d, 0x806d, 0x806e, 0x806e, 0x806e,
+    0x806e, 0x806e, 0x806f, 0x806f, 0x806f, 0x806f, 0x8070, 0x8070, 0x8070, 0x8070, 0x8070, 0x8071,
+    0x8071, 0x8071, 0x8071, 0x8071, 0x8072, 0x8072, 0x8072, 0x8072, 0x8073, 0x8073, 0x8073, 0x8073,
+    0x8073, 0x8074, 0x8074, 0x8074, 0x8074, 0x8074, 0x8075, 0x8075, 0x8075, 0x8075, 0x8076, 0x8076,
+    0x8076, 0x8076, 0x8076, 0x8077, 0x8077, 0x8077, 0x8077, 0x8077, 0x8078, 0x8078, 0x8078, 0x8078,
+    0x8079, 0x8079, 0x8079, 0x8079, 0x8079, 0x807a, 0x807a, 0x807a, 0x807a, 0x807b, 0x807b, 0x807b,
+    0x807b, 0x807b, 0x807c, 0x807c, 0x807c, 0x807c, 0x807d, 0x807d, 0x807d, 0x807d, 0x807d, 0x807e,
+    0x807e, 0x807e, 0x807e, 0x807f, 0x807f, 0x807f, 0x807f, 0x8080, 0x8080, 0x8080, 0x8080, 0x8080,
+    0x8081, 0x8081, 0x8081, 0x8081, 0x8082, 0x8082, 0x8082, 0x8082, 0x8082, 0x8083, 0x8083, 0x8083,
+    0x8083, 0x8084, 0x8084, 0x8084, 0x8084, 0x8085, 0x8085, 0x8085, 0x8085, 0x8086, 0x8086, 0x8086,
+    0x8086, 0x8086, 0x8087, 0x8087, 0x8087, 0x8087, 0x8088, 0x8088, 0x8088, 0x8088, 0x8089, 0x8089,
+    0x8089, 0x8089, 0x8089, 0x808a, 0x808a, 0x808a, 0x808a, 0x808b, 0x808b, 0x808b, 0x808b, 0x808c,
+    0x808c, 0x808c, 0x808c, 0x808d, 0x808d, 0x808d, 0x808d, 0x808e, 0x808e, 0x808e, 0x808e, 0x808f,
+    0x808f, 0x808f, 0x808f, 0x808f, 0x8090, 0x8090, 0x8090, 0x8090, 0x8091, 0x8091, 0x8091, 0x8091,
+    0x8092, 0x8092, 0x8092, 0x8092, 0x8093, 0x8093, 0x8093, 0x8093, 0x8094, 0x8094, 0x8094, 0x8094,
+    0x8095, 0x8095, 0x8095, 0x8095, 0x8096, 0x8096, 0x8096, 0x8096, 0x8097, 0x8097, 0x8097, 0x8097,
+    0x8098, 0x8098, 0x8098, 0x8098, 0x8099, 0x8099, 0x8099, 0x8099, 0x809a, 0x809a, 0x809a, 0x809a,
+    0x809b, 0x809b, 0x809b, 0x809b, 0x809c, 0x809c, 0x809c, 0x809c, 0x809d, 0x809d, 0x809d, 0x809d,
+    0x809e, 0x809e, 0x809e, 0x809e, 0x809f, 0x809f, 0x809f, 0x809f, 0x80a0, 0x80a0, 0x80a0, 0x80a0,
+    0x80a1, 0x80a1, 0x80a1, 0x80a2, 0x80a2, 0x80a2, 0x80a2, 0x80a3, 0x80a3, 0x80a3, 0x80a3, 0x80a4,
+    0x80a4, 0x80a4, 0x80a4, 0x80a5, 0x80a5, 0x80a5, 0x80a5, 0x80a6, 0x80a6, 0x80a6, 0x80a6, 0x80a7,
+    0x80a7, 0x80a7, 0x80a8, 0x80a8, 0x80a8, 0x80a8, 0x80a9, 0x80a9, 0x80a9, 0x80a9, 0x80aa, 0x80aa,
+    0x80aa, 0x80aa, 0x80ab, 0x80ab, 0x80ab, 0x80ab, 0x80ac, 0x80ac, 0x80ac, 0x80ad, 0x80ad, 0x80ad,
+    0x80ad, 0x80ae, 0x80ae, 0x80ae, 0x80ae, 0x80af, 0x80af, 0x80af, 0x80b0, 0x80b0, 0x80b0, 0x80b0,
+    0x80b1, 0x80b1, 0x80b1, 0x80b1, 0x80b2, 0x80b2, 0x80b2, 0x80b3, 0x80b3, 0x80b3, 0x80b3, 0x80b4,
+    0x80b4, 0x80b4, 0x80b4, 0x80b5, 0x80b5, 0x80b5, 0x80b6, 0x80b6, 0x80b6, 0x80b6, 0x80b7, 0x80b7,
+    0x80b7, 0x80b7, 0x80b8, 0x80b8, 0x80b8, 0x80b9, 0x80b9, 0x80b9, 0x80b9, 0x80ba, 0x80ba, 0x80ba,
+    0x80bb, 0x80bb, 0x80bb, 0x80bb, 0x80bc, 0x80bc, 0x80bc, 0x80bc, 0x80bd, 0x80bd, 0x80bd, 0x80be,
+    0x80be, 0x80be, 0x80be, 0x80bf, 0x80bf, 0x80bf, 0x80c0, 0x80c0, 0x80c0, 0x80c0, 0x80c1, 0x80c1,
+    0x80c1, 0x80c2, 0x80c2, 0x80c2, 0x80c2, 0x80c3, 0x80c3, 0x80c3, 0x80c4, 0x80c4, 0x80c4, 0x80c4,
+    0x80c5, 0x80c5, 0x80c5, 0x80c6, 0x80c6, 0x80c6, 0x80c6, 0x80c7, 0x80c7, 0x80c7, 0x80c8, 0x80c8,
+    0x80c8, 0x80c8, 0x80c9, 0x80c9, 0x80c9, 0x80ca, 0x80ca, 0x80ca, 0x80cb, 0x80cb, 0x80cb, 0x80cb,
+    0x80cc, 0x80cc, 0x80cc, 0x80cd, 0x80cd, 0x80cd, 0x80cd, 0x80ce, 0x80ce, 0x80ce, 0x80cf, 0x80cf,
+    0x80cf, 0x80d0, 0x80d0, 0x80d0, 0x80d0, 0x80d1, 0x80d1, 0x80d1, 0x80d2, 0x80d2, 0x80d2, 0x80d3,
+    0x80d3, 0x80d3, 0x80d3, 0x80d4, 0x80d4, 0x80d4, 0x80d5, 0x80d5, 0x80d5, 0x80d6, 0x80d6, 0x80d6,
+    0x80d6, 0x80d7, 0x80d7, 0x80d7, 0x80d8, 0x80d8, 0x80d8, 0x80d9, 0x80d9, 0x80d9, 0x80d9, 0x80da,
+    0x80da, 0x80da, 0x80db, 0x80db, 0x80db, 0x80dc, 0x80dc, 0x80dc, 0x80dc, 0x80dd, 0x80dd, 0x80dd,
+    0x80de, 0x80de, 0x80de, 0x80df, 0x80df, 0x80df, 0x80e0, 0x80e0, 0x80e0, 0x80e0, 0x80e1, 0x80e1,
+    0x80e1, 0x80e2, 0x80e2, 0x80e2, 0x80e3, 0x80e3, 0x80e3, 0x80e4, 0x80e4, 0x80e4, 0x80e5, 0x80e5,
+    0x80e5, 0x80e5, 0x80e6, 0x80e6, 0x80e6, 0x80e7, 0x80e7, 0x80e7, 0x80e8, 0x80e8, 0x80e8, 0x80e9,
+    0x80e9, 0x80e9, 0x80ea, 0x80ea, 0x80ea, 0x80eb, 0x80eb, 0x80eb, 0x80eb, 0x80ec, 0x80ec, 0x80ec,
+    0x80ed, 0x80ed, 0x80ed, 0x80ee, 0x80ee, 0x80ee, 0x80ef, 0x80ef, 0x80ef, 0x80f0, 0x80f0, 0x80f0,
+    0x80f1, 0x80f1, 0x80f1, 0x80f2, 0x80f2, 0x80f2, 0x80f2, 0x80f3, 0x80f3, 0x80f3, 0x80f4, 0x80f4,
+    0x80f4, 0x80f5, 0x80f5, 0x80f5, 0x80f6, 0x80f6, 0x80f6, 0x80f7, 0x80f7, 0x80f7, 0x80f8, 0x80f8,
+    0x80f8, 0x80f9, 0x80f9, 0x80f9, 0x80fa, 0x80fa, 0x80fa, 0x80fb, 0x80fb, 0x80fb, 0x80fc, 0x80fc,
+    0x80fc, 0x80fd, 0x80fd, 0x80fd, 0x80fe, 0x80fe, 0x80fe, 0x80ff, 0x80ff, 0x80ff, 0x8100, 0x8100,
+    0x8100, 0x8101, 0x8101, 0x8101, 0x8102, 0x8102, 0x8102, 0x8103, 0x8103, 0x8103, 0x8104, 0x8104,
+    0x8104, 0x8105, 0x8105, 0x8105, 0x8106, 0x8106, 0x8106, 0x8107, 0x8107, 0x8107, 0x8108, 0x8108,
+    0x8108, 0x8109, 0x8109, 0x8109, 0x810a, 0x810a, 0x810a, 0x810b, 0x810b, 0x810b, 0x810c, 0x810c,
+    0x810c, 0x810d, 0x810d, 0x810d, 0x810e, 0x810e, 0x810e, 0x810f, 0x810f, 0x810f, 0x8110, 0x8110,
+    0x8110, 0x8111, 0x8111, 0x8112, 0x8112, 0x8112, 0x8113, 0x8113, 0x8113, 0x8114, 0x8114, 0x8114,
+    0x8115, 0x8115, 0x8115, 0x8116, 0x8116, 0x8116, 0x8117, 0x8117, 0x8117, 0x8118, 0x8118, 0x8118,
+    0x8119, 0x8119, 0x8119, 0x811a, 0x811a, 0x811b, 0x811b, 0x811b, 0x811c, 0x811c, 0x811c, 0x811d,
+    0x811d, 0x811d, 0x811e, 0x811e, 0x811e, 0x811f, 0x811f, 0x811f, 0x8120, 0x8120, 0x8121, 0x8121,
+    0x8121, 0x8122, 0x8122, 0x8122, 0x8123, 0x8123, 0x8123, 0x8124, 0x8124, 0x8124, 0x8125, 0x8125,
+    0x8126, 0x8126, 0x8126, 0x8127, 0x8127, 0x8127, 0x8128, 0x8128, 0x8128, 0x8129, 0x8129, 0x8129,
+    0x812a, 0x812a, 0x812b, 0x812b, 0x812b, 0x812c, 0x812c, 0x812c, 0x812d, 0x812d, 0x812d, 0x812e,
+    0x812e, 0x812f, 0x812f, 0x812f, 0x8130, 0x8130, 0x8130, 0x8131, 0x8131, 0x8131, 0x8132, 0x8132,
+    0x8133, 0x8133, 0x8133, 0x8134, 0x8134, 0x8134, 0x8135, 0x8135, 0x8136, 0x8136, 0x8136, 0x8137,
+    0x8137, 0x8137, 0x8138, 0x8138, 0x8138, 0x8139, 0x8139, 0x813a, 0x813a, 0x813a, 0x813b, 0x813b,
+    0x813b, 0x813c, 0x813c, 0x813d, 0x813d, 0x813d, 0x813e, 0x813e, 0x813e, 0x813f, 0x813f, 0x8140,
+    0x8140, 0x8140, 0x8141, 0x8141, 0x8141, 0x8142, 0x8142, 0x8143, 0x8143, 0x8143, 0x8144, 0x8144,
+    0x8144, 0x8145, 0x8145, 0x8146, 0x8146, 0x8146, 0x8147, 0x8147, 0x8147, 0x8148, 0x8148, 0x8149,
+    0x8149, 0x8149, 0x814a, 0x814a, 0x814b, 0x814b, 0x814b, 0x814c, 0x814c, 0x814c, 0x814d, 0x814d,
+    0x814e, 0x814e, 0x814e, 0x814f, 0x814f, 0x8150, 0x8150, 0x8150, 0x8151, 0x8151, 0x8151, 0x8152,
+    0x8152, 0x8153, 0x8153, 0x8153, 0x8154, 0x8154, 0x8155, 0x8155, 0x8155, 0x8156, 0x8156, 0x8156,
+    0x8157, 0x8157, 0x8158, 0x8158, 0x8158, 0x8159, 0x8159, 0x815a, 0x815a, 0x815a, 0x815b, 0x815b,
+    0x815c, 0x815c, 0x815c, 0x815d, 0x815d, 0x815e, 0x815e, 0x815e, 0x815f, 0x815f, 0x815f, 0x8160,
+    0x8160, 0x8161, 0x8161, 0x8161, 0x8162, 0x8162, 0x8163, 0x8163, 0x8163, 0x8164, 0x8164, 0x8165,
+    0x8165, 0x8165, 0x8166, 0x8166, 0x8167, 0x8167, 0x8167, 0x8168, 0x8168, 0x8169, 0x8169, 0x8169,
+    0x816a, 0x816a, 0x816b, 0x816b, 0x816b, 0x816c, 0x816c, 0x816d, 0x816d, 0x816d, 0x816e, 0x816e,
+    0x816f, 0x816f, 0x816f, 0x8170, 0x8170, 0x8171, 0x8171, 0x8172, 0x8172, 0x8172, 0x8173, 0x8173,
+    0x8174, 0x8174, 0x8174, 0x8175, 0x8175, 0x8176, 0x8176, 0x8176, 0x8177, 0x8177, 0x8178, 0x8178,
+    0x8178, 0x8179, 0x8179, 0x817a, 0x817a, 0x817b, 0x817b, 0x817b, 0x817c, 0x817c, 0x817d, 0x817d,
+    0x817d, 0x817e, 0x817e, 0x817f, 0x817f, 0x817f, 0x8180, 0x8180, 0x8181, 0x8181, 0x8182, 0x8182,
+    0x8182, 0x8183, 0x8183, 0x8184, 0x8184, 0x8185, 0x8186, 0x8187, 0x8187, 0x8188, 0x8189, 0x818a,
+    0x818b, 0x818c, 0x818c, 0x818d, 0x818e, 0x818f, 0x8190, 0x8191, 0x8191, 0x8192, 0x8193, 0x8194,
+    0x8195, 0x8196, 0x8197, 0x8197, 0x8198, 0x8199, 0x819a, 0x819b, 0x819c, 0x819d, 0x819d, 0x819e,
+    0x819f, 0x81a0, 0x81a1, 0x81a2, 0x81a3, 0x81a4, 0x81a4, 0x81a5, 0x81a6, 0x81a7, 0x81a8, 0x81a9,
+    0x81aa, 0x81ab, 0x81ab, 0x81ac, 0x81ad, 0x81ae, 0x81af, 0x81b0, 0x81b1, 0x81b2, 0x81b2, 0x81b3,
+    0x81b4, 0x81b5, 0x81b6, 0x81b7, 0x81b8, 0x81b9, 0x81ba, 0x81ba, 0x81bb, 0x81bc, 0x81bd, 0x81be,
+    0x81bf, 0x81c0, 0x81c1, 0x81c2, 0x81c3, 0x81c3, 0x81c4, 0x81c5, 0x81c6, 0x81c7, 0x81c8, 0x81c9,
+    0x81ca, 0x81cb, 0x81cc, 0x81cd, 0x81ce, 0x81ce, 0x81cf, 0x81d0, 0x81d1, 0x81d2, 0x81d3, 0x81d4,
+    0x81d5, 0x81d6, 0x81d7, 0x81d8, 0x81d9, 0x81da, 0x81da, 0x81db, 0x81dc, 0x81dd, 0x81de, 0x81df,
+    0x81e0, 0x81e1, 0x81e2, 0x81e3, 0x81e4, 0x81e5, 0x81e6, 0x81e7, 0x81e8, 0x81e9, 0x81e9, 0x81ea,
+    0x81eb, 0x81ec, 0x81ed, 0x81ee, 0x81ef, 0x81f0, 0x81f1, 0x81f2, 0x81f3, 0x81f4, 0x81f5, 0x81f6,
+    0x81f7, 0x81f8, 0x81f9, 0x81fa, 0x81fb, 0x81fc, 0x81fd, 0x81fe, 0x81ff, 0x81ff, 0x8200, 0x8201,
+    0x8202, 0x8203, 0x8204, 0x8205, 0x8206, 0x8207, 0x8208, 0x8209, 0x820a, 0x820b, 0x820c, 0x820d,
+    0x820e, 0x820f, 0x8210, 0x8211, 0x8212, 0x8213, 0x8214, 0x8215, 0x8216, 0x8217, 0x8218, 0x8219,
+    0x821a, 0x821b, 0x821c, 0x821d, 0x821e, 0x821f, 0x8220, 0x8221, 0x8222, 0x8223, 0x8224, 0x8225,
+    0x8226, 0x8227, 0x8228, 0x8229, 0x822a, 0x822b, 0x822c, 0x822d, 0x822e, 0x822f, 0x8230, 0x8231,
+    0x8232, 0x8233, 0x8234, 0x8235, 0x8236, 0x8237, 0x8238, 0x8239, 0x823a, 0x823b, 0x823c, 0x823e,
+    0x823f, 0x8240, 0x8241, 0x8242, 0x8243, 0x8244, 0x8245, 0x8246, 0x8247, 0x8248, 0x8249, 0x824a,
+    0x824b, 0x824c, 0x824d, 0x824e, 0x824f, 0x8250, 0x8251, 0x8252, 0x8253, 0x8254, 0x8255, 0x8257,
+    0x8258, 0x8259, 0x825a, 0x825b, 0x825c, 0x825d, 0x825e, 0x825f, 0x8260, 0x8261, 0x8262, 0x8263,
+    0x8264, 0x8265, 0x8267, 0x8268, 0x8269, 0x826a, 0x826b, 0x826c, 0x826d, 0x826e, 0x826f, 0x8270,
+    0x8271, 0x8272, 0x8273, 0x8275, 0x8276, 0x8277, 0x8278, 0x8279, 0x827a, 0x827b, 0x827c, 0x827d,
+    0x827e, 0x827f, 0x8281, 0x8282, 0x8283, 0x8284, 0x8285, 0x8286, 0x8287, 0x8288, 0x8289, 0x828a,
+    0x828c, 0x828d, 0x828e, 0x828f, 0x8290, 0x8291, 0x8292, 0x8293, 0x8294, 0x8296, 0x8297, 0x8298,
+    0x8299, 0x829a, 0x829b, 0x829c, 0x829d, 0x829e, 0x82a0, 0x82a1, 0x82a2, 0x82a3, 0x82a4, 0x82a5,
+    0x82a6, 0x82a7, 0x82a9, 0x82aa, 0x82ab, 0x82ac, 0x82ad, 0x82ae, 0x82af, 0x82b1, 0x82b2, 0x82b3,
+    0x82b4, 0x82b5, 0x82b6, 0x82b7, 0x82b9, 0x82ba, 0x82bb, 0x82bc, 0x82bd, 0x82be, 0x82bf, 0x82c1,
+    0x82c2, 0x82c3, 0x82c4, 0x82c5, 0x82c6, 0x82c8, 0x82c9, 0x82ca, 0x82cb, 0x82cc, 0x82cd, 0x82cf,
+    0x82d0, 0x82d1, 0x82d2, 0x82d3, 0x82d4, 0x82d6, 0x82d7, 0x82d8, 0x82d9, 0x82da, 0x82db, 0x82dd,
+    0x82de, 0x82df, 0x82e0, 0x82e1, 0x82e3, 0x82e4, 0x82e5, 0x82e6, 0x82e7, 0x82e8, 0x82ea, 0x82eb,
+    0x82ec, 0x82ed, 0x82ee, 0x82f0, 0x82f1, 0x82f2, 0x82f3, 0x82f4, 0x82f6, 0x82f7, 0x82f8, 0x82f9,
+    0x82fa, 0x82fc, 0x82fd, 0x82fe, 0x82ff, 0x8300, 0x8302, 0x8303, 0x8304, 0x8305, 0x8307, 0x8308,
+    0x8309, 0x830a, 0x830b, 0x830d, 0x830e, 0x830f, 0x8310, 0x8312, 0x8313, 0x8314, 0x8315, 0x8316,
+    0x8318, 0x8319, 0x831a, 0x831b, 0x831d, 0x831e, 0x831f, 0x8320, 0x8322, 0x8323, 0x8324, 0x8325,
+    0x8326, 0x8328, 0x8329, 0x832a, 0x832b, 0x832d, 0x832e, 0x832f, 0x8330, 0x8332, 0x8333, 0x8334,
+    0x8335, 0x8337, 0x8338, 0x8339, 0x833b, 0x833c, 0x833d, 0x833e, 0x8340, 0x8341, 0x8342, 0x8343,
+    0x8345, 0x8346, 0x8347, 0x8348, 0x834a, 0x834b, 0x834c, 0x834e, 0x834f, 0x8350, 0x8351, 0x8353,
+    0x8354, 0x8355, 0x8356, 0x8358, 0x8359, 0x835a, 0x835c, 0x835d, 0x835e, 0x835f, 0x8361, 0x8362,
+    0x8363, 0x8365, 0x8366, 0x8367, 0x8369, 0x836a, 0x836b, 0x836c, 0x836e, 0x836f, 0x8370, 0x8372,
+    0x8373, 0x8374, 0x8376, 0x8377, 0x8378, 0x8379, 0x837b, 0x837c, 0x837d, 0x837f, 0x8380, 0x8381,
+    0x8383, 0x8384, 0x8385, 0x8387, 0x8388, 0x8389, 0x838b, 0x838c, 0x838d, 0x838f, 0x8390, 0x8391,
+    0x8393, 0x8394, 0x8395, 0x8397, 0x8398, 0x8399, 0x839b, 0x839c, 0x839d, 0x839f, 0x83a0, 0x83a1,
+    0x83a3, 0x83a4, 0x83a5, 0x83a7, 0x83a8, 0x83a9, 0x83ab, 0x83ac, 0x83ad, 0x83af, 0x83b0, 0x83b1,
+    0x83b3, 0x83b4, 0x83b6, 0x83b7, 0x83b8, 0x83ba, 0x83bb, 0x83bc, 0x83be, 0x83bf, 0x83c0, 0x83c2,
+    0x83c3, 0x83c5, 0x83c6, 0x83c7, 0x83c9, 0x83ca, 0x83cb, 0x83cd, 0x83ce, 0x83d0, 0x83d1, 0x83d2,
+    0x83d4, 0x83d5, 0x83d6, 0x83d8, 0x83d9, 0x83db, 0x83dc, 0x83dd, 0x83df, 0x83e0, 0x83e2, 0x83e3,
+    0x83e4, 0x83e6, 0x83e7, 0x83e8, 0x83ea, 0x83eb, 0x83ed, 0x83ee, 0x83ef, 0x83f1, 0x83f2, 0x83f4,
+    0x83f5, 0x83f7, 0x83f8, 0x83f9, 0x83fb, 0x83fc, 0x83fe, 0x83ff, 0x8400, 0x8402, 0x8403, 0x8405,
+    0x8406, 0x8407, 0x8409, 0x840a, 0x840c, 0x840d, 0x840f, 0x8410, 0x8411, 0x8413, 0x8414, 0x8416,
+    0x8417, 0x8419, 0x841a, 0x841b, 0x841d, 0x841e, 0x8420, 0x8421, 0x8423, 0x8424, 0x8426, 0x8427,
+    0x8428, 0x842a, 0x842b, 0x842d, 0x842e, 0x8430, 0x8431, 0x8433, 0x8434, 0x8436, 0x8437, 0x8438,
+    0x843a, 0x843b, 0x843d, 0x843e, 0x8440, 0x8441, 0x8443, 0x8444, 0x8446, 0x8447, 0x8449, 0x844a,
+    0x844b, 0x844d, 0x844e, 0x8450, 0x8451, 0x8453, 0x8454, 0x8456, 0x8457, 0x8459, 0x845a, 0x845c,
+    0x845d, 0x845f, 0x8460, 0x8462, 0x8463, 0x8465, 0x8466, 0x8468, 0x8469, 0x846b, 0x846c, 0x846e,
+    0x846f, 0x8471, 0x8472, 0x8474, 0x8475, 0x8477, 0x8478, 0x847a, 0x847b, 0x847d, 0x847e, 0x8480,
+    0x8481, 0x8483, 0x8484, 0x8486, 0x8487, 0x8489, 0x848a, 0x848c, 0x848d, 0x848f, 0x8490, 0x8492,
+    0x8493, 0x8495, 0x8496, 0x8498, 0x8499, 0x849b, 0x849c, 0x849e, 0x84a0, 0x84a1, 0x84a3, 0x84a4,
+    0x84a6, 0x84a7, 0x84a9, 0x84aa, 0x84ac, 0x84ad, 0x84af, 0x84b0, 0x84b2, 0x84b4, 0x84b5, 0x84b7,
+    0x84b8, 0x84ba, 0x84bb, 0x84bd, 0x84be, 0x84c0, 0x84c2, 0x84c3, 0x84c5, 0x84c6, 0x84c8, 0x84c9,
+    0x84cb, 0x84cc, 0x84ce, 0x84d0, 0x84d1, 0x84d3, 0x84d4, 0x84d6, 0x84d7, 0x84d9, 0x84db, 0x84dc,
+    0x84de, 0x84df, 0x84e1, 0x84e2, 0x84e4, 0x84e6, 0x84e7, 0x84e9, 0x84ea, 0x84ec, 0x84ed, 0x84ef,
+    0x84f1, 0x84f2, 0x84f4, 0x84f5, 0x84f7, 0x84f9, 0x84fa, 0x84fc, 0x84fd, 0x84ff, 0x8501, 0x8502,
+    0x8504, 0x8505, 0x8507, 0x8509, 0x850a, 0x850c, 0x850d, 0x850f, 0x8511, 0x8512, 0x8514, 0x8515,
+    0x8517, 0x8519, 0x851a, 0x851c, 0x851e, 0x851f, 0x8521, 0x8522, 0x8524, 0x8526, 0x8527, 0x8529,
+    0x852b, 0x852c, 0x852e, 0x852f, 0x8531, 0x8533, 0x8534, 0x8536, 0x8538, 0x8539, 0x853b, 0x853c,
+    0x853e, 0x8540, 0x8541, 0x8543, 0x8545, 0x8546, 0x8548, 0x854a, 0x854b, 0x854d, 0x854f, 0x8550,
+    0x8552, 0x8554, 0x8555, 0x8557, 0x8559, 0x855a, 0x855c, 0x855e, 0x855f, 0x8561, 0x8562, 0x8564,
+    0x8566, 0x8567, 0x8569, 0x856b, 0x856c, 0x856e, 0x8570, 0x8572, 0x8573, 0x8575, 0x8577, 0x8578,
+    0x857a, 0x857c, 0x857d, 0x857f, 0x8581, 0x8582, 0x8584, 0x8586, 0x8587, 0x8589, 0x858b, 0x858c,
+    0x858e, 0x8590, 0x8592, 0x8593, 0x8595, 0x8597, 0x8598, 0x859a, 0x859c, 0x859d, 0x859f, 0x85a1,
+    0x85a3, 0x85a4, 0x85a6, 0x85a8, 0x85a9, 0x85ab, 0x85ad, 0x85af, 0x85b0, 0x85b2, 0x85b4, 0x85b5,
+    0x85b7, 0x85b9, 0x85bb, 0x85bc, 0x85be, 0x85c0, 0x85c1, 0x85c3, 0x85c5, 0x85c7, 0x85c8, 0x85ca,
+    0x85cc, 0x85ce, 0x85cf, 0x85d1, 0x85d3, 0x85d4, 0x85d6, 0x85d8, 0x85da, 0x85db, 0x85dd, 0x85df,
+    0x85e1, 0x85e2, 0x85e4, 0x85e6, 0x85e8, 0x85e9, 0x85eb, 0x85ed, 0x85ef, 0x85f0, 0x85f2, 0x85f4,
+    0x85f6, 0x85f7, 0x85f9, 0x85fb, 0x85fd, 0x85ff, 0x8600, 0x8602, 0x8604, 0x8606, 0x8607, 0x8609,
+    0x860b, 0x860d, 0x860e, 0x8610, 0x8612, 0x8614, 0x8616, 0x8617, 0x8619, 0x861b, 0x861d, 0x861e,
+    0x8620, 0x8622, 0x8624, 0x8626, 0x8627, 0x8629, 0x862b, 0x862d, 0x862f, 0x8630, 0x8632, 0x8634,
+    0x8636, 0x8638, 0x8639, 0x863b, 0x863d, 0x863f, 0x8641, 0x8642, 0x8644, 0x8646, 0x8648, 0x864a,
+    0x864b, 0x864d, 0x864f, 0x8651, 0x8653, 0x8654, 0x8656, 0x8658, 0x865a, 0x865c, 0x865e, 0x865f,
+    0x8661, 0x8663, 0x8665, 0x8667, 0x8669, 0x866a, 0x866c, 0x866e, 0x8670, 0x8672, 0x8674, 0x8675,
+    0x8677, 0x8679, 0x867b, 0x867d, 0x867f, 0x8680, 0x8682, 0x8684, 0x8686, 0x8688, 0x868a, 0x868c,
+    0x868d, 0x868f, 0x8691, 0x8693, 0x8695, 0x8697, 0x8699, 0x869a, 0x869c, 0x869e, 0x86a0, 0x86a2,
+    0x86a4, 0x86a6, 0x86a7, 0x86a9, 0x86ab, 0x86ad, 0x86af, 0x86b1, 0x86b3, 0x86b5, 0x86b6, 0x86b8,
+    0x86ba, 0x86bc, 0x86be, 0x86c0, 0x86c2, 0x86c4, 0x86c5, 0x86c7, 0x86c9, 0x86cb, 0x86cd, 0x86cf,
+    0x86d1, 0x86d3, 0x86d5, 0x86d6, 0x86d8, 0x86da, 0x86dc, 0x86de, 0x86e0, 0x86e2, 0x86e4, 0x86e6,
+    0x86e8, 0x86ea, 0x86eb, 0x86ed, 0x86ef, 0x86f1, 0x86f3, 0x86f5, 0x86f7, 0x86fb, 0x86ff, 0x8702,
+    0x8706, 0x870a, 0x870e, 0x8712, 0x8716, 0x871a, 0x871d, 0x8721, 0x8725, 0x8729, 0x872d, 0x8731,
+    0x8735, 0x8739, 0x873d, 0x8740, 0x8744, 0x8748, 0x874c, 0x8750, 0x8754, 0x8758, 0x875c, 0x8760,
+    0x8764, 0x8768, 0x876c, 0x8770, 0x8774, 0x8778, 0x877c, 0x8780, 0x8784, 0x8788, 0x878c, 0x8790,
+    0x8794, 0x8798, 0x879c, 0x87a0, 0x87a4, 0x87a8, 0x87ac, 0x87b0, 0x87b4, 0x87b8, 0x87bc, 0x87c0,
+    0x87c4, 0x87c8, 0x87cc, 0x87d0, 0x87d4, 0x87d9, 0x87dd, 0x87e1, 0x87e5, 0x87e9, 0x87ed, 0x87f1,
+    0x87f5, 0x87f9, 0x87fe, 0x8801, 0x8803, 0x8805, 0x8807, 0x8809, 0x880b, 0x880d, 0x880f, 0x8811,
+    0x8814, 0x8816, 0x8818, 0x881a, 0x881c, 0x881e, 0x8820, 0x8822, 0x8824, 0x8826, 0x8828, 0x882b,
+    0x882d, 0x882f, 0x8831, 0x8833, 0x8835, 0x8837, 0x8839, 0x883c, 0x883e, 0x8840, 0x8842, 0x8844,
+    0x8846, 0x8848, 0x884b, 0x884d, 0x884f, 0x8851, 0x8853, 0x8855, 0x8857, 0x885a, 0x885c, 0x885e,
+    0x8860, 0x8862, 0x8864, 0x8867, 0x8869, 0x886b, 0x886d, 0x886f, 0x8872, 0x8874, 0x8876, 0x8878,
+    0x887a, 0x887d, 0x887f, 0x8881, 0x8883, 0x8885, 0x8888, 0x888a, 0x888c, 0x888e, 0x8890, 0x8893,
+    0x8895, 0x8897, 0x8899, 0x889c, 0x889e, 0x88a0, 0x88a2, 0x88a4, 0x88a7, 0x88a9, 0x88ab, 0x88ad,
+    0x88b0, 0x88b2, 0x88b4, 0x88b6, 0x88b9, 0x88bb, 0x88bd, 0x88c0, 0x88c2, 0x88c4, 0x88c6, 0x88c9,
+    0x88cb, 0x88cd, 0x88cf, 0x88d2, 0x88d4, 0x88d6, 0x88d9, 0x88db, 0x88dd, 0x88e0, 0x88e2, 0x88e4,
+    0x88e6, 0x88e9, 0x88eb, 0x88ed, 0x88f0, 0x88f2, 0x88f4, 0x88f7, 0x88f9, 0x88fb, 0x88fe, 0x8900,
+    0x8902, 0x8905, 0x8907, 0x8909, 0x890c, 0x890e, 0x8910, 0x8913, 0x8915, 0x8917, 0x891a, 0x891c,
+    0x891e, 0x8921, 0x8923, 0x8926, 0x8928, 0x892a, 0x892d, 0x892f, 0x8931, 0x8934, 0x8936, 0x8939,
+    0x893b, 0x893d, 0x8940, 0x8942, 0x8945, 0x8947, 0x8949, 0x894c, 0x894e, 0x8951, 0x8953, 0x8955,
+    0x8958, 0x895a, 0x895d, 0x895f, 0x8962, 0x8964, 0x8966, 0x8969, 0x896b, 0x896e, 0x8970, 0x8973,
+    0x8975, 0x8977, 0x897a, 0x897c, 0x897f, 0x8981, 0x8984, 0x8986, 0x8989, 0x898b, 0x898e, 0x8990,
+    0x8993, 0x8995, 0x8998, 0x899a, 0x899c, 0x899f, 0x89a1, 0x89a4, 0x89a6, 0x89a9, 0x89ab, 0x89ae,
+    0x89b0, 0x89b3, 0x89b5, 0x89b8, 0x89ba, 0x89bd, 0x89c0, 0x89c2, 0x89c5, 0x89c7, 0x89ca, 0x89cc,
+    0x89cf, 0x89d1, 0x89d4, 0x89d6, 0x89d9, 0x89db, 0x89de, 0x89e0, 0x89e3, 0x89e6, 0x89e8, 0x89eb,
+    0x89ed, 0x89f0, 0x89f2, 0x89f5, 0x89f7, 0x89fa, 0x89fd, 0x89ff, 0x8a02, 0x8a04, 0x8a07, 0x8a0a,
+    0x8a0c, 0x8a0f, 0x8a11, 0x8a14, 0x8a16, 0x8a19, 0x8a1c, 0x8a1e, 0x8a21, 0x8a23, 0x8a26, 0x8a29,
+    0x8a2b, 0x8a2e, 0x8a31, 0x8a33, 0x8a36, 0x8a38, 0x8a3b, 0x8a3e, 0x8a40, 0x8a43, 0x8a46, 0x8a48,
+    0x8a4b, 0x8a4e, 0x8a50, 0x8a53, 0x8a55, 0x8a58, 0x8a5b, 0x8a5d, 0x8a60, 0x8a63, 0x8a65, 0x8a68,
+    0x8a6b, 0x8a6d, 0x8a70, 0x8a73, 0x8a76, 0x8a78, 0x8a7b, 0x8a7e, 0x8a80, 0x8a83, 0x8a86, 0x8a88,
+    0x8a8b, 0x8a8e, 0x8a90, 0x8a93, 0x8a96, 0x8a99, 0x8a9b, 0x8a9e, 0x8aa1, 0x8aa3, 0x8aa6, 0x8aa9,
+    0x8aac, 0x8aae, 0x8ab1, 0x8ab4, 0x8ab7, 0x8ab9, 0x8abc, 0x8abf, 0x8ac2, 0x8ac4, 0x8ac7, 0x8aca,
+    0x8acd, 0x8acf, 0x8ad2, 0x8ad5, 0x8ad8, 0x8ada, 0x8add, 0x8ae0, 0x8ae3, 0x8ae5, 0x8ae8, 0x8aeb,
+    0x8aee, 0x8af1, 0x8af3, 0x8af6, 0x8af9, 0x8afc, 0x8aff, 0x8b01, 0x8b04, 0x8b07, 0x8b0a, 0x8b0d,
+    0x8b0f, 0x8b12, 0x8b15, 0x8b18, 0x8b1b, 0x8b1e, 0x8b20, 0x8b23, 0x8b26, 0x8b29, 0x8b2c, 0x8b2f,
+    0x8b31, 0x8b34, 0x8b37, 0x8b3a, 0x8b3d, 0x8b40, 0x8b43, 0x8b45, 0x8b48, 0x8b4b, 0x8b4e, 0x8b51,
+    0x8b54, 0x8b57, 0x8b59, 0x8b5c, 0x8b5f, 0x8b62, 0x8b65, 0x8b68, 0x8b6b, 0x8b6e, 0x8b71, 0x8b74,
+    0x8b76, 0x8b79, 0x8b7c, 0x8b7f, 0x8b82, 0x8b85, 0x8b88, 0x8b8b, 0x8b8e, 0x8b91, 0x8b94, 0x8b96,
+    0x8b99, 0x8b9c, 0x8b9f, 0x8ba2, 0x8ba5, 0x8ba8, 0x8bab, 0x8bae, 0x8bb1, 0x8bb4, 0x8bb7, 0x8bba,
+    0x8bbd, 0x8bc0, 0x8bc3, 0x8bc6, 0x8bc9, 0x8bcc, 0x8bcf, 0x8bd2, 0x8bd5, 0x8bd7, 0x8bda, 0x8bdd,
+    0x8be0, 0x8be3, 0x8be6, 0x8be9, 0x8bec, 0x8bef, 0x8bf2, 0x8bf5, 0x8bf8, 0x8bfb, 0x8bfe, 0x8c01,
+    0x8c02, 0x8c04, 0x8c05, 0x8c07, 0x8c08, 0x8c0a, 0x8c0b, 0x8c0d, 0x8c0e, 0x8c10, 0x8c11, 0x8c13,
+    0x8c14, 0x8c16, 0x8c17, 0x8c19, 0x8c1b, 0x8c1c, 0x8c1e, 0x8c1f, 0x8c21, 0x8c22, 0x8c24, 0x8c25,
+    0x8c27, 0x8c28, 0x8c2a, 0x8c2b, 0x8c2d, 0x8c2f, 0x8c30, 0x8c32, 0x8c33, 0x8c35, 0x8c36, 0x8c38,
+    0x8c39, 0x8c3b, 0x8c3c, 0x8c3e, 0x8c40, 0x8c41, 0x8c43, 0x8c44, 0x8c46, 0x8c47, 0x8c49, 0x8c4b,
+    0x8c4c, 0x8c4e, 0x8c4f, 0x8c51, 0x8c52, 0x8c54, 0x8c56, 0x8c57, 0x8c59, 0x8c5a, 0x8c5c, 0x8c5d,
+    0x8c5f, 0x8c61, 0x8c62, 0x8c64, 0x8c65, 0x8c67, 0x8c69, 0x8c6a, 0x8c6c, 0x8c6d, 0x8c6f, 0x8c70,
+    0x8c72, 0x8c74, 0x8c75, 0x8c77, 0x8c78, 0x8c7a, 0x8c7c, 0x8c7d, 0x8c7f, 0x8c80, 0x8c82, 0x8c84,
+    0x8c85, 0x8c87, 0x8c89, 0x8c8a, 0x8c8c, 0x8c8d, 0x8c8f, 0x8c91, 0x8c92, 0x8c94, 0x8c95, 0x8c97,
+    0x8c99, 0x8c9a, 0x8c9c, 0x8c9e, 0x8c9f, 0x8ca1, 0x8ca3, 0x8ca4, 0x8ca6, 0x8ca7, 0x8ca9, 0x8cab,
+    0x8cac, 0x8cae, 0x8cb0, 0x8cb1, 0x8cb3, 0x8cb5, 0x8cb6, 0x8cb8, 0x8cba, 0x8cbb, 0x8cbd, 0x8cbe,
+    0x8cc0, 0x8cc2, 0x8cc3, 0x8cc5, 0x8cc7, 0x8cc8, 0x8cca, 0x8ccc, 0x8ccd, 0x8ccf, 0x8cd1, 0x8cd2,
+    0x8cd4, 0x8cd6, 0x8cd7, 0x8cd9, 0x8cdb, 0x8cdc, 0x8cde, 0x8ce0, 0x8ce1, 0x8ce3, 0x8ce5, 0x8ce7,
+    0x8ce8, 0x8cea, 0x8cec, 0x8ced, 0x8cef, 0x8cf1, 0x8cf2, 0x8cf4, 0x8cf6, 0x8cf7, 0x8cf9, 0x8cfb,
+    0x8cfd, 0x8cfe, 0x8d00, 0x8d02, 0x8d03, 0x8d05, 0x8d07, 0x8d08, 0x8d0a, 0x8d0c, 0x8d0e, 0x8d0f,
+    0x8d11, 0x8d13, 0x8d14, 0x8d16, 0x8d18, 0x8d1a, 0x8d1b, 0x8d1d, 0x8d1f, 0x8d20, 0x8d22, 0x8d24,
+    0x8d26, 0x8d27, 0x8d29, 0x8d2b, 0x8d2d, 0x8d2e, 0x8d30, 0x8d32, 0x8d34, 0x8d35, 0x8d37, 0x8d39,
+    0x8d3b, 0x8d3c, 0x8d3e, 0x8d40, 0x8d42, 0x8d43, 0x8d45, 0x8d47, 0x8d49, 0x8d4a, 0x8d4c, 0x8d4e,
+    0x8d50, 0x8d51, 0x8d53, 0x8d55, 0x8d57, 0x8d58, 0x8d5a, 0x8d5c, 0x8d5e, 0x8d5f, 0x8d61, 0x8d63,
+    0x8d65, 0x8d67, 0x8d68, 0x8d6a, 0x8d6c, 0x8d6e, 0x8d6f, 0x8d71, 0x8d73, 0x8d75, 0x8d77, 0x8d78,
+    0x8d7a, 0x8d7c, 0x8d7e, 0x8d7f, 0x8d81, 0x8d83, 0x8d85, 0x8d87, 0x8d88, 0x8d8a, 0x8d8c, 0x8d8e,
+    0x8d90, 0x8d91, 0x8d93, 0x8d95, 0x8d97, 0x8d99, 0x8d9b, 0x8d9c, 0x8d9e, 0x8da0, 0x8da2, 0x8da4,
+    0x8da5, 0x8da7, 0x8da9, 0x8dab, 0x8dad, 0x8daf, 0x8db0, 0x8db2, 0x8db4, 0x8db6, 0x8db8, 0x8dba,
+    0x8dbb, 0x8dbd, 0x8dbf, 0x8dc1, 0x8dc3, 0x8dc5, 0x8dc6, 0x8dc8, 0x8dca, 0x8dcc, 0x8dce, 0x8dd0,
+    0x8dd1, 0x8dd3, 0x8dd5, 0x8dd7, 0x8dd9, 0x8ddb, 0x8ddd, 0x8dde, 0x8de0, 0x8de2, 0x8de4, 0x8de6,
+    0x8de8, 0x8dea, 0x8deb, 0x8ded, 0x8def, 0x8df1, 0x8df3, 0x8df5, 0x8df7, 0x8df9, 0x8dfa, 0x8dfc,
+    0x8dfe, 0x8e00, 0x8e02, 0x8e04, 0x8e06, 0x8e08, 0x8e09, 0x8e0b, 0x8e0d, 0x8e0f, 0x8e11, 0x8e13,
+    0x8e15, 0x8e17, 0x8e19, 0x8e1b, 0x8e1c, 0x8e1e, 0x8e20, 0x8e22, 0x8e24, 0x8e26, 0x8e28, 0x8e2a,
+    0x8e2c, 0x8e2e, 0x8e2f, 0x8e31, 0x8e33, 0x8e35, 0x8e37, 0x8e39, 0x8e3b, 0x8e3d, 0x8e3f, 0x8e41,
+    0x8e43, 0x8e45, 0x8e47, 0x8e48, 0x8e4a, 0x8e4c, 0x8e4e, 0x8e50, 0x8e52, 0x8e54, 0x8e56, 0x8e58,
+    0x8e5a, 0x8e5c, 0x8e5e, 0x8e60, 0x8e62, 0x8e64, 0x8e66, 0x8e67, 0x8e69, 0x8e6b, 0x8e6d, 0x8e6f,
+    0x8e71, 0x8e73, 0x8e75, 0x8e77, 0x8e79, 0x8e7b, 0x8e7d, 0x8e7f, 0x8e81, 0x8e83, 0x8e85, 0x8e87,
+    0x8e89, 0x8e8b, 0x8e8d, 0x8e8f, 0x8e91, 0x8e93, 0x8e95, 0x8e97, 0x8e99, 0x8e9b, 0x8e9d, 0x8e9f,
+    0x8ea0, 0x8ea2, 0x8ea4, 0x8ea6, 0x8ea8, 0x8eaa, 0x8eac, 0x8eae, 0x8eb0, 0x8eb2, 0x8eb4, 0x8eb6,
+    0x8eb8, 0x8eba, 0x8ebc, 0x8ebe, 0x8ec0, 0x8ec2, 0x8ec4, 0x8ec6, 0x8ec8, 0x8eca, 0x8ecc, 0x8ece,
+    0x8ed1, 0x8ed3, 0x8ed5, 0x8ed7, 0x8ed9, 0x8edb, 0x8edd, 0x8edf, 0x8ee1, 0x8ee3, 0x8ee5, 0x8ee7,
+    0x8ee9, 0x8eeb, 0x8eed, 0x8eef, 0x8ef1, 0x8ef3, 0x8ef5, 0x8ef7, 0x8ef9, 0x8efb, 0x8efd, 0x8eff,
+    0x8f01, 0x8f03, 0x8f05, 0x8f07, 0x8f09, 0x8f0b, 0x8f0e, 0x8f10, 0x8f12, 0x8f14, 0x8f16, 0x8f18,
+    0x8f1a, 0x8f1c, 0x8f1e, 0x8f20, 0x8f22, 0x8f24, 0x8f26, 0x8f28, 0x8f2a, 0x8f2c, 0x8f2f, 0x8f31,
+    0x8f33, 0x8f35, 0x8f37, 0x8f39, 0x8f3b, 0x8f3d, 0x8f3f, 0x8f41, 0x8f43, 0x8f45, 0x8f48, 0x8f4a,
+    0x8f4c, 0x8f4e, 0x8f50, 0x8f52, 0x8f54, 0x8f56, 0x8f58, 0x8f5a, 0x8f5d, 0x8f5f, 0x8f61, 0x8f63,
+    0x8f65, 0x8f67, 0x8f69, 0x8f6b, 0x8f6d, 0x8f6f, 0x8f72, 0x8f74, 0x8f76, 0x8f78, 0x8f7a, 0x8f7c,
+    0x8f7e, 0x8f80, 0x8f83, 0x8f85, 0x8f87, 0x8f89, 0x8f8b, 0x8f8d, 0x8f8f, 0x8f91, 0x8f94, 0x8f96,
+    0x8f98, 0x8f9a, 0x8f9c, 0x8f9e, 0x8fa0, 0x8fa3, 0x8fa5, 0x8fa7, 0x8fa9, 0x8fab, 0x8fad, 0x8faf,
+    0x8fb2, 0x8fb4, 0x8fb6, 0x8fb8, 0x8fba, 0x8fbc, 0x8fbf, 0x8fc1, 0x8fc3, 0x8fc5, 0x8fc7, 0x8fc9,
+    0x8fcc, 0x8fce, 0x8fd0, 0x8fd2, 0x8fd4, 0x8fd6, 0x8fd9, 0x8fdb, 0x8fdd, 0x8fdf, 0x8fe1, 0x8fe4,
+    0x8fe6, 0x8fe8, 0x8fea, 0x8fec, 0x8fee, 0x8ff1, 0x8ff3, 0x8ff5, 0x8ff7, 0x8ff9, 0x8ffc, 0x8ffe,
+    0x9000, 0x9002, 0x9004, 0x9007, 0x9009, 0x900b, 0x900d, 0x900f, 0x9012, 0x9014, 0x9016, 0x9018,
+    0x901b, 0x901d, 0x901f, 0x9021, 0x9024, 0x9026, 0x9028, 0x902a, 0x902d, 0x902f, 0x9031, 0x9033,
+    0x9036, 0x9038, 0x903a, 0x903c, 0x903f, 0x9041, 0x9043, 0x9045, 0x9048, 0x904a, 0x904c, 0x904f,
+    0x9051, 0x9053, 0x9055, 0x9058, 0x905a, 0x905c, 0x905f, 0x9061, 0x9063, 0x9066, 0x9068, 0x906a,
+    0x906d, 0x906f, 0x9071, 0x9074, 0x9076, 0x9078, 0x907b, 0x907d, 0x907f, 0x9082, 0x9084, 0x9086,
+    0x9089, 0x908b, 0x908d, 0x9090, 0x9092, 0x9094, 0x9097, 0x9099, 0x909c, 0x909e, 0x90a0, 0x90a3,
+    0x90a5, 0x90a8, 0x90aa, 0x90ac, 0x90af, 0x90b1, 0x90b3, 0x90b6, 0x90b8, 0x90bb, 0x90bd, 0x90c0,
+    0x90c2, 0x90c4, 0x90c7, 0x90c9, 0x90cc, 0x90ce, 0x90d1, 0x90d3, 0x90d5, 0x90d8, 0x90da, 0x90dd,
+    0x90df, 0x90e2, 0x90e4, 0x90e7, 0x90e9, 0x90eb, 0x90ee, 0x90f0, 0x90f3, 0x90f5, 0x90f8, 0x90fa,
+    0x90fd, 0x90ff, 0x9102, 0x9104, 0x9107, 0x9109, 0x910c, 0x910e, 0x9111, 0x9113, 0x9116, 0x9118,
+    0x911b, 0x911d, 0x9120, 0x9122, 0x9125, 0x9127, 0x912a, 0x912c, 0x912f, 0x9131, 0x9134, 0x9137,
+    0x9139, 0x913c, 0x913e, 0x9141, 0x9143, 0x9146, 0x9148, 0x914b, 0x914d, 0x9150, 0x9153, 0x9155,
+    0x9158, 0x915a, 0x915d, 0x9160, 0x9162, 0x9165, 0x9167, 0x916a, 0x916c, 0x916f, 0x9172, 0x9174,
+    0x9177, 0x9179, 0x917c, 0x917f, 0x9181, 0x9184, 0x9187, 0x9189, 0x918c, 0x918e, 0x9191, 0x9194,
+    0x9196, 0x9199, 0x919c, 0x919e, 0x91a1, 0x91a4, 0x91a6, 0x91a9, 0x91ac, 0x91ae, 0x91b1, 0x91b4,
+    0x91b6, 0x91b9, 0x91bc, 0x91be, 0x91c1, 0x91c4, 0x91c6, 0x91c9, 0x91cc, 0x91ce, 0x91d1, 0x91d4,
+    0x91d6, 0x91d9, 0x91dc, 0x91df, 0x91e1, 0x91e4, 0x91e7, 0x91e9, 0x91ec, 0x91ef, 0x91f2, 0x91f4,
+    0x91f7, 0x91fa, 0x91fd, 0x91ff, 0x9202, 0x9205, 0x9208, 0x920a, 0x920d, 0x9210, 0x9213, 0x9215,
+    0x9218, 0x921b, 0x921e, 0x9220, 0x9223, 0x9226, 0x9229, 0x922c, 0x922e, 0x9231, 0x9234, 0x9237,
+    0x923a, 0x923c, 0x923f, 0x9242, 0x9245, 0x9248, 0x924a, 0x924d, 0x9250, 0x9253, 0x9256, 0x9259,
+    0x925b, 0x925e, 0x9261, 0x9264, 0x9267, 0x926a, 0x926c, 0x926f, 0x9272, 0x9275, 0x9278, 0x927b,
+    0x927e, 0x9280, 0x9283, 0x9286, 0x9289, 0x928c, 0x928f, 0x9292, 0x9295, 0x9297, 0x929a, 0x929d,
+    0x92a0, 0x92a3, 0x92a6, 0x92a9, 0x92ac, 0x92af, 0x92b2, 0x92b4, 0x92b7, 0x92ba, 0x92bd, 0x92c0,
+    0x92c3, 0x92c6, 0x92c9, 0x92cc, 0x92cf, 0x92d2, 0x92d5, 0x92d8, 0x92db, 0x92de, 0x92e1, 0x92e3,
+    0x92e6, 0x92e9, 0x92ec, 0x92ef, 0x92f2, 0x92f5, 0x92f8, 0x92fb, 0x92fe, 0x9301, 0x9304, 0x9307,
+    0x930a, 0x930d, 0x9310, 0x9313, 0x9316, 0x9319, 0x931c, 0x931f, 0x9322, 0x9325, 0x9328, 0x932b,
+    0x932e, 0x9331, 0x9334, 0x9337, 0x933a, 0x933d, 0x9341, 0x9344, 0x9347, 0x934a, 0x934d, 0x9350,
+    0x9353, 0x9356, 0x9359, 0x935c, 0x935f, 0x9362, 0x9365, 0x9368, 0x936b, 0x936e, 0x9372, 0x9375,
+    0x9378, 0x937b, 0x937e, 0x9381, 0x9384, 0x9387, 0x938a, 0x938d, 0x9391, 0x9394, 0x9397, 0x939a,
+    0x939d, 0x93a0, 0x93a3, 0x93a6, 0x93aa, 0x93ad, 0x93b0, 0x93b3, 0x93b6, 0x93b9, 0x93bc, 0x93c0,
+    0x93c3, 0x93c6, 0x93c9, 0x93cc, 0x93cf, 0x93d3, 0x93d6, 0x93d9, 0x93dc, 0x93df, 0x93e2, 0x93e6,
+    0x93e9, 0x93ec, 0x93ef, 0x93f2, 0x93f6, 0x93f9, 0x93fc, 0x93ff, 0x9401, 0x9403, 0x9404, 0x9406,
+    0x9408, 0x9409, 0x940b, 0x940d, 0x940e, 0x9410, 0x9411, 0x9413, 0x9415, 0x9416, 0x9418, 0x9419,
+    0x941b, 0x941d, 0x941e, 0x9420, 0x9422, 0x9423, 0x9425, 0x9427, 0x9428, 0x942a, 0x942b, 0x942d,
+    0x942f, 0x9430, 0x9432, 0x9434, 0x9435, 0x9437, 0x9439, 0x943a, 0x943c, 0x943e, 0x943f, 0x9441,
+    0x9443, 0x9444, 0x9446, 0x9448, 0x9449, 0x944b, 0x944d, 0x944e, 0x9450, 0x9452, 0x9453, 0x9455,
+    0x9457, 0x9458, 0x945a, 0x945c, 0x945d, 0x945f, 0x9461, 0x9462, 0x9464, 0x9466, 0x9467, 0x9469,
+    0x946b, 0x946d, 0x946e, 0x9470, 0x9472, 0x9473, 0x9475, 0x9477, 0x9478, 0x947a, 0x947c, 0x947e,
+    0x947f, 0x9481, 0x9483, 0x9484, 0x9486, 0x9488, 0x948a, 0x948b, 0x948d, 0x948f, 0x9490, 0x9492,
+    0x9494, 0x9496, 0x9497, 0x9499, 0x949b, 0x949d, 0x949e, 0x94a0, 0x94a2, 0x94a4, 0x94a5, 0x94a7,
+    0x94a9, 0x94ab, 0x94ac, 0x94ae, 0x94b0, 0x94b2, 0x94b3, 0x94b5, 0x94b7, 0x94b9, 0x94ba, 0x94bc,
+    0x94be, 0x94c0, 0x94c1, 0x94c3, 0x94c5, 0x94c7, 0x94c8, 0x94ca, 0x94cc, 0x94ce, 0x94cf, 0x94d1,
+    0x94d3, 0x94d5, 0x94d7, 0x94d8, 0x94da, 0x94dc, 0x94de, 0x94e0, 0x94e1, 0x94e3, 0x94e5, 0x94e7,
+    0x94e8, 0x94ea, 0x94ec, 0x94ee, 0x94f0, 0x94f1, 0x94f3, 0x94f5, 0x94f7, 0x94f9, 0x94fa, 0x94fc,
+    0x94fe, 0x9500, 0x9502, 0x9504, 0x9505, 0x9507, 0x9509, 0x950b, 0x950d, 0x950e, 0x9510, 0x9512,
+    0x9514, 0x9516, 0x9518, 0x9519, 0x951b, 0x951d, 0x951f, 0x9521, 0x9523, 0x9524, 0x9526, 0x9528,
+    0x952a, 0x952c, 0x952e, 0x9530, 0x9531, 0x9533, 0x9535, 0x9537, 0x9539, 0x953b, 0x953d, 0x953e,
+    0x9540, 0x9542, 0x9544, 0x9546, 0x9548, 0x954a, 0x954b, 0x954d, 0x954f, 0x9551, 0x9553, 0x9555,
+    0x9557, 0x9559, 0x955a, 0x955c, 0x955e, 0x9560, 0x9562, 0x9564, 0x9566, 0x9568, 0x956a, 0x956b,
+    0x956d, 0x956f, 0x9571, 0x9573, 0x9575, 0x9577, 0x9579, 0x957b, 0x957d, 0x957e, 0x9580, 0x9582,
+    0x9584, 0x9586, 0x9588, 0x958a, 0x958c, 0x958e, 0x9590, 0x9592, 0x9594, 0x9595, 0x9597, 0x9599,
+    0x959b, 0x959d, 0x959f, 0x95a1, 0x95a3, 0x95a5, 0x95a7, 0x95a9, 0x95ab, 0x95ad, 0x95af, 0x95b1,
+    0x95b3, 0x95b4, 0x95b6, 0x95b8, 0x95ba, 0x95bc, 0x95be, 0x95c0, 0x95c2, 0x95c4, 0x95c6, 0x95c8,
+    0x95ca, 0x95cc, 0x95ce, 0x95d0, 0x95d2, 0x95d4, 0x95d6, 0x95d8, 0x95da, 0x95dc, 0x95de, 0x95e0,
+    0x95e2, 0x95e4, 0x95e6, 0x95e8, 0x95ea, 0x95ec, 0x95ee, 0x95f0, 0x95f2, 0x95f4, 0x95f6, 0x95f8,
+    0x95fa, 0x95fc, 0x95fe, 0x9600, 0x9602, 0x9604, 0x9606, 0x9608, 0x960a, 0x960c, 0x960e, 0x9610,
+    0x9612, 0x9614, 0x9616, 0x9618, 0x961a, 0x961c, 0x961e, 0x9620, 0x9622, 0x9624, 0x9626, 0x9628,
+    0x962a, 0x962c, 0x962e, 0x9630, 0x9632, 0x9634, 0x9636, 0x9638, 0x963a, 0x963c, 0x963e, 0x9640,
+    0x9642, 0x9644, 0x9647, 0x9649, 0x964b, 0x964d, 0x964f, 0x9651, 0x9653, 0x9655, 0x9657, 0x9659,
+    0x965b, 0x965d, 0x965f, 0x9661, 0x9663, 0x9665, 0x9668, 0x966a, 0x966c, 0x966e, 0x9670, 0x9672,
+    0x9674, 0x9676, 0x9678, 0x967a, 0x967c, 0x967e, 0x9681, 0x9683, 0x9685, 0x9687, 0x9689, 0x968b,
+    0x968d, 0x968f, 0x9691, 0x9693, 0x9696, 0x9698, 0x969a, 0x969c, 0x969e, 0x96a0, 0x96a2, 0x96a4,
+    0x96a6, 0x96a9, 0x96ab, 0x96ad, 0x96af, 0x96b1, 0x96b3, 0x96b5, 0x96b7, 0x96ba, 0x96bc, 0x96be,
+    0x96c0, 0x96c2, 0x96c4, 0x96c6, 0x96c9, 0x96cb, 0x96cd, 0x96cf, 0x96d1, 0x96d3, 0x96d5, 0x96d8,
+    0x96da, 0x96dc, 0x96de, 0x96e0, 0x96e2, 0x96e4, 0x96e7, 0x96e9, 0x96eb, 0x96ed, 0x96ef, 0x96f1,
+    0x96f4, 0x96f6, 0x96f8, 0x96fa, 0x96fc, 0x96ff, 0x9701, 0x9703, 0x9705, 0x9707, 0x9709, 0x970c,
+    0x970e, 0x9710, 0x9712, 0x9714, 0x9717, 0x9719, 0x971b, 0x971d, 0x971f, 0x9722, 0x9724, 0x9726,
+    0x9728, 0x972a, 0x972d, 0x972f, 0x9731, 0x9733, 0x9735, 0x9738, 0x973a, 0x973c, 0x973e, 0x9740,
+    0x9743, 0x9745, 0x9747, 0x9749, 0x974c, 0x974e, 0x9750, 0x9752, 0x9755, 0x9757, 0x9759, 0x975b,
+    0x975d, 0x9760, 0x9762, 0x9764, 0x9766, 0x9769, 0x976b, 0x976d, 0x976f, 0x9772, 0x9774, 0x9776,
+    0x9778, 0x977b, 0x977d, 0x977f, 0x9781, 0x9784, 0x9786, 0x9788, 0x978b, 0x978d, 0x978f, 0x9791,
+    0x9794, 0x9796, 0x9798, 0x979a, 0x979d, 0x979f, 0x97a1, 0x97a4, 0x97a6, 0x97a8, 0x97aa, 0x97ad,
+    0x97af, 0x97b1, 0x97b4, 0x97b6, 0x97b8, 0x97bb, 0x97bd, 0x97bf, 0x97c1, 0x97c4, 0x97c6, 0x97c8,
+    0x97cb, 0x97cd, 0x97cf, 0x97d2, 0x97d4, 0x97d6, 0x97d9, 0x97db, 0x97dd, 0x97e0, 0x97e2, 0x97e4,
+    0x97e6, 0x97e9, 0x97eb, 0x97ed, 0x97f0, 0x97f2, 0x97f4, 0x97f7, 0x97f9, 0x97fb, 0x97fe, 0x9800,
+    0x9801, 0x9802, 0x9804, 0x9805, 0x9806, 0x9807, 0x9808, 0x9809, 0x980b, 0x980c, 0x980d, 0x980e,
+    0x980f, 0x9811, 0x9812, 0x9813, 0x9814, 0x9815, 0x9816, 0x9818, 0x9819, 0x981a, 0x981b, 0x981c,
+    0x981e, 0x981f, 0x9820, 0x9821, 0x9822, 0x9824, 0x9825, 0x9826, 0x9827, 0x9828, 0x982a, 0x982b,
+    0x982c, 0x982d, 0x982e, 0x9830, 0x9831, 0x9832, 0x9833, 0x9834, 0x9836, 0x9837, 0x9838, 0x9839,
+    0x983a, 0x983c, 0x983d, 0x983e, 0x983f, 0x9840, 0x9842, 0x9843, 0x9844, 0x9845, 0x9846, 0x9848,
+    0x9849, 0x984a, 0x984b, 0x984d, 0x984e, 0x984f, 0x9850, 0x9851, 0x9853, 0x9854, 0x9855, 0x9856,
+    0x9858, 0x9859, 0x985a, 0x985b, 0x985c, 0x985e, 0x985f, 0x9860, 0x9861, 0x9863, 0x9864, 0x9865,
+    0x9866, 0x9868, 0x9869, 0x986a, 0x986b, 0x986c, 0x986e, 0x986f, 0x9870, 0x9871, 0x9873, 0x9874,
+    0x9875, 0x9876, 0x9878, 0x9879, 0x987a, 0x987b, 0x987d, 0x987e, 0x987f, 0x9880, 0x9882, 0x9883,
+    0x9884, 0x9885, 0x9887, 0x9888, 0x9889, 0x988a, 0x988c, 0x988d, 0x988e, 0x988f, 0x9891, 0x9892,
+    0x9893, 0x9894, 0x9896, 0x9897, 0x9898, 0x989b, 0x989d, 0x98a0, 0x98a2, 0x98a5, 0x98a7, 0x98aa,
+    0x98ad, 0x98af, 0x98b2, 0x98b4, 0x98b7, 0x98b9, 0x98bc, 0x98bf, 0x98c1, 0x98c4, 0x98c6, 0x98c9,
+    0x98cb, 0x98ce, 0x98d1, 0x98d3, 0x98d6, 0x98d8, 0x98db, 0x98de, 0x98e0, 0x98e3, 0x98e5, 0x98e8,
+    0x98eb, 0x98ed, 0x98f0, 0x98f3, 0x98f5, 0x98f8, 0x98fa, 0x98fd, 0x9900, 0x9902, 0x9905, 0x9908,
+    0x990a, 0x990d, 0x9910, 0x9912, 0x9915, 0x9918, 0x991a, 0x991d, 0x9920, 0x9922, 0x9925, 0x9928,
+    0x992a, 0x992d, 0x9930, 0x9933, 0x9935, 0x9938, 0x993b, 0x993d, 0x9940, 0x9943, 0x9946, 0x9948,
+    0x994b, 0x994e, 0x9950, 0x9953, 0x9956, 0x9959, 0x995b, 0x995e, 0x9961, 0x9964, 0x9966, 0x9969,
+    0x996c, 0x996f, 0x9972, 0x9974, 0x9977, 0x997a, 0x997d, 0x997f, 0x9982, 0x9985, 0x9988, 0x998b,
+    0x998d, 0x9990, 0x9993, 0x9996, 0x9999, 0x999b, 0x999e, 0x99a1, 0x99a4, 0x99a7, 0x99aa, 0x99ac,
+    0x99af, 0x99b2, 0x99b5, 0x99b8, 0x99bb, 0x99bd, 0x99c0, 0x99c3, 0x99c6, 0x99c9, 0x99cc, 0x99cf,
+    0x99d1, 0x99d4, 0x99d7, 0x99da, 0x99dd, 0x99e0, 0x99e3, 0x99e6, 0x99e9, 0x99eb, 0x99ee, 0x99f1,
+    0x99f4, 0x99f7, 0x99fa, 0x99fd, 0x9a00, 0x9a03, 0x9a06, 0x9a09, 0x9a0c, 0x9a0f, 0x9a11, 0x9a14,
+    0x9a17, 0x9a1a, 0x9a1d, 0x9a20, 0x9a23, 0x9a26, 0x9a29, 0x9a2c, 0x9a2f, 0x9a32, 0x9a35, 0x9a38,
+    0x9a3b, 0x9a3e, 0x9a41, 0x9a44, 0x9a47, 0x9a4a, 0x9a4d, 0x9a50, 0x9a53, 0x9a56, 0x9a59, 0x9a5c,
+    0x9a5f, 0x9a62, 0x9a65, 0x9a68, 0x9a6b, 0x9a6e, 0x9a71, 0x9a74, 0x9a77, 0x9a7a, 0x9a7d, 0x9a80,
+    0x9a83, 0x9a86, 0x9a8a, 0x9a8d, 0x9a90, 0x9a93, 0x9a96, 0x9a99, 0x9a9c, 0x9a9f, 0x9aa2, 0x9aa5,
+    0x9aa8, 0x9aab, 0x9aaf, 0x9ab2, 0x9ab5, 0x9ab8, 0x9abb, 0x9abe, 0x9ac1, 0x9ac4, 0x9ac7, 0x9acb,
+    0x9ace, 0x9ad1, 0x9ad4, 0x9ad7, 0x9ada, 0x9add, 0x9ae1, 0x9ae4, 0x9ae7, 0x9aea, 0x9aed, 0x9af0,
+    0x9af3, 0x9af7, 0x9afa, 0x9afd, 0x9b00, 0x9b03, 0x9b07, 0x9b0a, 0x9b0d, 0x9b10, 0x9b13, 0x9b16,
+    0x9b1a, 0x9b1d, 0x9b20, 0x9b23, 0x9b27, 0x9b2a, 0x9b2d, 0x9b30, 0x9b33, 0x9b37, 0x9b3a, 0x9b3d,
+    0x9b40, 0x9b44, 0x9b47, 0x9b4a, 0x9b4d, 0x9b51, 0x9b54, 0x9b57, 0x9b5a, 0x9b5e, 0x9b61, 0x9b64,
+    0x9b67, 0x9b6b, 0x9b6e, 0x9b71, 0x9b75, 0x9b78, 0x9b7b, 0x9b7e, 0x9b82, 0x9b85, 0x9b88, 0x9b8c,
+    0x9b8f, 0x9b92, 0x9b96, 0x9b99, 0x9b9c, 0x9ba0, 0x9ba3, 0x9ba6, 0x9baa, 0x9bad, 0x9bb0, 0x9bb4,
+    0x9bb7, 0x9bba, 0x9bbe, 0x9bc1, 0x9bc4, 0x9bc8, 0x9bcb, 0x9bcf, 0x9bd2, 0x9bd5, 0x9bd9, 0x9bdc,
+    0x9be0, 0x9be3, 0x9be6, 0x9bea, 0x9bed, 0x9bf0, 0x9bf4, 0x9bf7, 0x9bfb, 0x9bfe, 0x9c01, 0x9c02,
+    0x9c04, 0x9c06, 0x9c08, 0x9c09, 0x9c0b, 0x9c0d, 0x9c0f, 0x9c10, 0x9c12, 0x9c14, 0x9c15, 0x9c17,
+    0x9c19, 0x9c1b, 0x9c1c, 0x9c1e, 0x9c20, 0x9c22, 0x9c23, 0x9c25, 0x9c27, 0x9c29, 0x9c2a, 0x9c2c,
+    0x9c2e, 0x9c30, 0x9c31, 0x9c33, 0x9c35, 0x9c37, 0x9c38, 0x9c3a, 0x9c3c, 0x9c3e, 0x9c3f, 0x9c41,
+    0x9c43, 0x9c45, 0x9c46, 0x9c48, 0x9c4a, 0x9c4c, 0x9c4e, 0x9c4f, 0x9c51, 0x9c53, 0x9c55, 0x9c56,
+    0x9c58, 0x9c5a, 0x9c5c, 0x9c5e, 0x9c5f, 0x9c61, 0x9c63, 0x9c65, 0x9c67, 0x9c68, 0x9c6a, 0x9c6c,
+    0x9c6e, 0x9c70, 0x9c71, 0x9c73, 0x9c75, 0x9c77, 0x9c79, 0x9c7b, 0x9c7c, 0x9c7e, 0x9c80, 0x9c82,
+    0x9c84, 0x9c85, 0x9c87, 0x9c89, 0x9c8b, 0x9c8d, 0x9c8f, 0x9c90, 0x9c92, 0x9c94, 0x9c96, 0x9c98,
+    0x9c9a, 0x9c9c, 0x9c9d, 0x9c9f, 0x9ca1, 0x9ca3, 0x9ca5, 0x9ca7, 0x9ca8, 0x9caa, 0x9cac, 0x9cae,
+    0x9cb0, 0x9cb2, 0x9cb4, 0x9cb6, 0x9cb7, 0x9cb9, 0x9cbb, 0x9cbd, 0x9cbf, 0x9cc1, 0x9cc3, 0x9cc5,
+    0x9cc6, 0x9cc8, 0x9cca, 0x9ccc, 0x9cce, 0x9cd0, 0x9cd2, 0x9cd4, 0x9cd6, 0x9cd7, 0x9cd9, 0x9cdb,
+    0x9cdd, 0x9cdf, 0x9ce1, 0x9ce3, 0x9ce5, 0x9ce7, 0x9ce9, 0x9cea, 0x9cec, 0x9cee, 0x9cf0, 0x9cf2,
+    0x9cf4, 0x9cf6, 0x9cf8, 0x9cfa, 0x9cfc, 0x9cfe, 0x9d00, 0x9d02, 0x9d03, 0x9d05, 0x9d07, 0x9d09,
+    0x9d0b, 0x9d0d, 0x9d0f, 0x9d11, 0x9d13, 0x9d15, 0x9d17, 0x9d19, 0x9d1b, 0x9d1d, 0x9d1f, 0x9d21,
+    0x9d23, 0x9d25, 0x9d27, 0x9d29, 0x9d2a, 0x9d2c, 0x9d2e, 0x9d30, 0x9d32, 0x9d34, 0x9d36, 0x9d38,
+    0x9d3a, 0x9d3c, 0x9d3e, 0x9d40, 0x9d42, 0x9d44, 0x9d46, 0x9d48, 0x9d4a, 0x9d4c, 0x9d4e, 0x9d50,
+    0x9d52, 0x9d54, 0x9d56, 0x9d58, 0x9d5a, 0x9d5c, 0x9d5e, 0x9d60, 0x9d62, 0x9d64, 0x9d66, 0x9d68,
+    0x9d6a, 0x9d6c, 0x9d6e, 0x9d70, 0x9d72, 0x9d74, 0x9d76, 0x9d78, 0x9d7a, 0x9d7c, 0x9d7e, 0x9d80,
+    0x9d83, 0x9d85, 0x9d87, 0x9d89, 0x9d8b, 0x9d8d, 0x9d8f, 0x9d91, 0x9d93, 0x9d95, 0x9d97, 0x9d99,
+    0x9d9b, 0x9d9d, 0x9d9f, 0x9da1, 0x9da3, 0x9da5, 0x9da7, 0x9daa, 0x9dac, 0x9dae, 0x9db0, 0x9db2,
+    0x9db4, 0x9db6, 0x9db8, 0x9dba, 0x9dbc, 0x9dbe, 0x9dc0, 0x9dc2, 0x9dc5, 0x9dc7, 0x9dc9, 0x9dcb,
+    0x9dcd, 0x9dcf, 0x9dd1, 0x9dd3, 0x9dd5, 0x9dd7, 0x9dd9, 0x9ddc, 0x9dde, 0x9de0, 0x9de2, 0x9de4,
+    0x9de6, 0x9de8, 0x9dea, 0x9dec, 0x9def, 0x9df1, 0x9df3, 0x9df5, 0x9df7, 0x9df9, 0x9dfb, 0x9dfd,
+    0x9e00, 0x9e02, 0x9e04, 0x9e06, 0x9e08, 0x9e0a, 0x9e0c, 0x9e0f, 0x9e11, 0x9e13, 0x9e15, 0x9e17,
+    0x9e19, 0x9e1b, 0x9e1e, 0x9e20, 0x9e22, 0x9e24, 0x9e26, 0x9e28, 0x9e2b, 0x9e2d, 0x9e2f, 0x9e31,
+    0x9e33, 0x9e35, 0x9e38, 0x9e3a, 0x9e3c, 0x9e3e, 0x9e40, 0x9e42, 0x9e45, 0x9e47, 0x9e49, 0x9e4b,
+    0x9e4d, 0x9e50, 0x9e52, 0x9e54, 0x9e56, 0x9e58, 0x9e5b, 0x9e5d, 0x9e5f, 0x9e61, 0x9e63, 0x9e66,
+    0x9e68, 0x9e6a, 0x9e6c, 0x9e6e, 0x9e71, 0x9e73, 0x9e75, 0x9e77, 0x9e7a, 0x9e7c, 0x9e7e, 0x9e80,
+    0x9e82, 0x9e85, 0x9e87, 0x9e89, 0x9e8b, 0x9e8e, 0x9e90, 0x9e92, 0x9e94, 0x9e97, 0x9e99, 0x9e9b,
+    0x9e9d, 0x9ea0, 0x9ea2, 0x9ea4, 0x9ea6, 0x9ea9, 0x9eab, 0x9ead, 0x9eaf, 0x9eb2, 0x9eb4, 0x9eb6,
+    0x9eb8, 0x9ebb, 0x9ebd, 0x9ebf, 0x9ec2, 0x9ec4, 0x9ec6, 0x9ec8, 0x9ecb, 0x9ecd, 0x9ecf, 0x9ed2,
+    0x9ed4, 0x9ed6, 0x9ed8, 0x9edb, 0x9edd, 0x9edf, 0x9ee2, 0x9ee4, 0x9ee6, 0x9ee8, 0x9eeb, 0x9eed,
+    0x9eef, 0x9ef2, 0x9ef4, 0x9ef6, 0x9ef9, 0x9efb, 0x9efd, 0x9f00, 0x9f02, 0x9f04, 0x9f07, 0x9f09,
+    0x9f0b, 0x9f0e, 0x9f10, 0x9f12, 0x9f15, 0x9f17, 0x9f19, 0x9f1c, 0x9f1e, 0x9f20, 0x9f23, 0x9f25,
+    0x9f27, 0x9f2a, 0x9f2c, 0x9f2e, 0x9f31, 0x9f33, 0x9f35, 0x9f38, 0x9f3a, 0x9f3c, 0x9f3f, 0x9f41,
+    0x9f44, 0x9f46, 0x9f48, 0x9f4b, 0x9f4d, 0x9f4f, 0x9f52, 0x9f54, 0x9f57, 0x9f59, 0x9f5b, 0x9f5e,
+    0x9f60, 0x9f62, 0x9f65, 0x9f67, 0x9f6a, 0x9f6c, 0x9f6e, 0x9f71, 0x9f73, 0x9f76, 0x9f78, 0x9f7a,
+    0x9f7d, 0x9f7f, 0x9f82, 0x9f84, 0x9f87, 0x9f89, 0x9f8b, 0x9f8e, 0x9f90, 0x9f93, 0x9f95, 0x9f97,
+    0x9f9a, 0x9f9c, 0x9f9f, 0x9fa1, 0x9fa4, 0x9fa6, 0x9fa8, 0x9fab, 0x9fad, 0x9fb0, 0x9fb2, 0x9fb5,
+    0x9fb7, 0x9fba, 0x9fbc, 0x9fbe, 0x9fc1, 0x9fc3, 0x9fc6, 0x9fc8, 0x9fcb, 0x9fcd, 0x9fd0, 0x9fd2,
+    0x9fd5, 0x9fd7, 0x9fda, 0x9fdc, 0x9fde, 0x9fe1, 0x9fe3, 0x9fe6, 0x9fe8, 0x9feb, 0x9fed, 0x9ff0,
+    0x9ff2, 0x9ff5, 0x9ff7, 0x9ffa, 0x9ffc, 0x9fff, 0xa001, 0xa002, 0xa003, 0xa004, 0xa006, 0xa007,
+    0xa008, 0xa009, 0xa00b, 0xa00c, 0xa00d, 0xa00e, 0xa010, 0xa011, 0xa012, 0xa013, 0xa015, 0xa016,
+    0xa017, 0xa018, 0xa01a, 0xa01b, 0xa01c, 0xa01e, 0xa01f, 0xa020, 0xa021, 0xa023, 0xa024, 0xa025,
+    0xa026, 0xa028, 0xa029, 0xa02a, 0xa02c, 0xa02d, 0xa02e, 0xa02f, 0xa031, 0xa032, 0xa033, 0xa034,
+    0xa036, 0xa037, 0xa038, 0xa03a, 0xa03b, 0xa03c, 0xa03d, 0xa03f, 0xa040, 0xa041, 0xa043, 0xa044,
+    0xa045, 0xa047, 0xa048, 0xa049, 0xa04a, 0xa04c, 0xa04d, 0xa04e, 0xa050, 0xa051, 0xa052, 0xa054,
+    0xa055, 0xa056, 0xa057, 0xa059, 0xa05a, 0xa05b, 0xa05d, 0xa05e, 0xa05f, 0xa061, 0xa062, 0xa063,
+    0xa065, 0xa066, 0xa067, 0xa068, 0xa06a, 0xa06b, 0xa06c, 0xa06e, 0xa06f, 0xa070, 0xa072, 0xa073,
+    0xa074, 0xa076, 0xa077, 0xa078, 0xa07a, 0xa07b, 0xa07c, 0xa07e, 0xa07f, 0xa080, 0xa082, 0xa083,
+    0xa084, 0xa086, 0xa087, 0xa088, 0xa08a, 0xa08b, 0xa08c, 0xa08e, 0xa08f, 0xa090, 0xa092, 0xa093,
+    0xa094, 0xa096, 0xa097, 0xa098, 0xa09a, 0xa09b, 0xa09c, 0xa09e, 0xa09f, 0xa0a0, 0xa0a2, 0xa0a3,
+    0xa0a5, 0xa0a6, 0xa0a7, 0xa0a9, 0xa0aa, 0xa0ab, 0xa0ad, 0xa0ae, 0xa0af, 0xa0b1, 0xa0b2, 0xa0b3,
+    0xa0b5, 0xa0b6, 0xa0b8, 0xa0b9, 0xa0ba, 0xa0bc, 0xa0bd, 0xa0be, 0xa0c0, 0xa0c1, 0xa0c2, 0xa0c4,
+    0xa0c5, 0xa0c7, 0xa0c8, 0xa0c9, 0xa0cb, 0xa0cc, 0xa0cd, 0xa0cf, 0xa0d0, 0xa0d2, 0xa0d3, 0xa0d4,
+    0xa0d6, 0xa0d7, 0xa0d9, 0xa0da, 0xa0db, 0xa0dd, 0xa0de, 0xa0df, 0xa0e1, 0xa0e2, 0xa0e4, 0xa0e5,
+    0xa0e6, 0xa0e8, 0xa0e9, 0xa0eb, 0xa0ec, 0xa0ed, 0xa0ef, 0xa0f0, 0xa0f2, 0xa0f3, 0xa0f4, 0xa0f6,
+    0xa0f7, 0xa0f9, 0xa0fa, 0xa0fb, 0xa0fd, 0xa0fe, 0xa100, 0xa101, 0xa102, 0xa104, 0xa105, 0xa107,
+    0xa108, 0xa10a, 0xa10b, 0xa10c, 0xa10e, 0xa10f, 0xa111, 0xa112, 0xa113, 0xa115, 0xa116, 0xa118,
+    0xa119, 0xa11b, 0xa11c, 0xa11d, 0xa11f, 0xa120, 0xa122, 0xa123, 0xa125, 0xa126, 0xa127, 0xa129,
+    0xa12a, 0xa12c, 0xa12d, 0xa12f, 0xa130, 0xa131, 0xa133, 0xa134, 0xa136, 0xa137, 0xa139, 0xa13a,
+    0xa13c, 0xa13d, 0xa13e, 0xa140, 0xa141, 0xa143, 0xa144, 0xa146, 0xa147, 0xa14a, 0xa14d, 0xa150,
+    0xa153, 0xa156, 0xa159, 0xa15c, 0xa15f, 0xa161, 0xa164, 0xa167, 0xa16a, 0xa16d, 0xa170, 0xa173,
+    0xa176, 0xa179, 0xa17c, 0xa17f, 0xa182, 0xa185, 0xa188, 0xa18b, 0xa18e, 0xa191, 0xa194, 0xa197,
+    0xa19a, 0xa19d, 0xa1a0, 0xa1a3, 0xa1a6, 0xa1a9, 0xa1ac, 0xa1af, 0xa1b2, 0xa1b5, 0xa1b8, 0xa1bb,
+    0xa1be, 0xa1c1, 0xa1c4, 0xa1c7, 0xa1ca, 0xa1cd, 0xa1d0, 0xa1d3, 0xa1d6, 0xa1da, 0xa1dd, 0xa1e0,
+    0xa1e3, 0xa1e6, 0xa1e9, 0xa1ec, 0xa1ef, 0xa1f2, 0xa1f5, 0xa1f8, 0xa1fb, 0xa1ff, 0xa202, 0xa205,
+    0xa208, 0xa20b, 0xa20e, 0xa211, 0xa214, 0xa218, 0xa21b, 0xa21e, 0xa221, 0xa224, 0xa227, 0xa22b,
+    0xa22e, 0xa231, 0xa234, 0xa237, 0xa23a, 0xa23e, 0xa241, 0xa244, 0xa247, 0xa24a, 0xa24d, 0xa251,
+    0xa254, 0xa257, 0xa25a, 0xa25e, 0xa261, 0xa264, 0xa267, 0xa26a, 0xa26e, 0xa271, 0xa274, 0xa277,
+    0xa27b, 0xa27e, 0xa281, 0xa284, 0xa288, 0xa28b, 0xa28e, 0xa291, 0xa295, 0xa298, 0xa29b, 0xa29f,
+    0xa2a2, 0xa2a5, 0xa2a8, 0xa2ac, 0xa2af, 0xa2b2, 0xa2b6, 0xa2b9, 0xa2bc, 0xa2c0, 0xa2c3, 0xa2c6,
+    0xa2c9, 0xa2cd, 0xa2d0, 0xa2d4, 0xa2d7, 0xa2da, 0xa2de, 0xa2e1, 0xa2e4, 0xa2e8, 0xa2eb, 0xa2ee,
+    0xa2f2, 0xa2f5, 0xa2f8, 0xa2fc, 0xa2ff, 0xa303, 0xa306, 0xa309, 0xa30d, 0xa310, 0xa314, 0xa317,
+    0xa31a, 0xa31e, 0xa321, 0xa325, 0xa328, 0xa32c, 0xa32f, 0xa332, 0xa336, 0xa339, 0xa33d, 0xa340,
+    0xa344, 0xa347, 0xa34b, 0xa34e, 0xa351, 0xa355, 0xa358, 0xa35c, 0xa35f, 0xa363, 0xa366, 0xa36a,
+    0xa36d, 0xa371, 0xa374, 0xa378, 0xa37b, 0xa37f, 0xa382, 0xa386, 0xa389, 0xa38d, 0xa390, 0xa394,
+    0xa398, 0xa39b, 0xa39f, 0xa3a2, 0xa3a6, 0xa3a9, 0xa3ad, 0xa3b0, 0xa3b4, 0xa3b8, 0xa3bb, 0xa3bf,
+    0xa3c2, 0xa3c6, 0xa3c9, 0xa3cd, 0xa3d1, 0xa3d4, 0xa3d8, 0xa3db, 0xa3df, 0xa3e3, 0xa3e6, 0xa3ea,
+    0xa3ee, 0xa3f1, 0xa3f5, 0xa3f8, 0xa3fc, 0xa400, 0xa402, 0xa403, 0xa405, 0xa407, 0xa409, 0xa40b,
+    0xa40d, 0xa40e, 0xa410, 0xa412, 0xa414, 0xa416, 0xa418, 0xa41a, 0xa41b, 0xa41d, 0xa41f, 0xa421,
+    0xa423, 0xa425, 0xa426, 0xa428, 0xa42a, 0xa42c, 0xa42e, 0xa430, 0xa432, 0xa434, 0xa435, 0xa437,
+    0xa439, 0xa43b, 0xa43d, 0xa43f, 0xa441, 0xa443, 0xa444, 0xa446, 0xa448, 0xa44a, 0xa44c, 0xa44e,
+    0xa450, 0xa452, 0xa454, 0xa455, 0xa457, 0xa459, 0xa45b, 0xa45d, 0xa45f, 0xa461, 0xa463, 0xa465,
+    0xa467, 0xa469, 0xa46b, 0xa46c, 0xa46e, 0xa470, 0xa472, 0xa474, 0xa476, 0xa478, 0xa47a, 0xa47c,
+    0xa47e, 0xa480, 0xa482, 0xa484, 0xa486, 0xa488, 0xa489, 0xa48b, 0xa48d, 0xa48f, 0xa491, 0xa493,
+    0xa495, 0xa497, 0xa499, 0xa49b, 0xa49d, 0xa49f, 0xa4a1, 0xa4a3, 0xa4a5, 0xa4a7, 0xa4a9, 0xa4ab,
+    0xa4ad, 0xa4af, 0xa4b1, 0xa4b3, 0xa4b5, 0xa4b7, 0xa4b9, 0xa4bb, 0xa4bd, 0xa4bf, 0xa4c1, 0xa4c3,
+    0xa4c5, 0xa4c7, 0xa4c9, 0xa4cb, 0xa4cd, 0xa4cf, 0xa4d1, 0xa4d3, 0xa4d5, 0xa4d7, 0xa4d9, 0xa4db,
+    0xa4dd, 0xa4df, 0xa4e1, 0xa4e3, 0xa4e5, 0xa4e7, 0xa4e9, 0xa4eb, 0xa4ed, 0xa4ef, 0xa4f1, 0xa4f3,
+    0xa4f5, 0xa4f7, 0xa4fa, 0xa4fc, 0xa4fe, 0xa500, 0xa502, 0xa504, 0xa506, 0xa508, 0xa50a, 0xa50c,
+    0xa50e, 0xa510, 0xa512, 0xa514, 0xa516, 0xa519, 0xa51b, 0xa51d, 0xa51f, 0xa521, 0xa523, 0xa525,
+    0xa527, 0xa529, 0xa52b, 0xa52d, 0xa52f, 0xa532, 0xa534, 0xa536, 0xa538, 0xa53a, 0xa53c, 0xa53e,
+    0xa540, 0xa542, 0xa545, 0xa547, 0xa549, 0xa54b, 0xa54d, 0xa54f, 0xa551, 0xa553, 0xa556, 0xa558,
+    0xa55a, 0xa55c, 0xa55e, 0xa560, 0xa562, 0xa564, 0xa567, 0xa569, 0xa56b, 0xa56d, 0xa56f, 0xa571,
+    0xa574, 0xa576, 0xa578, 0xa57a, 0xa57c, 0xa57e, 0xa581, 0xa583, 0xa585, 0xa587, 0xa589, 0xa58b,
+    0xa58e, 0xa590, 0xa592, 0xa594, 0xa596, 0xa598, 0xa59b, 0xa59d, 0xa59f, 0xa5a1, 0xa5a3, 0xa5a6,
+    0xa5a8, 0xa5aa, 0xa5ac, 0xa5ae, 0xa5b1, 0xa5b3, 0xa5b5, 0xa5b7, 0xa5b9, 0xa5bc, 0xa5be, 0xa5c0,
+    0xa5c2, 0xa5c5, 0xa5c7, 0xa5c9, 0xa5cb, 0xa5cd, 0xa5d0, 0xa5d2, 0xa5d4, 0xa5d6, 0xa5d9, 0xa5db,
+    0xa5dd, 0xa5df, 0xa5e2, 0xa5e4, 0xa5e6, 0xa5e8, 0xa5eb, 0xa5ed, 0xa5ef, 0xa5f1, 0xa5f4, 0xa5f6,
+    0xa5f8, 0xa5fa, 0xa5fd, 0xa5ff, 0xa601, 0xa604, 0xa606, 0xa608, 0xa60a, 0xa60d, 0xa60f, 0xa611,
+    0xa614, 0xa616, 0xa618, 0xa61a, 0xa61d, 0xa61f, 0xa621, 0xa624, 0xa626, 0xa628, 0xa62a, 0xa62d,
+    0xa62f, 0xa631, 0xa634, 0xa636, 0xa638, 0xa63b, 0xa63d, 0xa63f, 0xa642, 0xa644, 0xa646, 0xa649,
+    0xa64b, 0xa64d, 0xa650, 0xa652, 0xa654, 0xa657, 0xa659, 0xa65b, 0xa65e, 0xa660, 0xa662, 0xa665,
+    0xa667, 0xa669, 0xa66c, 0xa66e, 0xa670, 0xa673, 0xa675, 0xa678, 0xa67a, 0xa67c, 0xa67f, 0xa681,
+    0xa683, 0xa686, 0xa688, 0xa68b, 0xa68d, 0xa68f, 0xa692, 0xa694, 0xa696, 0xa699, 0xa69b, 0xa69e,
+    0xa6a0, 0xa6a2, 0xa6a5, 0xa6a7, 0xa6aa, 0xa6ac, 0xa6ae, 0xa6b1, 0xa6b3, 0xa6b6, 0xa6b8, 0xa6bb,
+    0xa6bd, 0xa6bf, 0xa6c2, 0xa6c4, 0xa6c7, 0xa6c9, 0xa6cc, 0xa6ce, 0xa6d0, 0xa6d3, 0xa6d5, 0xa6d8,
+    0xa6da, 0xa6dd, 0xa6df, 0xa6e1, 0xa6e4, 0xa6e6, 0xa6e9, 0xa6eb, 0xa6ee, 0xa6f0, 0xa6f3, 0xa6f5,
+    0xa6f8, 0xa6fa, 0xa6fd, 0xa6ff, 0xa701, 0xa704, 0xa706, 0xa709, 0xa70b, 0xa70e, 0xa710, 0xa713,
+    0xa715, 0xa718, 0xa71a, 0xa71d, 0xa71f, 0xa722, 0xa724, 0xa727, 0xa729, 0xa72c, 0xa72e, 0xa731,
+    0xa733, 0xa736, 0xa738, 0xa73b, 0xa73d, 0xa740, 0xa742, 0xa745, 0xa747, 0xa74a, 0xa74c, 0xa74f,
+    0xa752, 0xa754, 0xa757, 0xa759, 0xa75c, 0xa75e, 0xa761, 0xa763, 0xa766, 0xa768, 0xa76b, 0xa76d,
+    0xa770, 0xa773, 0xa775, 0xa778, 0xa77a, 0xa77d, 0xa77f, 0xa782, 0xa785, 0xa787, 0xa78a, 0xa78c,
+    0xa78f, 0xa791, 0xa794, 0xa797, 0xa799, 0xa79c, 0xa79e, 0xa7a1, 0xa7a4, 0xa7a6, 0xa7a9, 0xa7ab,
+    0xa7ae, 0xa7b0, 0xa7b3, 0xa7b6, 0xa7b8, 0xa7bb, 0xa7be, 0xa7c0, 0xa7c3, 0xa7c5, 0xa7c8, 0xa7cb,
+    0xa7cd, 0xa7d0, 0xa7d2, 0xa7d5, 0xa7d8, 0xa7da, 0xa7dd, 0xa7e0, 0xa7e2, 0xa7e5, 0xa7e8, 0xa7ea,
+    0xa7ed, 0xa7ef, 0xa7f2, 0xa7f5, 0xa7f7, 0xa7fa, 0xa7fd, 0xa7ff, 0xa801, 0xa802, 0xa804, 0xa805,
+    0xa806, 0xa808, 0xa809, 0xa80a, 0xa80c, 0xa80d, 0xa80e, 0xa810, 0xa811, 0xa812, 0xa814, 0xa815,
+    0xa816, 0xa818, 0xa819, 0xa81a, 0xa81c, 0xa81d, 0xa81e, 0xa820, 0xa821, 0xa823, 0xa824, 0xa825,
+    0xa827, 0xa828, 0xa829, 0xa82b, 0xa82c, 0xa82d, 0xa82f, 0xa830, 0xa831, 0xa833, 0xa834, 0xa836,
+    0xa837, 0xa838, 0xa83a, 0xa83b, 0xa83c, 0xa83e, 0xa83f, 0xa841, 0xa842, 0xa843, 0xa845, 0xa846,
+    0xa847, 0xa849, 0xa84a, 0xa84c, 0xa84d, 0xa84e, 0xa850, 0xa851, 0xa852, 0xa854, 0xa855, 0xa857,
+    0xa858, 0xa859, 0xa85b, 0xa85c, 0xa85e, 0xa85f, 0xa860, 0xa862, 0xa863, 0xa865, 0xa866, 0xa867,
+    0xa869, 0xa86a, 0xa86c, 0xa86d, 0xa86e, 0xa870, 0xa871, 0xa873, 0xa874, 0xa875, 0xa877, 0xa878,
+    0xa87a, 0xa87b, 0xa87d, 0xa87e, 0xa87f, 0xa881, 0xa882, 0xa884, 0xa885, 0xa886, 0xa888, 0xa889,
+    0xa88b, 0xa88c, 0xa88e, 0xa88f, 0xa890, 0xa892, 0xa893, 0xa895, 0xa896, 0xa898, 0xa899, 0xa89a,
+    0xa89c, 0xa89d, 0xa89f, 0xa8a0, 0xa8a2, 0xa8a3, 0xa8a4, 0xa8a6, 0xa8a7, 0xa8a9, 0xa8aa, 0xa8ac,
+    0xa8ad, 0xa8af, 0xa8b0, 0xa8b1, 0xa8b3, 0xa8b4, 0xa8b6, 0xa8b7, 0xa8b9, 0xa8ba, 0xa8bc, 0xa8bd,
+    0xa8bf, 0xa8c0, 0xa8c2, 0xa8c3, 0xa8c4, 0xa8c6, 0xa8c7, 0xa8c9, 0xa8ca, 0xa8cc, 0xa8cd, 0xa8cf,
+    0xa8d0, 0xa8d2, 0xa8d3, 0xa8d5, 0xa8d6, 0xa8d8, 0xa8d9, 0xa8da, 0xa8dc, 0xa8dd, 0xa8df, 0xa8e0,
+    0xa8e2, 0xa8e3, 0xa8e5, 0xa8e6, 0xa8e8, 0xa8e9, 0xa8eb, 0xa8ec, 0xa8ee, 0xa8ef, 0xa8f1, 0xa8f2,
+    0xa8f4, 0xa8f5, 0xa8f7, 0xa8f8, 0xa8fa, 0xa8fb, 0xa8fd, 0xa8fe, 0xa900, 0xa901, 0xa903, 0xa904,
+    0xa906, 0xa907, 0xa909, 0xa90a, 0xa90c, 0xa90d, 0xa90f, 0xa910, 0xa912, 0xa913, 0xa915, 0xa916,
+    0xa918, 0xa919, 0xa91b, 0xa91c, 0xa91e, 0xa91f, 0xa921, 0xa922, 0xa924, 0xa926, 0xa927, 0xa929,
+    0xa92a, 0xa92c, 0xa92d, 0xa92f, 0xa930, 0xa932, 0xa933, 0xa935, 0xa936, 0xa938, 0xa939, 0xa93b,
+    0xa93c, 0xa93e, 0xa940, 0xa941, 0xa943, 0xa944, 0xa946, 0xa947, 0xa949, 0xa94a, 0xa94c, 0xa94d,
+    0xa94f, 0xa951, 0xa952, 0xa954, 0xa955, 0xa957, 0xa958, 0xa95a, 0xa95b, 0xa95d, 0xa95f, 0xa960,
+    0xa962, 0xa963, 0xa965, 0xa966, 0xa968, 0xa96a, 0xa96b, 0xa96d, 0xa96e, 0xa970, 0xa971, 0xa973,
+    0xa975, 0xa976, 0xa978, 0xa979, 0xa97b, 0xa97c, 0xa97e, 0xa980, 0xa981, 0xa983, 0xa984, 0xa986,
+    0xa987, 0xa989, 0xa98b, 0xa98c, 0xa98e, 0xa98f, 0xa991, 0xa993, 0xa994, 0xa996, 0xa997, 0xa999,
+    0xa99b, 0xa99c, 0xa99e, 0xa99f, 0xa9a1, 0xa9a3, 0xa9a4, 0xa9a6, 0xa9a7, 0xa9a9, 0xa9ab, 0xa9ac,
+    0xa9ae, 0xa9af, 0xa9b1, 0xa9b3, 0xa9b4, 0xa9b6, 0xa9b7, 0xa9b9, 0xa9bb, 0xa9bc, 0xa9be, 0xa9c0,
+    0xa9c1, 0xa9c3, 0xa9c4, 0xa9c6, 0xa9c8, 0xa9c9, 0xa9cb, 0xa9cd, 0xa9ce, 0xa9d0, 0xa9d1, 0xa9d3,
+    0xa9d5, 0xa9d6, 0xa9d8, 0xa9da, 0xa9db, 0xa9dd, 0xa9df, 0xa9e0, 0xa9e2, 0xa9e3, 0xa9e5, 0xa9e7,
+    0xa9e8, 0xa9ea, 0xa9ec, 0xa9ed, 0xa9ef, 0xa9f1, 0xa9f2, 0xa9f4, 0xa9f6, 0xa9f7, 0xa9f9, 0xa9fb,
+    0xa9fc, 0xa9fe, 0xa9ff, 0xaa01, 0xaa03, 0xaa04, 0xaa06, 0xaa08, 0xaa09, 0xaa0b, 0xaa0d, 0xaa0e,
+    0xaa10, 0xaa13, 0xaa17, 0xaa1a, 0xaa1d, 0xaa21, 0xaa24, 0xaa28, 0xaa2b, 0xaa2e, 0xaa32, 0xaa35,
+    0xaa38, 0xaa3c, 0xaa3f, 0xaa43, 0xaa46, 0xaa49, 0xaa4d, 0xaa50, 0xaa54, 0xaa57, 0xaa5a, 0xaa5e,
+    0xaa61, 0xaa65, 0xaa68, 0xaa6c, 0xaa6f, 0xaa72, 0xaa76, 0xaa79, 0xaa7d, 0xaa80, 0xaa84, 0xaa87,
+    0xaa8b, 0xaa8e, 0xaa92, 0xaa95, 0xaa99, 0xaa9c, 0xaaa0, 0xaaa3, 0xaaa7, 0xaaaa, 0xaaae, 0xaab1,
+    0xaab5, 0xaab8, 0xaabc, 0xaabf, 0xaac3, 0xaac6, 0xaaca, 0xaacd, 0xaad1, 0xaad5, 0xaad8, 0xaadc,
+    0xaadf, 0xaae3, 0xaae6, 0xaaea, 0xaaee, 0xaaf1, 0xaaf5, 0xaaf8, 0xaafc, 0xab00, 0xab03, 0xab07,
+    0xab0a, 0xab0e, 0xab12, 0xab15, 0xab19, 0xab1c, 0xab20, 0xab24, 0xab27, 0xab2b, 0xab2f, 0xab32,
+    0xab36, 0xab3a, 0xab3d, 0xab41, 0xab45, 0xab48, 0xab4c, 0xab50, 0xab54, 0xab57, 0xab5b, 0xab5f,
+    0xab62, 0xab66, 0xab6a, 0xab6d, 0xab71, 0xab75, 0xab79, 0xab7c, 0xab80, 0xab84, 0xab88, 0xab8b,
+    0xab8f, 0xab93, 0xab97, 0xab9a, 0xab9e, 0xaba2, 0xaba6, 0xabaa, 0xabad, 0xabb1, 0xabb5, 0xabb9,
+    0xabbd, 0xabc0, 0xabc4, 0xabc8, 0xabcc, 0xabd0, 0xabd4, 0xabd7, 0xabdb, 0xabdf, 0xabe3, 0xabe7,
+    0xabeb, 0xabee, 0xabf2, 0xabf6, 0xabfa, 0xabfe, 0xac01, 0xac03, 0xac05, 0xac07, 0xac09, 0xac0b,
+    0xac0d, 0xac0f, 0xac10, 0xac12, 0xac14, 0xac16, 0xac18, 0xac1a, 0xac1c, 0xac1e, 0xac20, 0xac22,
+    0xac24, 0xac26, 0xac28, 0xac2a, 0xac2c, 0xac2e, 0xac30, 0xac32, 0xac34, 0xac36, 0xac38, 0xac3a,
+    0xac3c, 0xac3e, 0xac40, 0xac42, 0xac44, 0xac46, 0xac48, 0xac4a, 0xac4c, 0xac4e, 0xac50, 0xac52,
+    0xac54, 0xac56, 0xac58, 0xac5a, 0xac5c, 0xac5e, 0xac60, 0xac62, 0xac64, 0xac66, 0xac69, 0xac6b,
+    0xac6d, 0xac6f, 0xac71, 0xac73, 0xac75, 0xac77, 0xac79, 0xac7b, 0xac7d, 0xac7f, 0xac81, 0xac83,
+    0xac85, 0xac87, 0xac8a, 0xac8c, 0xac8e, 0xac90, 0xac92, 0xac94, 0xac96, 0xac98, 0xac9a, 0xac9c,
+    0xac9e, 0xaca0, 0xaca3, 0xaca5, 0xaca7, 0xaca9, 0xacab, 0xacad, 0xacaf, 0xacb1, 0xacb3, 0xacb6,
+    0xacb8, 0xacba, 0xacbc, 0xacbe, 0xacc0, 0xacc2, 0xacc4, 0xacc7, 0xacc9, 0xaccb, 0xaccd, 0xaccf,
+    0xacd1, 0xacd3, 0xacd6, 0xacd8, 0xacda, 0xacdc, 0xacde, 0xace0, 0xace3, 0xace5, 0xace7, 0xace9,
+    0xaceb, 0xaced, 0xacf0, 0xacf2, 0xacf4, 0xacf6, 0xacf8, 0xacfa, 0xacfd, 0xacff, 0xad01, 0xad03,
+    0xad05, 0xad08, 0xad0a, 0xad0c, 0xad0e, 0xad10, 0xad13, 0xad15, 0xad17, 0xad19, 0xad1b, 0xad1e,
+    0xad20, 0xad22, 0xad24, 0xad27, 0xad29, 0xad2b, 0xad2d, 0xad2f, 0xad32, 0xad34, 0xad36, 0xad38,
+    0xad3b, 0xad3d, 0xad3f, 0xad41, 0xad44, 0xad46, 0xad48, 0xad4a, 0xad4d, 0xad4f, 0xad51, 0xad54,
+    0xad56, 0xad58, 0xad5a, 0xad5d, 0xad5f, 0xad61, 0xad63, 0xad66, 0xad68, 0xad6a, 0xad6d, 0xad6f,
+    0xad71, 0xad73, 0xad76, 0xad78, 0xad7a, 0xad7d, 0xad7f, 0xad81, 0xad84, 0xad86, 0xad88, 0xad8b,
+    0xad8d, 0xad8f, 0xad91, 0xad94, 0xad96, 0xad98, 0xad9b, 0xad9d, 0xad9f, 0xada2, 0xada4, 0xada6,
+    0xada9, 0xadab, 0xadae, 0xadb0, 0xadb2, 0xadb5, 0xadb7, 0xadb9, 0xadbc, 0xadbe, 0xadc0, 0xadc3,
+    0xadc5, 0xadc7, 0xadca, 0xadcc, 0xadcf, 0xadd1, 0xadd3, 0xadd6, 0xadd8, 0xadda, 0xaddd, 0xaddf,
+    0xade2, 0xade4, 0xade6, 0xade9, 0xadeb, 0xadee, 0xadf0, 0xadf2, 0xadf5, 0xadf7, 0xadfa, 0xadfc,
+    0xadff, 0xae01, 0xae03, 0xae06, 0xae08, 0xae0b, 0xae0d, 0xae10, 0xae12, 0xae14, 0xae17, 0xae19,
+    0xae1c, 0xae1e, 0xae21, 0xae23, 0xae25, 0xae28, 0xae2a, 0xae2d, 0xae2f, 0xae32, 0xae34, 0xae37,
+    0xae39, 0xae3c, 0xae3e, 0xae41, 0xae43, 0xae46, 0xae48, 0xae4b, 0xae4d, 0xae4f, 0xae52, 0xae54,
+    0xae57, 0xae59, 0xae5c, 0xae5e, 0xae61, 0xae63, 0xae66, 0xae68, 0xae6b, 0xae6d, 0xae70, 0xae72,
+    0xae75, 0xae78, 0xae7a, 0xae7d, 0xae7f, 0xae82, 0xae84, 0xae87, 0xae89, 0xae8c, 0xae8e, 0xae91,
+    0xae93, 0xae96, 0xae98, 0xae9b, 0xae9e, 0xaea0, 0xaea3, 0xaea5, 0xaea8, 0xaeaa, 0xaead, 0xaeaf,
+    0xaeb2, 0xaeb5, 0xaeb7, 0xaeba, 0xaebc, 0xaebf, 0xaec2, 0xaec4, 0xaec7, 0xaec9, 0xaecc, 0xaece,
+    0xaed1, 0xaed4, 0xaed6, 0xaed9, 0xaedb, 0xaede, 0xaee1, 0xaee3, 0xaee6, 0xaee8, 0xaeeb, 0xaeee,
+    0xaef0, 0xaef3, 0xaef6, 0xaef8, 0xaefb, 0xaefd, 0xaf00, 0xaf03, 0xaf05, 0xaf08, 0xaf0b, 0xaf0d,
+    0xaf10, 0xaf13, 0xaf15, 0xaf18, 0xaf1a, 0xaf1d, 0xaf20, 0xaf22, 0xaf25, 0xaf28, 0xaf2a, 0xaf2d,
+    0xaf30, 0xaf32, 0xaf35, 0xaf38, 0xaf3a, 0xaf3d, 0xaf40, 0xaf43, 0xaf45, 0xaf48, 0xaf4b, 0xaf4d,
+    0xaf50, 0xaf53, 0xaf55, 0xaf58, 0xaf5b, 0xaf5d, 0xaf60, 0xaf63, 0xaf66, 0xaf68, 0xaf6b, 0xaf6e,
+    0xaf70, 0xaf73, 0xaf76, 0xaf79, 0xaf7b, 0xaf7e, 0xaf81, 0xaf84, 0xaf86, 0xaf89, 0xaf8c, 0xaf8f,
+    0xaf91, 0xaf94, 0xaf97, 0xaf9a, 0xaf9c, 0xaf9f, 0xafa2, 0xafa5, 0xafa7, 0xafaa, 0xafad, 0xafb0,
+    0xafb2, 0xafb5, 0xafb8, 0xafbb, 0xafbd, 0xafc0, 0xafc3, 0xafc6, 0xafc9, 0xafcb, 0xafce, 0xafd1,
+    0xafd4, 0xafd7, 0xafd9, 0xafdc, 0xafdf, 0xafe2, 0xafe5, 0xafe7, 0xafea, 0xafed, 0xaff0, 0xaff3,
+    0xaff6, 0xaff8, 0xaffb, 0xaffe, 0xb000, 0xb002, 0xb003, 0xb005, 0xb006, 0xb007, 0xb009, 0xb00a,
+    0xb00c, 0xb00d, 0xb00f, 0xb010, 0xb011, 0xb013, 0xb014, 0xb016, 0xb017, 0xb019, 0xb01a, 0xb01b,
+    0xb01d, 0xb01e, 0xb020, 0xb021, 0xb023, 0xb024, 0xb026, 0xb027, 0xb028, 0xb02a, 0xb02b, 0xb02d,
+    0xb02e, 0xb030, 0xb031, 0xb033, 0xb034, 0xb036, 0xb037, 0xb038, 0xb03a, 0xb03b, 0xb03d, 0xb03e,
+    0xb040, 0xb041, 0xb043, 0xb044, 0xb046, 0xb047, 0xb049, 0xb04a, 0xb04b, 0xb04d, 0xb04e, 0xb050,
+    0xb051, 0xb053, 0xb054, 0xb056, 0xb057, 0xb059, 0xb05a, 0xb05c, 0xb05d, 0xb05f, 0xb060, 0xb062,
+    0xb063, 0xb065, 0xb066, 0xb068, 0xb069, 0xb06b, 0xb06c, 0xb06e, 0xb06f, 0xb071, 0xb072, 0xb074,
+    0xb075, 0xb077, 0xb078, 0xb07a, 0xb07b, 0xb07d, 0xb07e, 0xb080, 0xb081, 0xb083, 0xb084, 0xb086,
+    0xb087, 0xb089, 0xb08a, 0xb08c, 0xb08d, 0xb08f, 0xb090, 0xb092, 0xb093, 0xb095, 0xb096, 0xb098,
+    0xb099, 0xb09b, 0xb09c, 0xb09e, 0xb0a0, 0xb0a1, 0xb0a3, 0xb0a4, 0xb0a6, 0xb0a7, 0xb0a9, 0xb0aa,
+    0xb0ac, 0xb0ad, 0xb0af, 0xb0b0, 0xb0b2, 0xb0b4, 0xb0b5, 0xb0b7, 0xb0b8, 0xb0ba, 0xb0bb, 0xb0bd,
+    0xb0be, 0xb0c0, 0xb0c2, 0xb0c3, 0xb0c5, 0xb0c6, 0xb0c8, 0xb0c9, 0xb0cb, 0xb0cc, 0xb0ce, 0xb0d0,
+    0xb0d1, 0xb0d3, 0xb0d4, 0xb0d6, 0xb0d7, 0xb0d9, 0xb0db, 0xb0dc, 0xb0de, 0xb0df, 0xb0e1, 0xb0e2,
+    0xb0e4, 0xb0e6, 0xb0e7, 0xb0e9, 0xb0ea, 0xb0ec, 0xb0ed, 0xb0ef, 0xb0f1, 0xb0f2, 0xb0f4, 0xb0f5,
+    0xb0f7, 0xb0f9, 0xb0fa, 0xb0fc, 0xb0fd, 0xb0ff, 0xb101, 0xb102, 0xb104, 0xb105, 0xb107, 0xb109,
+    0xb10a, 0xb10c, 0xb10d, 0xb10f, 0xb111, 0xb112, 0xb114, 0xb115, 0xb117, 0xb119, 0xb11a, 0xb11c,
+    0xb11e, 0xb11f, 0xb121, 0xb122, 0xb124, 0xb126, 0xb127, 0xb129, 0xb12b, 0xb12c, 0xb12e, 0xb12f,
+    0xb131, 0xb133, 0xb134, 0xb136, 0xb138, 0xb139, 0xb13b, 0xb13c, 0xb13e, 0xb140, 0xb141, 0xb143,
+    0xb145, 0xb146, 0xb148, 0xb14a, 0xb14b, 0xb14d, 0xb14f, 0xb150, 0xb152, 0xb154, 0xb155, 0xb157,
+    0xb159, 0xb15a, 0xb15c, 0xb15e, 0xb15f, 0xb161, 0xb162, 0xb164, 0xb166, 0xb167, 0xb169, 0xb16b,
+    0xb16c, 0xb16e, 0xb170, 0xb172, 0xb173, 0xb175, 0xb177, 0xb178, 0xb17a, 0xb17c, 0xb17d, 0xb17f,
+    0xb181, 0xb182, 0xb184, 0xb186, 0xb187, 0xb189, 0xb18b, 0xb18c, 0xb18e, 0xb190, 0xb192, 0xb193,
+    0xb195, 0xb197, 0xb198, 0xb19a, 0xb19c, 0xb19d, 0xb19f, 0xb1a1, 0xb1a3, 0xb1a4, 0xb1a6, 0xb1a8,
+    0xb1a9, 0xb1ab, 0xb1ad, 0xb1af, 0xb1b0, 0xb1b2, 0xb1b4, 0xb1b5, 0xb1b7, 0xb1b9, 0xb1bb, 0xb1bc,
+    0xb1be, 0xb1c0, 0xb1c1, 0xb1c3, 0xb1c5, 0xb1c7, 0xb1c8, 0xb1ca, 0xb1cc, 0xb1ce, 0xb1cf, 0xb1d1,
+    0xb1d3, 0xb1d4, 0xb1d6, 0xb1d8, 0xb1da, 0xb1db, 0xb1dd, 0xb1df, 0xb1e1, 0xb1e2, 0xb1e4, 0xb1e6,
+    0xb1e8, 0xb1e9, 0xb1eb, 0xb1ed, 0xb1ef, 0xb1f0, 0xb1f2, 0xb1f4, 0xb1f6, 0xb1f7, 0xb1f9, 0xb1fb,
+    0xb1fd, 0xb1ff, 0xb200, 0xb202, 0xb204, 0xb206, 0xb207, 0xb209, 0xb20b, 0xb20d, 0xb20e, 0xb210,
+    0xb212, 0xb214, 0xb216, 0xb217, 0xb219, 0xb21b, 0xb21d, 0xb21e, 0xb220, 0xb222, 0xb224, 0xb226,
+    0xb227, 0xb229, 0xb22b, 0xb22d, 0xb22f, 0xb230, 0xb232, 0xb234, 0xb236, 0xb238, 0xb239, 0xb23b,
+    0xb23d, 0xb23f, 0xb241, 0xb242, 0xb244, 0xb246, 0xb248, 0xb24a, 0xb24b, 0xb24d, 0xb24f, 0xb251,
+    0xb253, 0xb254, 0xb256, 0xb258, 0xb25a, 0xb25c, 0xb25e, 0xb25f, 0xb261, 0xb263, 0xb265, 0xb267,
+    0xb269, 0xb26a, 0xb26c, 0xb26e, 0xb270, 0xb272, 0xb274, 0xb275, 0xb277, 0xb279, 0xb27b, 0xb27d,
+    0xb27f, 0xb280, 0xb282, 0xb284, 0xb286, 0xb288, 0xb28a, 0xb28c, 0xb28d, 0xb28f, 0xb291, 0xb293,
+    0xb295, 0xb297, 0xb299, 0xb29a, 0xb29c, 0xb29e, 0xb2a0, 0xb2a2, 0xb2a4, 0xb2a6, 0xb2a7, 0xb2a9,
+    0xb2ab, 0xb2ad, 0xb2af, 0xb2b1, 0xb2b3, 0xb2b5, 0xb2b6, 0xb2b8, 0xb2ba, 0xb2bc, 0xb2be, 0xb2c0,
+    0xb2c2, 0xb2c4, 0xb2c5, 0xb2c7, 0xb2c9, 0xb2cb, 0xb2cd, 0xb2cf, 0xb2d1, 0xb2d3, 0xb2d5, 0xb2d6,
+    0xb2d8, 0xb2da, 0xb2dc, 0xb2de, 0xb2e0, 0xb2e2, 0xb2e4, 0xb2e6, 0xb2e8, 0xb2ea, 0xb2eb, 0xb2ed,
+    0xb2ef, 0xb2f1, 0xb2f3, 0xb2f5, 0xb2f7, 0xb2fb, 0xb2ff, 0xb302, 0xb306, 0xb30a, 0xb30e, 0xb312,
+    0xb316, 0xb31a, 0xb31d, 0xb321, 0xb325, 0xb329, 0xb32d, 0xb331, 0xb335, 0xb339, 0xb33d, 0xb340,
+    0xb344, 0xb348, 0xb34c, 0xb350, 0xb354, 0xb358, 0xb35c, 0xb360, 0xb364, 0xb368, 0xb36c, 0xb370,
+    0xb374, 0xb378, 0xb37c, 0xb380, 0xb384, 0xb388, 0xb38c, 0xb390, 0xb394, 0xb398, 0xb39c, 0xb3a0,
+    0xb3a4, 0xb3a8, 0xb3ac, 0xb3b0, 0xb3b4, 0xb3b8, 0xb3bc, 0xb3c0, 0xb3c4, 0xb3c8, 0xb3cc, 0xb3d0,
+    0xb3d4, 0xb3d9, 0xb3dd, 0xb3e1, 0xb3e5, 0xb3e9, 0xb3ed, 0xb3f1, 0xb3f5, 0xb3f9, 0xb3fe, 0xb401,
+    0xb403, 0xb405, 0xb407, 0xb409, 0xb40b, 0xb40d, 0xb40f, 0xb411, 0xb414, 0xb416, 0xb418, 0xb41a,
+    0xb41c, 0xb41e, 0xb420, 0xb422, 0xb424, 0xb426, 0xb428, 0xb42b, 0xb42d, 0xb42f, 0xb431, 0xb433,
+    0xb435, 0xb437, 0xb439, 0xb43c, 0xb43e, 0xb440, 0xb442, 0xb444, 0xb446, 0xb448, 0xb44b, 0xb44d,
+    0xb44f, 0xb451, 0xb453, 0xb455, 0xb457, 0xb45a, 0xb45c, 0xb45e, 0xb460, 0xb462, 0xb464, 0xb467,
+    0xb469, 0xb46b, 0xb46d, 0xb46f, 0xb472, 0xb474, 0xb476, 0xb478, 0xb47a, 0xb47d, 0xb47f, 0xb481,
+    0xb483, 0xb485, 0xb488, 0xb48a, 0xb48c, 0xb48e, 0xb490, 0xb493, 0xb495, 0xb497, 0xb499, 0xb49c,
+    0xb49e, 0xb4a0, 0xb4a2, 0xb4a4, 0xb4a7, 0xb4a9, 0xb4ab, 0xb4ad, 0xb4b0, 0xb4b2, 0xb4b4, 0xb4b6,
+    0xb4b9, 0xb4bb, 0xb4bd, 0xb4c0, 0xb4c2, 0xb4c4, 0xb4c6, 0xb4c9, 0xb4cb, 0xb4cd, 0xb4cf, 0xb4d2,
+    0xb4d4, 0xb4d6, 0xb4d9, 0xb4db, 0xb4dd, 0xb4e0, 0xb4e2, 0xb4e4, 0xb4e6, 0xb4e9, 0xb4eb, 0xb4ed,
+    0xb4f0, 0xb4f2, 0xb4f4, 0xb4f7, 0xb4f9, 0xb4fb, 0xb4fe, 0xb500, 0xb502, 0xb505, 0xb507, 0xb509,
+    0xb50c, 0xb50e, 0xb510, 0xb513, 0xb515, 0xb517, 0xb51a, 0xb51c, 0xb51e, 0xb521, 0xb523, 0xb526,
+    0xb528, 0xb52a, 0xb52d, 0xb52f, 0xb531, 0xb534, 0xb536, 0xb539, 0xb53b, 0xb53d, 0xb540, 0xb542,
+    0xb545, 0xb547, 0xb549, 0xb54c, 0xb54e, 0xb551, 0xb553, 0xb555, 0xb558, 0xb55a, 0xb55d, 0xb55f,
+    0xb562, 0xb564, 0xb566, 0xb569, 0xb56b, 0xb56e, 0xb570, 0xb573, 0xb575, 0xb577, 0xb57a, 0xb57c,
+    0xb57f, 0xb581, 0xb584, 0xb586, 0xb589, 0xb58b, 0xb58e, 0xb590, 0xb593, 0xb595, 0xb598, 0xb59a,
+    0xb59d, 0xb59f, 0xb5a1, 0xb5a4, 0xb5a6, 0xb5a9, 0xb5ab, 0xb5ae, 0xb5b0, 0xb5b3, 0xb5b5, 0xb5b8,
+    0xb5ba, 0xb5bd, 0xb5c0, 0xb5c2, 0xb5c5, 0xb5c7, 0xb5ca, 0xb5cc, 0xb5cf, 0xb5d1, 0xb5d4, 0xb5d6,
+    0xb5d9, 0xb5db, 0xb5de, 0xb5e0, 0xb5e3, 0xb5e6, 0xb5e8, 0xb5eb, 0xb5ed, 0xb5f0, 0xb5f2, 0xb5f5,
+    0xb5f7, 0xb5fa, 0xb5fd, 0xb5ff, 0xb602, 0xb604, 0xb607, 0xb60a, 0xb60c, 0xb60f, 0xb611, 0xb614,
+    0xb616, 0xb619, 0xb61c, 0xb61e, 0xb621, 0xb623, 0xb626, 0xb629, 0xb62b, 0xb62e, 0xb631, 0xb633,
+    0xb636, 0xb638, 0xb63b, 0xb63e, 0xb640, 0xb643, 0xb646, 0xb648, 0xb64b, 0xb64e, 0xb650, 0xb653,
+    0xb655, 0xb658, 0xb65b, 0xb65d, 0xb660, 0xb663, 0xb665, 0xb668, 0xb66b, 0xb66d, 0xb670, 0xb673,
+    0xb676, 0xb678, 0xb67b, 0xb67e, 0xb680, 0xb683, 0xb686, 0xb688, 0xb68b, 0xb68e, 0xb690, 0xb693,
+    0xb696, 0xb699, 0xb69b, 0xb69e, 0xb6a1, 0xb6a3, 0xb6a6, 0xb6a9, 0xb6ac, 0xb6ae, 0xb6b1, 0xb6b4,
+    0xb6b7, 0xb6b9, 0xb6bc, 0xb6bf, 0xb6c2, 0xb6c4, 0xb6c7, 0xb6ca, 0xb6cd, 0xb6cf, 0xb6d2, 0xb6d5,
+    0xb6d8, 0xb6da, 0xb6dd, 0xb6e0, 0xb6e3, 0xb6e5, 0xb6e8, 0xb6eb, 0xb6ee, 0xb6f1, 0xb6f3, 0xb6f6,
+    0xb6f9, 0xb6fc, 0xb6ff, 0xb701, 0xb704, 0xb707, 0xb70a, 0xb70d, 0xb70f, 0xb712, 0xb715, 0xb718,
+    0xb71b, 0xb71e, 0xb720, 0xb723, 0xb726, 0xb729, 0xb72c, 0xb72f, 0xb731, 0xb734, 0xb737, 0xb73a,
+    0xb73d, 0xb740, 0xb743, 0xb745, 0xb748, 0xb74b, 0xb74e, 0xb751, 0xb754, 0xb757, 0xb759, 0xb75c,
+    0xb75f, 0xb762, 0xb765, 0xb768, 0xb76b, 0xb76e, 0xb771, 0xb774, 0xb776, 0xb779, 0xb77c, 0xb77f,
+    0xb782, 0xb785, 0xb788, 0xb78b, 0xb78e, 0xb791, 0xb794, 0xb796, 0xb799, 0xb79c, 0xb79f, 0xb7a2,
+    0xb7a5, 0xb7a8, 0xb7ab, 0xb7ae, 0xb7b1, 0xb7b4, 0xb7b7, 0xb7ba, 0xb7bd, 0xb7c0, 0xb7c3, 0xb7c6,
+    0xb7c9, 0xb7cc, 0xb7cf, 0xb7d2, 0xb7d5, 0xb7d7, 0xb7da, 0xb7dd, 0xb7e0, 0xb7e3, 0xb7e6, 0xb7e9,
+    0xb7ec, 0xb7ef, 0xb7f2, 0xb7f5, 0xb7f8, 0xb7fb, 0xb7fe, 0xb801, 0xb802, 0xb804, 0xb805, 0xb807,
+    0xb808, 0xb80a, 0xb80b, 0xb80d, 0xb80e, 0xb810, 0xb811, 0xb813, 0xb814, 0xb816, 0xb817, 0xb819,
+    0xb81b, 0xb81c, 0xb81e, 0xb81f, 0xb821, 0xb822, 0xb824, 0xb825, 0xb827, 0xb828, 0xb82a, 0xb82b,
+    0xb82d, 0xb82f, 0xb830, 0xb832, 0xb833, 0xb835, 0xb836, 0xb838, 0xb839, 0xb83b, 0xb83c, 0xb83e,
+    0xb840, 0xb841, 0xb843, 0xb844, 0xb846, 0xb847, 0xb849, 0xb84b, 0xb84c, 0xb84e, 0xb84f, 0xb851,
+    0xb852, 0xb854, 0xb856, 0xb857, 0xb859, 0xb85a, 0xb85c, 0xb85d, 0xb85f, 0xb861, 0xb862, 0xb864,
+    0xb865, 0xb867, 0xb869, 0xb86a, 0xb86c, 0xb86d, 0xb86f, 0xb870, 0xb872, 0xb874, 0xb875, 0xb877,
+    0xb878, 0xb87a, 0xb87c, 0xb87d, 0xb87f, 0xb880, 0xb882, 0xb884, 0xb885, 0xb887, 0xb889, 0xb88a,
+    0xb88c, 0xb88d, 0xb88f, 0xb891, 0xb892, 0xb894, 0xb895, 0xb897, 0xb899, 0xb89a, 0xb89c, 0xb89e,
+    0xb89f, 0xb8a1, 0xb8a3, 0xb8a4, 0xb8a6, 0xb8a7, 0xb8a9, 0xb8ab, 0xb8ac, 0xb8ae, 0xb8b0, 0xb8b1,
+    0xb8b3, 0xb8b5, 0xb8b6, 0xb8b8, 0xb8ba, 0xb8bb, 0xb8bd, 0xb8be, 0xb8c0, 0xb8c2, 0xb8c3, 0xb8c5,
+    0xb8c7, 0xb8c8, 0xb8ca, 0xb8cc, 0xb8cd, 0xb8cf, 0xb8d1, 0xb8d2, 0xb8d4, 0xb8d6, 0xb8d7, 0xb8d9,
+    0xb8db, 0xb8dc, 0xb8de, 0xb8e0, 0xb8e1, 0xb8e3, 0xb8e5, 0xb8e7, 0xb8e8, 0xb8ea, 0xb8ec, 0xb8ed,
+    0xb8ef, 0xb8f1, 0xb8f2, 0xb8f4, 0xb8f6, 0xb8f7, 0xb8f9, 0xb8fb, 0xb8fd, 0xb8fe, 0xb900, 0xb902,
+    0xb903, 0xb905, 0xb907, 0xb908, 0xb90a, 0xb90c, 0xb90e, 0xb90f, 0xb911, 0xb913, 0xb914, 0xb916,
+    0xb918, 0xb91a, 0xb91b, 0xb91d, 0xb91f, 0xb920, 0xb922, 0xb924, 0xb926, 0xb927, 0xb929, 0xb92b,
+    0xb92d, 0xb92e, 0xb930, 0xb932, 0xb934, 0xb935, 0xb937, 0xb939, 0xb93b, 0xb93c, 0xb93e, 0xb940,
+    0xb942, 0xb943, 0xb945, 0xb947, 0xb949, 0xb94a, 0xb94c, 0xb94e, 0xb950, 0xb951, 0xb953, 0xb955,
+    0xb957, 0xb958, 0xb95a, 0xb95c, 0xb95e, 0xb95f, 0xb961, 0xb963, 0xb965, 0xb967, 0xb968, 0xb96a,
+    0xb96c, 0xb96e, 0xb96f, 0xb971, 0xb973, 0xb975, 0xb977, 0xb978, 0xb97a, 0xb97c, 0xb97e, 0xb97f,
+    0xb981, 0xb983, 0xb985, 0xb987, 0xb988, 0xb98a, 0xb98c, 0xb98e, 0xb990, 0xb991, 0xb993, 0xb995,
+    0xb997, 0xb999, 0xb99b, 0xb99c, 0xb99e, 0xb9a0, 0xb9a2, 0xb9a4, 0xb9a5, 0xb9a7, 0xb9a9, 0xb9ab,
+    0xb9ad, 0xb9af, 0xb9b0, 0xb9b2, 0xb9b4, 0xb9b6, 0xb9b8, 0xb9ba, 0xb9bb, 0xb9bd, 0xb9bf, 0xb9c1,
+    0xb9c3, 0xb9c5, 0xb9c6, 0xb9c8, 0xb9ca, 0xb9cc, 0xb9ce, 0xb9d0, 0xb9d1, 0xb9d3, 0xb9d5, 0xb9d7,
+    0xb9d9, 0xb9db, 0xb9dd, 0xb9de, 0xb9e0, 0xb9e2, 0xb9e4, 0xb9e6, 0xb9e8, 0xb9ea, 0xb9eb, 0xb9ed,
+    0xb9ef, 0xb9f1, 0xb9f3, 0xb9f5, 0xb9f7, 0xb9f9, 0xb9fa, 0xb9fc, 0xb9fe, 0xba00, 0xba02, 0xba04,
+    0xba06, 0xba08, 0xba09, 0xba0b, 0xba0d, 0xba0f, 0xba11, 0xba13, 0xba15, 0xba17, 0xba19, 0xba1b,
+    0xba1c, 0xba1e, 0xba20, 0xba22, 0xba24, 0xba26, 0xba28, 0xba2a, 0xba2c, 0xba2e, 0xba2f, 0xba31,
+    0xba33, 0xba35, 0xba37, 0xba39, 0xba3b, 0xba3d, 0xba3f, 0xba41, 0xba43, 0xba45, 0xba47, 0xba48,
+    0xba4a, 0xba4c, 0xba4e, 0xba50, 0xba52, 0xba54, 0xba56, 0xba58, 0xba5a, 0xba5c, 0xba5e, 0xba60,
+    0xba62, 0xba64, 0xba66, 0xba67, 0xba69, 0xba6b, 0xba6d, 0xba6f, 0xba71, 0xba73, 0xba75, 0xba77,
+    0xba79, 0xba7b, 0xba7d, 0xba7f, 0xba81, 0xba83, 0xba85, 0xba87, 0xba89, 0xba8b, 0xba8d, 0xba8f,
+    0xba91, 0xba93, 0xba95, 0xba97, 0xba99, 0xba9b, 0xba9d, 0xba9f, 0xbaa0, 0xbaa2, 0xbaa4, 0xbaa6,
+    0xbaa8, 0xbaaa, 0xbaac, 0xbaae, 0xbab0, 0xbab2, 0xbab4, 0xbab6, 0xbab8, 0xbaba, 0xbabc, 0xbabe,
+    0xbac0, 0xbac2, 0xbac4, 0xbac6, 0xbac8, 0xbaca, 0xbacc, 0xbace, 0xbad1, 0xbad3, 0xbad5, 0xbad7,
+    0xbad9, 0xbadb, 0xbadd, 0xbadf, 0xbae1, 0xbae3, 0xbae5, 0xbae7, 0xbae9, 0xbaeb, 0xbaed, 0xbaef,
+    0xbaf1, 0xbaf3, 0xbaf5, 0xbaf7, 0xbaf9, 0xbafb, 0xbafd, 0xbaff, 0xbb01, 0xbb03, 0xbb05, 0xbb07,
+    0xbb09, 0xbb0b, 0xbb0e, 0xbb10, 0xbb12, 0xbb14, 0xbb16, 0xbb18, 0xbb1a, 0xbb1c, 0xbb1e, 0xbb20,
+    0xbb22, 0xbb24, 0xbb26, 0xbb28, 0xbb2a, 0xbb2c, 0xbb2f, 0xbb31, 0xbb33, 0xbb35, 0xbb37, 0xbb39,
+    0xbb3b, 0xbb3d, 0xbb3f, 0xbb41, 0xbb43, 0xbb45, 0xbb48, 0xbb4a, 0xbb4c, 0xbb4e, 0xbb50, 0xbb52,
+    0xbb54, 0xbb56, 0xbb58, 0xbb5a, 0xbb5d, 0xbb5f, 0xbb61, 0xbb63, 0xbb65, 0xbb67, 0xbb69, 0xbb6b,
+    0xbb6d, 0xbb6f, 0xbb72, 0xbb74, 0xbb76, 0xbb78, 0xbb7a, 0xbb7c, 0xbb7e, 0xbb80, 0xbb83, 0xbb85,
+    0xbb87, 0xbb89, 0xbb8b, 0xbb8d, 0xbb8f, 0xbb91, 0xbb94, 0xbb96, 0xbb98, 0xbb9a, 0xbb9c, 0xbb9e,
+    0xbba0, 0xbba3, 0xbba5, 0xbba7, 0xbba9, 0xbbab, 0xbbad, 0xbbaf, 0xbbb2, 0xbbb4, 0xbbb6, 0xbbb8,
+    0xbbba, 0xbbbc, 0xbbbf, 0xbbc1, 0xbbc3, 0xbbc5, 0xbbc7, 0xbbc9, 0xbbcc, 0xbbce, 0xbbd0, 0xbbd2,
+    0xbbd4, 0xbbd6, 0xbbd9, 0xbbdb, 0xbbdd, 0xbbdf, 0xbbe1, 0xbbe4, 0xbbe6, 0xbbe8, 0xbbea, 0xbbec,
+    0xbbee, 0xbbf1, 0xbbf3, 0xbbf5, 0xbbf7, 0xbbf9, 0xbbfc, 0xbbfe, 0xbc00, 0xbc02, 0xbc04, 0xbc07,
+    0xbc09, 0xbc0b, 0xbc0d, 0xbc10, 0xbc12, 0xbc14, 0xbc16, 0xbc18, 0xbc1b, 0xbc1d, 0xbc1f, 0xbc22,
+    0xbc24, 0xbc26, 0xbc28, 0xbc2b, 0xbc2d, 0xbc2f, 0xbc32, 0xbc34, 0xbc36, 0xbc39, 0xbc3b, 0xbc3d,
+    0xbc3f, 0xbc42, 0xbc44, 0xbc47, 0xbc49, 0xbc4b, 0xbc4e, 0xbc50, 0xbc52, 0xbc55, 0xbc57, 0xbc59,
+    0xbc5c, 0xbc5e, 0xbc61, 0xbc63, 0xbc66, 0xbc68, 0xbc6a, 0xbc6d, 0xbc6f, 0xbc72, 0xbc74, 0xbc77,
+    0xbc79, 0xbc7b, 0xbc7e, 0xbc80, 0xbc83, 0xbc85, 0xbc88, 0xbc8a, 0xbc8d, 0xbc8f, 0xbc92, 0xbc94,
+    0xbc97, 0xbc99, 0xbc9c, 0xbc9f, 0xbca1, 0xbca4, 0xbca6, 0xbca9, 0xbcab, 0xbcae, 0xbcb0, 0xbcb3,
+    0xbcb6, 0xbcb8, 0xbcbb, 0xbcbd, 0xbcc0, 0xbcc3, 0xbcc5, 0xbcc8, 0xbccb, 0xbccd, 0xbcd0, 0xbcd2,
+    0xbcd5, 0xbcd8, 0xbcda, 0xbcdd, 0xbce0, 0xbce2, 0xbce5, 0xbce8, 0xbceb, 0xbced, 0xbcf0, 0xbcf3,
+    0xbcf5, 0xbcf8, 0xbcfb, 0xbcfe, 0xbd00, 0xbd03, 0xbd06, 0xbd09, 0xbd0b, 0xbd0e, 0xbd11, 0xbd14,
+    0xbd17, 0xbd19, 0xbd1c, 0xbd1f, 0xbd22, 0xbd25, 0xbd27, 0xbd2a, 0xbd2d, 0xbd30, 0xbd33, 0xbd36,
+    0xbd39, 0xbd3b, 0xbd3e, 0xbd41, 0xbd44, 0xbd47, 0xbd4a, 0xbd4d, 0xbd50, 0xbd53, 0xbd56, 0xbd59,
+    0xbd5b, 0xbd5e, 0xbd61, 0xbd64, 0xbd67, 0xbd6a, 0xbd6d, 0xbd70, 0xbd73, 0xbd76, 0xbd79, 0xbd7c,
+    0xbd7f, 0xbd82, 0xbd85, 0xbd88, 0xbd8b, 0xbd8f, 0xbd92, 0xbd95, 0xbd98, 0xbd9b, 0xbd9e, 0xbda1,
+    0xbda4, 0xbda7, 0xbdaa, 0xbdad, 0xbdb1, 0xbdb4, 0xbdb7, 0xbdba, 0xbdbd, 0xbdc0, 0xbdc3, 0xbdc7,
+    0xbdca, 0xbdcd, 0xbdd0, 0xbdd3, 0xbdd7, 0xbdda, 0xbddd, 0xbde0, 0xbde3, 0xbde7, 0xbdea, 0xbded,
+    0xbdf0, 0xbdf4, 0xbdf7, 0xbdfa, 0xbdfe, 0xbe01, 0xbe04, 0xbe08, 0xbe0b, 0xbe0e, 0xbe12, 0xbe15,
+    0xbe18, 0xbe1c, 0xbe1f, 0xbe22, 0xbe26, 0xbe29, 0xbe2c, 0xbe30, 0xbe33, 0xbe37, 0xbe3a, 0xbe3e,
+    0xbe41, 0xbe44, 0xbe48, 0xbe4b, 0xbe4f, 0xbe52, 0xbe56, 0xbe59, 0xbe5d, 0xbe60, 0xbe64, 0xbe67,
+    0xbe6b, 0xbe6e, 0xbe72, 0xbe75, 0xbe79, 0xbe7c, 0xbe80, 0xbe84, 0xbe87, 0xbe8b, 0xbe8e, 0xbe92,
+    0xbe96, 0xbe99, 0xbe9d, 0xbea1, 0xbea4, 0xbea8, 0xbeac, 0xbeaf, 0xbeb3, 0xbeb7, 0xbeba, 0xbebe,
+    0xbec2, 0xbec5, 0xbec9, 0xbecd, 0xbed1, 0xbed4, 0xbed8, 0xbedc, 0xbee0, 0xbee3, 0xbee7, 0xbeeb,
+    0xbeef, 0xbef3, 0xbef6, 0xbefa, 0xbefe, 0xbf02, 0xbf06, 0xbf0a, 0xbf0e, 0xbf12, 0xbf15, 0xbf19,
+    0xbf1d, 0xbf21, 0xbf25, 0xbf29, 0xbf2d, 0xbf31, 0xbf35, 0xbf39, 0xbf3d, 0xbf41, 0xbf45, 0xbf49,
+    0xbf4d, 0xbf51, 0xbf55, 0xbf59, 0xbf5d, 0xbf61, 0xbf65, 0xbf69, 0xbf6d, 0xbf71, 0xbf75, 0xbf79,
+    0xbf7e, 0xbf82, 0xbf86, 0xbf8a, 0xbf8e, 0xbf92, 0xbf96, 0xbf9b, 0xbf9f, 0xbfa3, 0xbfa7, 0xbfab,
+    0xbfb0, 0xbfb4, 0xbfb8, 0xbfbc, 0xbfc1, 0xbfc5, 0xbfc9, 0xbfcd, 0xbfd2, 0xbfd6, 0xbfda, 0xbfdf,
+    0xbfe3, 0xbfe7, 0xbfec, 0xbff0, 0xbff4, 0xbff9, 0xbffd, 0xc001, 0xc003, 0xc005, 0xc007, 0xc00a,
+    0xc00c, 0xc00e, 0xc010, 0xc013, 0xc015, 0xc017, 0xc019, 0xc01c, 0xc01e, 0xc020, 0xc022, 0xc025,
+    0xc027, 0xc029, 0xc02c, 0xc02e, 0xc030, 0xc032, 0xc035, 0xc037, 0xc039, 0xc03c, 0xc03e, 0xc040,
+    0xc043, 0xc045, 0xc047, 0xc04a, 0xc04c, 0xc04e, 0xc051, 0xc053, 0xc056, 0xc058, 0xc05a, 0xc05d,
+    0xc05f, 0xc062, 0xc064, 0xc066, 0xc069, 0xc06b, 0xc06e, 0xc070, 0xc073, 0xc075, 0xc077, 0xc07a,
+    0xc07c, 0xc07f, 0xc081, 0xc084, 0xc086, 0xc089, 0xc08b, 0xc08e, 0xc090, 0xc093, 0xc095, 0xc098,
+    0xc09a, 0xc09d, 0xc09f, 0xc0a2, 0xc0a5, 0xc0a7, 0xc0aa, 0xc0ac, 0xc0af, 0xc0b1, 0xc0b4, 0xc0b7,
+    0xc0b9, 0xc0bc, 0xc0be, 0xc0c1, 0xc0c4, 0xc0c6, 0xc0c9, 0xc0cc, 0xc0ce, 0xc0d1, 0xc0d3, 0xc0d6,
+    0xc0d9, 0xc0db, 0xc0de, 0xc0e1, 0xc0e3, 0xc0e6, 0xc0e9, 0xc0ec, 0xc0ee, 0xc0f1, 0xc0f4, 0xc0f6,
+    0xc0f9, 0xc0fc, 0xc0ff, 0xc101, 0xc104, 0xc107, 0xc10a, 0xc10c, 0xc10f, 0xc112, 0xc115, 0xc118,
+    0xc11a, 0xc11d, 0xc120, 0xc123, 0xc126, 0xc129, 0xc12b, 0xc12e, 0xc131, 0xc134, 0xc137, 0xc13a,
+    0xc13d, 0xc13f, 0xc142, 0xc145, 0xc148, 0xc14b, 0xc14e, 0xc151, 0xc154, 0xc157, 0xc15a, 0xc15d,
+    0xc160, 0xc163, 0xc165, 0xc168, 0xc16b, 0xc16e, 0xc171, 0xc174, 0xc177, 0xc17a, 0xc17d, 0xc180,
+    0xc183, 0xc187, 0xc18a, 0xc18d, 0xc190, 0xc193, 0xc196, 0xc199, 0xc19c, 0xc19f, 0xc1a2, 0xc1a5,
+    0xc1a8, 0xc1ab, 0xc1af, 0xc1b2, 0xc1b5, 0xc1b8, 0xc1bb, 0xc1be, 0xc1c1, 0xc1c5, 0xc1c8, 0xc1cb,
+    0xc1ce, 0xc1d1, 0xc1d5, 0xc1d8, 0xc1db, 0xc1de, 0xc1e1, 0xc1e5, 0xc1e8, 0xc1eb, 0xc1ee, 0xc1f2,
+    0xc1f5, 0xc1f8, 0xc1fc, 0xc1ff, 0xc202, 0xc205, 0xc209, 0xc20c, 0xc20f, 0xc213, 0xc216, 0xc219,
+    0xc21d, 0xc220, 0xc224, 0xc227, 0xc22a, 0xc22e, 0xc231, 0xc235, 0xc238, 0xc23b, 0xc23f, 0xc242,
+    0xc246, 0xc249, 0xc24d, 0xc250, 0xc254, 0xc257, 0xc25a, 0xc25e, 0xc262, 0xc265, 0xc269, 0xc26c,
+    0xc270, 0xc273, 0xc277, 0xc27a, 0xc27e, 0xc281, 0xc285, 0xc289, 0xc28c, 0xc290, 0xc293, 0xc297,
+    0xc29b, 0xc29e, 0xc2a2, 0xc2a6, 0xc2a9, 0xc2ad, 0xc2b1, 0xc2b4, 0xc2b8, 0xc2bc, 0xc2bf, 0xc2c3,
+    0xc2c7, 0xc2cb, 0xc2ce, 0xc2d2, 0xc2d6, 0xc2da, 0xc2dd, 0xc2e1, 0xc2e5, 0xc2e9, 0xc2ec, 0xc2f0,
+    0xc2f4, 0xc2f8, 0xc2fc, 0xc300, 0xc303, 0xc307, 0xc30b, 0xc30f, 0xc313, 0xc317, 0xc31b, 0xc31f,
+    0xc323, 0xc327, 0xc32a, 0xc32e, 0xc332, 0xc336, 0xc33a, 0xc33e, 0xc342, 0xc346, 0xc34a, 0xc34e,
+    0xc352, 0xc356, 0xc35a, 0xc35e, 0xc362, 0xc367, 0xc36b, 0xc36f, 0xc373, 0xc377, 0xc37b, 0xc37f,
+    0xc383, 0xc387, 0xc38c, 0xc390, 0xc394, 0xc398, 0xc39c, 0xc3a0, 0xc3a5, 0xc3a9, 0xc3ad, 0xc3b1,
+    0xc3b5, 0xc3ba, 0xc3be, 0xc3c2, 0xc3c6, 0xc3cb, 0xc3cf, 0xc3d3, 0xc3d8, 0xc3dc, 0xc3e0, 0xc3e5,
+    0xc3e9, 0xc3ed, 0xc3f2, 0xc3f6, 0xc3fa, 0xc3ff, 0xc402, 0xc404, 0xc406, 0xc408, 0xc40a, 0xc40d,
+    0xc40f, 0xc411, 0xc413, 0xc416, 0xc418, 0xc41a, 0xc41c, 0xc41f, 0xc421, 0xc423, 0xc426, 0xc428,
+    0xc42a, 0xc42c, 0xc42f, 0xc431, 0xc433, 0xc436, 0xc438, 0xc43a, 0xc43d, 0xc43f, 0xc441, 0xc444,
+    0xc446, 0xc448, 0xc44b, 0xc44d, 0xc44f, 0xc452, 0xc454, 0xc456, 0xc459, 0xc45b, 0xc45e, 0xc460,
+    0xc462, 0xc465, 0xc467, 0xc46a, 0xc46c, 0xc46f, 0xc471, 0xc473, 0xc476, 0xc478, 0xc47b, 0xc47d,
+    0xc480, 0xc482, 0xc485, 0xc487, 0xc48a, 0xc48c, 0xc48f, 0xc491, 0xc494, 0xc496, 0xc499, 0xc49b,
+    0xc49e, 0xc4a0, 0xc4a3, 0xc4a6, 0xc4a8, 0xc4ab, 0xc4ad, 0xc4b0, 0xc4b2, 0xc4b5, 0xc4b8, 0xc4ba,
+    0xc4bd, 0xc4bf, 0xc4c2, 0xc4c5, 0xc4c7, 0xc4ca, 0xc4cc, 0xc4cf, 0xc4d2, 0xc4d4, 0xc4d7, 0xc4da,
+    0xc4dc, 0xc4df, 0xc4e2, 0xc4e4, 0xc4e7, 0xc4ea, 0xc4ed, 0xc4ef, 0xc4f2, 0xc4f5, 0xc4f7, 0xc4fa,
+    0xc4fd, 0xc500, 0xc502, 0xc505, 0xc508, 0xc50b, 0xc50d, 0xc510, 0xc513, 0xc516, 0xc519, 0xc51b,
+    0xc51e, 0xc521, 0xc524, 0xc527, 0xc52a, 0xc52c, 0xc52f, 0xc532, 0xc535, 0xc538, 0xc53b, 0xc53e,
+    0xc540, 0xc543, 0xc546, 0xc549, 0xc54c, 0xc54f, 0xc552, 0xc555, 0xc558, 0xc55b, 0xc55e, 0xc561,
+    0xc564, 0xc567, 0xc56a, 0xc56d, 0xc570, 0xc573, 0xc576, 0xc579, 0xc57c, 0xc57f, 0xc582, 0xc585,
+    0xc588, 0xc58b, 0xc58e, 0xc591, 0xc594, 0xc597, 0xc59a, 0xc59d, 0xc5a0, 0xc5a3, 0xc5a6, 0xc5a9,
+    0xc5ad, 0xc5b0, 0xc5b3, 0xc5b6, 0xc5b9, 0xc5bc, 0xc5bf, 0xc5c3, 0xc5c6, 0xc5c9, 0xc5cc, 0xc5cf,
+    0xc5d3, 0xc5d6, 0xc5d9, 0xc5dc, 0xc5df, 0xc5e3, 0xc5e6, 0xc5e9, 0xc5ec, 0xc5f0, 0xc5f3, 0xc5f6,
+    0xc5f9, 0xc5fd, 0xc600, 0xc603, 0xc607, 0xc60a, 0xc60d, 0xc611, 0xc614, 0xc617, 0xc61b, 0xc61e,
+    0xc621, 0xc625, 0xc628, 0xc62c, 0xc62f, 0xc632, 0xc636, 0xc639, 0xc63d, 0xc640, 0xc643, 0xc647,
+    0xc64a, 0xc64e, 0xc651, 0xc655, 0xc658, 0xc65c, 0xc65f, 0xc663, 0xc666, 0xc66a, 0xc66d, 0xc671,
+    0xc674, 0xc678, 0xc67c, 0xc67f, 0xc683, 0xc686, 0xc68a, 0xc68e, 0xc691, 0xc695, 0xc698, 0xc69c,
+    0xc6a0, 0xc6a3, 0xc6a7, 0xc6ab, 0xc6ae, 0xc6b2, 0xc6b6, 0xc6b9, 0xc6bd, 0xc6c1, 0xc6c4, 0xc6c8,
+    0xc6cc, 0xc6d0, 0xc6d3, 0xc6d7, 0xc6db, 0xc6df, 0xc6e2, 0xc6e6, 0xc6ea, 0xc6ee, 0xc6f2, 0xc6f6,
+    0xc6f9, 0xc6fd, 0xc701, 0xc705, 0xc709, 0xc70d, 0xc711, 0xc714, 0xc718, 0xc71c, 0xc720, 0xc724,
+    0xc728, 0xc72c, 0xc730, 0xc734, 0xc738, 0xc73c, 0xc740, 0xc744, 0xc748, 0xc74c, 0xc750, 0xc754,
+    0xc758, 0xc75c, 0xc760, 0xc764, 0xc768, 0xc76c, 0xc770, 0xc774, 0xc778, 0xc77d, 0xc781, 0xc785,
+    0xc789, 0xc78d, 0xc791, 0xc795, 0xc79a, 0xc79e, 0xc7a2, 0xc7a6, 0xc7aa, 0xc7af, 0xc7b3, 0xc7b7,
+    0xc7bb, 0xc7c0, 0xc7c4, 0xc7c8, 0xc7cc, 0xc7d1, 0xc7d5, 0xc7d9, 0xc7de, 0xc7e2, 0xc7e6, 0xc7eb,
+    0xc7ef, 0xc7f3, 0xc7f8, 0xc7fc, 0xc800, 0xc802, 0xc805, 0xc807, 0xc809, 0xc80b, 0xc80e, 0xc810,
+    0xc812, 0xc814, 0xc816, 0xc819, 0xc81b, 0xc81d, 0xc820, 0xc822, 0xc824, 0xc826, 0xc829, 0xc82b,
+    0xc82d, 0xc830, 0xc832, 0xc834, 0xc836, 0xc839, 0xc83b, 0xc83d, 0xc840, 0xc842, 0xc844, 0xc847,
+    0xc849, 0xc84c, 0xc84e, 0xc850, 0xc853, 0xc855, 0xc857, 0xc85a, 0xc85c, 0xc85f, 0xc861, 0xc863,
+    0xc866, 0xc868, 0xc86b, 0xc86d, 0xc870, 0xc872, 0xc874, 0xc877, 0xc879, 0xc87c, 0xc87e, 0xc881,
+    0xc883, 0xc888, 0xc88d, 0xc892, 0xc897, 0xc89c, 0xc8a1, 0xc8a6, 0xc8ac, 0xc8b1, 0xc8b6, 0xc8bb,
+    0xc8c0, 0xc8c6, 0xc8cb, 0xc8d0, 0xc8d5, 0xc8db, 0xc8e0, 0xc8e5, 0xc8eb, 0xc8f0, 0xc8f6, 0xc8fb,
+    0xc901, 0xc906, 0xc90c, 0xc911, 0xc917, 0xc91d, 0xc922, 0xc928, 0xc92d, 0xc933, 0xc939, 0xc93f,
+    0xc944, 0xc94a, 0xc950, 0xc956, 0xc95c, 0xc962, 0xc968, 0xc96e, 0xc974, 0xc97a, 0xc980, 0xc986,
+    0xc98c, 0xc992, 0xc998, 0xc99e, 0xc9a4, 0xc9ab, 0xc9b1, 0xc9b7, 0xc9bd, 0xc9c4, 0xc9ca, 0xc9d1,
+    0xc9d7, 0xc9dd, 0xc9e4, 0xc9ea, 0xc9f1, 0xc9f7, 0xc9fe, 0xca05, 0xca0b, 0xca12, 0xca19, 0xca1f,
+    0xca26, 0xca2d, 0xca34, 0xca3a, 0xca41, 0xca48, 0xca4f, 0xca56, 0xca5d, 0xca64, 0xca6b, 0xca72,
+    0xca79, 0xca80, 0xca88, 0xca8f, 0xca96, 0xca9d, 0xcaa5, 0xcaac, 0xcab3, 0xcabb, 0xcac2, 0xcaca,
+    0xcad1, 0xcad9, 0xcae0, 0xcae8, 0xcaef, 0xcaf7, 0xcaff, 0xcb06, 0xcb0e, 0xcb16, 0xcb1e, 0xcb25,
+    0xcb2d, 0xcb35, 0xcb3d, 0xcb45, 0xcb4d, 0xcb55, 0xcb5d, 0xcb65, 0xcb6e, 0xcb76, 0xcb7e, 0xcb86,
+    0xcb8f, 0xcb97, 0xcb9f, 0xcba8, 0xcbb0, 0xcbb9, 0xcbc1, 0xcbca, 0xcbd2, 0xcbdb, 0xcbe4, 0xcbec,
+    0xcbf5, 0xcbfe, 0xcc03, 0xcc08, 0xcc0c, 0xcc11, 0xcc15, 0xcc1a, 0xcc1e, 0xcc23, 0xcc27, 0xcc2c,
+    0xcc30, 0xcc35, 0xcc3a, 0xcc3e, 0xcc43, 0xcc48, 0xcc4c, 0xcc51, 0xcc56, 0xcc5b, 0xcc5f, 0xcc64,
+    0xcc69, 0xcc6e, 0xcc73, 0xcc78, 0xcc7d, 0xcc82, 0xcc87, 0xcc8c, 0xcc91, 0xcc96, 0xcc9b, 0xcca0,
+    0xcca5, 0xccaa, 0xccaf, 0xccb4, 0xccb9, 0xccbf, 0xccc4, 0xccc9, 0xccce, 0xccd4, 0xccd9, 0xccde,
+    0xcce4, 0xcce9, 0xccef, 0xccf4, 0xccf9, 0xccff, 0xcd04, 0xcd0a, 0xcd10, 0xcd15, 0xcd1b, 0xcd20,
+    0xcd26, 0xcd2c, 0xcd31, 0xcd37, 0xcd3d, 0xcd43, 0xcd48, 0xcd4e, 0xcd54, 0xcd5a, 0xcd60, 0xcd66,
+    0xcd6c, 0xcd72, 0xcd78, 0xcd7e, 0xcd84, 0xcd8a, 0xcd90, 0xcd96, 0xcd9c, 0xcda2, 0xcda9, 0xcdaf,
+    0xcdb5, 0xcdbb, 0xcdc2, 0xcdc8, 0xcdcf, 0xcdd5, 0xcddb, 0xcde2, 0xcde8, 0xcdef, 0xcdf5, 0xcdfc,
+    0xce03, 0xce09, 0xce10, 0xce16, 0xce1d, 0xce24, 0xce2b, 0xce31, 0xce38, 0xce3f, 0xce46, 0xce4d,
+    0xce54, 0xce5b, 0xce62, 0xce69, 0xce70, 0xce77, 0xce7e, 0xce85, 0xce8d, 0xce94, 0xce9b, 0xcea2,
+    0xceaa, 0xceb1, 0xceb8, 0xcec0, 0xcec7, 0xcecf, 0xced6, 0xcede, 0xcee5, 0xceed, 0xcef5, 0xcefc,
+    0xcf04, 0xcf0c, 0xcf13, 0xcf1b, 0xcf23, 0xcf2b, 0xcf33, 0xcf3b, 0xcf43, 0xcf4b, 0xcf53, 0xcf5b,
+    0xcf63, 0xcf6b, 0xcf73, 0xcf7b, 0xcf84, 0xcf8c, 0xcf94, 0xcf9d, 0xcfa5, 0xcfad, 0xcfb6, 0xcfbe,
+    0xcfc7, 0xcfd0, 0xcfd8, 0xcfe1, 0xcfe9, 0xcff2, 0xcffb, 0xd002, 0xd006, 0xd00b, 0xd00f, 0xd014,
+    0xd018, 0xd01d, 0xd021, 0xd026, 0xd02a, 0xd02f, 0xd034, 0xd038, 0xd03d, 0xd041, 0xd046, 0xd04b,
+    0xd050, 0xd054, 0xd059, 0xd05e, 0xd063, 0xd068, 0xd06c, 0xd071, 0xd076, 0xd07b, 0xd080, 0xd085,
+    0xd08a, 0xd08f, 0xd094, 0xd099, 0xd09e, 0xd0a3, 0xd0a8, 0xd0ae, 0xd0b3, 0xd0b8, 0xd0bd, 0xd0c2,
+    0xd0c8, 0xd0cd, 0xd0d2, 0xd0d7, 0xd0dd, 0xd0e2, 0xd0e7, 0xd0ed, 0xd0f2, 0xd0f8, 0xd0fd, 0xd103,
+    0xd108, 0xd10e, 0xd113, 0xd119, 0xd11f, 0xd124, 0xd12a, 0xd130, 0xd135, 0xd13b, 0xd141, 0xd147,
+    0xd14c, 0xd152, 0xd158, 0xd15e, 0xd164, 0xd16a, 0xd170, 0xd176, 0xd17c, 0xd182, 0xd188, 0xd18e,
+    0xd194, 0xd19a, 0xd1a1, 0xd1a7, 0xd1ad, 0xd1b3, 0xd1ba, 0xd1c0, 0xd1c6, 0xd1cd, 0xd1d3, 0xd1d9,
+    0xd1e0, 0xd1e6, 0xd1ed, 0xd1f3, 0xd1fa, 0xd200, 0xd207, 0xd20e, 0xd214, 0xd21b, 0xd222, 0xd229,
+    0xd22f, 0xd236, 0xd23d, 0xd244, 0xd24b, 0xd252, 0xd259, 0xd260, 0xd267, 0xd26e, 0xd275, 0xd27c,
+    0xd283, 0xd28a, 0xd292, 0xd299, 0xd2a0, 0xd2a7, 0xd2af, 0xd2b6, 0xd2bd, 0xd2c5, 0xd2cc, 0xd2d4,
+    0xd2db, 0xd2e3, 0xd2ea, 0xd2f2, 0xd2fa, 0xd301, 0xd309, 0xd311, 0xd319, 0xd321, 0xd328, 0xd330,
+    0xd338, 0xd340, 0xd348, 0xd350, 0xd358, 0xd360, 0xd369, 0xd371, 0xd379, 0xd381, 0xd389, 0xd392,
+    0xd39a, 0xd3a2, 0xd3ab, 0xd3b3, 0xd3bc, 0xd3c4, 0xd3cd, 0xd3d5, 0xd3de, 0xd3e7, 0xd3ef, 0xd3f8,
+    0xd401, 0xd405, 0xd409, 0xd40e, 0xd412, 0xd417, 0xd41b, 0xd420, 0xd424, 0xd429, 0xd42d, 0xd432,
+    0xd437, 0xd43b, 0xd440, 0xd445, 0xd449, 0xd44e, 0xd453, 0xd458, 0xd45c, 0xd461, 0xd466, 0xd46b,
+    0xd470, 0xd475, 0xd47a, 0xd47f, 0xd483, 0xd488, 0xd48d, 0xd492, 0xd498, 0xd49d, 0xd4a2, 0xd4a7,
+    0xd4ac, 0xd4b1, 0xd4b6, 0xd4bb, 0xd4c1, 0xd4c6, 0xd4cb, 0xd4d0, 0xd4d6, 0xd4db, 0xd4e0, 0xd4e6,
+    0xd4eb, 0xd4f1, 0xd4f6, 0xd4fc, 0xd501, 0xd507, 0xd50c, 0xd512, 0xd517, 0xd51d, 0xd522, 0xd528,
+    0xd52e, 0xd534, 0xd539, 0xd53f, 0xd545, 0xd54b, 0xd550, 0xd556, 0xd55c, 0xd562, 0xd568, 0xd56e,
+    0xd574, 0xd57a, 0xd580, 0xd586, 0xd58c, 0xd592, 0xd598, 0xd59f, 0xd5a5, 0xd5ab, 0xd5b1, 0xd5b8,
+    0xd5be, 0xd5c4, 0xd5cb, 0xd5d1, 0xd5d7, 0xd5de, 0xd5e4, 0xd5eb, 0xd5f1, 0xd5f8, 0xd5fe, 0xd605,
+    0xd60c, 0xd612, 0xd619, 0xd620, 0xd626, 0xd62d, 0xd634, 0xd63b, 0xd642, 0xd649, 0xd650, 0xd657,
+    0xd65d, 0xd665, 0xd66c, 0xd673, 0xd67a, 0xd681, 0xd688, 0xd68f, 0xd696, 0xd69e, 0xd6a5, 0xd6ac,
+    0xd6b4, 0xd6bb, 0xd6c3, 0xd6ca, 0xd6d1, 0xd6d9, 0xd6e1, 0xd6e8, 0xd6f0, 0xd6f7, 0xd6ff, 0xd707,
+    0xd70f, 0xd716, 0xd71e, 0xd726, 0xd72e, 0xd736, 0xd73e, 0xd746, 0xd74e, 0xd756, 0xd75e, 0xd766,
+    0xd76e, 0xd776, 0xd77f, 0xd787, 0xd78f, 0xd797, 0xd7a0, 0xd7a8, 0xd7b1, 0xd7b9, 0xd7c2, 0xd7ca,
+    0xd7d3, 0xd7db, 0xd7e4, 0xd7ed, 0xd7f5, 0xd7fe, 0xd804, 0xd808, 0xd80c, 0xd811, 0xd815, 0xd81a,
+    0xd81e, 0xd823, 0xd827, 0xd82c, 0xd831, 0xd835, 0xd83a, 0xd83f, 0xd843, 0xd848, 0xd84d, 0xd851,
+    0xd856, 0xd85b, 0xd860, 0xd865, 0xd869, 0xd86e, 0xd873, 0xd878, 0xd87d, 0xd882, 0xd887, 0xd88c,
+    0xd891, 0xd896, 0xd89b, 0xd8a0, 0xd8a5, 0xd8aa, 0xd8af, 0xd8b5, 0xd8ba, 0xd8bf, 0xd8c4, 0xd8c9,
+    0xd8cf, 0xd8d4, 0xd8d9, 0xd8df, 0xd8e4, 0xd8e9, 0xd8ef, 0xd8f4, 0xd8fa, 0xd8ff, 0xd905, 0xd90a,
+    0xd910, 0xd915, 0xd91b, 0xd921, 0xd926, 0xd92c, 0xd932, 0xd937, 0xd93d, 0xd943, 0xd949, 0xd94f,
+    0xd954, 0xd95a, 0xd960, 0xd966, 0xd96c, 0xd972, 0xd978, 0xd97e, 0xd984, 0xd98a, 0xd990, 0xd996,
+    0xd99d, 0xd9a3, 0xd9a9, 0xd9af, 0xd9b6, 0xd9bc, 0xd9c2, 0xd9c9, 0xd9cf, 0xd9d5, 0xd9dc, 0xd9e2,
+    0xd9e9, 0xd9ef, 0xd9f6, 0xd9fc, 0xda03, 0xda0a, 0xda10, 0xda17, 0xda1e, 0xda24, 0xda2b, 0xda32,
+    0xda39, 0xda40, 0xda46, 0xda4d, 0xda54, 0xda5b, 0xda62, 0xda69, 0xda70, 0xda78, 0xda7f, 0xda86,
+    0xda8d, 0xda94, 0xda9b, 0xdaa3, 0xdaaa, 0xdab1, 0xdab9, 0xdac0, 0xdac8, 0xdacf, 0xdad7, 0xdade,
+    0xdae6, 0xdaed, 0xdaf5, 0xdafd, 0xdb04, 0xdb0c, 0xdb14, 0xdb1c, 0xdb23, 0xdb2b, 0xdb33, 0xdb3b,
+    0xdb43, 0xdb4b, 0xdb53, 0xdb5b, 0xdb63, 0xdb6c, 0xdb74, 0xdb7c, 0xdb84, 0xdb8c, 0xdb95, 0xdb9d,
+    0xdba6, 0xdbae, 0xdbb6, 0xdbbf, 0xdbc7, 0xdbd0, 0xdbd9, 0xdbe1, 0xdbea, 0xdbf3, 0xdbfb, 0xdc02,
+    0xdc07, 0xdc0b, 0xdc0f, 0xdc14, 0xdc18, 0xdc1d, 0xdc21, 0xdc26, 0xdc2b, 0xdc2f, 0xdc34, 0xdc38,
+    0xdc3d, 0xdc42, 0xdc46, 0xdc4b, 0xdc50, 0xdc55, 0xdc59, 0xdc5e, 0xdc63, 0xdc68, 0xdc6d, 0xdc72,
+    0xdc77, 0xdc7b, 0xdc80, 0xdc85, 0xdc8a, 0xdc8f, 0xdc94, 0xdc99, 0xdc9e, 0xdca4, 0xdca9, 0xdcae,
+    0xdcb3, 0xdcb8, 0xdcbd, 0xdcc3, 0xdcc8, 0xdccd, 0xdcd2, 0xdcd8, 0xdcdd, 0xdce2, 0xdce8, 0xdced,
+    0xdcf3, 0xdcf8, 0xdcfe, 0xdd03, 0xdd09, 0xdd0e, 0xdd14, 0xdd19, 0xdd1f, 0xdd25, 0xdd2a, 0xdd30,
+    0xdd36, 0xdd3b, 0xdd41, 0xdd47, 0xdd4d, 0xdd53, 0xdd58, 0xdd5e, 0xdd64, 0xdd6a, 0xdd70, 0xdd76,
+    0xdd7c, 0xdd82, 0xdd88, 0xdd8e, 0xdd95, 0xdd9b, 0xdda1, 0xdda7, 0xddad, 0xddb4, 0xddba, 0xddc0,
+    0xddc7, 0xddcd, 0xddd3, 0xddda, 0xdde0, 0xdde7, 0xdded, 0xddf4, 0xddfa, 0xde01, 0xde07, 0xde0e,
+    0xde15, 0xde1b, 0xde22, 0xde29, 0xde30, 0xde37, 0xde3d, 0xde44, 0xde4b, 0xde52, 0xde59, 0xde60,
+    0xde67, 0xde6e, 0xde75, 0xde7c, 0xde84, 0xde8b, 0xde92, 0xde99, 0xdea0, 0xdea8, 0xdeaf, 0xdeb6,
+    0xdebe, 0xdec5, 0xdecd, 0xded4, 0xdedc, 0xdee3, 0xdeeb, 0xdef3, 0xdefa, 0xdf02, 0xdf0a, 0xdf11,
+    0xdf19, 0xdf21, 0xdf29, 0xdf31, 0xdf39, 0xdf41, 0xdf49, 0xdf51, 0xdf59, 0xdf61, 0xdf69, 0xdf71,
+    0xdf79, 0xdf82, 0xdf8a, 0xdf92, 0xdf9b, 0xdfa3, 0xdfab, 0xdfb4, 0xdfbc, 0xdfc5, 0xdfcd, 0xdfd6,
+    0xdfdf, 0xdfe7, 0xdff0, 0xdff9, 0xe001, 0xe005, 0xe00a, 0xe00e, 0xe013, 0xe017, 0xe01c, 0xe020,
+    0xe025, 0xe029, 0xe02e, 0xe032, 0xe037, 0xe03c, 0xe040, 0xe045, 0xe04a, 0xe04e, 0xe053, 0xe058,
+    0xe05d, 0xe062, 0xe066, 0xe06b, 0xe070, 0xe075, 0xe07a, 0xe07f, 0xe084, 0xe089, 0xe08e, 0xe093,
+    0xe098, 0xe09d, 0xe0a2, 0xe0a7, 0xe0ac, 0xe0b1, 0xe0b7, 0xe0bc, 0xe0c1, 0xe0c6, 0xe0cb, 0xe0d1,
+    0xe0d6, 0xe0db, 0xe0e1, 0xe0e6, 0xe0eb, 0xe0f1, 0xe0f6, 0xe0fc, 0xe101, 0xe107, 0xe10c, 0xe112,
+    0xe118, 0xe11d, 0xe123, 0xe128, 0xe12e, 0xe134, 0xe13a, 0xe13f, 0xe145, 0xe14b, 0xe151, 0xe157,
+    0xe15d, 0xe162, 0xe168, 0xe16e, 0xe174, 0xe17a, 0xe180, 0xe186, 0xe18d, 0xe193, 0xe199, 0xe19f,
+    0xe1a5, 0xe1ab, 0xe1b2, 0xe1b8, 0xe1be, 0xe1cb, 0xe1d8, 0xe1e5, 0xe1f2, 0xe1ff, 0xe20c, 0xe219,
+    0xe227, 0xe234, 0xe242, 0xe250, 0xe25e, 0xe26c, 0xe27a, 0xe288, 0xe297, 0xe2a5, 0xe2b4, 0xe2c3,
+    0xe2d2, 0xe2e1, 0xe2f0, 0xe2ff, 0xe30f, 0xe31f, 0xe32e, 0xe33e, 0xe34e, 0xe35e, 0xe36f, 0xe37f,
+    0xe390, 0xe3a0, 0xe3b1, 0xe3c2, 0xe3d3, 0xe3e5, 0xe3f6, 0xe404, 0xe40d, 0xe416, 0xe41f, 0xe428,
+    0xe431, 0xe43a, 0xe444, 0xe44d, 0xe456, 0xe460, 0xe46a, 0xe473, 0xe47d, 0xe487, 0xe491, 0xe49b,
+    0xe4a5, 0xe4b0, 0xe4ba, 0xe4c5, 0xe4cf, 0xe4da, 0xe4e4, 0xe4ef, 0xe4fa, 0xe505, 0xe510, 0xe51b,
+    0xe527, 0xe532, 0xe53e, 0xe549, 0xe555, 0xe561, 0xe56c, 0xe578, 0xe585, 0xe591, 0xe59d, 0xe5a9,
+    0xe5b6, 0xe5c3, 0xe5cf, 0xe5dc, 0xe5e9, 0xe5f6, 0xe603, 0xe611, 0xe61e, 0xe62b, 0xe639, 0xe647,
+    0xe655, 0xe663, 0xe671, 0xe67f, 0xe68d, 0xe69c, 0xe6aa, 0xe6b9, 0xe6c8, 0xe6d7, 0xe6e6, 0xe6f5,
+    0xe705, 0xe714, 0xe724, 0xe734, 0xe744, 0xe754, 0xe764, 0xe774, 0xe785, 0xe795, 0xe7a6, 0xe7b7,
+    0xe7c8, 0xe7d9, 0xe7ea, 0xe7fc, 0xe807, 0xe810, 0xe819, 0xe822, 0xe82b, 0xe834, 0xe83d, 0xe847,
+    0xe850, 0xe85a, 0xe863, 0xe86d, 0xe877, 0xe881, 0xe88b, 0xe895, 0xe89f, 0xe8a9, 0xe8b3, 0xe8be,
+    0xe8c8, 0xe8d3, 0xe8dd, 0xe8e8, 0xe8f3, 0xe8fe, 0xe909, 0xe914, 0xe91f, 0xe92b, 0xe936, 0xe941,
+    0xe94d, 0xe959, 0xe965, 0xe971, 0xe97d, 0xe989, 0xe995, 0xe9a1, 0xe9ae, 0xe9ba, 0xe9c7, 0xe9d4,
+    0xe9e1, 0xe9ee, 0xe9fb, 0xea08, 0xea15, 0xea23, 0xea30, 0xea3e, 0xea4c, 0xea5a, 0xea68, 0xea76,
+    0xea84, 0xea92, 0xeaa1, 0xeab0, 0xeabe, 0xeacd, 0xeadc, 0xeaeb, 0xeafb, 0xeb0a, 0xeb1a, 0xeb29,
+    0xeb39, 0xeb49, 0xeb59, 0xeb69, 0xeb7a, 0xeb8a, 0xeb9b, 0xebac, 0xebbd, 0xebce, 0xebdf, 0xebf0,
+    0xec01, 0xec0a, 0xec13, 0xec1c, 0xec25, 0xec2e, 0xec37, 0xec41, 0xec4a, 0xec53, 0xec5d, 0xec67,
+    0xec70, 0xec7a, 0xec84, 0xec8e, 0xec98, 0xeca2, 0xecac, 0xecb7, 0xecc1, 0xeccc, 0xecd6, 0xece1,
+    0xecec, 0xecf7, 0xed02, 0xed0d, 0xed18, 0xed23, 0xed2e, 0xed3a, 0xed45, 0xed51, 0xed5d, 0xed69,
+    0xed75, 0xed81, 0xed8d, 0xed99, 0xeda5, 0xedb2, 0xedbf, 0xedcb, 0xedd8, 0xede5, 0xedf2, 0xedff,
+    0xee0c, 0xee1a, 0xee27, 0xee35, 0xee43, 0xee50, 0xee5e, 0xee6c, 0xee7b, 0xee89, 0xee97, 0xeea6,
+    0xeeb5, 0xeec3, 0xeed2, 0xeee1, 0xeef1, 0xef00, 0xef0f, 0xef1f, 0xef2f, 0xef3f, 0xef4f, 0xef5f,
+    0xef6f, 0xef7f, 0xef90, 0xefa1, 0xefb2, 0xefc3, 0xefd4, 0xefe5, 0xeff6, 0xf004, 0xf00d, 0xf016,
+    0xf01f, 0xf028, 0xf031, 0xf03a, 0xf044, 0xf04d, 0xf057, 0xf060, 0xf06a, 0xf074, 0xf07e, 0xf087,
+    0xf091, 0xf09c, 0xf0a6, 0xf0b0, 0xf0ba, 0xf0c5, 0xf0cf, 0xf0da, 0xf0e5, 0xf0f0, 0xf0fa, 0xf105,
+    0xf111, 0xf11c, 0xf127, 0xf132, 0xf13e, 0xf149, 0xf155, 0xf161, 0xf16d, 0xf179, 0xf185, 0xf191,
+    0xf19d, 0xf1aa, 0xf1b6, 0xf1c3, 0xf1d0, 0xf1dc, 0xf1e9, 0xf1f6, 0xf204, 0xf211, 0xf21e, 0xf22c,
+    0xf239, 0xf247, 0xf255, 0xf263, 0xf271, 0xf27f, 0xf28e, 0xf29c, 0xf2ab, 0xf2ba, 0xf2c8, 0xf2d7,
+    0xf2e7, 0xf2f6, 0xf305, 0xf315, 0xf324, 0xf334, 0xf344, 0xf354, 0xf364, 0xf375, 0xf385, 0xf396,
+    0xf3a6, 0xf3b7, 0xf3c8, 0xf3da, 0xf3eb, 0xf3fc, 0xf407, 0xf410, 0xf419, 0xf422, 0xf42b, 0xf434,
+    0xf43e, 0xf447, 0xf450, 0xf45a, 0xf464, 0xf46d, 0xf477, 0xf481, 0xf48b, 0xf495, 0xf49f, 0xf4a9,
+    0xf4b4, 0xf4be, 0xf4c8, 0xf4d3, 0xf4de, 0xf4e8, 0xf4f3, 0xf4fe, 0xf509, 0xf514, 0xf520, 0xf52b,
+    0xf536, 0xf542, 0xf54d, 0xf559, 0xf565, 0xf571, 0xf57d, 0xf589, 0xf595, 0xf5a2, 0xf5ae, 0xf5bb,
+    0xf5c7, 0xf5d4, 0xf5e1, 0xf5ee, 0xf5fb, 0xf608, 0xf616, 0xf623, 0xf631, 0xf63e, 0xf64c, 0xf65a,
+    0xf668, 0xf676, 0xf684, 0xf693, 0xf6a1, 0xf6b0, 0xf6bf, 0xf6ce, 0xf6dd, 0xf6ec, 0xf6fb, 0xf70b,
+    0xf71a, 0xf72a, 0xf73a, 0xf74a, 0xf75a, 0xf76a, 0xf77a, 0xf78b, 0xf79b, 0xf7ac, 0xf7bd, 0xf7ce,
+    0xf7e0, 0xf7f1, 0xf801, 0xf80a, 0xf813, 0xf81c, 0xf825, 0xf82e, 0xf838, 0xf841, 0xf84a, 0xf854,
+    0xf85d, 0xf867, 0xf871, 0xf87a, 0xf884, 0xf88e, 0xf898, 0xf8a3, 0xf8ad, 0xf8b7, 0xf8c2, 0xf8cc,
+    0xf8d7, 0xf8e1, 0xf8ec, 0xf8f7, 0xf902, 0xf90d, 0xf918, 0xf923, 0xf92f, 0xf93a, 0xf946, 0xf951,
+    0xf95d, 0xf969, 0xf975, 0xf981, 0xf98d, 0xf999, 0xf9a6, 0xf9b2, 0xf9bf, 0xf9cc, 0xf9d8, 0xf9e5,
+    0xf9f2, 0xf9ff, 0xfa0d, 0xfa1a, 0xfa28, 0xfa35, 0xfa43, 0xfa51, 0xfa5f, 0xfa6d, 0xfa7b, 0xfa89,
+    0xfa98, 0xfaa6, 0xfab5, 0xfac4, 0xfad3, 0xfae2, 0xfaf1, 0xfb00, 0xfb10, 0xfb1f, 0xfb2f, 0xfb3f,
+    0xfb4f, 0xfb5f, 0xfb70, 0xfb80, 0xfb91, 0xfba1, 0xfbb2, 0xfbc3, 0xfbd4, 0xfbe6, 0xfbf7, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00, 0xfc00,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000,
+];
@@ -9,11 +9,12 @@ mod rle;
 mod piz;
 mod pxr24;
 mod b44;
+mod dwa;
 
 
 
 use crate::meta::attribute::{IntegerBounds, SampleType, ChannelList};
-use crate::error::{Result, Error, usize_to_i32};
+use crate::error::{Result, UnitResult, Error, usize_to_i32};
 use crate::meta::header::Header;
 
 
@@ -27,7 +28,9 @@ pub type Bytes<'s> = &'s [u8];
 /// Use uncompressed data for fastest loading and writing speeds.
 /// Use RLE compression for fast loading and writing with slight memory savings.
 /// Use ZIP compression for slow processing with large memory savings.
+/// New compression methods may be added without a major version, so matches must include a wildcard arm.
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[non_exhaustive]
 pub enum Compression {
 
     /// Store uncompressed values.
@@ -45,12 +48,17 @@ pub enum Compression {
     /// Uses ZIP compression to compress each line. Slowly produces small images
     /// which can be read with moderate speed. This compression method is lossless.
     /// Might be slightly faster but larger than `ZIP16´.
-    ZIP1,  // TODO ZIP { individual_lines: bool, compression_level: Option<u8> }  // TODO specify zip compression level?
+    /// The optional value is the deflate compression level, from `0` (fastest) to `10` (smallest).
+    /// If no level is specified, the default level of `4` is used.
+    /// The level is not stored in the file, so it is always `None` after reading.
+    /// Use `Compression::same_method` to compare compression methods regardless of their level.
+    ZIP1(Option<u8>),  // TODO ZIP { individual_lines: bool, compression_level: Option<u8> }
 
     /// Uses ZIP compression to compress blocks of 16 lines. Slowly produces small images
     /// which can be read with moderate speed. This compression method is lossless.
     /// Might be slightly slower but smaller than `ZIP1´.
-    ZIP16, // TODO collapse with ZIP1
+    /// The optional value is the deflate compression level, as in `ZIP1`.
+    ZIP16(Option<u8>), // TODO collapse with ZIP1
 
     /// PIZ compression works well for noisy and natural images. Works better with larger tiles.
     /// Only supported for flat images, but not for deep data.
@@ -80,7 +88,8 @@ pub enum Compression {
     // are compressed with zlib, similar to ZIP. PXR24 compression preserves image
     // channels of type HALF and UINT exactly, but the relative error of FLOAT data
     // increases to about ???.
-    PXR24, // TODO specify zip compression level?
+    /// The optional value is the deflate compression level, as in `ZIP1`.
+    PXR24(Option<u8>),
 
     /// This is a lossy compression method for f16 images.
     /// It's the predecessor of the `B44A` compression,
@@ -122,17 +131,25 @@ pub enum Compression {
     /// Only supported for flat images, not for deep data.
     B44A, // TODO collapse with B44
 
-    /// __This lossy compression is not yet supported by this implementation.__
+    /// This lossy compression method compresses red, green, blue, and luminance
+    /// channels using a discrete cosine transform, similar to JPEG.
+    /// All other channels are compressed without loss.
+    /// The optional value is the compression level, where higher values produce smaller files.
+    /// If no level is specified, the default level of `45.0` is used.
+    /// Only supported for flat images, not for deep data.
     // lossy DCT based compression, in blocks
     // of 32 scanlines. More efficient for partial buffer access.
-    DWAA(Option<f32>), // TODO does this have a default value? make this non optional? default Compression Level setting is 45.0
+    DWAA(Option<f32>),
 
-    /// __This lossy compression is not yet supported by this implementation.__
+    /// Like `DWAA`, but compresses blocks of 256 lines instead of 32 lines.
+    /// This produces smaller files and decodes whole images faster,
+    /// but is less efficient when only a small part of the image is needed.
+    /// Only supported for flat images, not for deep data.
     // lossy DCT based compression, in blocks
     // of 256 scanlines. More efficient space
     // wise and faster to decode full frames
     // than DWAA_COMPRESSION.
-    DWAB(Option<f32>), // TODO collapse with B44. default Compression Level setting is 45.0
+    DWAB(Option<f32>), // TODO collapse with DWAA
 }
 
 impl std::fmt::Display for Compression {
@@ -140,14 +157,14 @@ impl std::fmt::Display for Compression {
         write!(formatter, "{} compression", match self {
             Compression::Uncompressed => "no",
             Compression::RLE => "rle",
-            Compression::ZIP1 => "zip line",
-            Compression::ZIP16 => "zip block",
+            Compression::ZIP1(_) => "zip line",
+            Compression::ZIP16(_) => "zip block",
             Compression::B44 => "b44",
             Compression::B44A => "b44a",
             Compression::DWAA(_) => "dwaa",
             Compression::DWAB(_) => "dwab",
             Compression::PIZ => "piz",
-            Compression::PXR24 => "pxr24",
+            Compression::PXR24(_) => "pxr24",
         })
     }
 }
@@ -166,14 +183,22 @@ impl Compression {
         use self::Compression::*;
         let compressed_little_endian = match self {
             Uncompressed => Ok(convert_current_to_little_endian(&uncompressed_native_endian, &header.channels, pixel_section)),
-            ZIP16 => zip::compress_bytes(&header.channels, &uncompressed_native_endian, pixel_section),
-            ZIP1 => zip::compress_bytes(&header.channels, &uncompressed_native_endian, pixel_section),
+            ZIP1(level) | ZIP16(level) => zip::compress_bytes(
+                &header.channels, &uncompressed_native_endian, pixel_section,
+                level.unwrap_or(zip::DEFAULT_COMPRESSION_LEVEL)
+            ),
             RLE => rle::compress_bytes(&header.channels, &uncompressed_native_endian, pixel_section),
             PIZ => piz::compress(&header.channels, &uncompressed_native_endian, pixel_section),
-            PXR24 => pxr24::compress(&header.channels, &uncompressed_native_endian, pixel_section),
+            PXR24(level) => pxr24::compress(
+                &header.channels, &uncompressed_native_endian, pixel_section,
+                level.unwrap_or(zip::DEFAULT_COMPRESSION_LEVEL)
+            ),
             B44 => b44::compress(&header.channels, &uncompressed_native_endian, pixel_section, false),
             B44A => b44::compress(&header.channels, &uncompressed_native_endian, pixel_section, true),
-            _ => return Err(Error::unsupported(format!("yet unimplemented compression method: {}", self)))
+            DWAA(level) | DWAB(level) => dwa::compress(
+                &header.channels, &uncompressed_native_endian, pixel_section,
+                level.unwrap_or(dwa::DEFAULT_COMPRESSION_LEVEL)
+            ),
         };
 
         let compressed_little_endian = compressed_little_endian.map_err(|_|
@@ -197,7 +222,7 @@ impl Compression {
         assert!(pixel_section.validate(Some(max_tile_size)).is_ok(), "decompress tile coordinate bug");
         if header.deep { assert!(self.supports_deep_data()) }
 
-        let expected_byte_size = pixel_section.size.area() * header.channels.bytes_per_pixel; // FIXME this needs to account for subsampling anywhere
+        let expected_byte_size = header.channels.byte_size_of_section(pixel_section);
 
         // note: always true where self == Uncompressed
         if compressed.len() == expected_byte_size {
@@ -208,13 +233,12 @@ impl Compression {
             use self::Compression::*;
             let bytes = match self {
                 Uncompressed => Ok(convert_little_endian_to_current(&compressed, &header.channels, pixel_section)),
-                ZIP16 => zip::decompress_bytes(&header.channels, compressed, pixel_section, expected_byte_size, pedantic),
-                ZIP1 => zip::decompress_bytes(&header.channels, compressed, pixel_section, expected_byte_size, pedantic),
+                ZIP1(_) | ZIP16(_) => zip::decompress_bytes(&header.channels, compressed, pixel_section, expected_byte_size, pedantic),
                 RLE => rle::decompress_bytes(&header.channels, compressed, pixel_section, expected_byte_size, pedantic),
                 PIZ => piz::decompress(&header.channels, compressed, pixel_section, expected_byte_size, pedantic),
-                PXR24 => pxr24::decompress(&header.channels, compressed, pixel_section, expected_byte_size, pedantic),
+                PXR24(_) => pxr24::decompress(&header.channels, compressed, pixel_section, expected_byte_size, pedantic),
                 B44 | B44A => b44::decompress(&header.channels, compressed, pixel_section, expected_byte_size, pedantic),
-                _ => return Err(Error::unsupported(format!("yet unimplemented compression method: {}", self)))
+                DWAA(_) | DWAB(_) => dwa::decompress(&header.channels, compressed, pixel_section, expected_byte_size, pedantic),
             };
 
             // map all errors to compression errors
@@ -237,16 +261,77 @@ impl Compression {
         }
     }
 
+    /// Compress one of the two sections of a deep block,
+    /// which are the pixel offset table and the sample data.
+    /// The bytes must already be in little-endian byte order.
+    pub fn compress_deep_section(self, uncompressed_little_endian: ByteVec) -> Result<ByteVec> {
+        if !self.supports_deep_data() {
+            return Err(Error::invalid("compression method does not support deep data"));
+        }
+
+        use self::Compression::*;
+        let compressed = match self {
+            Uncompressed => return Ok(uncompressed_little_endian),
+            RLE => rle::compress_raw(uncompressed_little_endian.clone()),
+            ZIP1(level) | ZIP16(level) => zip::compress_raw(
+                uncompressed_little_endian.clone(),
+                level.unwrap_or(zip::DEFAULT_COMPRESSION_LEVEL)
+            ),
+            _ => unreachable!(),
+        };
+
+        let compressed = compressed.map_err(|_|
+            Error::invalid(format!("deep data cannot be compressed ({})", self))
+        )?;
+
+        // only write compressed if it actually is smaller than raw
+        if compressed.len() < uncompressed_little_endian.len() { Ok(compressed) }
+        else { Ok(uncompressed_little_endian) }
+    }
+
+    /// Decompress one of the two sections of a deep block,
+    /// which are the pixel offset table and the sample data.
+    /// Unlike flat image sections, these bytes are not converted to the current endianness.
+    pub fn decompress_deep_section(self, compressed: ByteVec, expected_byte_size: usize, pedantic: bool) -> Result<ByteVec> {
+        if !self.supports_deep_data() {
+            return Err(Error::invalid("compression method does not support deep data"));
+        }
+
+        // note: always true where self == Uncompressed
+        if compressed.len() == expected_byte_size {
+            // the compressed data was larger than the raw data, so the small raw data has been written
+            return Ok(compressed);
+        }
+
+        use self::Compression::*;
+        let bytes = match self {
+            RLE => rle::decompress_raw(compressed, expected_byte_size, pedantic),
+            ZIP1(_) | ZIP16(_) => zip::decompress_raw(compressed, expected_byte_size, pedantic),
+            _ => Err(Error::invalid("deep data size")),
+        };
+
+        let bytes = bytes.map_err(|error| Error::invalid(format!(
+            "compressed {:?} deep data ({})",
+            self, error.to_string()
+        )))?;
+
+        if bytes.len() != expected_byte_size {
+            Err(Error::invalid("decompressed deep data"))
+        }
+
+        else { Ok(bytes) }
+    }
+
     /// For scan line images and deep scan line images, one or more scan lines may be
     /// stored together as a scan line block. The number of scan lines per block
     /// depends on how the pixel data are compressed.
     pub fn scan_lines_per_block(self) -> usize {
         use self::Compression::*;
         match self {
-            Uncompressed | RLE   | ZIP1    => 1,
-            ZIP16 | PXR24                  => 16,
-            PIZ   | B44   | B44A | DWAA(_) => 32,
-            DWAB(_)                        => 256,
+            Uncompressed | RLE   | ZIP1(_)  => 1,
+            ZIP16(_) | PXR24(_)             => 16,
+            PIZ   | B44   | B44A | DWAA(_)  => 32,
+            DWAB(_)                         => 256,
         }
     }
 
@@ -254,19 +339,62 @@ impl Compression {
     pub fn supports_deep_data(self) -> bool {
         use self::Compression::*;
         match self {
-            Uncompressed | RLE | ZIP1 => true,
+            Uncompressed | RLE | ZIP1(_) | ZIP16(_) => true,
             _ => false,
         }
     }
 
+    /// ZIP compression of single lines, using the default deflate level.
+    pub const fn zip1() -> Self { Compression::ZIP1(None) }
+
+    /// ZIP compression of blocks of 16 lines, using the default deflate level.
+    pub const fn zip16() -> Self { Compression::ZIP16(None) }
+
+    /// PXR24 compression, using the default deflate level.
+    pub const fn pxr24() -> Self { Compression::PXR24(None) }
+
+    /// Use the specified deflate level, from `0` (fastest) to `10` (smallest),
+    /// if this is `ZIP1`, `ZIP16`, or `PXR24` compression. Other compression methods are returned unchanged.
+    pub fn with_deflate_level(self, level: u8) -> Self {
+        use self::Compression::*;
+        match self {
+            ZIP1(_) => ZIP1(Some(level)),
+            ZIP16(_) => ZIP16(Some(level)),
+            PXR24(_) => PXR24(Some(level)),
+            other => other,
+        }
+    }
+
+    /// Whether both produce the same file, which is true if they only differ in the deflate level,
+    /// because the deflate level is not stored in the file.
+    /// Unlike `==`, this is true for a method written with a level and the same method read from the file.
+    pub fn same_method(self, other: Self) -> bool {
+        use self::Compression::*;
+        match (self, other) {
+            (ZIP1(_), ZIP1(_)) | (ZIP16(_), ZIP16(_)) | (PXR24(_), PXR24(_)) => true,
+            _ => self == other,
+        }
+    }
+
+    /// Check whether the compression parameters are valid, for example that the deflate level is at most `10`.
+    pub fn validate(self) -> UnitResult {
+        use self::Compression::*;
+        match self {
+            ZIP1(Some(level)) | ZIP16(Some(level)) | PXR24(Some(level)) if level > zip::MAX_COMPRESSION_LEVEL =>
+                Err(Error::invalid("deflate compression level")),
+
+            _ => Ok(()),
+        }
+    }
+
     /// Most compression methods will reconstruct the exact pixel bytes,
     /// but some might throw away unimportant data for specific types of samples.
     pub fn is_lossless_for(self, sample_type: SampleType) -> bool {
         use self::Compression::*;
         match self {
-            PXR24 => sample_type != SampleType::F32, // pxr reduces f32 to f24
+            PXR24(_) => sample_type != SampleType::F32, // pxr reduces f32 to f24
             B44 | B44A => sample_type != SampleType::F16, // b44 only compresses f16 values, others are left uncompressed
-            Uncompressed | RLE | ZIP1 | ZIP16 | PIZ => true,
+            Uncompressed | RLE | ZIP1(_) | ZIP16(_) | PIZ => true,
             DWAB(_) | DWAA(_) => false,
         }
     }
@@ -276,8 +404,8 @@ impl Compression {
     pub fn may_loose_data(self) -> bool {
         use self::Compression::*;
         match self {
-            Uncompressed | RLE | ZIP1 | ZIP16 | PIZ => false,
-            PXR24 | B44 | B44A | DWAB(_) | DWAA(_)  => true,
+            Uncompressed | RLE | ZIP1(_) | ZIP16(_) | PIZ => false,
+            PXR24(_) | B44 | B44A | DWAB(_) | DWAA(_)  => true,
         }
     }
 
@@ -310,7 +438,7 @@ fn convert_current_to_little_endian(bytes: Bytes<'_>, channels: &ChannelList, re
                 if mod_p(y, usize_to_i32(channel.sampling.y())) != 0 { continue; }
 
                 // FIXME do not match on every value
-                for _x in 0 .. rectangle.size.width() / channel.sampling.x() {
+                for _x in 0 .. channel.subsampled_section_resolution(rectangle).x() {
                     match channel.sample_type {
                         SampleType::F16 => little.write_as_little_endian(&u16::read_from_native_endian(&mut native).expect("read from in-memory buffer failed")),
                         SampleType::F32 => little.write_as_little_endian(&f32::read_from_native_endian(&mut native).expect("read from in-memory buffer failed")),
@@ -372,7 +500,7 @@ fn convert_little_endian_to_current(bytes: Bytes<'_>, channels: &ChannelList, re
                 if mod_p(y, usize_to_i32(channel.sampling.y())) != 0 { continue; }
 
                 // FIXME do not match on every value
-                for _x in 0 .. rectangle.size.width() / channel.sampling.x() {
+                for _x in 0 .. channel.subsampled_section_resolution(rectangle).x() {
                     match channel.sample_type {
                         SampleType::F16 => native.write_as_native_endian(&u16::read_from_little_endian(&mut little).expect("read from in-memory buffer failed")),
                         SampleType::F32 => native.write_as_native_endian(&f32::read_from_little_endian(&mut little).expect("read from in-memory buffer failed")),
@@ -4,7 +4,7 @@
 //! based on the PIZ image format, customized for OpenEXR.
 // inspired by  https://github.com/AcademySoftwareFoundation/openexr/blob/master/OpenEXR/IlmImf/ImfPizCompressor.cpp
 
-mod huffman;
+pub(super) mod huffman; // also used by dwa
 mod wavelet;
 
 use crate::prelude::*;
@@ -33,12 +33,12 @@ pub fn decompress(
     channels: &ChannelList,
     compressed: ByteVec,
     rectangle: IntegerBounds,
-    expected_byte_size: usize, // TODO remove expected byte size as it can be computed with `rectangle.size.area() * channels.bytes_per_pixel`
+    expected_byte_size: usize, // TODO remove expected byte size as it can be computed with `channels.byte_size_of_section(rectangle)`
     pedantic: bool
 ) -> Result<ByteVec>
 {
     let expected_u16_count = expected_byte_size / 2;
-    debug_assert_eq!(expected_byte_size, rectangle.size.area() * channels.bytes_per_pixel);
+    debug_assert_eq!(expected_byte_size, channels.byte_size_of_section(rectangle));
     debug_assert!(!channels.list.is_empty());
 
     if compressed.is_empty() {
@@ -81,7 +81,7 @@ pub fn decompress(
                 tmp_start_index: tmp_read_index,
                 tmp_end_index: tmp_read_index,
                 y_sampling: channel.sampling.y(),
-                resolution: channel.subsampled_resolution(rectangle.size),
+                resolution: channel.subsampled_section_resolution(rectangle),
                 samples_per_pixel: channel.sample_type.bytes_per_sample() / SampleType::F16.bytes_per_sample()
             };
 
@@ -168,7 +168,7 @@ pub fn compress(
         let mut tmp_end_index = 0;
 
         let vec = channels.list.iter().map(|channel| {
-            let number_samples = channel.subsampled_resolution(rectangle.size);
+            let number_samples = channel.subsampled_section_resolution(rectangle);
             let byte_size = channel.sample_type.bytes_per_sample() / SampleType::F16.bytes_per_sample();
             let byte_count = byte_size * number_samples.area();
 
@@ -40,7 +40,7 @@ use lebe::io::ReadPrimitive;
 
 
 #[cfg_attr(target_endian = "big", allow(unused, unreachable_code))]
-pub fn compress(channels: &ChannelList, remaining_bytes: Bytes<'_>, area: IntegerBounds) -> Result<ByteVec> {
+pub fn compress(channels: &ChannelList, remaining_bytes: Bytes<'_>, area: IntegerBounds, level: u8) -> Result<ByteVec> {
     #[cfg(target_endian = "big")] {
         return Err(Error::unsupported(
             "PXR24 compression method not supported yet on big endian processor architecture"
@@ -53,13 +53,13 @@ pub fn compress(channels: &ChannelList, remaining_bytes: Bytes<'_>, area: Intege
     let remaining_bytes = super::convert_current_to_little_endian(remaining_bytes, channels, area);
     let mut remaining_bytes = remaining_bytes.as_slice(); // TODO less allocation
 
-    let bytes_per_pixel: usize = channels.list.iter()
-        .map(|channel| match channel.sample_type {
+    let byte_count: usize = channels.list.iter()
+        .map(|channel| channel.subsampled_section_resolution(area).area() * match channel.sample_type {
             SampleType::F16 => 2, SampleType::F32 => 3, SampleType::U32 => 4,
         })
         .sum();
 
-    let mut raw = vec![0_u8; bytes_per_pixel * area.size.area()];
+    let mut raw = vec![0_u8; byte_count];
 
     {
         let mut write = raw.as_mut_slice();
@@ -70,7 +70,7 @@ pub fn compress(channels: &ChannelList, remaining_bytes: Bytes<'_>, area: Intege
                 if mod_p(y, usize_to_i32(channel.sampling.1)) != 0 { continue; }
 
                 // this apparently can't be a closure in Rust 1.43 due to borrowing ambiguity
-                let sample_count_x = channel.subsampled_resolution(area.size).0;
+                let sample_count_x = channel.subsampled_section_resolution(area).x();
                 macro_rules! split_off_write_slice { () => {{
                     let (slice, rest) = write.split_at_mut(sample_count_x);
                     write = rest;
@@ -134,7 +134,7 @@ pub fn compress(channels: &ChannelList, remaining_bytes: Bytes<'_>, area: Intege
         debug_assert_eq!(write.len(), 0, "bytes left after compression");
     }
 
-    Ok(miniz_oxide::deflate::compress_to_vec_zlib(raw.as_slice(), 4))
+    Ok(miniz_oxide::deflate::compress_to_vec_zlib(raw.as_slice(), level))
 }
 
 #[cfg_attr(target_endian = "big", allow(unused, unreachable_code))]
@@ -157,7 +157,7 @@ pub fn decompress(channels: &ChannelList, bytes: ByteVec, area: IntegerBounds, e
         for channel in &channels.list {
             if mod_p(y, usize_to_i32(channel.sampling.1)) != 0 { continue; }
 
-            let sample_count_x = channel.subsampled_resolution(area.size).0;
+            let sample_count_x = channel.subsampled_section_resolution(area).x();
             let mut read_sample_line = ||{
                 if sample_count_x > read.len() { return Err(Error::invalid("not enough data")) }
                 let (samples, rest) = read.split_at(sample_count_x);
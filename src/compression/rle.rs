@@ -16,6 +16,12 @@ pub fn decompress_bytes(
     expected_byte_size: usize,
     pedantic: bool,
 ) -> Result<ByteVec> {
+    let decompressed = decompress_raw(compressed, expected_byte_size, pedantic)?;
+    Ok(super::convert_little_endian_to_current(&decompressed, channels, rectangle))// TODO no alloc
+}
+
+/// Decompress the bytes without converting them to the current endianness, as is done for deep data.
+pub fn decompress_raw(compressed: ByteVec, expected_byte_size: usize, pedantic: bool) -> Result<ByteVec> {
     let mut remaining = compressed.as_slice();
     let mut decompressed = Vec::with_capacity(expected_byte_size.min(8*2048));
 
@@ -40,16 +46,25 @@ pub fn decompress_bytes(
 
     differences_to_samples(&mut decompressed);
     interleave_byte_blocks(&mut decompressed);
-    Ok(super::convert_little_endian_to_current(&decompressed, channels, rectangle))// TODO no alloc
+    Ok(decompressed)
 }
 
 pub fn compress_bytes(channels: &ChannelList, uncompressed: Bytes<'_>, rectangle: IntegerBounds) -> Result<ByteVec> {
     // see https://github.com/AcademySoftwareFoundation/openexr/blob/3bd93f85bcb74c77255f28cdbb913fdbfbb39dfe/OpenEXR/IlmImf/ImfTiledOutputFile.cpp#L750-L842
-    let mut data = super::convert_current_to_little_endian(uncompressed, channels, rectangle);// TODO no alloc
+    let data = super::convert_current_to_little_endian(uncompressed, channels, rectangle);// TODO no alloc
+    compress_raw(data)
+}
 
+/// Compress the bytes without converting them to little endian, as is done for deep data.
+pub fn compress_raw(mut data: ByteVec) -> Result<ByteVec> {
     separate_bytes_fragments(&mut data);
     samples_to_differences(&mut data);
 
+    Ok(compress_runs(&data))
+}
+
+/// Run length encoding, without any further byte reordering.
+pub fn compress_runs(data: Bytes<'_>) -> ByteVec {
     let mut compressed = Vec::with_capacity(data.len());
     let mut run_start = 0;
     let mut run_end = 1;
@@ -86,7 +101,7 @@ pub fn compress_bytes(channels: &ChannelList, uncompressed: Bytes<'_>, rectangle
         }
     }
 
-    Ok(compressed)
+    compressed
 }
 
 fn take_1(slice: &mut &[u8]) -> Result<u8> {
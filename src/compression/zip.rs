@@ -6,6 +6,12 @@ use super::*;
 use super::optimize_bytes::*;
 use crate::error::Result;
 
+/// The deflate compression level used if no other level is specified.
+pub const DEFAULT_COMPRESSION_LEVEL: u8 = 4;
+
+/// The deflate compression level that produces the smallest files.
+pub const MAX_COMPRESSION_LEVEL: u8 = 10;
+
 // scanline decompression routine, see https://github.com/openexr/openexr/blob/master/OpenEXR/IlmImf/ImfScanLineInputFile.cpp
 // 1. Uncompress the data, if necessary (If the line is uncompressed, it's in XDR format, regardless of the compressor's output format.)
 // 3. Convert one scan line's worth of pixel data back from the machine-independent representation
@@ -17,8 +23,14 @@ pub fn decompress_bytes(
     data: ByteVec,
     rectangle: IntegerBounds,
     expected_byte_size: usize,
-    _pedantic: bool,
+    pedantic: bool,
 ) -> Result<ByteVec> {
+    let decompressed = decompress_raw(data, expected_byte_size, pedantic)?;
+    Ok(super::convert_little_endian_to_current(&decompressed, channels, rectangle))// TODO no alloc
+}
+
+/// Decompress the bytes without converting them to the current endianness, as is done for deep data.
+pub fn decompress_raw(data: ByteVec, expected_byte_size: usize, _pedantic: bool) -> Result<ByteVec> {
     let options = zune_inflate::DeflateOptions::default().set_limit(expected_byte_size).set_size_hint(expected_byte_size);
     let mut decoder = zune_inflate::DeflateDecoder::new_with_options(&data, options);
     let mut decompressed = decoder.decode_zlib()
@@ -27,15 +39,19 @@ pub fn decompress_bytes(
     differences_to_samples(&mut decompressed);
     interleave_byte_blocks(&mut decompressed);
 
-    Ok(super::convert_little_endian_to_current(&decompressed, channels, rectangle))// TODO no alloc
+    Ok(decompressed)
 }
 
-pub fn compress_bytes(channels: &ChannelList, uncompressed: Bytes<'_>, rectangle: IntegerBounds) -> Result<ByteVec> {
+pub fn compress_bytes(channels: &ChannelList, uncompressed: Bytes<'_>, rectangle: IntegerBounds, level: u8) -> Result<ByteVec> {
     // see https://github.com/AcademySoftwareFoundation/openexr/blob/3bd93f85bcb74c77255f28cdbb913fdbfbb39dfe/OpenEXR/IlmImf/ImfTiledOutputFile.cpp#L750-L842
-    let mut packed = convert_current_to_little_endian(uncompressed, channels, rectangle);
+    let packed = convert_current_to_little_endian(uncompressed, channels, rectangle);
+    compress_raw(packed, level)
+}
 
+/// Compress the bytes without converting them to little endian, as is done for deep data.
+pub fn compress_raw(mut packed: ByteVec, level: u8) -> Result<ByteVec> {
     separate_bytes_fragments(&mut packed);
     samples_to_differences(&mut packed);
 
-    Ok(miniz_oxide::deflate::compress_to_vec_zlib(packed.as_slice(), 4))
+    Ok(miniz_oxide::deflate::compress_to_vec_zlib(packed.as_slice(), level))
 }
@@ -1,267 +1,458 @@
+//! Read and write the channels of a layer as a tree of channel groups, derived from the dots in the channel names.
+//!
+//! Renderers often store many outputs in a single layer, with channel names like `diffuse.direct.R`.
+//! The channel `diffuse.direct.R` is the channel `R` of the group `direct`, which is a child of the group `diffuse`.
+//! Use `read().….all_channels().channel_groups()` to read each group of a layer separately,
+//! and write a layer containing `ChannelGroups` to produce the dot-separated channel names.
 
 use std::collections::HashMap;
 use crate::image::write::channels::{WritableChannels, ChannelsWriter};
-use crate::meta::attribute::{LevelMode, ChannelList, Text, TextSlice, ChannelInfo};
+use crate::meta::attribute::{LevelMode, ChannelList, ChannelDescription, Text, TextSlice};
 use crate::meta::header::Header;
 use crate::image::read::layers::{ReadChannels, ChannelsReader};
 use crate::block::{BlockIndex, UncompressedBlock};
-use crate::block::lines::{collect_uncompressed_block_from_lines, LineIndex};
-use std::io::{Cursor, Read};
-use crate::error::{Result, UnitResult};
+use crate::error::{Error, Result, UnitResult};
 use crate::block::chunk::TileCoordinates;
+use crate::math::RoundingMode;
 use crate::prelude::SmallVec;
 
 
+/// A tree of channel groups. Each group may contain channels, and any number of child groups.
+/// `ChannelGroup` contains the channels of a single group, for example `AnyChannels<FlatSamples>`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChannelGroups<ChannelGroup> {
+
+    /// The channels directly inside this group, like `R` in the group `diffuse.direct`.
+    /// `None` if this group only contains child groups.
+    pub channel_group: Option<ChannelGroup>,
 
+    /// The child groups, by their name, like `direct` in the group `diffuse`.
+    pub children: HashMap<Text, Self>,
+}
 
+/// Specify to read the channels of each layer as a tree of channel groups,
+/// reading the channels of each group with the specified channel reader.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ReadChannelGroups<ReadChannelGroup> {
 
-pub struct ChannelGroups<ChannelGroup> {
-    channel_group: Option<ChannelGroup>,
-    children: HashMap<Text, Self>
+    /// The channel reading specification, which is used for each group separately
+    pub read_channels: ReadChannelGroup,
+}
+
+/// Processes pixel blocks from a file and accumulates them into a tree of channel groups.
+#[derive(Debug, Clone)]
+pub struct ChannelGroupsReader<ChannelGroupReader> {
+    groups: ChannelGroups<usize>, // index into `group_readers`
+    group_readers: Vec<IndexedGroup<ChannelGroupReader>>,
+}
+
+/// A temporary writer for a tree of channel groups.
+#[derive(Debug, Clone)]
+pub struct ChannelGroupsWriter<ChannelGroupWriter> {
+    group_writers: Vec<IndexedGroup<ChannelGroupWriter>>,
 }
 
+/// The reader or writer of a single group, and where the channels of the group are in the layer.
+#[derive(Debug, Clone)]
+struct IndexedGroup<ReaderOrWriter> {
 
-impl<ChannelGroup> ChannelGroups<ChannelGroup>  {
+    /// The header of the layer, containing only the channels of this group, without the group path in their names.
+    header: Header,
 
+    /// For each channel in the group header, the index of the channel in the header of the layer.
+    channel_indices: SmallVec<[usize; 8]>,
 
-    // pub fn visit_groups_mut(&mut self, visitor: impl Fn(&mut Channels)) {
-    // }
+    reader_or_writer: ReaderOrWriter,
+}
 
 
+impl<ChannelGroup> ChannelGroups<ChannelGroup> {
 
-    pub fn groups(&self) -> SmallVec<[&ChannelGroup; 12]> {
-        let children = self.children.iter().flat_map(|group| group.groups());
-        self.channel_group.iter().chain(children).collect()
+    /// A group without any channels or child groups.
+    pub fn empty() -> Self {
+        ChannelGroups { channel_group: None, children: HashMap::new() }
     }
 
-    pub fn lookup_group(&self, group_name: &TextSlice) -> Option<&ChannelGroup> {
-        let dot_index = group_name.iter().position('.');
-        if let Some(dot_index) = dot_index {
-            let group_name = &group_name[.. dot_index];
-            let child_name = &group_name[dot_index + 1 ..];
-            self.children.get(group_name)
-                .and_then(|child| child.lookup(child_name))
-        }
-        else {
-            self.channel_group.lookup(name)
-        }
+    /// A group containing only the specified channels, without any child groups.
+    pub fn new(channel_group: ChannelGroup) -> Self {
+        ChannelGroups { channel_group: Some(channel_group), children: HashMap::new() }
     }
 
+    /// Add the channels of the group with the specified path, like `diffuse.direct`, creating any missing parent groups.
+    /// The empty path refers to this group. Replaces the channels of an existing group with the same path.
+    pub fn with_group(mut self, path: impl Into<Text>, channel_group: ChannelGroup) -> Self {
+        self.insert_group(path.into().as_slice(), channel_group);
+        self
+    }
 
-    /*pub fn insert_group(&mut self, full_name: &TextSlice, value: ChannelGroup) {
-        let dot_index = full_name.iter().position('.');
-        if let Some(dot_index) = dot_index {
-            let group_name = &group_name[.. dot_index];
-            let name_rest = &group_name[dot_index + 1 ..];
+    /// Insert the channels of the group with the specified path, like `diffuse.direct`, creating any missing parent groups.
+    /// The empty path refers to this group. Returns the previous channels of the group, if any.
+    pub fn insert_group(&mut self, path: &TextSlice, channel_group: ChannelGroup) -> Option<ChannelGroup> {
+        if path.is_empty() {
+            return self.channel_group.replace(channel_group);
+        }
 
-            self.children.entry(Text::from_slice_unchecked(group_name))
-                .or_insert(|| );
+        let (group_name, child_path) = split_first_name(path);
 
-            // self.children.insert(Text::from_slice_unchecked(group_name), value)
-            //     .and_then(|child| child.lookup(name_rest));
-        }
-        else {
-            self.channel_group.lookup(name);
-        }
-    }*/
+        self.children.entry(Text::from_slice_unchecked(group_name))
+            .or_insert_with(Self::empty)
+            .insert_group(child_path, channel_group)
+    }
 
-    pub fn map<T>(self, mapper: impl FnMut(ChannelGroup) -> T) -> ChannelGroups<T> {
-        ChannelGroups {
-            children: self.channel_group.iter().map(&mapper).collect(),
-            channel_group: self.channel_group.map(mapper),
-        }
+    /// The channels of the group with the specified path, like `diffuse.direct`.
+    /// The empty path refers to this group.
+    pub fn lookup_group(&self, path: &TextSlice) -> Option<&ChannelGroup> {
+        self.lookup_child(path).and_then(|group| group.channel_group.as_ref())
     }
-}
 
+    /// The channels of the group with the specified path, like `diffuse.direct`.
+    /// The empty path refers to this group.
+    pub fn lookup_group_mut(&mut self, path: &TextSlice) -> Option<&mut ChannelGroup> {
+        self.lookup_child_mut(path).and_then(|group| group.channel_group.as_mut())
+    }
 
-pub fn parse_channel_list_groups<T>(channels: impl Iterator<Item=(Text, T)>)
-    -> ChannelGroups<SmallVec<(Text, T)>>
-{
-    fn insert_into_groups(groups: &mut ChannelGroups<SmallVec<(Text, T)>>, name: Text, value: T) {
-        let dot_index = name.as_slice().iter().position('.');
+    /// The group with the specified path, like `diffuse.direct`, including its child groups.
+    /// The empty path refers to this group.
+    pub fn lookup_child(&self, path: &TextSlice) -> Option<&Self> {
+        if path.is_empty() { return Some(self) }
 
-        if let Some(dot_index) = dot_index {
-            // insert into child group
+        let (group_name, child_path) = split_first_name(path);
+        self.children.get(group_name)?.lookup_child(child_path)
+    }
+
+    /// The group with the specified path, like `diffuse.direct`, including its child groups.
+    /// The empty path refers to this group.
+    pub fn lookup_child_mut(&mut self, path: &TextSlice) -> Option<&mut Self> {
+        if path.is_empty() { return Some(self) }
 
-            let group_name = Text::from_slice_unchecked(&name.as_slice()[.. dot_index]);
-            let child_channel = Text::from_slice_unchecked(&name.as_slice()[dot_index + 1 ..]);
+        let (group_name, child_path) = split_first_name(path);
+        self.children.get_mut(group_name)?.lookup_child_mut(child_path)
+    }
 
-            let child_group = groups.children.entry(group_name)
-                .or_insert(ChannelGroups { channel_group: None, children: Default::default() });
+    /// The channels of all groups in this tree, sorted by the path of the group, like `diffuse.direct`.
+    /// The path of this group is empty.
+    pub fn groups(&self) -> SmallVec<[(Text, &ChannelGroup); 12]> {
+        fn collect_groups<'g, G>(groups: &'g ChannelGroups<G>, path: &TextSlice, result: &mut SmallVec<[(Text, &'g G); 12]>) {
+            if let Some(group) = &groups.channel_group {
+                result.push((Text::from_slice_unchecked(path), group));
+            }
 
-            insert_into_groups(child_group, child_channel, value);
+            for (name, child) in &groups.children {
+                collect_groups(child, child_path(path, name).as_slice(), result);
+            }
         }
 
-        else {
-            // insert directly into group
+        let mut groups = SmallVec::new();
+        collect_groups(self, &[], &mut groups);
+        groups.sort_unstable_by(|(path, _), (other_path, _)| path.cmp(other_path));
+        groups
+    }
 
-            if groups.channel_group.is_none() {
-                groups.channel_group = Some(SmallVec::new());
-            }
+    /// Convert the channels of each group, keeping the structure of the tree.
+    pub fn map<T>(self, mut mapper: impl FnMut(ChannelGroup) -> T) -> ChannelGroups<T> {
+        self.try_map(&mut |group| Ok(mapper(group))).expect("infallible mapping failed")
+    }
 
-            groups.channel_group.unwrap().push(value);
+    /// Convert the channels of each group, keeping the structure of the tree, aborting on the first error.
+    fn try_map<T>(self, mapper: &mut impl FnMut(ChannelGroup) -> Result<T>) -> Result<ChannelGroups<T>> {
+        let channel_group = self.channel_group.map(&mut *mapper).transpose()?;
+
+        let children: Result<HashMap<Text, ChannelGroups<T>>> = self.children.into_iter()
+            .map(|(name, child)| Ok((name, child.try_map(mapper)?)))
+            .collect();
+
+        Ok(ChannelGroups { channel_group, children: children? })
+    }
+}
+
+/// Group the values by the dots in their channel names.
+/// Each value is inserted into its group, together with the name of the channel inside that group.
+/// For example, the channel `diffuse.direct.R` is inserted as `R` into the group `direct` inside the group `diffuse`.
+pub fn parse_channel_list_groups<T>(channels: impl Iterator<Item=(Text, T)>)
+    -> ChannelGroups<SmallVec<[(Text, T); 12]>>
+{
+    fn insert_into_groups<T>(groups: &mut ChannelGroups<SmallVec<[(Text, T); 12]>>, name: &TextSlice, value: T) {
+        match name.iter().position(|&byte| byte == b'.') {
+            Some(dot_index) => {
+                let child_group = groups.children.entry(Text::from_slice_unchecked(&name[.. dot_index]))
+                    .or_insert_with(ChannelGroups::empty);
+
+                insert_into_groups(child_group, &name[dot_index + 1 ..], value);
+            },
+
+            None => groups.channel_group.get_or_insert_with(SmallVec::new)
+                .push((Text::from_slice_unchecked(name), value)),
         }
     }
 
-    let mut result = ChannelGroups { channel_group: None, children: HashMap::default() };
-    for (name, value) in channels { insert_into_groups(&mut result, name, value); }
+    let mut result = ChannelGroups::empty();
+    for (name, value) in channels { insert_into_groups(&mut result, name.as_slice(), value); }
     result
 }
 
+/// Splits the path into the name of the first group and the remaining path, which may be empty.
+fn split_first_name(path: &TextSlice) -> (&TextSlice, &TextSlice) {
+    match path.iter().position(|&byte| byte == b'.') {
+        Some(dot_index) => (&path[.. dot_index], &path[dot_index + 1 ..]),
+        None => (path, &[]),
+    }
+}
+
+/// The path of a child group or channel, which is the name for children of the root group.
+fn child_path(path: &TextSlice, name: &Text) -> Text {
+    if path.is_empty() { name.clone() }
+    else { Text::join_with_dots(&[path, name.as_slice()]) }
+}
+
 
 impl<'slf, ChannelGroup> WritableChannels<'slf> for ChannelGroups<ChannelGroup>
     where ChannelGroup: WritableChannels<'slf>
 {
     fn infer_channel_list(&self) -> ChannelList {
-        // TODO what about empty groups with NO channels??
-
-        let child_channels = self.children.iter().flat_map(|(group_name, child)| {
-            let mut child_channels = child.infer_channel_list().list;
-            for channel in &mut child_channels { channel.name.push_front(group_name) };
-            child_channels
-        });
+        let mut channels: SmallVec<[ChannelDescription; 5]> = self.groups().into_iter()
+            .flat_map(|(path, group)| {
+                group.infer_channel_list().list.into_iter().map(move |channel| ChannelDescription {
+                    name: child_path(path.as_slice(), &channel.name),
+                    .. channel
+                })
+            })
+            .collect();
 
-        let mut own_channels = self.channel_group
-            .map(|chans| chans.infer_channel_list().list)
-            .unwrap_or_default();
+        channels.sort_unstable_by(|channel, other| channel.name.cmp(&other.name));
+        ChannelList::new(channels) // might be empty, but will be checked in MetaData::validate()
+    }
 
-        own_channels.extend(child_channels);
-        own_channels.sort_unstable(); // TODO only once at end
-        ChannelList::new(own_channels) // might be empty, but will be checked in MetaData::validate()
+    fn infer_level_modes(&self) -> (LevelMode, RoundingMode) {
+        // without any groups, the empty channel list will be reported when validating the header.
+        // different level modes of the groups are reported by `validate_channels`
+        self.groups().first().map_or((LevelMode::Singular, RoundingMode::Down), |(_, group)| group.infer_level_modes())
     }
 
-    fn level_mode(&self) -> LevelMode {
-        fn find_mode_or_none(channels: &Self) -> Option<LevelMode> {
-            channels.channel_group.map(WritableChannels::level_mode).or_else(|| {
-                channels.children.iter().map(find_mode_or_none).next()
-            })
-        }
+    fn validate_channels(&self) -> UnitResult {
+        let mode = self.infer_level_modes();
 
-        let mode = find_mode_or_none(self)
-            .expect("empty channel groups (check failed)"); // TODO only happens for empty channels, right? panic maybe?
+        for (_, group) in self.groups() {
+            group.validate_channels()?;
 
-        if let Some(chans) = self.channel_group.as_ref() {
-            debug_assert_eq!(chans.level_mode(), mode, "level mode must be equal for all legacy channel groups")
+            if group.infer_level_modes() != mode {
+                return Err(Error::invalid("level mode must be the same across all channel groups"));
+            }
         }
 
-        debug_assert!(
-            self.children.values()
-                .flat_map(find_mode_or_none)
-                .all(|child_mode| child_mode == mode),
-
-            "level mode must be equal for all legacy channel groups"
-        );
-
-        mode
+        Ok(())
     }
 
-    type Writer = GroupChannelsWriter<'slf, ChannelGroup>;
+    type Writer = ChannelGroupsWriter<ChannelGroup::Writer>;
 
     fn create_writer(&'slf self, header: &Header) -> Self::Writer {
-        let channels = header.channels.list.iter()
-            .map(|channel_info|{
-                // hashmap order is not guaranteed? so look up each channel group manually instead of generating new
-                let channels = self.lookup_group(channel_info.name.as_slice())
-                    .expect("channels not found bug");
-
-                channels.create_writer(header) // channel_info.name.clone()
+        let group_writers = self.groups().into_iter()
+            .map(|(path, group)| {
+                let group_header = Header { channels: group.infer_channel_list(), .. header.clone() };
+
+                let channel_indices = group_header.channels.list.iter()
+                    .map(|channel| {
+                        header.channels.find_index_of_channel(&child_path(path.as_slice(), &channel.name))
+                            .expect("channel group not found in header bug")
+                    })
+                    .collect();
+
+                IndexedGroup { reader_or_writer: group.create_writer(&group_header), header: group_header, channel_indices }
             })
             .collect();
 
-        GroupChannelsWriter { channels_list: channels }
+        ChannelGroupsWriter { group_writers }
     }
 }
 
-struct GroupChannelsWriter<'c, ChannelGroupWriter> {
-    channels_list: Vec<&'c ChannelGroupWriter>,
-}
-
-impl<'c, Channels> ChannelsWriter for GroupChannelsWriter<'c, Channels> where Channels: ChannelsWriter {
-    fn extract_uncompressed_block(&self, header: &Header, block: BlockIndex) -> Vec<u8> {
-        let mut blocks_per_channel: Vec<Cursor<Vec<u8>>> = self
-            .channels_list.iter()
-            .map(|channels| Cursor::new(channels.extract_uncompressed_block(header, block)))
+impl<ChannelGroupWriter> ChannelsWriter for ChannelGroupsWriter<ChannelGroupWriter> where ChannelGroupWriter: ChannelsWriter {
+    fn extract_uncompressed_block(&self, header: &Header, block_index: BlockIndex) -> Vec<u8> {
+        let group_blocks: Vec<UncompressedBlock> = self.group_writers.iter()
+            .map(|group| UncompressedBlock {
+                index: block_index,
+                data: group.reader_or_writer.extract_uncompressed_block(&group.header, block_index)
+            })
             .collect();
 
-        UncompressedBlock::uncompressed_block_from_lines(header, block, |line|{
-            let channel_reader = &mut blocks_per_channel[line.location.channel]; // TODO subsampling
+        // split the blocks of all groups into lines only once, and then collect the lines by their channel index in the layer
+        let mut channel_lines: Vec<Vec<&[u8]>> = vec![Vec::new(); header.channels.list.len()];
+        for (group, group_block) in self.group_writers.iter().zip(&group_blocks) {
+            for line in group_block.lines(&group.header.channels) {
+                channel_lines[group.channel_indices[line.location.channel]].push(line.value);
+            }
+        }
+
+        // the lines of each channel are in the same order in the block of the layer
+        let mut lines: SmallVec<[std::slice::Iter<'_, &[u8]>; 8]> = channel_lines.iter()
+            .map(|lines| lines.iter())
+            .collect();
 
-            // read from specific channel into total byte block
-            // this assumes that the lines in the callback are iterated in strictly increasing order
-            // because each channel reader is consumed
-            channel_reader.read_exact(line.value)
-                .expect("collecting grouped channel byte block failed");
+        UncompressedBlock::collect_block_data_from_lines(&header.channels, block_index, |line| {
+            let group_line = lines[line.location.channel].next().expect("channel group line bug");
+            line.value.copy_from_slice(group_line);
         })
     }
 }
 
 
-struct ReadChannelGroups<ReadChannelGroup> {
-    read_channels: ReadChannelGroup
-}
-
-struct ChannelGroupsReader<ChannelGroupReader> {
-    channels: ChannelGroups<usize>,
-    indexed_channels: Vec<ChannelGroupReader>,
-}
-
 impl<'s, ReadChannelGroup> ReadChannels<'s> for ReadChannelGroups<ReadChannelGroup>
     where ReadChannelGroup: ReadChannels<'s>
 {
     type Reader = ChannelGroupsReader<ReadChannelGroup::Reader>;
 
     fn create_channels_reader(&'s self, header: &Header) -> Result<Self::Reader> {
-        let swap = |(a,b)| (b,a);
+        if header.deep {
+            return Err(Error::unsupported("channel groups of deep data"));
+        }
+
         let channel_groups = parse_channel_list_groups(
-            header.channels.list.iter().enumerate().map(swap)
+            header.channels.list.iter().enumerate()
+                .map(|(index, channel)| (channel.name.clone(), index))
         );
 
-        let mut indexed_channels = Vec::new();
-        let channel_groups = channel_groups.map(|channels| {
+        let mut group_readers = Vec::new();
+        let groups = channel_groups.try_map(&mut |channels| {
+            let group_header = Header {
+                channels: ChannelList::new(channels.iter()
+                    .map(|(name, index)| ChannelDescription { name: name.clone(), .. header.channels.list[*index].clone() })
+                    .collect()),
 
-            let mut channels_header = header.clone(); // TODO no clone?
-            channels_header.channels = ChannelList::new(channels.iter().map(|(name, index)|{
-                let mut channel_info = header.channels.list[index].clone();
-                channel_info.name = name;
-                channel_info
-            }).collect()); // FIXME does not comply to `header.chunk_count` and that stuff?? change ReadChannels fn signature?
+                .. header.clone()
+            };
 
-            indexed_channels.push(self.read_channels.create_channels_reader(&channels_header));
+            group_readers.push(IndexedGroup {
+                reader_or_writer: self.read_channels.create_channels_reader(&group_header)?,
+                channel_indices: channels.iter().map(|&(_, index)| index).collect(),
+                header: group_header,
+            });
 
-            // FIXME this is not the original order indexed_channels.len() - 1
-            indexed_channels[]
-        });
+            Ok(group_readers.len() - 1)
+        })?;
 
-        Ok(ChannelGroupsReader {
-            channels: channel_groups,
-            indexed_channels,
-        })
-
-        /*Ok(ChannelGroupsReader {
-            channels: header.channels.list.iter().map(|channel| {
-                let mut channels_header = header.clone();
-
-                let reader = self.read_channels.create_channels_reader(&channels_header);
-                (channels_header, reader)
-            }).collect(),
-        })*/
+        Ok(ChannelGroupsReader { groups, group_readers })
     }
 }
 
 impl<ChannelGroupReader> ChannelsReader for ChannelGroupsReader<ChannelGroupReader> where ChannelGroupReader: ChannelsReader {
     type Channels = ChannelGroups<ChannelGroupReader::Channels>;
 
-    fn filter_block(&self, tile: (usize, &TileCoordinates)) -> bool {
-        self.indexed_channels.iter().any(|channel| channel.filter_block(tile))
+    fn filter_block(&self, tile: TileCoordinates) -> bool {
+        self.group_readers.iter().any(|group| group.reader_or_writer.filter_block(tile))
     }
 
     fn read_block(&mut self, header: &Header, block: UncompressedBlock) -> UnitResult {
-        block.for_lines(|line|{
+        let tile = TileCoordinates {
+            tile_index: block.index.pixel_position / header.max_block_pixel_size(),
+            level_index: block.index.level,
+        };
+
+        // split the block into lines only once, and then dispatch the lines to the groups by their channel index
+        let mut channel_lines: Vec<Vec<&[u8]>> = vec![Vec::new(); header.channels.list.len()];
+        for line in block.lines(&header.channels) {
+            channel_lines[line.location.channel].push(line.value);
+        }
 
-        })
+        for group in &mut self.group_readers {
+            if !group.reader_or_writer.filter_block(tile) { continue }
+
+            // the lines of each channel are in the same order in the block of the group
+            let mut group_lines: SmallVec<[std::slice::Iter<'_, &[u8]>; 8]> = group.channel_indices.iter()
+                .map(|&index| channel_lines[index].iter())
+                .collect();
+
+            let group_block = UncompressedBlock::from_lines(&group.header.channels, block.index, |line| {
+                let group_line = group_lines[line.location.channel].next().expect("channel group line bug");
+                line.value.copy_from_slice(group_line);
+            });
+
+            group.reader_or_writer.read_block(&group.header, group_block)?;
+        }
+
+        Ok(())
     }
 
     fn into_channels(self) -> Self::Channels {
+        let mut group_readers: Vec<Option<IndexedGroup<ChannelGroupReader>>> =
+            self.group_readers.into_iter().map(Some).collect();
 
+        self.groups.map(|index| {
+            group_readers[index].take().expect("channel group index bug")
+                .reader_or_writer.into_channels()
+        })
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_and_lookup_groups(){
+        let names = ["A", "diffuse.direct.B", "diffuse.direct.R", "diffuse.indirect.R", "specular.R"];
+        let groups = parse_channel_list_groups(names.iter().enumerate().map(|(index, name)| (Text::from(*name), index)));
+
+        let indices = |path: &str| groups.lookup_group(path.as_bytes())
+            .map(|channels| channels.iter().map(|(name, index)| (name.to_string(), *index)).collect::<Vec<_>>());
+
+        assert_eq!(indices(""), Some(vec![ ("A".to_string(), 0) ]));
+        assert_eq!(indices("diffuse"), None);
+        assert_eq!(indices("diffuse.direct"), Some(vec![ ("B".to_string(), 1), ("R".to_string(), 2) ]));
+        assert_eq!(indices("diffuse.indirect"), Some(vec![ ("R".to_string(), 3) ]));
+        assert_eq!(indices("specular"), Some(vec![ ("R".to_string(), 4) ]));
+        assert_eq!(indices("specular.R"), None);
+
+        assert!(groups.lookup_child(b"diffuse").is_some());
+        assert_eq!(groups.lookup_child(b"diffuse").unwrap().children.len(), 2);
+
+        let paths: Vec<String> = groups.groups().iter().map(|(path, _)| path.to_string()).collect();
+        assert_eq!(paths, [ "", "diffuse.direct", "diffuse.indirect", "specular" ]);
+    }
+
+    #[test]
+    fn insert_and_map_groups(){
+        let mut groups = ChannelGroups::new(0)
+            .with_group("diffuse.direct", 1)
+            .with_group("diffuse", 2);
+
+        assert_eq!(groups.insert_group(b"diffuse.direct", 3), Some(1));
+        assert_eq!(groups.insert_group(b"specular", 4), None);
+
+        *groups.lookup_group_mut(b"specular").unwrap() += 1;
+
+        let groups = groups.map(|value| value * 10);
+        let values: Vec<(String, i32)> = groups.groups().into_iter().map(|(path, &value)| (path.to_string(), value)).collect();
+
+        assert_eq!(values, [
+            ("".to_string(), 0), ("diffuse".to_string(), 20),
+            ("diffuse.direct".to_string(), 30), ("specular".to_string(), 50)
+        ]);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn empty_groups_level_mode(){
+        use crate::image::{AnyChannels, FlatSamples};
+
+        let groups: ChannelGroups<AnyChannels<FlatSamples>> = ChannelGroups::empty();
+        assert_eq!(groups.infer_level_modes(), (LevelMode::Singular, RoundingMode::Down));
+        assert!(groups.infer_channel_list().list.is_empty());
+        groups.validate_channels().unwrap();
+    }
+
+    #[test]
+    fn reject_different_level_modes(){
+        use crate::image::{AnyChannels, AnyChannel, FlatSamples, Levels};
+
+        let channels = |levels: Levels<FlatSamples>| AnyChannels::sort(smallvec::smallvec![ AnyChannel::new("R", levels) ]);
+        let singular = channels(Levels::Singular(FlatSamples::F32(vec![0.0; 4])));
+        let mip = channels(Levels::Mip {
+            rounding_mode: RoundingMode::Down,
+            level_data: vec![ FlatSamples::F32(vec![0.0; 4]), FlatSamples::F32(vec![0.0; 1]) ],
+        });
+
+        let same = ChannelGroups::new(singular.clone()).with_group("diffuse", singular.clone());
+        same.validate_channels().unwrap();
+
+        let different = ChannelGroups::new(singular).with_group("diffuse", mip);
+        assert!(different.validate_channels().is_err());
+    }
+}
@@ -7,6 +7,7 @@ use crate::image::{Layer, FlatSamples, SpecificChannels, AnyChannels, FlatSample
 use crate::image::write::channels::{GetPixel, WritableChannels, ChannelsWriter};
 use crate::meta::header::{LayerAttributes, Header};
 use crate::block::BlockIndex;
+use crate::error::UnitResult;
 
 /// Something that has a two-dimensional rectangular shape
 pub trait GetBounds {
@@ -170,6 +171,10 @@ impl<'slf, Channels:'slf> WritableChannels<'slf> for CroppedChannels<Channels> w
         self.full_channels.infer_level_modes()
     }
 
+    fn validate_channels(&self) -> UnitResult {
+        self.full_channels.validate_channels()
+    }
+
     type Writer = CroppedWriter<Channels::Writer>;
 
     fn create_writer(&'slf self, header: &Header) -> Self::Writer {
@@ -0,0 +1,848 @@
+//! Operations on deep data.
+//! Composite the samples of each deep pixel into a single flat value,
+//! for example to display a deep image in software that only supports flat images.
+//! Merge multiple deep layers into a single layer, and tidy up overlapping samples.
+//!
+//! The samples are interpreted as described in the official "Interpreting OpenEXR Deep Pixels" document:
+//! Color channels are premultiplied with alpha, `Z` is the front of each sample,
+//! and the optional `ZBack` channel is the back of volumetric samples.
+
+use crate::image::*;
+use crate::error::{Error, Result};
+use std::cmp::Ordering;
+
+
+/// Specifies how the samples of each deep pixel are composited into a single flat value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct FlattenOptions {
+
+    /// Split volumetric samples, which extend from `Z` to `ZBack`,
+    /// where they overlap other samples, and merge the coincident parts before compositing.
+    /// This is more accurate for overlapping volumes, but slower.
+    /// Has no effect if the layer does not contain a `ZBack` channel.
+    pub split_volumes: bool,
+}
+
+
+impl AnyChannels<DeepSamples> {
+
+    /// Composite the samples of each pixel front to back, producing one flat sample per pixel.
+    /// The samples are sorted by `Z` and `ZBack`, and then composited using the `A` channel.
+    /// Requires a `Z` channel. Without an `A` channel, all samples are considered opaque.
+    ///
+    /// All channels except `Z`, `ZBack` and `u32` channels are composited.
+    /// `Z`, `ZBack` and `u32` channels contain the value of the front-most sample.
+    /// Pixels without any samples are transparent black with a depth of zero.
+    pub fn flatten(&self, options: FlattenOptions) -> Result<AnyChannels<FlatSamples>> {
+        let channels: SmallVec<[(&Text, &DeepSamples); 8]> = self.list.iter()
+            .map(|channel| (&channel.name, &channel.sample_data))
+            .collect();
+
+        let flat_samples = flatten_channels(&channels, options)?;
+
+        Ok(AnyChannels {
+            list: self.list.iter().zip(flat_samples)
+                .map(|(channel, sample_data)| AnyChannel {
+                    name: channel.name.clone(),
+                    quantize_linearly: channel.quantize_linearly,
+                    sampling: channel.sampling,
+                    sample_data,
+                })
+                .collect()
+        })
+    }
+}
+
+impl AnyChannels<DeepAndFlatSamples> {
+
+    /// Composite the samples of each pixel front to back, producing one flat sample per pixel.
+    /// If the channels are already flat, they are cloned.
+    /// See `AnyChannels<DeepSamples>::flatten` for details.
+    pub fn flatten(&self, options: FlattenOptions) -> Result<AnyChannels<FlatSamples>> {
+        let deep_channels: SmallVec<[(&Text, &DeepSamples); 8]> = self.list.iter()
+            .filter_map(|channel| match &channel.sample_data {
+                DeepAndFlatSamples::Deep(samples) => Some((&channel.name, samples)),
+                DeepAndFlatSamples::Flat(_) => None,
+            })
+            .collect();
+
+        let flat_samples: Vec<FlatSamples> =
+            if deep_channels.is_empty() {
+                self.list.iter().map(|channel| match &channel.sample_data {
+                    DeepAndFlatSamples::Flat(samples) => samples.clone(),
+                    DeepAndFlatSamples::Deep(_) => unreachable!(),
+                }).collect()
+            }
+
+            else if deep_channels.len() == self.list.len() {
+                flatten_channels(&deep_channels, options)?
+            }
+
+            else {
+                return Err(Error::invalid("layer contains both deep and flat channels"))
+            };
+
+        Ok(AnyChannels {
+            list: self.list.iter().zip(flat_samples)
+                .map(|(channel, sample_data)| AnyChannel {
+                    name: channel.name.clone(),
+                    quantize_linearly: channel.quantize_linearly,
+                    sampling: channel.sampling,
+                    sample_data,
+                })
+                .collect()
+        })
+    }
+}
+
+impl Layer<AnyChannels<DeepAndFlatSamples>> {
+
+    /// Composite the samples of each pixel front to back, producing a flat layer.
+    /// Flat layers are cloned. See `AnyChannels<DeepSamples>::flatten` for details.
+    pub fn flatten(&self, options: FlattenOptions) -> Result<Layer<AnyChannels<FlatSamples>>> {
+        let mut attributes = self.attributes.clone();
+        attributes.deep_image_state = None;
+
+        Ok(Layer {
+            channel_data: self.channel_data.flatten(options)?,
+            attributes,
+            size: self.size,
+            encoding: self.encoding,
+        })
+    }
+}
+
+impl Image<Layers<AnyChannels<DeepAndFlatSamples>>> {
+
+    /// Composite the samples of each pixel in each deep layer front to back, producing a flat image.
+    /// Flat layers are cloned. See `AnyChannels<DeepSamples>::flatten` for details.
+    pub fn flatten(&self, options: FlattenOptions) -> Result<FlatImage> {
+        Ok(Image {
+            attributes: self.attributes.clone(),
+            layer_data: self.layer_data.iter()
+                .map(|layer| layer.flatten(options))
+                .collect::<Result<Layers<_>>>()?,
+        })
+    }
+}
+
+
+/// A channel sample storage that can contain deep samples.
+/// Enables merging and tidying deep layers.
+pub trait DeepSampleStorage: Sized {
+
+    /// Returns the deep samples, or `None` if these samples are flat.
+    fn as_deep_samples(&self) -> Option<&DeepSamples>;
+
+    /// Wrap the deep samples into this storage.
+    fn from_deep_samples(samples: DeepSamples) -> Self;
+}
+
+impl DeepSampleStorage for DeepSamples {
+    fn as_deep_samples(&self) -> Option<&DeepSamples> { Some(self) }
+    fn from_deep_samples(samples: DeepSamples) -> Self { samples }
+}
+
+impl DeepSampleStorage for DeepAndFlatSamples {
+    fn as_deep_samples(&self) -> Option<&DeepSamples> {
+        match self {
+            DeepAndFlatSamples::Deep(samples) => Some(samples),
+            DeepAndFlatSamples::Flat(_) => None,
+        }
+    }
+
+    fn from_deep_samples(samples: DeepSamples) -> Self { DeepAndFlatSamples::Deep(samples) }
+}
+
+impl<Samples: DeepSampleStorage> AnyChannels<Samples> {
+
+    /// Sort the samples of each pixel by depth, split overlapping volumetric samples,
+    /// and combine the coincident sections into a single sample.
+    /// Afterwards, the samples of each pixel are sorted and do not overlap.
+    /// Requires a `Z` channel. Returns an error if any channel is flat.
+    pub fn tidy(&self) -> Result<Self> {
+        let channels = deep_channel_refs(self)?;
+        let roles = ChannelRoles::find(&channels)?;
+
+        let samples = process_pixels(&channels, &roles, |samples| {
+            sort_samples(samples, &roles);
+            split_volumes(samples, &roles);
+            sort_samples(samples, &roles);
+            merge_coincident_samples(samples, &roles);
+        });
+
+        Ok(AnyChannels {
+            list: self.list.iter().zip(samples)
+                .map(|(channel, samples)| AnyChannel {
+                    name: channel.name.clone(),
+                    quantize_linearly: channel.quantize_linearly,
+                    sampling: channel.sampling,
+                    sample_data: Samples::from_deep_samples(samples),
+                })
+                .collect()
+        })
+    }
+
+    /// Combine the samples of multiple lists of deep channels with the same number of pixels.
+    /// The samples of each pixel are interleaved and then sorted by depth.
+    /// The result contains all channels of all lists.
+    /// If a list does not contain a channel, its samples contain zero in that channel,
+    /// except for the `A` channel, which contains one, because samples without alpha are opaque.
+    /// Requires a `Z` channel in each list. Returns an error if any channel is flat.
+    pub fn merge(channel_lists: &[&Self]) -> Result<Self> {
+        let lists = channel_lists.iter()
+            .map(|&list| deep_channel_refs(list))
+            .collect::<Result<SmallVec<[_; 4]>>>()?;
+
+        let first_list = lists.first().ok_or(Error::invalid("no deep channels to merge"))?;
+        let pixel_count = first_list.first().ok_or(Error::invalid("no deep channels to merge"))?.1.pixel_count();
+
+        let mut merged_channels: SmallVec<[&AnyChannel<Samples>; 8]> = SmallVec::new();
+        for (list, deep_list) in channel_lists.iter().zip(&lists) {
+            ChannelRoles::find(deep_list)?;
+
+            if deep_list[0].1.pixel_count() != pixel_count {
+                return Err(Error::invalid("merged deep channels have different sizes"));
+            }
+
+            for channel in &list.list {
+                if !merged_channels.iter().any(|merged| merged.name == channel.name) {
+                    merged_channels.push(channel);
+                }
+            }
+        }
+
+        merged_channels.sort_unstable_by_key(|channel| channel.name.clone()); // TODO no clone?
+
+        // the samples of each pixel in a merged list, in the order of the merged lists
+        let mut sample_offsets = Vec::with_capacity(pixel_count + 1);
+        sample_offsets.push(0);
+
+        for pixel_index in 0 .. pixel_count {
+            let pixel_sample_count: usize = lists.iter().map(|list| list[0].1.pixel_sample_count(pixel_index)).sum();
+            sample_offsets.push(sample_offsets[pixel_index] + pixel_sample_count);
+        }
+
+        let total_sample_count = *sample_offsets.last().expect("sample offsets bug");
+
+        let interleaved = merged_channels.iter().map(|merged_channel| {
+            let sample_type = merged_channel.sample_data.as_deep_samples().expect("deep channel bug").values.sample_type();
+
+            let sources: SmallVec<[Option<&DeepSamples>; 4]> = lists.iter()
+                .map(|list| list.iter().find(|(name, _)| **name == merged_channel.name).map(|&(_, samples)| samples))
+                .collect();
+
+            if sources.iter().flatten().any(|samples| samples.values.sample_type() != sample_type) {
+                return Err(Error::invalid("merged deep channels have different sample types"));
+            }
+
+            // samples without alpha are opaque, as in `flatten`
+            let is_alpha = merged_channel.name.eq("A");
+
+            fn interleave<T: Copy>(
+                pixel_count: usize, total_sample_count: usize, lists: &[SmallVec<[(&Text, &DeepSamples); 8]>],
+                sources: &[Option<&DeepSamples>], missing_value: T, source_values: impl Fn(&DeepSamples) -> &[T]
+            ) -> Vec<T> {
+                let mut values = Vec::with_capacity(total_sample_count);
+
+                for pixel_index in 0 .. pixel_count {
+                    for (list, source) in lists.iter().zip(sources) {
+                        match source {
+                            Some(source) => values.extend_from_slice(&source_values(source)[source.pixel_sample_range(pixel_index)]),
+                            None => values.resize(values.len() + list[0].1.pixel_sample_count(pixel_index), missing_value),
+                        }
+                    }
+                }
+
+                values
+            }
+
+            let values = match sample_type {
+                SampleType::F16 => FlatSamples::F16(interleave(pixel_count, total_sample_count, &lists, &sources, if is_alpha { f16::ONE } else { f16::ZERO }, |samples| match &samples.values {
+                    FlatSamples::F16(values) => values.as_slice(), _ => unreachable!(),
+                })),
+
+                SampleType::F32 => FlatSamples::F32(interleave(pixel_count, total_sample_count, &lists, &sources, if is_alpha { 1.0 } else { 0.0 }, |samples| match &samples.values {
+                    FlatSamples::F32(values) => values.as_slice(), _ => unreachable!(),
+                })),
+
+                SampleType::U32 => FlatSamples::U32(interleave(pixel_count, total_sample_count, &lists, &sources, if is_alpha { 1 } else { 0 }, |samples| match &samples.values {
+                    FlatSamples::U32(values) => values.as_slice(), _ => unreachable!(),
+                })),
+            };
+
+            Ok(DeepSamples { sample_offsets: sample_offsets.clone(), values })
+        }).collect::<Result<Vec<DeepSamples>>>()?;
+
+        let interleaved_refs: SmallVec<[(&Text, &DeepSamples); 8]> = merged_channels.iter()
+            .map(|channel| &channel.name).zip(&interleaved)
+            .collect();
+
+        let roles = ChannelRoles::find(&interleaved_refs)?;
+        let sorted = process_pixels(&interleaved_refs, &roles, |samples| sort_samples(samples, &roles));
+
+        Ok(AnyChannels {
+            list: merged_channels.iter().zip(sorted)
+                .map(|(channel, samples)| AnyChannel {
+                    name: channel.name.clone(),
+                    quantize_linearly: channel.quantize_linearly,
+                    sampling: channel.sampling,
+                    sample_data: Samples::from_deep_samples(samples),
+                })
+                .collect()
+        })
+    }
+}
+
+impl<Samples: DeepSampleStorage> Layer<AnyChannels<Samples>> {
+
+    /// Sort the samples of each pixel by depth, split overlapping volumetric samples,
+    /// and combine the coincident sections into a single sample.
+    /// Marks the layer as tidy in `attributes.deep_image_state`.
+    /// See `AnyChannels::tidy` for details.
+    pub fn tidy(&self) -> Result<Self> {
+        let mut attributes = self.attributes.clone();
+        attributes.deep_image_state = Some(DeepImageState::Tidy);
+
+        Ok(Layer {
+            channel_data: self.channel_data.tidy()?,
+            attributes,
+            size: self.size,
+            encoding: self.encoding,
+        })
+    }
+
+    /// Combine the samples of multiple deep layers with the same data window into a single layer.
+    /// The samples of each pixel are interleaved and then sorted by depth.
+    /// The attributes and encoding of the first layer are used,
+    /// and the layer is marked as sorted in `attributes.deep_image_state`.
+    /// Call `tidy` afterwards to also split overlapping samples.
+    /// See `AnyChannels::merge` for details.
+    pub fn merge(layers: &[&Self]) -> Result<Self> {
+        let first_layer = layers.first().ok_or(Error::invalid("no deep layers to merge"))?;
+
+        if layers.iter().any(|layer| layer.absolute_bounds() != first_layer.absolute_bounds()) {
+            return Err(Error::invalid("merged deep layers must have the same data window"));
+        }
+
+        let channel_lists: SmallVec<[&AnyChannels<Samples>; 4]> = layers.iter()
+            .map(|layer| &layer.channel_data)
+            .collect();
+
+        let mut attributes = first_layer.attributes.clone();
+        attributes.deep_image_state = Some(DeepImageState::Sorted);
+
+        Ok(Layer {
+            channel_data: AnyChannels::merge(&channel_lists)?,
+            attributes,
+            size: first_layer.size,
+            encoding: first_layer.encoding,
+        })
+    }
+}
+
+/// Reference all deep samples and the names of the channels.
+/// Returns an error if any channel is flat.
+fn deep_channel_refs<Samples: DeepSampleStorage>(channels: &AnyChannels<Samples>) -> Result<SmallVec<[(&Text, &DeepSamples); 8]>> {
+    channels.list.iter()
+        .map(|channel| {
+            let samples = channel.sample_data.as_deep_samples()
+                .ok_or(Error::invalid("flat channel in deep layer"))?;
+
+            Ok((&channel.name, samples))
+        })
+        .collect()
+}
+
+/// Rebuild the samples of all deep channels, processing the samples of each pixel with the specified closure.
+/// The values of `u32` channels are copied from the original sample and never converted to `f32`.
+fn process_pixels(
+    channels: &[(&Text, &DeepSamples)], roles: &ChannelRoles,
+    mut process_pixel: impl FnMut(&mut Vec<PixelSample>)
+) -> Vec<DeepSamples>
+{
+    let pixel_count = channels[roles.depth].1.pixel_count();
+
+    let mut sample_offsets = Vec::with_capacity(pixel_count + 1);
+    sample_offsets.push(0);
+
+    let mut channel_values: Vec<FlatSamples> = channels.iter()
+        .map(|&(_, samples)| match samples.values {
+            FlatSamples::F16(_) => FlatSamples::F16(Vec::with_capacity(samples.total_sample_count())),
+            FlatSamples::F32(_) => FlatSamples::F32(Vec::with_capacity(samples.total_sample_count())),
+            FlatSamples::U32(_) => FlatSamples::U32(Vec::with_capacity(samples.total_sample_count())),
+        })
+        .collect();
+
+    let mut samples = Vec::new();
+
+    for pixel_index in 0 .. pixel_count {
+        collect_pixel_samples(channels, pixel_index, &mut samples);
+        process_pixel(&mut samples);
+
+        for (channel_index, (values, &(_, source))) in channel_values.iter_mut().zip(channels).enumerate() {
+            match (values, &source.values) {
+                (FlatSamples::F16(values), _) => values.extend(samples.iter().map(|sample| f16::from_f32(sample.values[channel_index]))),
+                (FlatSamples::F32(values), _) => values.extend(samples.iter().map(|sample| sample.values[channel_index])),
+                (FlatSamples::U32(values), FlatSamples::U32(ids)) => values.extend(samples.iter().map(|sample| ids[sample.source_index])),
+                _ => unreachable!("sample type mismatch bug"),
+            }
+        }
+
+        sample_offsets.push(sample_offsets[pixel_index] + samples.len());
+    }
+
+    channel_values.into_iter()
+        .map(|values| DeepSamples { sample_offsets: sample_offsets.clone(), values })
+        .collect()
+}
+
+
+/// Composite all pixels of the deep channels, returning one list of flat samples per channel.
+fn flatten_channels(channels: &[(&Text, &DeepSamples)], options: FlattenOptions) -> Result<Vec<FlatSamples>> {
+    let roles = ChannelRoles::find(channels)?;
+    let pixel_count = channels[roles.depth].1.pixel_count();
+
+    let mut flat_values: Vec<Vec<f32>> = vec![Vec::with_capacity(pixel_count); channels.len()];
+    let mut front_sample_indices = Vec::with_capacity(pixel_count);
+
+    let mut samples = Vec::new();
+    let mut flat_pixel = vec![0.0; channels.len()];
+
+    for pixel_index in 0 .. pixel_count {
+        collect_pixel_samples(channels, pixel_index, &mut samples);
+        sort_samples(&mut samples, &roles);
+
+        if options.split_volumes {
+            split_volumes(&mut samples, &roles);
+            sort_samples(&mut samples, &roles);
+            merge_coincident_samples(&mut samples, &roles);
+        }
+
+        composite_samples(&samples, &roles, &mut flat_pixel);
+        front_sample_indices.push(samples.first().map(|sample| sample.source_index));
+
+        for (values, &value) in flat_values.iter_mut().zip(&flat_pixel) {
+            values.push(value);
+        }
+    }
+
+    Ok(channels.iter().zip(flat_values).map(|(&(_, deep_samples), values)| {
+        match &deep_samples.values {
+            FlatSamples::F16(_) => FlatSamples::F16(values.into_iter().map(f16::from_f32).collect()),
+            FlatSamples::F32(_) => FlatSamples::F32(values),
+
+            // ids cannot be composited or converted to f32 without loss
+            FlatSamples::U32(ids) => FlatSamples::U32(
+                front_sample_indices.iter()
+                    .map(|index| index.map_or(0, |index| ids[index]))
+                    .collect()
+            ),
+        }
+    }).collect())
+}
+
+
+/// Describes which of the deep channels contain depth and alpha.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ChannelRoles {
+
+    /// The index of the `Z` channel.
+    depth: usize,
+
+    /// The index of the `ZBack` channel, if any.
+    depth_back: Option<usize>,
+
+    /// The index of the `A` channel, if any.
+    alpha: Option<usize>,
+
+    /// For each channel, whether it is composited
+    /// or whether it contains the value of the front-most sample.
+    composited: SmallVec<[bool; 8]>,
+}
+
+impl ChannelRoles {
+
+    /// Find the depth and alpha channels, and check that all channels contain the same number of samples.
+    fn find(channels: &[(&Text, &DeepSamples)]) -> Result<Self> {
+        let find_channel = |name: &str| channels.iter().position(|&(channel_name, _)| channel_name.eq(name));
+
+        let depth = find_channel("Z").ok_or(Error::invalid("deep layer without a `Z` channel"))?;
+        let depth_back = find_channel("ZBack");
+        let alpha = find_channel("A");
+
+        let sample_offsets = &channels[depth].1.sample_offsets;
+        if channels.iter().any(|&(_, samples)| &samples.sample_offsets != sample_offsets) {
+            return Err(Error::invalid("deep channels with different sample counts"));
+        }
+
+        let composited = channels.iter().enumerate()
+            .map(|(index, &(_, samples))| {
+                let is_depth = index == depth || Some(index) == depth_back;
+                let is_id = matches!(samples.values, FlatSamples::U32(_));
+                !is_depth && !is_id
+            })
+            .collect();
+
+        Ok(ChannelRoles { depth, depth_back, alpha, composited })
+    }
+
+    /// The front of the sample.
+    fn front(&self, sample: &PixelSample) -> f32 {
+        sample.values[self.depth]
+    }
+
+    /// The back of the sample. Equal to the front for point samples.
+    fn back(&self, sample: &PixelSample) -> f32 {
+        let front = self.front(sample);
+        self.depth_back.map_or(front, |back| sample.values[back].max(front))
+    }
+
+    /// The alpha of the sample, clamped to the range from zero to one.
+    fn alpha(&self, sample: &PixelSample) -> f32 {
+        self.alpha.map_or(1.0, |alpha| sample.values[alpha].max(0.0).min(1.0))
+    }
+
+    /// The indices of all channels that are composited.
+    fn composited_channels(&self) -> impl '_ + Iterator<Item=usize> {
+        self.composited.iter().enumerate()
+            .filter(|&(_, &composited)| composited)
+            .map(|(index, _)| index)
+    }
+}
+
+
+/// A single sample of a deep pixel, with the values of all channels converted to `f32`.
+#[derive(Debug, Clone, PartialEq)]
+struct PixelSample {
+
+    /// The index of the original sample in the deep samples.
+    /// Used to look up the original values of channels that are not composited.
+    source_index: usize,
+
+    /// One value for each channel.
+    values: SmallVec<[f32; 8]>,
+}
+
+/// Replace the contents of the vector with all samples of the specified pixel.
+fn collect_pixel_samples(channels: &[(&Text, &DeepSamples)], pixel_index: usize, samples: &mut Vec<PixelSample>) {
+    samples.clear();
+
+    let sample_range = channels[0].1.pixel_sample_range(pixel_index);
+    samples.extend(sample_range.map(|source_index| PixelSample {
+        values: channels.iter().map(|&(_, channel)| channel.values.value_by_flat_index(source_index).to_f32()).collect(),
+        source_index,
+    }));
+}
+
+/// Sort the samples by front and then by back, keeping the order of equal samples.
+fn sort_samples(samples: &mut [PixelSample], roles: &ChannelRoles) {
+    samples.sort_by(|a, b| {
+        let front = roles.front(a).partial_cmp(&roles.front(b)).unwrap_or(Ordering::Equal);
+        front.then_with(|| roles.back(a).partial_cmp(&roles.back(b)).unwrap_or(Ordering::Equal))
+    });
+}
+
+/// Split volumetric samples at the front and back of all other samples,
+/// such that overlapping samples are split into coincident sections.
+fn split_volumes(samples: &mut Vec<PixelSample>, roles: &ChannelRoles) {
+    let depth_back = match roles.depth_back {
+        Some(depth_back) => depth_back,
+        None => return,
+    };
+
+    let mut boundaries: Vec<f32> = Vec::with_capacity(samples.len() * 2);
+    for sample in samples.iter() {
+        boundaries.push(roles.front(sample));
+        boundaries.push(roles.back(sample));
+    }
+
+    boundaries.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+    boundaries.dedup();
+
+    let mut sections = Vec::with_capacity(samples.len());
+    for sample in samples.drain(..) {
+        let (front, back) = (roles.front(&sample), roles.back(&sample));
+
+        if !(back > front) {
+            sections.push(sample);
+            continue;
+        }
+
+        let inner_boundaries = boundaries.iter().copied()
+            .filter(|&boundary| boundary > front && boundary < back);
+
+        let mut section_front = front;
+        for section_back in inner_boundaries.chain(std::iter::once(back)) {
+            let mut section = sample.clone();
+            let fraction = (section_back - section_front) / (back - front);
+            let alpha = roles.alpha(&sample);
+
+            // the alpha of a section of a homogeneous volume is `1 - (1 - alpha) ^ fraction`
+            let section_alpha = if alpha < 1.0 { -((-alpha).ln_1p() * fraction).exp_m1() } else { 1.0 };
+            let scale = if alpha > 0.0 { section_alpha / alpha } else { fraction };
+
+            for channel in roles.composited_channels() {
+                section.values[channel] *= scale;
+            }
+
+            section.values[roles.depth] = section_front;
+            section.values[depth_back] = section_back;
+            sections.push(section);
+
+            section_front = section_back;
+        }
+    }
+
+    *samples = sections;
+}
+
+/// Merge neighbouring samples with the same front and back into a single sample.
+/// The samples must be sorted.
+fn merge_coincident_samples(samples: &mut Vec<PixelSample>, roles: &ChannelRoles) {
+    let mut merged = Vec::with_capacity(samples.len());
+    let mut start = 0;
+
+    while start < samples.len() {
+        let interval = (roles.front(&samples[start]), roles.back(&samples[start]));
+
+        let end = start + samples[start ..].iter()
+            .take_while(|sample| (roles.front(sample), roles.back(sample)) == interval)
+            .count();
+
+        merged.push(merge_samples(&samples[start .. end], roles));
+        start = end;
+    }
+
+    *samples = merged;
+}
+
+/// Combine multiple samples with the same front and back into a single sample.
+fn merge_samples(samples: &[PixelSample], roles: &ChannelRoles) -> PixelSample {
+    let mut merged = samples[0].clone();
+    if samples.len() == 1 { return merged; }
+
+    let opaque_count = samples.iter().filter(|sample| roles.alpha(sample) >= 1.0).count();
+
+    if opaque_count > 0 {
+        // the opaque samples hide all other samples, so use their average
+        for channel in roles.composited_channels() {
+            let sum: f32 = samples.iter()
+                .filter(|sample| roles.alpha(sample) >= 1.0)
+                .map(|sample| sample.values[channel])
+                .sum();
+
+            merged.values[channel] = sum / opaque_count as f32;
+        }
+    }
+
+    else {
+        // add the optical densities of all samples
+        let densities: SmallVec<[f32; 8]> = samples.iter()
+            .map(|sample| -(-roles.alpha(sample)).ln_1p())
+            .collect();
+
+        let total_density: f32 = densities.iter().sum();
+        let merged_alpha = -(-total_density).exp_m1();
+
+        for channel in roles.composited_channels() {
+            let weighted_sum: f32 = samples.iter().zip(&densities)
+                .map(|(sample, &density)| {
+                    let alpha = roles.alpha(sample);
+                    if alpha > 0.0 { sample.values[channel] * density / alpha }
+                    else { sample.values[channel] }
+                })
+                .sum();
+
+            merged.values[channel] =
+                if total_density > 0.0 { weighted_sum * merged_alpha / total_density }
+                else { weighted_sum };
+        }
+    }
+
+    merged
+}
+
+/// Composite the sorted samples front to back, writing one value per channel into the flat pixel.
+fn composite_samples(samples: &[PixelSample], roles: &ChannelRoles, flat_pixel: &mut [f32]) {
+    for value in flat_pixel.iter_mut() { *value = 0.0; }
+
+    if let Some(front) = samples.first() {
+        for (channel, _) in roles.composited.iter().enumerate().filter(|&(_, &composited)| !composited) {
+            flat_pixel[channel] = front.values[channel];
+        }
+    }
+
+    let mut alpha = 0.0;
+    for sample in samples {
+        if alpha >= 1.0 { break; }
+
+        let transmission = 1.0 - alpha;
+        for channel in roles.composited_channels() {
+            flat_pixel[channel] += transmission * sample.values[channel];
+        }
+
+        alpha = match roles.alpha {
+            Some(alpha_channel) => flat_pixel[alpha_channel],
+            None => 1.0,
+        };
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn deep_channels(sample_offsets: Vec<usize>, channels: Vec<(&str, Vec<f32>)>) -> AnyChannels<DeepSamples> {
+        AnyChannels::sort(channels.into_iter()
+            .map(|(name, values)| AnyChannel::new(name, DeepSamples {
+                sample_offsets: sample_offsets.clone(),
+                values: FlatSamples::F32(values)
+            }))
+            .collect())
+    }
+
+    fn flat_values(channels: &AnyChannels<FlatSamples>, name: &str) -> Vec<f32> {
+        channels.list.iter().find(|channel| channel.name.eq(name)).unwrap()
+            .sample_data.values_as_f32().collect()
+    }
+
+    #[test]
+    fn composite_sorted_by_depth(){
+        // the second pixel contains a transparent red sample behind an opaque green sample
+        let channels = deep_channels(vec![0, 0, 2, 3], vec![
+            ("Z", vec![5.0, 1.0, 2.0]),
+            ("A", vec![0.5, 1.0, 0.5]),
+            ("R", vec![0.5, 0.0, 0.25]),
+            ("G", vec![0.0, 1.0, 0.0]),
+        ]);
+
+        let flat = channels.flatten(FlattenOptions::default()).unwrap();
+        assert_eq!(flat_values(&flat, "Z"), vec![0.0, 1.0, 2.0]);
+        assert_eq!(flat_values(&flat, "A"), vec![0.0, 1.0, 0.5]);
+        assert_eq!(flat_values(&flat, "R"), vec![0.0, 0.0, 0.25]);
+        assert_eq!(flat_values(&flat, "G"), vec![0.0, 1.0, 0.0]);
+    }
+
+    #[test]
+    fn composite_transparent_samples(){
+        let channels = deep_channels(vec![0, 2], vec![
+            ("Z", vec![2.0, 1.0]),
+            ("A", vec![0.5, 0.5]),
+            ("R", vec![0.5, 0.25]),
+        ]);
+
+        let flat = channels.flatten(FlattenOptions::default()).unwrap();
+        assert_eq!(flat_values(&flat, "A"), vec![0.75]);
+        assert_eq!(flat_values(&flat, "R"), vec![0.25 + 0.5 * 0.5]);
+    }
+
+    #[test]
+    fn split_overlapping_volumes(){
+        // two identical volumes that overlap in the middle
+        let channels = deep_channels(vec![0, 2], vec![
+            ("Z", vec![0.0, 1.0]),
+            ("ZBack", vec![2.0, 3.0]),
+            ("A", vec![0.75, 0.75]),
+            ("R", vec![0.75, 0.75]),
+        ]);
+
+        let flat = channels.flatten(FlattenOptions { split_volumes: true }).unwrap();
+        let unsplit = channels.flatten(FlattenOptions { split_volumes: false }).unwrap();
+
+        // the total alpha does not depend on the order of the sections
+        let expected_alpha = 1.0 - 0.25 * 0.25;
+        assert!((flat_values(&flat, "A")[0] - expected_alpha).abs() < 0.0001);
+        assert!((flat_values(&unsplit, "A")[0] - expected_alpha).abs() < 0.0001);
+
+        // the color of unlit volumes equals their alpha
+        assert!((flat_values(&flat, "R")[0] - expected_alpha).abs() < 0.0001);
+        assert_eq!(flat_values(&flat, "Z"), vec![0.0]);
+    }
+
+    fn deep_values(channels: &AnyChannels<DeepSamples>, name: &str) -> (Vec<usize>, Vec<f32>) {
+        let samples = &channels.list.iter().find(|channel| channel.name.eq(name)).unwrap().sample_data;
+        (samples.sample_offsets.clone(), samples.values.values_as_f32().collect())
+    }
+
+    #[test]
+    fn tidy_overlapping_volumes(){
+        let mut channels = deep_channels(vec![0, 1, 3], vec![
+            ("Z", vec![4.0, 2.0, 1.0]),
+            ("ZBack", vec![4.0, 3.0, 2.0]),
+            ("A", vec![1.0, 0.75, 0.0]),
+        ]);
+
+        channels.list.push(AnyChannel::new("id", DeepSamples {
+            sample_offsets: vec![0, 1, 3], values: FlatSamples::U32(vec![7, u32::MAX, 9])
+        }));
+
+        // the samples of the second pixel touch, but do not overlap
+        let tidy = channels.tidy().unwrap();
+        assert_eq!(deep_values(&tidy, "Z"), (vec![0, 1, 3], vec![4.0, 1.0, 2.0]));
+        assert_eq!(deep_values(&tidy, "ZBack"), (vec![0, 1, 3], vec![4.0, 2.0, 3.0]));
+
+        let ids = &tidy.list.iter().find(|channel| channel.name.eq("id")).unwrap().sample_data.values;
+        assert_eq!(ids, &FlatSamples::U32(vec![7, 9, u32::MAX]));
+
+        // the volume is split at the front of the point sample inside it
+        let overlapping = deep_channels(vec![0, 2], vec![
+            ("Z", vec![0.0, 1.0]),
+            ("ZBack", vec![2.0, 1.0]),
+            ("A", vec![0.75, 1.0]),
+        ]);
+
+        let tidy = overlapping.tidy().unwrap();
+        assert_eq!(deep_values(&tidy, "Z"), (vec![0, 3], vec![0.0, 1.0, 1.0]));
+        assert_eq!(deep_values(&tidy, "ZBack"), (vec![0, 3], vec![1.0, 1.0, 2.0]));
+        assert_eq!(deep_values(&tidy, "A"), (vec![0, 3], vec![0.5, 1.0, 0.5]));
+    }
+
+    #[test]
+    fn merge_channel_lists(){
+        let first = deep_channels(vec![0, 1, 1], vec![
+            ("Z", vec![3.0]),
+            ("A", vec![0.5]),
+        ]);
+
+        let second = deep_channels(vec![0, 2, 3], vec![
+            ("Z", vec![4.0, 1.0, 2.0]),
+            ("R", vec![0.5, 0.25, 1.0]),
+        ]);
+
+        let merged = AnyChannels::merge(&[&first, &second]).unwrap();
+        let names: Vec<String> = merged.list.iter().map(|channel| channel.name.to_string()).collect();
+        assert_eq!(names, vec!["A", "R", "Z"]);
+
+        assert_eq!(deep_values(&merged, "Z"), (vec![0, 3, 4], vec![1.0, 3.0, 4.0, 2.0]));
+        assert_eq!(deep_values(&merged, "A"), (vec![0, 3, 4], vec![1.0, 0.5, 1.0, 1.0]));
+        assert_eq!(deep_values(&merged, "R"), (vec![0, 3, 4], vec![0.25, 0.0, 0.5, 1.0]));
+
+        let too_small = deep_channels(vec![0, 1], vec![ ("Z", vec![3.0]) ]);
+        assert!(AnyChannels::merge(&[&first, &too_small]).is_err());
+    }
+
+    #[test]
+    fn merge_layers(){
+        let layer = |position: Vec2<i32>| Layer::new(
+            Vec2(2, 1),
+            LayerAttributes { layer_position: position, .. LayerAttributes::named("deep") },
+            Encoding::FAST_LOSSLESS,
+            deep_channels(vec![0, 1, 1], vec![ ("Z", vec![1.0]) ])
+        );
+
+        let merged = Layer::merge(&[&layer(Vec2(0, 0)), &layer(Vec2(0, 0))]).unwrap();
+        assert_eq!(merged.attributes.deep_image_state, Some(DeepImageState::Sorted));
+        assert_eq!(merged.channel_data.list[0].sample_data.sample_offsets, vec![0, 2, 2]);
+        assert_eq!(merged.tidy().unwrap().attributes.deep_image_state, Some(DeepImageState::Tidy));
+
+        assert!(Layer::merge(&[&layer(Vec2(0, 0)), &layer(Vec2(1, 0))]).is_err());
+    }
+
+    #[test]
+    fn flatten_without_depth(){
+        let channels = deep_channels(vec![0, 1], vec![ ("A", vec![1.0]) ]);
+        assert!(channels.flatten(FlattenOptions::default()).is_err());
+    }
+}
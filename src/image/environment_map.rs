@@ -0,0 +1,382 @@
+//! Convert environment maps between the latitude-longitude layout and the cube layout,
+//! as `exrenvmap` does, and map directions to pixel positions.
+//!
+//! Follows the conventions of the original OpenEXR library (`ImfEnvmap`):
+//! The y axis points up. A latitude-longitude map contains the top at the first row and
+//! the direction `(0, 0, 1)` in the center. A cube map contains six square faces stacked vertically,
+//! in the order `+X`, `-X`, `+Y`, `-Y`, `+Z`, `-Z`.
+//! All pixel positions are relative to the data window, and the center of the first pixel is at zero.
+
+use crate::image::*;
+use crate::image::texture::TextureFilter;
+use crate::error::{Error, Result};
+use std::f32::consts::PI;
+
+
+/// A direction in 3D space. Does not need to be normalized.
+pub type Direction = [f32; 3];
+
+/// One of the six faces of a cube map, in the order in which they are stored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CubeFace {
+
+    /// The face in positive x direction.
+    PositiveX,
+
+    /// The face in negative x direction.
+    NegativeX,
+
+    /// The face in positive y direction, at the top.
+    PositiveY,
+
+    /// The face in negative y direction, at the bottom.
+    NegativeY,
+
+    /// The face in positive z direction.
+    PositiveZ,
+
+    /// The face in negative z direction.
+    NegativeZ,
+}
+
+/// Specifies how an environment map is converted to a different layout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EnvironmentMapOptions {
+
+    /// How the pixels of the original map are interpolated.
+    /// `Trilinear` behaves like `Bilinear`, as only the largest resolution level is used.
+    pub filter: TextureFilter,
+
+    /// The number of samples along each axis of a resulting pixel.
+    /// Use more samples when the resulting map is smaller than the original map, to avoid aliasing.
+    pub supersampling: usize,
+}
+
+impl Default for EnvironmentMapOptions {
+    fn default() -> Self {
+        EnvironmentMapOptions { filter: TextureFilter::Bilinear, supersampling: 2 }
+    }
+}
+
+impl CubeFace {
+
+    /// All faces, in the order in which they are stored.
+    pub const ALL: [CubeFace; 6] = [
+        CubeFace::PositiveX, CubeFace::NegativeX,
+        CubeFace::PositiveY, CubeFace::NegativeY,
+        CubeFace::PositiveZ, CubeFace::NegativeZ,
+    ];
+
+    /// The index of this face in the cube map, from zero to five.
+    pub fn index(self) -> usize {
+        CubeFace::ALL.iter().position(|&face| face == self).expect("cube face bug")
+    }
+}
+
+
+/// The direction of a pixel position in a latitude-longitude map of the specified size.
+pub fn lat_long_direction(size: Vec2<usize>, pixel_position: Vec2<f32>) -> Direction {
+    let latitude =
+        if size.height() > 1 { -PI * (pixel_position.y() / (size.height() - 1) as f32 - 0.5) }
+        else { 0.0 };
+
+    let longitude =
+        if size.width() > 1 { -2.0 * PI * (pixel_position.x() / (size.width() - 1) as f32 - 0.5) }
+        else { 0.0 };
+
+    [ longitude.sin() * latitude.cos(), latitude.sin(), longitude.cos() * latitude.cos() ]
+}
+
+/// The pixel position of a direction in a latitude-longitude map of the specified size.
+pub fn lat_long_pixel_position(size: Vec2<usize>, direction: Direction) -> Vec2<f32> {
+    let [x, y, z] = direction;
+    let horizontal_length = (x * x + z * z).sqrt();
+    let length = (x * x + y * y + z * z).sqrt();
+
+    let latitude =
+        if length == 0.0 { 0.0 }
+        else if horizontal_length < y.abs() { (horizontal_length / length).acos() * y.signum() }
+        else { (y / length).asin() };
+
+    let longitude = if x == 0.0 && z == 0.0 { 0.0 } else { x.atan2(z) };
+
+    Vec2(
+        (longitude / (-2.0 * PI) + 0.5) * size.width().saturating_sub(1) as f32,
+        (latitude / -PI + 0.5) * size.height().saturating_sub(1) as f32,
+    )
+}
+
+/// The width and height of a single face of a cube map of the specified size.
+pub fn cube_face_size(size: Vec2<usize>) -> usize {
+    size.width().min(size.height() / 6)
+}
+
+/// The direction of a pixel position inside a face of a cube map of the specified size.
+pub fn cube_direction(face: CubeFace, size: Vec2<usize>, position_in_face: Vec2<f32>) -> Direction {
+    let face_size = cube_face_size(size);
+
+    let (a, b) =
+        if face_size > 1 {(
+            position_in_face.x() / (face_size - 1) as f32 * 2.0 - 1.0,
+            position_in_face.y() / (face_size - 1) as f32 * 2.0 - 1.0,
+        )}
+        else { (0.0, 0.0) };
+
+    match face {
+        CubeFace::PositiveX => [ 1.0, a, b ],
+        CubeFace::NegativeX => [ -1.0, a, b ],
+        CubeFace::PositiveY => [ a, 1.0, b ],
+        CubeFace::NegativeY => [ a, -1.0, b ],
+        CubeFace::PositiveZ => [ a, b, 1.0 ],
+        CubeFace::NegativeZ => [ a, b, -1.0 ],
+    }
+}
+
+/// The face that contains the direction, and the position of the direction inside that face,
+/// in a cube map of the specified size.
+pub fn cube_face_and_position(size: Vec2<usize>, direction: Direction) -> (CubeFace, Vec2<f32>) {
+    let [x, y, z] = direction;
+    let (abs_x, abs_y, abs_z) = (x.abs(), y.abs(), z.abs());
+    let max_position = cube_face_size(size).saturating_sub(1) as f32;
+    let position = |a: f32, b: f32, length: f32| Vec2((a / length + 1.0) / 2.0 * max_position, (b / length + 1.0) / 2.0 * max_position);
+
+    if abs_x >= abs_y && abs_x >= abs_z {
+        if abs_x == 0.0 { return (CubeFace::PositiveX, Vec2(0.0, 0.0)); }
+        (if x > 0.0 { CubeFace::PositiveX } else { CubeFace::NegativeX }, position(y, z, abs_x))
+    }
+    else if abs_y >= abs_z {
+        (if y > 0.0 { CubeFace::PositiveY } else { CubeFace::NegativeY }, position(x, z, abs_y))
+    }
+    else {
+        (if z > 0.0 { CubeFace::PositiveZ } else { CubeFace::NegativeZ }, position(x, y, abs_z))
+    }
+}
+
+/// The pixel position in a cube map of the specified size, given a position inside one of its faces.
+pub fn cube_pixel_position(face: CubeFace, size: Vec2<usize>, position_in_face: Vec2<f32>) -> Vec2<f32> {
+    let face_size = cube_face_size(size) as f32;
+    let (min_x, min_y) = (0.0, face.index() as f32 * face_size);
+    let (max_x, max_y) = (min_x + face_size - 1.0, min_y + face_size - 1.0);
+    let (a, b) = (position_in_face.x(), position_in_face.y());
+
+    match face {
+        CubeFace::PositiveX => Vec2(min_x + b, max_y - a),
+        CubeFace::NegativeX => Vec2(max_x - b, max_y - a),
+        CubeFace::PositiveY => Vec2(min_x + a, max_y - b),
+        CubeFace::NegativeY => Vec2(min_x + a, min_y + b),
+        CubeFace::PositiveZ => Vec2(max_x - a, max_y - b),
+        CubeFace::NegativeZ => Vec2(min_x + a, max_y - b),
+    }
+}
+
+
+impl Layer<AnyChannels<FlatSamples>> {
+
+    /// Convert this environment map to a cube map, with the specified width and height of each face.
+    /// The `environment_map` attribute of this layer defines the original layout.
+    /// The resulting layer has the `environment_map` attribute set to `Cube`, and its data window starts at zero.
+    ///
+    /// Returns an error if this layer has no `environment_map` attribute or contains subsampled channels.
+    pub fn to_cube_map(&self, face_size: usize, options: EnvironmentMapOptions) -> Result<Self> {
+        let size = Vec2(face_size, face_size * 6);
+
+        self.convert_environment_map(EnvironmentMap::Cube, size, options, |position: Vec2<f32>| {
+            let face = CubeFace::ALL[((position.y() / face_size as f32) as usize).min(5)];
+            let face_start = face.index() as f32 * face_size as f32;
+
+            // invert `cube_pixel_position` for this face, which maps the pixel corner to the face corner
+            let position = Vec2(position.x() - 0.5, position.y() - face_start - 0.5);
+            let max = face_size as f32 - 1.0;
+
+            let position_in_face = match face {
+                CubeFace::PositiveX => Vec2(max - position.y(), position.x()),
+                CubeFace::NegativeX => Vec2(max - position.y(), max - position.x()),
+                CubeFace::PositiveY => Vec2(position.x(), max - position.y()),
+                CubeFace::NegativeY => Vec2(position.x(), position.y()),
+                CubeFace::PositiveZ => Vec2(max - position.x(), max - position.y()),
+                CubeFace::NegativeZ => Vec2(position.x(), max - position.y()),
+            };
+
+            cube_direction(face, size, position_in_face)
+        })
+    }
+
+    /// Convert this environment map to a latitude-longitude map with the specified size.
+    /// The `environment_map` attribute of this layer defines the original layout.
+    /// The resulting layer has the `environment_map` attribute set to `LatitudeLongitude`, and its data window starts at zero.
+    ///
+    /// Returns an error if this layer has no `environment_map` attribute or contains subsampled channels.
+    pub fn to_lat_long_map(&self, size: Vec2<usize>, options: EnvironmentMapOptions) -> Result<Self> {
+        self.convert_environment_map(EnvironmentMap::LatitudeLongitude, size, options, |position: Vec2<f32>| {
+            lat_long_direction(size, position - Vec2(0.5, 0.5))
+        })
+    }
+
+    /// For each pixel in the new layout, sample the directions within the pixel area.
+    /// The direction function receives a continuous position, where the first pixel covers the area from zero to one.
+    fn convert_environment_map(
+        &self, layout: EnvironmentMap, size: Vec2<usize>, options: EnvironmentMapOptions,
+        direction: impl Fn(Vec2<f32>) -> Direction
+    ) -> Result<Self>
+    {
+        let original_layout = self.attributes.environment_map.ok_or(Error::invalid("environment map attribute"))?;
+
+        if self.channel_data.list.iter().any(|channel| channel.sampling != Vec2(1, 1)) {
+            return Err(Error::unsupported("environment maps with subsampled channels"));
+        }
+
+        let supersampling = options.supersampling.max(1);
+        let subsample_offset = |index: usize| (index as f32 + 0.5) / supersampling as f32;
+
+        // the directions of all subsamples of all pixels, computed once for all channels
+        let directions: Vec<Direction> = (0 .. size.area())
+            .flat_map(|index| {
+                let pixel = Vec2((index % size.width()) as f32, (index / size.width()) as f32);
+                (0 .. supersampling * supersampling).map(move |sub| (pixel, sub))
+            })
+            .map(|(pixel, sub)| direction(pixel + Vec2(subsample_offset(sub % supersampling), subsample_offset(sub / supersampling))))
+            .collect();
+
+        let list = self.channel_data.list.iter().map(|channel| {
+            let values: Vec<f32> = channel.sample_data.values_as_f32().collect();
+
+            let converted: Vec<f32> = directions.chunks_exact(supersampling * supersampling)
+                .map(|pixel_directions| {
+                    let sum: f32 = pixel_directions.iter()
+                        .map(|&direction| lookup(&values, self.size, original_layout, direction, options.filter))
+                        .sum();
+
+                    sum / pixel_directions.len() as f32
+                })
+                .collect();
+
+            AnyChannel {
+                name: channel.name.clone(),
+                quantize_linearly: channel.quantize_linearly,
+                sampling: channel.sampling,
+                sample_data: match channel.sample_data {
+                    FlatSamples::F16(_) => FlatSamples::F16(converted.into_iter().map(f16::from_f32).collect()),
+                    FlatSamples::F32(_) => FlatSamples::F32(converted),
+                    FlatSamples::U32(_) => FlatSamples::U32(converted.into_iter().map(|value| value.round() as u32).collect()),
+                },
+            }
+        }).collect();
+
+        Ok(Layer {
+            channel_data: AnyChannels { list },
+            attributes: LayerAttributes {
+                environment_map: Some(layout),
+                layer_position: Vec2(0, 0),
+                .. self.attributes.clone()
+            },
+            size,
+            encoding: self.encoding,
+        })
+    }
+}
+
+/// Look up the value of a direction in an environment map.
+fn lookup(values: &[f32], size: Vec2<usize>, layout: EnvironmentMap, direction: Direction, filter: TextureFilter) -> f32 {
+    match layout {
+        EnvironmentMap::LatitudeLongitude => {
+            let position = lat_long_pixel_position(size, direction);
+
+            filter_pixels(values, position, filter, |x, y| {
+                let x = x.max(0).min(size.width() as i64 - 1) as usize;
+                let y = y.max(0).min(size.height() as i64 - 1) as usize;
+                y * size.width() + x
+            })
+        },
+
+        EnvironmentMap::Cube => {
+            let (face, position_in_face) = cube_face_and_position(size, direction);
+            let max = cube_face_size(size) as i64 - 1;
+
+            filter_pixels(values, position_in_face, filter, |x, y| {
+                let position_in_face = Vec2(x.max(0).min(max) as f32, y.max(0).min(max) as f32);
+                let position = cube_pixel_position(face, size, position_in_face);
+                position.y() as usize * size.width() + position.x() as usize
+            })
+        },
+    }
+}
+
+/// Sample the values around a pixel position.
+/// The pixel index function returns the index of an integer position, clamped to the map or to the cube face.
+fn filter_pixels(values: &[f32], position: Vec2<f32>, filter: TextureFilter, pixel_index: impl Fn(i64, i64) -> usize) -> f32 {
+    match filter {
+        TextureFilter::Nearest => values[pixel_index(position.x().round() as i64, position.y().round() as i64)],
+
+        TextureFilter::Bilinear | TextureFilter::Trilinear => {
+            let (left, top) = (position.x().floor(), position.y().floor());
+            let (tx, ty) = (position.x() - left, position.y() - top);
+            let (left, top) = (left as i64, top as i64);
+
+            let value = |x: i64, y: i64| values[pixel_index(x, y)];
+            let upper = value(left, top) * (1.0 - tx) + value(left + 1, top) * tx;
+            let lower = value(left, top + 1) * (1.0 - tx) + value(left + 1, top + 1) * tx;
+            upper * (1.0 - ty) + lower * ty
+        },
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn assert_same_direction(a: Direction, b: Direction) {
+        let length = |[x, y, z]: Direction| (x * x + y * y + z * z).sqrt();
+        let (la, lb) = (length(a), length(b));
+        for axis in 0 .. 3 { assert!((a[axis] / la - b[axis] / lb).abs() < 0.001, "{:?} != {:?}", a, b); }
+    }
+
+    #[test]
+    fn lat_long_conventions() {
+        let size = Vec2(65, 33);
+        assert_same_direction(lat_long_direction(size, Vec2(32.0, 16.0)), [0.0, 0.0, 1.0]);
+        assert_same_direction(lat_long_direction(size, Vec2(10.0, 0.0)), [0.0, 1.0, 0.0]);
+        assert_same_direction(lat_long_direction(size, Vec2(16.0, 16.0)), [1.0, 0.0, 0.0]);
+
+        let position = Vec2(20.0, 7.0);
+        let roundtrip = lat_long_pixel_position(size, lat_long_direction(size, position));
+        assert!((roundtrip - position).x().abs() < 0.001 && (roundtrip - position).y().abs() < 0.001);
+    }
+
+    #[test]
+    fn cube_conventions() {
+        let size = Vec2(16, 96);
+        assert_eq!(cube_face_size(size), 16);
+
+        for &face in &CubeFace::ALL {
+            let position = Vec2(3.0, 11.0);
+            let (found_face, found_position) = cube_face_and_position(size, cube_direction(face, size, position));
+            assert_eq!(found_face, face);
+            assert!((found_position - position).x().abs() < 0.001 && (found_position - position).y().abs() < 0.001);
+
+            let pixel = cube_pixel_position(face, size, position);
+            assert_eq!((pixel.y() / 16.0) as usize, face.index());
+        }
+    }
+
+    #[test]
+    fn convert_constant_map() {
+        let layer = Layer::new(
+            Vec2(32, 16),
+            LayerAttributes { environment_map: Some(EnvironmentMap::LatitudeLongitude), .. LayerAttributes::default() },
+            Encoding::default(),
+            AnyChannels::sort(smallvec![ AnyChannel::new("Y", FlatSamples::F32(vec![ 0.25; 32 * 16 ])) ])
+        );
+
+        let cube = layer.to_cube_map(8, EnvironmentMapOptions::default()).unwrap();
+        assert_eq!(cube.size, Vec2(8, 48));
+        assert_eq!(cube.attributes.environment_map, Some(EnvironmentMap::Cube));
+        assert!(cube.channel_data.list[0].sample_data.values_as_f32().all(|value| (value - 0.25).abs() < 0.0001));
+
+        let lat_long = cube.to_lat_long_map(Vec2(20, 10), EnvironmentMapOptions::default()).unwrap();
+        assert_eq!(lat_long.attributes.environment_map, Some(EnvironmentMap::LatitudeLongitude));
+        assert!(lat_long.channel_data.list[0].sample_data.values_as_f32().all(|value| (value - 0.25).abs() < 0.0001));
+
+        assert!(Layer { attributes: LayerAttributes::default(), .. layer }.to_cube_map(8, EnvironmentMapOptions::default()).is_err());
+    }
+}
@@ -0,0 +1,350 @@
+//! Convert between rgb channels and luminance/chroma channels (`Y`, `RY`, `BY`),
+//! as the `RgbaInputFile` and `RgbaOutputFile` of the original OpenEXR library do.
+//!
+//! Luminance/chroma images store the luminance `Y` at full resolution,
+//! and the chroma `RY = (R - Y) / Y` and `BY = (B - Y) / Y` with half the resolution in both dimensions.
+//! The luminance weights of the red, green and blue primaries are defined by the chromaticities of the image.
+//! Chroma is decimated and reconstructed using the same low pass filters as the original library.
+
+use crate::image::*;
+use crate::meta::attribute::{Chromaticities, ChannelList, SampleType};
+use crate::error::{Error, Result, UnitResult};
+
+
+/// The sampling rate of the chroma channels written by `to_luminance_chroma`.
+pub const CHROMA_SAMPLING: Vec2<usize> = Vec2(2, 2);
+
+/// Weights of the samples left and right of the removed samples when decimating chroma,
+/// from the nearest to the farthest neighbour. The sample itself has the weight `DECIMATION_CENTER_WEIGHT`.
+const DECIMATION_WEIGHTS: [f32; 7] = [ 0.313659, -0.093067, 0.043978, -0.021586, 0.009801, -0.003771, 0.001064 ];
+const DECIMATION_CENTER_WEIGHT: f32 = 0.499846;
+
+/// Weights of the neighbouring samples of a missing sample when reconstructing chroma,
+/// from the nearest to the farthest neighbour.
+const RECONSTRUCTION_WEIGHTS: [f32; 7] = [ 0.627123, -0.186077, 0.087929, -0.043159, 0.019597, -0.007540, 0.002128 ];
+
+
+/// Computes how much the red, green and blue primaries contribute to the luminance `Y`.
+/// The weights sum up to one.
+pub fn luminance_weights(chromaticities: Chromaticities) -> [f32; 3] {
+    let Chromaticities { red, green, blue, white } = chromaticities;
+
+    // the xyz coordinates of the white point, with a luminance of one
+    let white_x = white.x() / white.y();
+    let white_z = (1.0 - white.x() - white.y()) / white.y();
+    let white_xz = white_x + white_z;
+
+    // the scale of each primary, such that all primaries add up to the white point,
+    // computed without dividing by the y coordinate of a primary, which may be zero
+    let denominator = red.x() * (blue.y() - green.y()) + blue.x() * (green.y() - red.y()) + green.x() * (red.y() - blue.y());
+
+    let red_scale = (
+        white_x * (blue.y() - green.y())
+            - green.x() * ((blue.y() - 1.0) + blue.y() * white_xz)
+            + blue.x() * ((green.y() - 1.0) + green.y() * white_xz)
+    ) / denominator;
+
+    let green_scale = (
+        white_x * (red.y() - blue.y())
+            + red.x() * ((blue.y() - 1.0) + blue.y() * white_xz)
+            - blue.x() * ((red.y() - 1.0) + red.y() * white_xz)
+    ) / denominator;
+
+    let blue_scale = (
+        white_x * (green.y() - red.y())
+            - red.x() * ((green.y() - 1.0) + green.y() * white_xz)
+            + green.x() * ((red.y() - 1.0) + red.y() * white_xz)
+    ) / denominator;
+
+    let weights = [ red_scale * red.y(), green_scale * green.y(), blue_scale * blue.y() ];
+    let sum: f32 = weights.iter().sum();
+    [ weights[0] / sum, weights[1] / sum, weights[2] / sum ]
+}
+
+/// Convert a red, green and blue value to luminance `Y` and chroma `RY` and `BY`, in the order `[Y, RY, BY]`.
+/// The chroma is zero where the luminance is zero.
+pub fn rgb_to_luminance_chroma(rgb: [f32; 3], weights: [f32; 3]) -> [f32; 3] {
+    let [red, green, blue] = rgb;
+    let luminance = weights[0] * red + weights[1] * green + weights[2] * blue;
+
+    let chroma = |value: f32| {
+        if (value - luminance).abs() < f16::MAX.to_f32() * luminance { (value - luminance) / luminance }
+        else { 0.0 }
+    };
+
+    [ luminance, chroma(red), chroma(blue) ]
+}
+
+/// Convert luminance `Y` and chroma `RY` and `BY` to a red, green and blue value, in the order `[R, G, B]`.
+pub fn luminance_chroma_to_rgb(luminance_chroma: [f32; 3], weights: [f32; 3]) -> [f32; 3] {
+    let [luminance, red_chroma, blue_chroma] = luminance_chroma;
+    if red_chroma == 0.0 && blue_chroma == 0.0 { return [ luminance; 3 ] }
+
+    let red = (red_chroma + 1.0) * luminance;
+    let blue = (blue_chroma + 1.0) * luminance;
+    let green = (luminance - red * weights[0] - blue * weights[2]) / weights[1];
+    [ red, green, blue ]
+}
+
+/// Whether these channels contain luminance and chroma, but no rgb channels.
+pub fn is_luminance_chroma(channels: &ChannelList) -> bool {
+    let contains = |name: &str| channels.list.iter().any(|channel| channel.name.eq(name));
+    contains("Y") && contains("RY") && contains("BY") && !(contains("R") || contains("G") || contains("B"))
+}
+
+/// Whether luminance with this sampling rate can be converted to rgb channels.
+/// Only the chroma may be subsampled.
+pub(crate) fn validate_luminance_sampling(sampling: Vec2<usize>) -> UnitResult {
+    if sampling != Vec2(1, 1) { Err(Error::unsupported("subsampled luminance")) }
+    else { Ok(()) }
+}
+
+/// The channels that result from converting these luminance and chroma channels to rgb channels.
+pub(crate) fn rgb_channel_list(channels: &ChannelList) -> ChannelList {
+    let luminance_chroma = ["Y", "RY", "BY"];
+
+    let luminance_type = channels.list.iter().find(|channel| channel.name.eq("Y")).map(|channel| channel.sample_type);
+    let rgb_type = if luminance_type == Some(SampleType::F16) { SampleType::F16 } else { SampleType::F32 };
+
+    let mut list: SmallVec<[ChannelDescription; 5]> = channels.list.iter()
+        .filter(|channel| luminance_chroma.iter().all(|&name| !channel.name.eq(name)))
+        .cloned()
+        .chain(["R", "G", "B"].iter().map(|&name| ChannelDescription::new(name, rgb_type, false)))
+        .collect();
+
+    list.sort_unstable_by_key(|channel| channel.name.clone());
+    ChannelList::new(list)
+}
+
+impl Layer<AnyChannels<FlatSamples>> {
+
+    /// Convert the `R`, `G` and `B` channels of this layer to `Y`, `RY` and `BY` channels,
+    /// which can be compressed much better, especially using `B44` compression.
+    /// The luminance has full resolution, whereas the chroma is decimated to half the resolution in both dimensions.
+    /// All other channels, for example alpha, are not modified. The new channels contain `f16` samples.
+    /// Pass the chromaticities of the image, or `Chromaticities::default()`.
+    ///
+    /// Returns an error if this layer has no rgb channels, contains subsampled channels,
+    /// or has an odd data window position or size.
+    pub fn to_luminance_chroma(&self, chromaticities: Chromaticities) -> Result<Self> {
+        let position = self.attributes.layer_position;
+        if position.x() % 2 != 0 || position.y() % 2 != 0 || self.size.width() % 2 != 0 || self.size.height() % 2 != 0 {
+            return Err(Error::invalid("luminance chroma conversion requires an even data window position and size"));
+        }
+
+        Ok(Layer { channel_data: self.channel_data.rgb_to_luminance_chroma(self.size, chromaticities)?, .. self.clone() })
+    }
+
+    /// Convert the `Y`, `RY` and `BY` channels of this layer to `R`, `G` and `B` channels,
+    /// reconstructing the full resolution of subsampled chroma.
+    /// All other channels, for example alpha, are not modified.
+    /// The new channels contain `f16` samples if the luminance channel does, and `f32` samples otherwise.
+    /// Pass the chromaticities of the image, or `Chromaticities::default()`.
+    ///
+    /// Returns an error if this layer has no luminance and chroma channels.
+    pub fn to_rgb(&self, chromaticities: Chromaticities) -> Result<Self> {
+        Ok(Layer { channel_data: self.channel_data.luminance_chroma_to_rgb(self.size, chromaticities)?, .. self.clone() })
+    }
+}
+
+impl AnyChannels<FlatSamples> {
+
+    /// See `Layer::to_luminance_chroma`. The size is the size of the layer.
+    pub(crate) fn rgb_to_luminance_chroma(&self, size: Vec2<usize>, chromaticities: Chromaticities) -> Result<Self> {
+        let weights = luminance_weights(chromaticities);
+        let [red, green, blue] = self.full_resolution_values(["R", "G", "B"])?;
+
+        let mut luminance = Vec::with_capacity(size.area());
+        let mut red_chroma = Vec::with_capacity(size.area());
+        let mut blue_chroma = Vec::with_capacity(size.area());
+
+        for ((red, green), blue) in red.into_iter().zip(green).zip(blue) {
+            let [y, ry, by] = rgb_to_luminance_chroma([red, green, blue], weights);
+            luminance.push(y);
+            red_chroma.push(ry);
+            blue_chroma.push(by);
+        }
+
+        let channel = |name: &str, sampling: Vec2<usize>, values: Vec<f32>| AnyChannel {
+            name: Text::from(name),
+            sample_data: FlatSamples::F16(values.into_iter().map(f16::from_f32).collect()),
+            quantize_linearly: name != "Y", // chroma is linear, luminance is perceptual
+            sampling,
+        };
+
+        Ok(self.replace_channels(&["R", "G", "B"], smallvec![
+            channel("Y", Vec2(1, 1), luminance),
+            channel("RY", CHROMA_SAMPLING, decimate(&red_chroma, size)),
+            channel("BY", CHROMA_SAMPLING, decimate(&blue_chroma, size)),
+        ]))
+    }
+
+    /// See `Layer::to_rgb`. The size is the size of the layer.
+    pub(crate) fn luminance_chroma_to_rgb(&self, size: Vec2<usize>, chromaticities: Chromaticities) -> Result<Self> {
+        let weights = luminance_weights(chromaticities);
+        let [luminance, red_chroma, blue_chroma] = self.find_channels(["Y", "RY", "BY"])?;
+
+        validate_luminance_sampling(luminance.sampling)?;
+
+        let red_chroma = reconstruct(red_chroma, size);
+        let blue_chroma = reconstruct(blue_chroma, size);
+
+        let mut red = Vec::with_capacity(size.area());
+        let mut green = Vec::with_capacity(size.area());
+        let mut blue = Vec::with_capacity(size.area());
+
+        for ((y, ry), by) in luminance.sample_data.values_as_f32().zip(red_chroma).zip(blue_chroma) {
+            let [r, g, b] = luminance_chroma_to_rgb([y, ry, by], weights);
+            red.push(r);
+            green.push(g);
+            blue.push(b);
+        }
+
+        let channel = |name: &str, values: Vec<f32>| AnyChannel {
+            name: Text::from(name),
+            quantize_linearly: false,
+            sampling: Vec2(1, 1),
+            sample_data: match luminance.sample_data {
+                FlatSamples::F16(_) => FlatSamples::F16(values.into_iter().map(f16::from_f32).collect()),
+                FlatSamples::F32(_) | FlatSamples::U32(_) => FlatSamples::F32(values),
+            },
+        };
+
+        Ok(self.replace_channels(&["Y", "RY", "BY"], smallvec![
+            channel("R", red), channel("G", green), channel("B", blue),
+        ]))
+    }
+
+    fn find_channels(&self, names: [&str; 3]) -> Result<[&AnyChannel<FlatSamples>; 3]> {
+        let find = |name: &str| self.list.iter().find(|channel| channel.name.eq(name))
+            .ok_or_else(|| Error::invalid(format!("layer does not contain a `{}` channel", name)));
+
+        Ok([ find(names[0])?, find(names[1])?, find(names[2])? ])
+    }
+
+    fn full_resolution_values(&self, names: [&str; 3]) -> Result<[Vec<f32>; 3]> {
+        let channels = self.find_channels(names)?;
+
+        if channels.iter().any(|channel| channel.sampling != Vec2(1, 1)) {
+            return Err(Error::unsupported("luminance chroma conversion of subsampled rgb channels"));
+        }
+
+        let [first, second, third] = channels;
+        Ok([ first.sample_data.values_as_f32().collect(), second.sample_data.values_as_f32().collect(), third.sample_data.values_as_f32().collect() ])
+    }
+
+    /// Remove the channels with the specified names, and insert the new channels, keeping the list sorted.
+    fn replace_channels(&self, removed_names: &[&str], new_channels: SmallVec<[AnyChannel<FlatSamples>; 4]>) -> Self {
+        let kept_channels = self.list.iter()
+            .filter(|channel| removed_names.iter().all(|&name| !channel.name.eq(name)))
+            .cloned();
+
+        AnyChannels::sort(kept_channels.chain(new_channels).collect())
+    }
+}
+
+/// Reduce full resolution chroma values to `CHROMA_SAMPLING`, applying a low pass filter along each axis.
+fn decimate(values: &[f32], size: Vec2<usize>) -> Vec<f32> {
+    let decimated_size = size / CHROMA_SAMPLING;
+
+    let horizontal: Vec<f32> = (0 .. size.height())
+        .flat_map(|y| (0 .. decimated_size.width()).map(move |x| Vec2(x, y)))
+        .map(|Vec2(x, y)| decimate_sample(x, size.width(), |x| values[y * size.width() + x]))
+        .collect();
+
+    (0 .. decimated_size.height())
+        .flat_map(|y| (0 .. decimated_size.width()).map(move |x| Vec2(x, y)))
+        .map(|Vec2(x, y)| decimate_sample(y, size.height(), |y| horizontal[y * decimated_size.width() + x]))
+        .collect()
+}
+
+/// Compute a decimated sample from the full resolution samples around it. Clamps at the edges.
+fn decimate_sample(index: usize, length: usize, value: impl Fn(usize) -> f32) -> f32 {
+    let center = index * 2;
+    let clamped = |index: i64| value(index.max(0).min(length as i64 - 1) as usize);
+
+    DECIMATION_WEIGHTS.iter().enumerate().fold(DECIMATION_CENTER_WEIGHT * value(center), |sum, (offset, weight)| {
+        let distance = offset as i64 * 2 + 1;
+        sum + weight * (clamped(center as i64 - distance) + clamped(center as i64 + distance))
+    })
+}
+
+/// Expand the chroma samples to the full resolution of the layer.
+/// Channels with a sampling rate of two are filtered along that axis,
+/// all other sampling rates repeat the nearest sample above and to the left.
+fn reconstruct(channel: &AnyChannel<FlatSamples>, size: Vec2<usize>) -> Vec<f32> {
+    let values: Vec<f32> = channel.sample_data.values_as_f32().collect();
+    let sampling = channel.sampling;
+    let resolution = size / sampling;
+
+    let horizontal: Vec<f32> = (0 .. resolution.height())
+        .flat_map(|y| (0 .. size.width()).map(move |x| Vec2(x, y)))
+        .map(|Vec2(x, y)| reconstruct_sample(x, sampling.x(), resolution.width(), |x| values[y * resolution.width() + x]))
+        .collect();
+
+    (0 .. size.height())
+        .flat_map(|y| (0 .. size.width()).map(move |x| Vec2(x, y)))
+        .map(|Vec2(x, y)| reconstruct_sample(y, sampling.y(), resolution.height(), |y| horizontal[y * size.width() + x]))
+        .collect()
+}
+
+/// Compute the full resolution value at the index from the subsampled values around it. Clamps at the edges.
+fn reconstruct_sample(index: usize, sampling: usize, sample_count: usize, value: impl Fn(usize) -> f32) -> f32 {
+    let last = sample_count.max(1) - 1;
+    if sampling != 2 { return value((index / sampling).min(last)) }
+
+    let left = index / 2;
+    if index % 2 == 0 { return value(left.min(last)) }
+
+    let clamped = |index: i64| value(index.max(0).min(last as i64) as usize);
+    RECONSTRUCTION_WEIGHTS.iter().enumerate().fold(0.0, |sum, (offset, weight)| {
+        let offset = offset as i64;
+        sum + weight * (clamped(left as i64 - offset) + clamped(left as i64 + 1 + offset))
+    })
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn rec709_luminance_weights() {
+        let weights = luminance_weights(Chromaticities::default());
+        let expected = [ 0.2126, 0.7152, 0.0722 ];
+
+        for (weight, expected) in weights.iter().zip(expected.iter()) {
+            assert!((weight - expected).abs() < 0.001, "{:?}", weights);
+        }
+    }
+
+    #[test]
+    fn convert_pixel_roundtrip() {
+        let weights = luminance_weights(Chromaticities::default());
+
+        for &rgb in &[ [0.2, 0.5, 0.9], [1.0, 1.0, 1.0], [0.0, 0.0, 0.0], [4.0, 0.1, 0.0] ] {
+            let converted = luminance_chroma_to_rgb(rgb_to_luminance_chroma(rgb, weights), weights);
+
+            for (converted, original) in converted.iter().zip(rgb.iter()) {
+                assert!((converted - original).abs() < 0.0001, "{:?} became {:?}", rgb, converted);
+            }
+        }
+    }
+
+    #[test]
+    fn filters_preserve_constant_chroma() {
+        let size = Vec2(10, 6);
+        let decimated = decimate(&vec![0.25; size.area()], size);
+        assert_eq!(decimated.len(), 15);
+        assert!(decimated.iter().all(|value| (value - 0.25).abs() < 0.0001));
+
+        let channel = AnyChannel {
+            name: Text::from("RY"), sampling: CHROMA_SAMPLING, quantize_linearly: true,
+            sample_data: FlatSamples::F32(decimated),
+        };
+
+        let reconstructed = reconstruct(&channel, size);
+        assert_eq!(reconstructed.len(), size.area());
+        assert!(reconstructed.iter().all(|value| (value - 0.25).abs() < 0.0001));
+    }
+}
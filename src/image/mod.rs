@@ -16,6 +16,7 @@
 //! 1. `RgbaLayersImage`: Multiple layers, fixed set of channels: rgb, optional a.
 //! 1. `FlatImage`: Multiple layers, any channels, no deep data.
 //! 1. `AnyImage`: All supported data (multiple layers, arbitrary channels, no deep data yet)
+//! 1. `DeepAndFlatImage`: Multiple layers, any channels, deep data or flat data.
 //!
 //! You can also use your own types inside an image,
 //! for example if you want to use a custom sample storage.
@@ -26,9 +27,15 @@
 pub mod read;
 pub mod write;
 pub mod crop;
+pub mod deep;
+pub mod resample;
+pub mod texture;
+pub mod environment_map;
+pub mod luminance_chroma;
+pub mod multi_view;
 pub mod pixel_vec;
 pub mod recursive;
-// pub mod channel_groups;
+pub mod channel_groups;
 
 
 use crate::meta::header::{ImageAttributes, LayerAttributes};
@@ -46,6 +53,10 @@ pub(crate) fn ignore_progress(_progress: f64){}
 /// It currently does not support deep data yet.
 pub type AnyImage = Image<Layers<AnyChannels<Levels<FlatSamples>>>>;
 
+/// This image type contains multiple layers with arbitrary channels, where each layer may contain deep data.
+/// Does not contain resolution levels.
+pub type DeepAndFlatImage = Image<Layers<AnyChannels<DeepAndFlatSamples>>>;
+
 /// This image type contains the most common exr features and can represent almost any plain image.
 /// Does not contain resolution levels. Does not support deep data.
 pub type FlatImage = Image<Layers<AnyChannels<FlatSamples>>>;
@@ -157,9 +168,14 @@ pub struct SpecificChannels<Pixels, ChannelsDescription> {
     /// Should always be a tuple containing `ChannelDescription`s, one description for each channel.
     pub channels: ChannelsDescription, // TODO this is awkward. can this be not a type parameter please? maybe vec<option<chan_info>> ??
 
-    /// Your custom pixel storage
-    // TODO should also support `Levels<YourStorage>`, where levels are desired!
+    /// Your custom pixel storage.
+    /// Use `Levels<YourStorage>` to write mip maps or rip maps, with one pixel storage for each resolution level.
     pub pixels: Pixels, // TODO rename to "pixels"?
+
+    /// When reading with a `ChannelNamePolicy`, contains the requested channel name
+    /// and the name of the channel in the file that was read instead, for each channel where these names differ.
+    /// Empty if every channel was read from a channel with the requested name. Ignored when writing.
+    pub source_names: SmallVec<[(Text, Text); 4]>,
 }
 
 
@@ -230,8 +246,7 @@ pub enum Levels<Samples> {
     },
 }
 
-/// A list of resolution levels. `Samples` can currently only be `FlatSamples`.
-// or `DeepAndFlatSamples` (not yet implemented).
+/// A list of resolution levels. `Samples` can be `FlatSamples` or `DeepAndFlatSamples`.
 pub type LevelMaps<Samples> = Vec<Samples>;
 
 /// In addition to the full resolution image,
@@ -249,12 +264,17 @@ pub struct RipMaps<Samples> {
 }
 
 
-// TODO deep data
-/*#[derive(Clone, PartialEq)]
+/// The samples of a channel that may or may not contain deep data.
+/// Layers can contain either flat or deep data, but not both.
+#[derive(Debug, Clone, PartialEq)]
 pub enum DeepAndFlatSamples {
+
+    /// The channel contains any number of samples per pixel.
     Deep(DeepSamples),
+
+    /// The channel contains exactly one sample per pixel.
     Flat(FlatSamples)
-}*/
+}
 
 /// A vector of non-deep values (one value per pixel per channel).
 /// Stores row after row in a single vector.
@@ -278,12 +298,19 @@ pub enum FlatSamples {
 }
 
 
-/*#[derive(Clone, PartialEq)]
-pub enum DeepSamples {
-    F16(Vec<Vec<f16>>),
-    F32(Vec<Vec<f32>>),
-    U32(Vec<Vec<u32>>),
-}*/
+/// The samples of a single deep channel. Each pixel can contain any number of samples, including none.
+/// Stores the samples of all pixels in a single vector, row after row.
+#[derive(Clone, PartialEq)] // debug is implemented manually
+pub struct DeepSamples {
+
+    /// For each pixel, the index of its first sample in `values`, row after row.
+    /// Contains one additional entry at the end, which is the total number of samples.
+    /// The samples of the pixel with the flat index `i` are `values[sample_offsets[i] .. sample_offsets[i + 1]]`.
+    pub sample_offsets: Vec<usize>,
+
+    /// The samples of all pixels, pixel after pixel.
+    pub values: FlatSamples,
+}
 
 use crate::block::samples::*;
 use crate::meta::attribute::*;
@@ -319,7 +346,15 @@ impl<SampleStorage, Channels> SpecificChannels<SampleStorage, Channels> {
             Channels: Sync + Clone + IntoRecursive,
             <Channels as IntoRecursive>::Recursive: WritableChannelsDescription<<SampleStorage::Pixel as IntoRecursive>::Recursive>,
     {
-        SpecificChannels { channels, pixels: source_samples }
+        SpecificChannels { channels, pixels: source_samples, source_names: SmallVec::new() }
+    }
+
+    /// The name of the channel in the file that was read for the requested channel.
+    /// Returns the requested name if the channel was not read through an alias.
+    pub fn source_name<'s>(&'s self, requested: &'s Text) -> &'s Text {
+        self.source_names.iter()
+            .find(|(name, _)| name == requested)
+            .map_or(requested, |(_, source_name)| source_name)
     }
 }
 
@@ -415,7 +450,8 @@ impl<RecursiveChannels: CheckDuplicates, RecursivePixel> SpecificChannelsBuilder
     {
         SpecificChannels {
             channels: self.channels,
-            pixels: get_pixel
+            pixels: get_pixel,
+            source_names: SmallVec::new(),
         }
     }
 
@@ -432,7 +468,8 @@ impl<RecursiveChannels: CheckDuplicates, RecursivePixel> SpecificChannelsBuilder
     {
         SpecificChannels {
             channels: self.channels,
-            pixels: get_pixel
+            pixels: get_pixel,
+            source_names: SmallVec::new(),
         }
     }
 }
@@ -458,7 +495,8 @@ impl<SampleStorage> SpecificChannels<
                 ChannelDescription::named("B", B::PREFERRED_SAMPLE_TYPE),
                 ChannelDescription::named("A", A::PREFERRED_SAMPLE_TYPE),
             ),
-            pixels: source_samples
+            pixels: source_samples,
+            source_names: SmallVec::new(),
         }
     }
 }
@@ -482,7 +520,8 @@ impl<SampleStorage> SpecificChannels<
                 ChannelDescription::named("G", G::PREFERRED_SAMPLE_TYPE),
                 ChannelDescription::named("B", B::PREFERRED_SAMPLE_TYPE),
             ),
-            pixels: source_samples
+            pixels: source_samples,
+            source_names: SmallVec::new(),
         }
     }
 }
@@ -690,6 +729,42 @@ impl FlatSamples {
     }
 }
 
+impl DeepSamples {
+
+    /// The number of pixels in this channel. Should be the width times the height.
+    pub fn pixel_count(&self) -> usize {
+        self.sample_offsets.len().saturating_sub(1)
+    }
+
+    /// The total number of samples in all pixels of this channel.
+    pub fn total_sample_count(&self) -> usize {
+        self.values.len()
+    }
+
+    /// The indices into `values` of the samples in the specified pixel.
+    /// The flat index can be obtained using `Vec2::flatten_for_width`.
+    pub fn pixel_sample_range(&self, pixel_index: usize) -> std::ops::Range<usize> {
+        self.sample_offsets[pixel_index] .. self.sample_offsets[pixel_index + 1]
+    }
+
+    /// The number of samples in the specified pixel.
+    pub fn pixel_sample_count(&self, pixel_index: usize) -> usize {
+        self.pixel_sample_range(pixel_index).len()
+    }
+
+    /// The largest number of samples in any single pixel.
+    pub fn max_pixel_sample_count(&self) -> usize {
+        self.sample_offsets.windows(2).map(|offsets| offsets[1] - offsets[0]).max().unwrap_or(0)
+    }
+
+    /// All samples of the specified pixel as iterator.
+    /// Matches the underlying sample type again for every sample,
+    /// match yourself if performance is critical! Does not allocate.
+    pub fn pixel_values<'s>(&'s self, pixel_index: usize) -> impl 's + Iterator<Item = Sample> {
+        self.pixel_sample_range(pixel_index).map(move |index| self.values.value_by_flat_index(index))
+    }
+}
+
 
 impl<'s, ChannelData:'s> Layer<ChannelData> {
 
@@ -745,7 +820,7 @@ impl Encoding {
 
     /// ZIP compression with blocks of 16 lines. Slow, but produces small files without visible artefacts.
     pub const SMALL_LOSSLESS: Encoding = Encoding {
-        compression: Compression::ZIP16,
+        compression: Compression::ZIP16(None),
         blocks: Blocks::ScanLines, // largest possible, but also with high probability of parallel workers
         line_order: LineOrder::Increasing
     };
@@ -868,6 +943,11 @@ impl std::fmt::Debug for FlatSamples {
     }
 }
 
+impl std::fmt::Debug for DeepSamples {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(formatter, "DeepSamples {{ pixels: {}, values: {:?} }}", self.pixel_count(), self.values)
+    }
+}
 
 
 /// Compare the result of a round trip test with the original method.
@@ -925,6 +1005,12 @@ pub mod validate_results {
     /// If invalid, contains the error message.
     pub type ValidationResult = std::result::Result<(), String>;
 
+    /// Compare the encodings, except for the deflate level, which is not stored in a file.
+    fn same_encoding(encoding: Encoding, other: Encoding) -> bool {
+        encoding.compression.same_method(other.compression)
+            && encoding.blocks == other.blocks
+            && encoding.line_order == other.line_order
+    }
 
     impl<C> ValidateResult for Image<C> where C: ValidateResult {
         fn validate_result(&self, other: &Self, options: ValidationOptions, location: String) -> ValidationResult {
@@ -939,7 +1025,7 @@ pub mod validate_results {
         fn validate_result(&self, other: &Self, _overridden: ValidationOptions, location: String) -> ValidationResult {
             let location = format!("{} (layer `{:?}`)", location, self.attributes.layer_name);
             if self.attributes != other.attributes { Err(location + " > attributes") }
-            else if self.encoding != other.encoding { Err(location + " > encoding") }
+            else if !same_encoding(self.encoding, other.encoding) { Err(location + " > encoding") }
             else if self.size != other.size { Err(location + " > size") }
             else if self.channel_data.list.len() != other.channel_data.list.len() { Err(location + " > channel count") }
             else {
@@ -974,7 +1060,7 @@ pub mod validate_results {
 
             // TODO dedup with above
             if self.attributes != other.attributes { Err(location + " > attributes") }
-            else if self.encoding != other.encoding { Err(location + " > encoding") }
+            else if !same_encoding(self.encoding, other.encoding) { Err(location + " > encoding") }
             else if self.size != other.size { Err(location + " > size") }
             else {
                 let options = ValidationOptions {
@@ -1034,6 +1120,23 @@ pub mod validate_results {
         }
     }
 
+    impl ValidateResult for DeepSamples {
+        fn validate_result(&self, other: &Self, options: ValidationOptions, location: String) -> ValidationResult {
+            if self.sample_offsets != other.sample_offsets { Err(location + " > deep sample offsets") }
+            else { self.values.validate_result(&other.values, options, location + " > deep samples") }
+        }
+    }
+
+    impl ValidateResult for DeepAndFlatSamples {
+        fn validate_result(&self, other: &Self, options: ValidationOptions, location: String) -> ValidationResult {
+            match (self, other) {
+                (DeepAndFlatSamples::Deep(samples), DeepAndFlatSamples::Deep(other_samples)) => samples.validate_result(other_samples, options, location),
+                (DeepAndFlatSamples::Flat(samples), DeepAndFlatSamples::Flat(other_samples)) => samples.validate_result(other_samples, options, location),
+                _ => Err(location + " > deep and flat samples mismatch"),
+            }
+        }
+    }
+
     impl<T> ValidateResult for &[T] where T: ValidateResult {
         fn validate_result(&self, other: &Self, options: ValidationOptions, location: String) -> ValidationResult {
             if self.len() != other.len() { Err(location + " count") }
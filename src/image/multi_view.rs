@@ -0,0 +1,268 @@
+//! Access the views of multi-view images, for example stereo images with a left and a right view.
+//!
+//! The OpenEXR specification describes two ways to store multiple views in one file:
+//!
+//! 1. Single-part files list all views in the `multiView` attribute of the layer.
+//!     The first view is the default view. Its channels may have no view in their name, like `R`.
+//!     The names of all other channels contain the view as the second-to-last component, like `right.R` or `diffuse.right.R`.
+//!     Channels with more than one component and no view in that place, like `disparity.x`, do not belong to any view.
+//!
+//! 1. Multi-part files store each view in a separate layer, which has a `view` attribute.
+//!
+//! Use `read().….view("right")` to read a single view from either kind of file,
+//! `Layer::from_views` to write a single-part multi-view layer,
+//! and `layers_from_views` to write a multi-part multi-view image.
+
+use crate::image::*;
+use crate::meta::header::LayerAttributes;
+use crate::error::{Error, Result, UnitResult};
+
+
+/// The view that the channel belongs to, in a single-part multi-view layer with the specified views.
+/// Channels without a dot in the name belong to the first view, which is the default view.
+/// Returns `None` if the channel does not belong to any view.
+pub fn view_of_channel<'v>(channel_name: &Text, views: &'v [Text]) -> Option<&'v Text> {
+    match view_component(channel_name) {
+        None => views.first(),
+        Some(component) => views.iter().find(|view| view.bytes() == component),
+    }
+}
+
+/// Inserts the view into the channel name, as the second-to-last component.
+/// For example, `R` becomes `right.R` and `diffuse.R` becomes `diffuse.right.R`.
+/// Channels without a dot in the name remain unchanged in the default view, which is the first view.
+pub fn channel_name_in_view(channel_name: &Text, view: &Text, views: &[Text]) -> Text {
+    let bytes = channel_name.bytes();
+    let is_default_view = views.first() == Some(view);
+
+    match bytes.iter().rposition(|&byte| byte == b'.') {
+        None if is_default_view => channel_name.clone(),
+        None => Text::join_with_dots(&[view.bytes(), bytes]),
+        Some(dot) => Text::join_with_dots(&[&bytes[.. dot], view.bytes(), &bytes[dot + 1 ..]]),
+    }
+}
+
+/// Removes the view from the channel name, if the second-to-last component of the name is one of the views.
+/// For example, `right.R` becomes `R` and `diffuse.right.R` becomes `diffuse.R`.
+pub fn channel_name_without_view(channel_name: &Text, views: &[Text]) -> Text {
+    match view_component(channel_name) {
+        Some(component) if views.iter().any(|view| view.bytes() == component) => {
+            let bytes = channel_name.bytes();
+            let last_dot = bytes.iter().rposition(|&byte| byte == b'.').expect("view component without dot");
+            let view_start = last_dot - component.len();
+
+            if view_start == 0 { Text::from_slice_unchecked(&bytes[last_dot + 1 ..]) }
+            else { Text::join_with_dots(&[&bytes[.. view_start - 1], &bytes[last_dot + 1 ..]]) }
+        },
+
+        _ => channel_name.clone(),
+    }
+}
+
+/// Whether the layer contains the view, either as a layer of a multi-part multi-view image with that `view_name`,
+/// or as a single-part multi-view layer that lists the view in its `multi_view_names`.
+pub fn layer_contains_view(attributes: &LayerAttributes, view: &Text) -> bool {
+    match (&attributes.view_name, &attributes.multi_view_names) {
+        (Some(view_name), _) => view_name == view,
+        (None, Some(views)) => views.contains(view),
+        (None, None) => false,
+    }
+}
+
+/// Creates a multi-part multi-view image, containing one layer per view.
+/// Sets the `view_name` attribute of each layer, and appends the view to the layer name, like `rgba.right`,
+/// because each layer in a file requires a unique name. Layers without a name are named after their view.
+/// Returns an error if no view is specified or if a view is specified twice.
+pub fn layers_from_views<Channels, View>(views: impl IntoIterator<Item=(View, Layer<Channels>)>) -> Result<Layers<Channels>>
+    where View: Into<Text>
+{
+    let views: Layers<Channels> = views.into_iter()
+        .map(|(view, mut layer)| {
+            let view = view.into();
+
+            layer.attributes.layer_name = Some(match &layer.attributes.layer_name {
+                Some(name) => Text::join_with_dots(&[name.bytes(), view.bytes()]),
+                None => view.clone(),
+            });
+
+            layer.attributes.view_name = Some(view);
+            layer
+        })
+        .collect();
+
+    let view_names: Vec<Text> = views.iter()
+        .map(|layer| layer.attributes.view_name.clone().expect("view name bug"))
+        .collect();
+
+    validate_views(&view_names)?;
+    Ok(views)
+}
+
+impl<Samples> Layer<AnyChannels<Samples>> {
+
+    /// Creates a single-part multi-view layer, which contains the channels of all views.
+    /// The first view is the default view. The view is inserted into the names of the channels,
+    /// except for channels of the default view without a dot in their name. See `channel_name_in_view`.
+    /// Sets the `multi_view_names` attribute.
+    /// Returns an error if no view is specified, if a view is specified twice, or if a view name contains a dot.
+    pub fn from_views<View>(
+        dimensions: impl Into<Vec2<usize>>,
+        mut attributes: LayerAttributes,
+        encoding: Encoding,
+        views: impl IntoIterator<Item=(View, AnyChannels<Samples>)>
+    ) -> Result<Self>
+        where View: Into<Text>
+    {
+        let views: Vec<(Text, AnyChannels<Samples>)> = views.into_iter()
+            .map(|(view, channels)| (view.into(), channels))
+            .collect();
+
+        let view_names: Vec<Text> = views.iter().map(|(view, _)| view.clone()).collect();
+        validate_views(&view_names)?;
+
+        if view_names.iter().any(|view| view.bytes().contains(&b'.')) {
+            return Err(Error::invalid("view name in single-part multi-view layer contains a dot"));
+        }
+
+        let channels = views.into_iter()
+            .flat_map(|(view, channels)| {
+                let view_names = &view_names;
+
+                channels.list.into_iter().map(move |channel| AnyChannel {
+                    name: channel_name_in_view(&channel.name, &view, view_names),
+                    .. channel
+                })
+            })
+            .collect();
+
+        attributes.multi_view_names = Some(view_names);
+        attributes.view_name = None;
+
+        Ok(Layer {
+            channel_data: AnyChannels::sort(channels),
+            attributes, size: dimensions.into(), encoding
+        })
+    }
+
+    /// Extracts a single view from this layer, if the layer contains the view.
+    /// A layer of a multi-part multi-view image is returned as it is, if it has the requested `view_name`.
+    /// Of a single-part multi-view layer, only the channels of the view are returned,
+    /// with the view removed from their names, and `view_name` set instead of `multi_view_names`.
+    pub fn view(&self, view: impl Into<Text>) -> Option<Self> where Samples: Clone {
+        let view = view.into();
+        if !layer_contains_view(&self.attributes, &view) { return None }
+
+        let views = match (&self.attributes.view_name, &self.attributes.multi_view_names) {
+            (None, Some(views)) => views,
+            _ => return Some(self.clone()),
+        };
+
+        let channels = channels_in_view(self.channel_data.list.iter().map(|channel| &channel.name), &view, views)
+            .into_iter()
+            .map(|(index, name)| AnyChannel { name, .. self.channel_data.list[index].clone() })
+            .collect();
+
+        let mut attributes = self.attributes.clone();
+        attributes.multi_view_names = None;
+        attributes.view_name = Some(view);
+
+        Some(Layer {
+            channel_data: AnyChannels::sort(channels),
+            attributes, size: self.size, encoding: self.encoding
+        })
+    }
+}
+
+/// The index of each channel that belongs to the view, paired with the channel name without the view.
+pub(crate) fn channels_in_view<'n>(
+    channel_names: impl Iterator<Item=&'n Text>, view: &Text, views: &[Text]
+) -> SmallVec<[(usize, Text); 8]>
+{
+    channel_names.enumerate()
+        .filter(|(_, name)| view_of_channel(name, views) == Some(view))
+        .map(|(index, name)| (index, channel_name_without_view(name, views)))
+        .collect()
+}
+
+fn validate_views(views: &[Text]) -> UnitResult {
+    if views.is_empty() {
+        return Err(Error::invalid("multi-view image without views"));
+    }
+
+    for (index, view) in views.iter().enumerate() {
+        if view.bytes().is_empty() {
+            return Err(Error::invalid("empty view name"));
+        }
+
+        if views[.. index].contains(view) {
+            return Err(Error::invalid("duplicate view name"));
+        }
+    }
+
+    Ok(())
+}
+
+/// The second-to-last component of the channel name, if the name contains a dot.
+fn view_component(channel_name: &Text) -> Option<&[u8]> {
+    let mut components = channel_name.bytes().rsplit(|&byte| byte == b'.');
+    components.next();
+    components.next()
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn views() -> Vec<Text> {
+        vec![ Text::from("left"), Text::from("right") ]
+    }
+
+    #[test]
+    fn channel_views(){
+        let views = views();
+        let view_of = |name: &str| view_of_channel(&Text::from(name), &views).map(|view| view.to_string());
+
+        assert_eq!(view_of("R").as_deref(), Some("left"));
+        assert_eq!(view_of("right.R").as_deref(), Some("right"));
+        assert_eq!(view_of("left.R").as_deref(), Some("left"));
+        assert_eq!(view_of("diffuse.right.R").as_deref(), Some("right"));
+        assert_eq!(view_of("diffuse.R"), None);
+        assert_eq!(view_of("disparity.x"), None);
+    }
+
+    #[test]
+    fn channel_names_in_views(){
+        let views = views();
+        let (left, right) = (&views[0], &views[1]);
+
+        let in_view = |name: &str, view: &Text| channel_name_in_view(&Text::from(name), view, &views).to_string();
+        let without_view = |name: &str| channel_name_without_view(&Text::from(name), &views).to_string();
+
+        assert_eq!(in_view("R", left), "R");
+        assert_eq!(in_view("R", right), "right.R");
+        assert_eq!(in_view("diffuse.R", left), "diffuse.left.R");
+        assert_eq!(in_view("diffuse.R", right), "diffuse.right.R");
+
+        assert_eq!(without_view("R"), "R");
+        assert_eq!(without_view("right.R"), "R");
+        assert_eq!(without_view("diffuse.right.R"), "diffuse.R");
+        assert_eq!(without_view("disparity.x"), "disparity.x");
+
+        for name in &["R", "diffuse.R", "a.b.c"] {
+            for view in &views {
+                let named = channel_name_in_view(&Text::from(*name), view, &views);
+                assert_eq!(view_of_channel(&named, &views), Some(view));
+                assert_eq!(channel_name_without_view(&named, &views).to_string(), *name);
+            }
+        }
+    }
+
+    #[test]
+    fn invalid_views(){
+        assert!(validate_views(&views()).is_ok());
+        assert!(validate_views(&[]).is_err());
+        assert!(validate_views(&[ Text::from("left"), Text::from("left") ]).is_err());
+        assert!(validate_views(&[ Text::from("") ]).is_err());
+    }
+}
@@ -2,9 +2,9 @@
 
 use crate::image::*;
 use crate::meta::header::{Header};
-use crate::error::{Result, UnitResult};
-use crate::block::UncompressedBlock;
-use crate::block::lines::{LineRef};
+use crate::error::{Result, UnitResult, Error};
+use crate::block::{UncompressedBlock, UncompressedDeepBlock};
+use crate::block::lines::{LineRef, DeepLineRef};
 use crate::math::Vec2;
 use crate::meta::attribute::{Text, ChannelDescription};
 use crate::image::read::layers::{ReadChannels, ChannelsReader};
@@ -12,7 +12,7 @@ use crate::block::chunk::TileCoordinates;
 
 /// A template that creates an [AnyChannelsReader] for each layer in the image.
 /// This loads all channels for each layer.
-/// The `ReadSamples` can, for example, be [ReadFlatSamples] or [ReadAllLevels<ReadFlatSamples>].
+/// The `ReadSamples` can, for example, be [ReadFlatSamples], [ReadAnySamples] or [ReadAllLevels<ReadFlatSamples>].
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct ReadAnyChannels<ReadSamples> {
 
@@ -69,6 +69,12 @@ pub trait SamplesReader {
     /// Load a single pixel line, which has not been filtered, into the reader, accumulating the sample data
     fn read_line(&mut self, line: LineRef<'_>) -> UnitResult;
 
+    /// Load a single line of deep pixels, which has not been filtered, into the reader, accumulating the sample data.
+    /// Returns an error by default, as most readers only accept flat samples.
+    fn read_deep_line(&mut self, _line: DeepLineRef<'_>) -> UnitResult {
+        Err(Error::unsupported("deep data cannot be read as flat samples"))
+    }
+
     /// Deliver the final accumulated sample storage for the image
     fn into_samples(self) -> Self::Samples;
 }
@@ -112,6 +118,14 @@ impl<S: SamplesReader> ChannelsReader for AnyChannelsReader<S> {
         Ok(())
     }
 
+    fn read_deep_block(&mut self, header: &Header, decompressed: UncompressedDeepBlock) -> UnitResult {
+        for line in decompressed.lines(&header.channels) {
+            self.sample_channels_reader[line.location.channel].samples.read_deep_line(line)?;
+        }
+
+        Ok(())
+    }
+
     fn into_channels(self) -> Self::Channels {
         AnyChannels { // not using `new()` as the channels are already sorted
             list: self.sample_channels_reader.into_iter()
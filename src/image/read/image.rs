@@ -4,13 +4,15 @@
 use crate::image::*;
 use crate::meta::header::{Header, ImageAttributes};
 use crate::error::{Result, UnitResult};
-use crate::block::{UncompressedBlock, BlockIndex};
+use crate::block::{UncompressedBlock, UncompressedDeepBlock, BlockIndex};
 use crate::block::chunk::TileCoordinates;
 use std::path::Path;
 use std::io::{Read, BufReader};
 use std::io::Seek;
 use crate::meta::MetaData;
 use crate::block::reader::ChunksReader;
+use crate::image::read::region::ReadRegion;
+use crate::meta::attribute::IntegerBounds;
 
 /// Specify whether to read the image in parallel,
 /// whether to use pedantic error handling,
@@ -51,6 +53,22 @@ impl<F, L> ReadImage<F, L> where F: FnMut(f64)
     /// This might be slower but uses less memory and less synchronization.
     pub fn non_parallel(self) -> Self { Self { parallel: false, ..self } }
 
+    /// Read only the pixels inside the specified rectangle of each layer.
+    /// Only the blocks that overlap the rectangle are decompressed.
+    /// The rectangle uses the same coordinate system as the data window of each layer.
+    ///
+    /// The data window of each resulting layer is the rectangle.
+    /// Pixels of the rectangle outside of the original data window keep their initial value,
+    /// for example zero for `AnyChannels`. Only the largest resolution level is read.
+    pub fn only_region(self, region: IntegerBounds) -> ReadImage<F, ReadRegion<L>> {
+        ReadImage {
+            read_layers: ReadRegion { region, read_layers: self.read_layers },
+            on_progress: self.on_progress,
+            pedantic: self.pedantic,
+            parallel: self.parallel
+        }
+    }
+
     /// Specify a function to be called regularly throughout the loading process.
     /// Replaces all previously specified progress functions in this reader.
     pub fn on_progress<OnProgress>(self, on_progress: OnProgress) -> ReadImage<OnProgress, L>
@@ -112,14 +130,36 @@ impl<F, L> ReadImage<F, L> where F: FnMut(f64)
         let layers_reader = read_layers.create_layers_reader(chunks_reader.headers())?;
         let mut image_collector = ImageWithAttributesReader::new(chunks_reader.headers(), layers_reader)?;
 
-        let block_reader = chunks_reader
+        let contains_deep_data = chunks_reader.headers().iter().any(|header| header.deep);
+
+        let mut block_reader = chunks_reader
             .filter_chunks(pedantic, |meta, tile, block| {
                 image_collector.filter_block(meta, tile, block)
             })?
             .on_progress(on_progress);
 
+        // TODO decompress deep blocks in parallel (documented in `flat_and_deep_data()` until then)
+        if contains_deep_data {
+            while let Some(chunk) = block_reader.read_next_chunk() {
+                let chunk = chunk?;
+                let meta_data = block_reader.meta_data();
+
+                let is_deep = meta_data.headers.get(chunk.layer_index)
+                    .map_or(false, |header| header.deep); // invalid indices are reported by the decompressor
+
+                if is_deep {
+                    let block = UncompressedDeepBlock::decompress_chunk(chunk, meta_data, pedantic)?;
+                    image_collector.read_deep_block(&meta_data.headers, block)?;
+                }
+                else {
+                    let block = UncompressedBlock::decompress_chunk(chunk, meta_data, pedantic)?;
+                    image_collector.read_block(&meta_data.headers, block)?;
+                }
+            }
+        }
+
         // TODO propagate send requirement further upwards
-        if parallel {
+        else if parallel {
             block_reader.decompress_parallel(pedantic, |meta_data, block|{
                 image_collector.read_block(&meta_data.headers, block)
             })?;
@@ -162,6 +202,11 @@ impl<L> ImageWithAttributesReader<L> where L: LayersReader {
         self.layers_reader.read_block(headers, block)
     }
 
+    /// Load a single deep pixel block, which has not been filtered, into the reader, accumulating the image
+    fn read_deep_block(&mut self, headers: &[Header], block: UncompressedDeepBlock) -> UnitResult {
+        self.layers_reader.read_deep_block(headers, block)
+    }
+
     /// Deliver the complete accumulated image
     fn into_image(self) -> Image<L::Layers> {
         Image {
@@ -203,6 +248,12 @@ pub trait LayersReader {
     /// Load a single pixel block, which has not been filtered, into the reader, accumulating the layer
     fn read_block(&mut self, headers: &[Header], block: UncompressedBlock) -> UnitResult;
 
+    /// Load a single deep pixel block, which has not been filtered, into the reader, accumulating the layer.
+    /// Returns an error by default, as most readers only accept flat samples.
+    fn read_deep_block(&mut self, _headers: &[Header], _block: UncompressedDeepBlock) -> UnitResult {
+        Err(Error::unsupported("deep data cannot be read as flat samples"))
+    }
+
     /// Deliver the final accumulated layers for the image
     fn into_layers(self) -> Self::Layers;
 }
@@ -3,11 +3,14 @@
 use crate::image::*;
 use crate::meta::header::{Header, LayerAttributes};
 use crate::error::{Result, UnitResult, Error};
-use crate::block::{UncompressedBlock, BlockIndex};
+use crate::block::{UncompressedBlock, UncompressedDeepBlock, BlockIndex};
 use crate::math::Vec2;
 use crate::image::read::image::{ReadLayers, LayersReader};
 use crate::block::chunk::TileCoordinates;
 use crate::meta::MetaData;
+use crate::image::read::luminance_chroma::ReadLuminanceChromaAsRgb;
+use crate::image::read::multi_view::ReadView;
+use crate::image::channel_groups::ReadChannelGroups;
 
 /// Specify to read all channels, aborting if any one is invalid.
 /// [`ReadRgbaChannels`] or [`ReadAnyChannels<ReadFlatSamples>`].
@@ -19,7 +22,6 @@ pub struct ReadAllLayers<ReadChannels> {
 }
 
 /// Specify to read only the first layer which meets the previously specified requirements
-// FIXME do not throw error on deep data but just skip it!
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct ReadFirstValidLayer<ReadChannels> {
 
@@ -27,6 +29,56 @@ pub struct ReadFirstValidLayer<ReadChannels> {
     pub read_channels: ReadChannels,
 }
 
+/// Specify to read all layers that meet the previously specified requirements,
+/// skipping all other layers instead of aborting.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ReadAllValidLayers<ReadChannels, OnSkippedLayer> {
+
+    /// The channel reading specification
+    pub read_channels: ReadChannels,
+
+    /// Called once for each layer that is not read
+    pub on_skipped_layer: OnSkippedLayer,
+}
+
+/// Describes a layer that was skipped by `all_valid_layers()`, and why.
+#[derive(Debug)]
+pub struct SkippedLayer {
+
+    /// The index of the layer in the file
+    pub layer_index: usize,
+
+    /// The name of the layer, if it has one
+    pub layer_name: Option<Text>,
+
+    /// Why the layer could not be read
+    pub reason: Error,
+}
+
+/// Specify to read only the layers that are chosen by a [`SelectLayer`], aborting if any chosen layer is invalid.
+/// The chunks of all other layers are skipped without being decompressed.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ReadSelectedLayers<ReadChannels, Select> {
+
+    /// The channel reading specification
+    pub read_channels: ReadChannels,
+
+    /// Decides which layers are read
+    pub select: Select,
+}
+
+/// Decides whether a layer should be read, based on its header.
+/// Implemented for closures of the form `Fn(&Header) -> bool` and for [`LayerNames`].
+pub trait SelectLayer {
+
+    /// Whether the layer with this header should be read
+    fn select_layer(&self, header: &Header) -> bool;
+}
+
+/// Selects all layers whose name is contained in this list.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct LayerNames(pub SmallVec<[Text; 4]>);
+
 /// A template that creates a [`ChannelsReader`] once for all channels per layer.
 pub trait ReadChannels<'s> {
 
@@ -40,17 +92,54 @@ pub trait ReadChannels<'s> {
     /// Read only the first layer which meets the previously specified requirements
     /// For example, skips layers with deep data, if specified earlier.
     /// Aborts if the image contains no layers.
-    // TODO test if this filters non-deep layers while ignoring deep data layers!
     fn first_valid_layer(self) -> ReadFirstValidLayer<Self> where Self:Sized { ReadFirstValidLayer { read_channels: self } }
 
-// FIXME do not throw error on deep data but just skip it!
-
-
     /// Reads all layers, including an empty list. Aborts if any of the layers are invalid,
     /// even if only one of the layers contains unexpected data.
     fn all_layers(self) -> ReadAllLayers<Self> where Self:Sized { ReadAllLayers { read_channels: self } }
 
-    // TODO pub fn all_valid_layers(self) -> ReadAllValidLayers<Self> { ReadAllValidLayers { read_channels: self } }
+    /// Reads all layers for which the function returns true, in the order of the file.
+    /// The chunks of all other layers are skipped, and their channels are not inspected.
+    /// Aborts if any of the selected layers are invalid.
+    fn layers_where<S>(self, select: S) -> ReadSelectedLayers<Self, S> where Self:Sized, S: Fn(&Header) -> bool {
+        ReadSelectedLayers { read_channels: self, select }
+    }
+
+    /// Reads all layers with one of the specified names, in the order of the file.
+    /// Names that do not exist in the file are ignored. See `layers_where` for details.
+    fn layers_named<N>(self, names: impl IntoIterator<Item=N>) -> ReadSelectedLayers<Self, LayerNames> where Self:Sized, N: Into<Text> {
+        ReadSelectedLayers { read_channels: self, select: LayerNames(names.into_iter().map(Into::into).collect()) }
+    }
+
+    /// Reads all layers that contain the specified view of a multi-view image, for example `"right"`, in the order of the file.
+    /// Supports both multi-part images with one layer per view, and single-part images with the view in the channel names.
+    /// Of single-part multi-view layers, only the channels of the view are read, with the view removed from their names,
+    /// such that `right.R` is read as `R`. All other layers are skipped. See `exr::image::multi_view` for details.
+    fn view(self, view: impl Into<Text>) -> ReadView<Self> where Self:Sized {
+        ReadView { read_channels: self, view: view.into() }
+    }
+
+    /// Reads the channels of each layer as a tree of channel groups, derived from the dots in the channel names,
+    /// reading the channels of each group as specified previously. For example, the channel `diffuse.direct.R`
+    /// is read as `R` into the group `direct` inside the group `diffuse`. Aborts if any group cannot be read as specified.
+    fn channel_groups(self) -> ReadChannelGroups<Self> where Self:Sized {
+        ReadChannelGroups { read_channels: self }
+    }
+
+    /// Converts layers that contain luminance and chroma channels (`Y`, `RY`, `BY`) instead of rgb channels
+    /// to `R`, `G` and `B` channels, using the chromaticities of the image, before reading them as specified previously.
+    /// Subsampled chroma is reconstructed to the full resolution. Only the largest resolution level is converted.
+    /// Other layers are read without any conversion.
+    fn luminance_chroma_as_rgb(self) -> ReadLuminanceChromaAsRgb<Self> where Self:Sized {
+        ReadLuminanceChromaAsRgb { read_channels: self }
+    }
+
+    /// Reads all layers that meet the previously specified requirements, including an empty list.
+    /// Skips layers that cannot be read, for example deep layers or layers without the requested channels,
+    /// instead of aborting. Use `on_skipped_layer` on the result to find out which layers were skipped.
+    fn all_valid_layers(self) -> ReadAllValidLayers<Self, fn(SkippedLayer)> where Self:Sized {
+        ReadAllValidLayers { read_channels: self, on_skipped_layer: ignore_skipped_layer }
+    }
 }
 
 
@@ -62,6 +151,14 @@ pub struct AllLayersReader<ChannelsReader> {
     layer_readers: SmallVec<[LayerReader<ChannelsReader>; 2]>, // TODO unpack struct?
 }
 
+/// Processes pixel blocks from a file and accumulates them into a list of the selected or valid layers.
+/// For example, `ChannelsReader` can be
+/// `SpecificChannelsReader` or `AnyChannelsReader<FlatSamplesReader>`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SelectedLayersReader<ChannelsReader> {
+    pub(crate) layer_readers: SmallVec<[Option<LayerReader<ChannelsReader>>; 2]>, // by layer index, none if not selected
+}
+
 /// Processes pixel blocks from a file and accumulates them into a single layers, using only the first.
 /// For example, `ChannelsReader` can be
 /// `SpecificChannelsReader` or `AnyChannelsReader<FlatSamplesReader>`.
@@ -94,13 +191,19 @@ pub trait ChannelsReader {
     /// Load a single pixel block, which has not been filtered, into the reader, accumulating the channel data
     fn read_block(&mut self, header: &Header, block: UncompressedBlock) -> UnitResult;
 
+    /// Load a single deep pixel block, which has not been filtered, into the reader, accumulating the channel data.
+    /// Returns an error by default, as most readers only accept flat samples.
+    fn read_deep_block(&mut self, _header: &Header, _block: UncompressedDeepBlock) -> UnitResult {
+        Err(Error::unsupported("deep data cannot be read as flat samples"))
+    }
+
     /// Deliver the final accumulated channel collection for the image
     fn into_channels(self) -> Self::Channels;
 }
 
 
 impl<C> LayerReader<C> {
-    fn new(header: &Header, channels_reader: C) -> Result<Self> {
+    pub(crate) fn new(header: &Header, channels_reader: C) -> Result<Self> {
         Ok(LayerReader {
             channels_reader,
             attributes: header.own_attributes.clone(),
@@ -146,6 +249,12 @@ impl<C> LayersReader for AllLayersReader<C> where C: ChannelsReader {
             .channels_reader.read_block(headers.get(block.index.layer).expect("invalid header index in block"), block)
     }
 
+    fn read_deep_block(&mut self, headers: &[Header], block: UncompressedDeepBlock) -> UnitResult {
+        self.layer_readers
+            .get_mut(block.index.layer).expect("invalid layer index argument")
+            .channels_reader.read_deep_block(headers.get(block.index.layer).expect("invalid header index in block"), block)
+    }
+
     fn into_layers(self) -> Self::Layers {
         self.layer_readers
             .into_iter()
@@ -160,6 +269,105 @@ impl<C> LayersReader for AllLayersReader<C> where C: ChannelsReader {
 }
 
 
+fn ignore_skipped_layer(_: SkippedLayer) {}
+
+impl<C, F> ReadAllValidLayers<C, F> {
+
+    /// Specify a function to be called once for each layer that cannot be read, before any pixels are loaded.
+    /// Replaces the previously specified function. To collect the skipped layers, use a `RefCell` or a `Mutex`.
+    pub fn on_skipped_layer<OnSkippedLayer>(self, on_skipped_layer: OnSkippedLayer) -> ReadAllValidLayers<C, OnSkippedLayer>
+        where OnSkippedLayer: Fn(SkippedLayer)
+    {
+        ReadAllValidLayers { read_channels: self.read_channels, on_skipped_layer }
+    }
+}
+
+impl<'s, C, F> ReadLayers<'s> for ReadAllValidLayers<C, F> where C: ReadChannels<'s>, F: Fn(SkippedLayer) {
+    type Layers = Layers<<C::Reader as ChannelsReader>::Channels>;
+    type Reader = SelectedLayersReader<C::Reader>;
+
+    fn create_layers_reader(&'s self, headers: &[Header]) -> Result<Self::Reader> {
+        let layer_readers = headers.iter().enumerate()
+            .map(|(layer_index, header)| {
+                let reader = self.read_channels.create_channels_reader(header)
+                    .and_then(|reader| LayerReader::new(header, reader));
+
+                reader.map_err(|reason| (self.on_skipped_layer)(SkippedLayer {
+                    layer_index, reason,
+                    layer_name: header.own_attributes.layer_name.clone(),
+                })).ok()
+            })
+            .collect();
+
+        Ok(SelectedLayersReader { layer_readers })
+    }
+}
+
+impl<F> SelectLayer for F where F: Fn(&Header) -> bool {
+    fn select_layer(&self, header: &Header) -> bool { self(header) }
+}
+
+impl SelectLayer for LayerNames {
+    fn select_layer(&self, header: &Header) -> bool {
+        header.own_attributes.layer_name.as_ref()
+            .map_or(false, |layer_name| self.0.contains(layer_name))
+    }
+}
+
+impl<'s, C, S> ReadLayers<'s> for ReadSelectedLayers<C, S> where C: ReadChannels<'s>, S: SelectLayer {
+    type Layers = Layers<<C::Reader as ChannelsReader>::Channels>;
+    type Reader = SelectedLayersReader<C::Reader>;
+
+    fn create_layers_reader(&'s self, headers: &[Header]) -> Result<Self::Reader> {
+        let readers: Result<_> = headers.iter()
+            .map(|header| {
+                if !self.select.select_layer(header) { return Ok(None) }
+                LayerReader::new(header, self.read_channels.create_channels_reader(header)?).map(Some)
+            })
+            .collect();
+
+        Ok(SelectedLayersReader {
+            layer_readers: readers?
+        })
+    }
+}
+
+impl<C> LayersReader for SelectedLayersReader<C> where C: ChannelsReader {
+    type Layers = Layers<C::Channels>;
+
+    fn filter_block(&self, _: &MetaData, tile: TileCoordinates, block: BlockIndex) -> bool {
+        let layer = self.layer_readers.get(block.layer).expect("invalid layer index argument");
+        layer.as_ref().map_or(false, |layer| layer.channels_reader.filter_block(tile))
+    }
+
+    fn read_block(&mut self, headers: &[Header], block: UncompressedBlock) -> UnitResult {
+        self.layer_readers
+            .get_mut(block.index.layer).expect("invalid layer index argument")
+            .as_mut().expect("block should have been filtered out")
+            .channels_reader.read_block(headers.get(block.index.layer).expect("invalid header index in block"), block)
+    }
+
+    fn read_deep_block(&mut self, headers: &[Header], block: UncompressedDeepBlock) -> UnitResult {
+        self.layer_readers
+            .get_mut(block.index.layer).expect("invalid layer index argument")
+            .as_mut().expect("block should have been filtered out")
+            .channels_reader.read_deep_block(headers.get(block.index.layer).expect("invalid header index in block"), block)
+    }
+
+    fn into_layers(self) -> Self::Layers {
+        self.layer_readers
+            .into_iter().flatten()
+            .map(|layer| Layer {
+                channel_data: layer.channels_reader.into_channels(),
+                attributes: layer.attributes,
+                size: layer.size,
+                encoding: layer.encoding
+            })
+            .collect()
+    }
+}
+
+
 impl<'s, C> ReadLayers<'s> for ReadFirstValidLayer<C> where C: ReadChannels<'s> {
     type Layers = Layer<<C::Reader as ChannelsReader>::Channels>;
     type Reader = FirstValidLayerReader<C::Reader>;
@@ -175,7 +383,16 @@ impl<'s, C> ReadLayers<'s> for ReadFirstValidLayer<C> where C: ReadChannels<'s>
                     .ok()
             )
             .next()
-            .ok_or(Error::invalid("no layer in the image matched your specified requirements"))
+            .ok_or_else(|| {
+                // deep layers are skipped by readers that do not support deep data
+                if headers.iter().any(|header| header.deep) {
+                    Error::unsupported("no flat layer in the image matched your specified requirements")
+                }
+
+                else {
+                    Error::invalid("no layer in the image matched your specified requirements")
+                }
+            })
     }
 }
 
@@ -192,6 +409,11 @@ impl<C> LayersReader for FirstValidLayerReader<C> where C: ChannelsReader {
         self.layer_reader.channels_reader.read_block(&headers[self.layer_index], block)
     }
 
+    fn read_deep_block(&mut self, headers: &[Header], block: UncompressedDeepBlock) -> UnitResult {
+        debug_assert_eq!(block.index.layer, self.layer_index, "block should have been filtered out");
+        self.layer_reader.channels_reader.read_deep_block(&headers[self.layer_index], block)
+    }
+
     fn into_layers(self) -> Self::Layers {
         Layer {
             channel_data: self.layer_reader.channels_reader.into_channels(),
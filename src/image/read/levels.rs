@@ -9,15 +9,21 @@ use crate::block::chunk::TileCoordinates;
 use crate::image::read::specific_channels::*;
 use crate::image::recursive::*;
 use crate::math::Vec2;
-use crate::block::lines::LineRef;
+use crate::block::lines::{LineRef, DeepLineRef};
 use crate::block::samples::*;
 use crate::meta::header::{Header};
+use crate::block::{UncompressedBlock, UncompressedDeepBlock, BlockIndex};
+use crate::image::read::image::{ReadLayers, LayersReader};
+use crate::image::read::layers::{ReadFirstValidLayer, ReadAllLayers, ReadSelectedLayers, LayerNames};
+use crate::image::read::multi_view::ReadView;
+use crate::image::read::luminance_chroma::ReadLuminanceChromaAsRgb;
+use crate::image::channel_groups::ReadChannelGroups;
 
 
 // Note: In the resulting image, the `FlatSamples` are placed
 // directly inside the channels, without `LargestLevel<>` indirection
 /// Specify to read only the highest resolution level, skipping all smaller variations.
-/// The sample storage can be [`ReadFlatSamples`].
+/// The sample storage can be [`ReadFlatSamples`] or [`ReadAnySamples`].
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct ReadLargestLevel<DeepOrFlatSamples> {
 
@@ -42,8 +48,10 @@ impl<DeepOrFlatSamples> ReadLargestLevel<DeepOrFlatSamples> {
     /// The type of the pixel can be defined by the second closure;
     /// it must be a tuple containing four values, each being either `f16`, `f32`, `u32` or `Sample`.
     ///
-    /// Throws an error for images with deep data or subsampling.
+    /// Throws an error for images with deep data.
     /// Use `specific_channels` or `all_channels` if you want to read something other than rgba.
+    /// Call `luminance_chroma_as_rgb` on the result to also read layers with luminance and chroma channels.
+    /// Call `with_channel_names(ChannelNamePolicy::rgba())` on the result to also accept lowercase or long names, or greyscale layers.
     pub fn rgba_channels<R,G,B,A, Create, Set, Pixels>(
         self, create_pixels: Create, set_pixel: Set
     ) -> CollectPixels<
@@ -68,8 +76,10 @@ impl<DeepOrFlatSamples> ReadLargestLevel<DeepOrFlatSamples> {
     /// The type of the pixel can be defined by the second closure;
     /// it must be a tuple containing three values, each being either `f16`, `f32`, `u32` or `Sample`.
     ///
-    /// Throws an error for images with deep data or subsampling.
+    /// Throws an error for images with deep data.
     /// Use `specific_channels` or `all_channels` if you want to read something other than rgb.
+    /// Call `luminance_chroma_as_rgb` on the result to also read layers with luminance and chroma channels.
+    /// Call `with_channel_names(ChannelNamePolicy::rgba())` on the result to also accept lowercase or long names, or greyscale layers.
     pub fn rgb_channels<R,G,B, Create, Set, Pixels>(
         self, create_pixels: Create, set_pixel: Set
     ) -> CollectPixels<
@@ -91,7 +101,7 @@ impl<DeepOrFlatSamples> ReadLargestLevel<DeepOrFlatSamples> {
     /// or `.optional("ChannelName", default_value)` on the result of this function.
     /// Call `collect_pixels` afterwards to define the pixel container for your set of channels.
     ///
-    /// Throws an error for images with deep data or subsampling.
+    /// Throws an error for images with deep data.
     pub fn specific_channels(self) -> ReadZeroChannels {
         ReadZeroChannels { }
     }
@@ -110,8 +120,64 @@ impl<ReadDeepOrFlatSamples> ReadAllLevels<ReadDeepOrFlatSamples> {
     /// Read all arbitrary channels in each layer.
     pub fn all_channels(self) -> ReadAnyChannels<Self> { ReadAnyChannels { read_samples: self } }
 
-    // TODO specific channels for multiple resolution levels
+    /// Read only layers that contain rgba channels, for all resolution levels. Skips any other channels in the layer.
+    /// The alpha channel will contain the value `1.0` if no alpha channel can be found in the image.
+    ///
+    /// Using two closures, define how to store the pixels of each level.
+    /// The first closure creates the pixels of a level, and the second closure inserts a single pixel.
+    /// The resulting channels contain a `Levels<Pixels>`.
+    ///
+    /// Throws an error for images with deep data.
+    pub fn rgba_channels<R,G,B,A, Create, Set, Pixels>(
+        self, create_pixels: Create, set_pixel: Set
+    ) -> ReadChannelLevels<CollectPixels<
+        ReadOptionalChannel<ReadRequiredChannel<ReadRequiredChannel<ReadRequiredChannel<NoneMore, R>, G>, B>, A>,
+        (R, G, B, A), Pixels, Create, Set
+    >>
+        where
+            R: FromNativeSample, G: FromNativeSample, B: FromNativeSample, A: FromNativeSample,
+            Create: Fn(Vec2<usize>, &RgbaChannels) -> Pixels,
+            Set: Fn(&mut Pixels, Vec2<usize>, (R,G,B,A)),
+    {
+        self.specific_channels()
+            .required("R").required("G").required("B")
+            .optional("A", A::from_f32(1.0))
+            .collect_pixels(create_pixels, set_pixel)
+    }
+
+    /// Read only layers that contain rgb channels, for all resolution levels. Skips any other channels in the layer.
+    ///
+    /// Using two closures, define how to store the pixels of each level.
+    /// The first closure creates the pixels of a level, and the second closure inserts a single pixel.
+    /// The resulting channels contain a `Levels<Pixels>`.
+    ///
+    /// Throws an error for images with deep data.
+    pub fn rgb_channels<R,G,B, Create, Set, Pixels>(
+        self, create_pixels: Create, set_pixel: Set
+    ) -> ReadChannelLevels<CollectPixels<
+        ReadRequiredChannel<ReadRequiredChannel<ReadRequiredChannel<NoneMore, R>, G>, B>,
+        (R, G, B), Pixels, Create, Set
+    >>
+        where
+            R: FromNativeSample, G: FromNativeSample, B: FromNativeSample,
+            Create: Fn(Vec2<usize>, &RgbChannels) -> Pixels,
+            Set: Fn(&mut Pixels, Vec2<usize>, (R,G,B)),
+    {
+        self.specific_channels()
+            .required("R").required("G").required("B")
+            .collect_pixels(create_pixels, set_pixel)
+    }
 
+    /// Read only layers that contain the specified channels, for all resolution levels,
+    /// skipping any other channels in the layer.
+    /// Further specify which channels should be included by calling `.required("ChannelName")`
+    /// or `.optional("ChannelName", default_value)` on the result of this function.
+    /// Call `collect_pixels` afterwards to define the pixel container of each level.
+    ///
+    /// Throws an error for images with deep data.
+    pub fn specific_channels(self) -> ReadChannelLevels<ReadZeroChannels> {
+        ReadChannelLevels::new()
+    }
 }
 
 /*pub struct ReadLevels<S> {
@@ -141,53 +207,48 @@ impl<S: ReadSamplesLevel> ReadSamples for ReadAllLevels<S> {
     fn create_sample_reader(&self, header: &Header, channel: &ChannelDescription) -> Result<Self::Reader> {
         let data_size = header.layer_size / channel.sampling;
 
-        let levels = {
-            if let crate::meta::BlockDescription::Tiles(tiles) = &header.blocks {
-                match tiles.level_mode {
-                    LevelMode::Singular => Levels::Singular(self.read_samples.create_samples_level_reader(header, channel, Vec2(0,0), header.layer_size)?),
-
-                    LevelMode::MipMap => Levels::Mip {
-                        rounding_mode: tiles.rounding_mode,
-                        level_data: {
-                            let round = tiles.rounding_mode;
-                            let maps: Result<LevelMaps<S::Reader>> = mip_map_levels(round, data_size)
-                                .map(|(index, level_size)| self.read_samples.create_samples_level_reader(header, channel, Vec2(index, index), level_size))
-                                .collect();
-
-                            maps?
-                        },
-                    },
-
-                    // TODO put this into Levels::new(..) ?
-                    LevelMode::RipMap => Levels::Rip {
-                        rounding_mode: tiles.rounding_mode,
-                        level_data: {
-                            let round = tiles.rounding_mode;
-                            let level_count_x = compute_level_count(round, data_size.width());
-                            let level_count_y = compute_level_count(round, data_size.height());
-                            let maps: Result<LevelMaps<S::Reader>> = rip_map_levels(round, data_size)
-                                .map(|(index, level_size)| self.read_samples.create_samples_level_reader(header, channel, index, level_size))
-                                .collect();
-
-                            RipMaps {
-                                map_data: maps?,
-                                level_count: Vec2(level_count_x, level_count_y)
-                            }
-                        },
-                    },
-                }
-            }
-
-            // scan line blocks never have mip maps
-            else {
-                Levels::Singular(self.read_samples.create_samples_level_reader(header, channel, Vec2(0, 0), data_size)?)
-            }
-        };
+        let levels = create_levels(header, data_size, |level, resolution|
+            self.read_samples.create_samples_level_reader(header, channel, level, resolution)
+        )?;
 
         Ok(AllLevelsReader { levels })
     }
 }
 
+/// Create a value for each resolution level of the layer, given the level index and the level resolution.
+/// The data size is the resolution of the largest level.
+pub(crate) fn create_levels<T>(
+    header: &Header, data_size: Vec2<usize>,
+    mut create_level: impl FnMut(Vec2<usize>, Vec2<usize>) -> Result<T>
+) -> Result<Levels<T>>
+{
+    Ok(match header.blocks {
+        BlockDescription::Tiles(TileDescription { level_mode: LevelMode::MipMap, rounding_mode, .. }) => Levels::Mip {
+            rounding_mode,
+            level_data: mip_map_levels(rounding_mode, data_size)
+                .map(|(index, level_size)| create_level(Vec2(index, index), level_size))
+                .collect::<Result<LevelMaps<T>>>()?,
+        },
+
+        BlockDescription::Tiles(TileDescription { level_mode: LevelMode::RipMap, rounding_mode, .. }) => Levels::Rip {
+            rounding_mode,
+            level_data: RipMaps {
+                map_data: rip_map_levels(rounding_mode, data_size)
+                    .map(|(index, level_size)| create_level(index, level_size))
+                    .collect::<Result<LevelMaps<T>>>()?,
+
+                level_count: Vec2(
+                    compute_level_count(rounding_mode, data_size.width()),
+                    compute_level_count(rounding_mode, data_size.height())
+                ),
+            },
+        },
+
+        // scan line blocks never have mip maps
+        _ => Levels::Singular(create_level(Vec2(0, 0), data_size)?),
+    })
+}
+
 
 impl<S: SamplesReader> SamplesReader for AllLevelsReader<S> {
     type Samples = Levels<S::Samples>;
@@ -200,6 +261,10 @@ impl<S: SamplesReader> SamplesReader for AllLevelsReader<S> {
         self.levels.get_level_mut(line.location.level)?.read_line(line)
     }
 
+    fn read_deep_line(&mut self, line: DeepLineRef<'_>) -> UnitResult {
+        self.levels.get_level_mut(line.location.level)?.read_deep_line(line)
+    }
+
     fn into_samples(self) -> Self::Samples {
         match self.levels {
             Levels::Singular(level) => Levels::Singular(level.into_samples()),
@@ -217,3 +282,308 @@ impl<S: SamplesReader> SamplesReader for AllLevelsReader<S> {
         }
     }
 }
+
+
+/// Specify to read only one resolution level of each layer, chosen by a function, skipping all other levels.
+/// The sample storage can be [`ReadFlatSamples`] or [`ReadAnySamples`].
+/// The size of each resulting layer is the size of the chosen level,
+/// and the chosen level is read as if it were the largest level.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ReadLevelBy<DeepOrFlatSamples, SelectLevel> {
+
+    /// The sample reading specification
+    pub read_samples: DeepOrFlatSamples,
+
+    /// Chooses the index of a level from the resolutions of all levels of a layer.
+    /// The resolutions are ordered as in the file, starting with the largest level.
+    pub select_level: SelectLevel,
+}
+
+impl<DeepOrFlatSamples, SelectLevel> ReadLevelBy<DeepOrFlatSamples, SelectLevel> {
+
+    fn with_channels<ReadChannels>(self, read_channels: impl FnOnce(ReadLargestLevel<DeepOrFlatSamples>) -> ReadChannels)
+        -> ReadChannelsOfLevel<ReadChannels, SelectLevel>
+    {
+        ReadChannelsOfLevel {
+            read_channels: read_channels(ReadLargestLevel { read_samples: self.read_samples }),
+            select_level: self.select_level
+        }
+    }
+
+    /// Read all arbitrary channels of the chosen level in each layer.
+    pub fn all_channels(self) -> ReadChannelsOfLevel<ReadAnyChannels<DeepOrFlatSamples>, SelectLevel> {
+        self.with_channels(ReadLargestLevel::all_channels)
+    }
+
+    /// Read only layers that contain rgba channels, using only the chosen level. Skips any other channels in the layer.
+    /// The alpha channel will contain the value `1.0` if no alpha channel can be found in the image.
+    /// See `ReadLargestLevel::rgba_channels` for details.
+    pub fn rgba_channels<R,G,B,A, Create, Set, Pixels>(
+        self, create_pixels: Create, set_pixel: Set
+    ) -> ReadChannelsOfLevel<CollectPixels<
+        ReadOptionalChannel<ReadRequiredChannel<ReadRequiredChannel<ReadRequiredChannel<NoneMore, R>, G>, B>, A>,
+        (R, G, B, A), Pixels, Create, Set
+    >, SelectLevel>
+        where
+            R: FromNativeSample, G: FromNativeSample, B: FromNativeSample, A: FromNativeSample,
+            Create: Fn(Vec2<usize>, &RgbaChannels) -> Pixels,
+            Set: Fn(&mut Pixels, Vec2<usize>, (R,G,B,A)),
+    {
+        self.with_channels(|levels| levels.rgba_channels(create_pixels, set_pixel))
+    }
+
+    /// Read only layers that contain rgb channels, using only the chosen level. Skips any other channels in the layer.
+    /// See `ReadLargestLevel::rgb_channels` for details.
+    pub fn rgb_channels<R,G,B, Create, Set, Pixels>(
+        self, create_pixels: Create, set_pixel: Set
+    ) -> ReadChannelsOfLevel<CollectPixels<
+        ReadRequiredChannel<ReadRequiredChannel<ReadRequiredChannel<NoneMore, R>, G>, B>,
+        (R, G, B), Pixels, Create, Set
+    >, SelectLevel>
+        where
+            R: FromNativeSample, G: FromNativeSample, B: FromNativeSample,
+            Create: Fn(Vec2<usize>, &RgbChannels) -> Pixels,
+            Set: Fn(&mut Pixels, Vec2<usize>, (R,G,B)),
+    {
+        self.with_channels(|levels| levels.rgb_channels(create_pixels, set_pixel))
+    }
+
+    /// Read only layers that contain the specified channels, using only the chosen level,
+    /// skipping any other channels in the layer.
+    /// Further specify which channels should be included by calling `.required("ChannelName")`
+    /// or `.optional("ChannelName", default_value)` on the result of this function.
+    /// Call `collect_pixels` afterwards to define the pixel container for your set of channels.
+    pub fn specific_channels(self) -> ReadChannelsOfLevel<ReadZeroChannels, SelectLevel> {
+        self.with_channels(ReadLargestLevel::specific_channels)
+    }
+}
+
+/// Specify how to read the channels of the chosen resolution level of each layer.
+/// Continue by choosing the layers, which then only contain the chosen level.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ReadChannelsOfLevel<ReadChannels, SelectLevel> {
+
+    /// The channel reading specification
+    pub read_channels: ReadChannels,
+
+    /// Chooses the index of a level from the resolutions of all levels of a layer.
+    pub select_level: SelectLevel,
+}
+
+impl<C, F> ReadChannelsOfLevel<C, F> {
+
+    fn map_channels<ReadChannels>(self, map: impl FnOnce(C) -> ReadChannels) -> ReadChannelsOfLevel<ReadChannels, F> {
+        ReadChannelsOfLevel { read_channels: map(self.read_channels), select_level: self.select_level }
+    }
+
+    fn with_layers<ReadLayers>(self, read_layers: impl FnOnce(C) -> ReadLayers) -> ReadSpecificLevel<ReadLayers, F> {
+        ReadSpecificLevel { read_layers: read_layers(self.read_channels), select_level: self.select_level }
+    }
+
+    /// Read only the first layer which meets the previously specified requirements.
+    /// See `ReadChannels::first_valid_layer` for details.
+    pub fn first_valid_layer(self) -> ReadSpecificLevel<ReadFirstValidLayer<C>, F> {
+        self.with_layers(|read_channels| ReadFirstValidLayer { read_channels })
+    }
+
+    /// Reads all layers, including an empty list. Aborts if any of the layers are invalid.
+    /// See `ReadChannels::all_layers` for details.
+    pub fn all_layers(self) -> ReadSpecificLevel<ReadAllLayers<C>, F> {
+        self.with_layers(|read_channels| ReadAllLayers { read_channels })
+    }
+
+    /// Reads all layers for which the function returns true, in the order of the file.
+    /// See `ReadChannels::layers_where` for details.
+    pub fn layers_where<S>(self, select: S) -> ReadSpecificLevel<ReadSelectedLayers<C, S>, F> where S: Fn(&Header) -> bool {
+        self.with_layers(|read_channels| ReadSelectedLayers { read_channels, select })
+    }
+
+    /// Reads all layers with one of the specified names, in the order of the file.
+    /// See `ReadChannels::layers_named` for details.
+    pub fn layers_named<N>(self, names: impl IntoIterator<Item=N>) -> ReadSpecificLevel<ReadSelectedLayers<C, LayerNames>, F> where N: Into<Text> {
+        let select = LayerNames(names.into_iter().map(Into::into).collect());
+        self.with_layers(|read_channels| ReadSelectedLayers { read_channels, select })
+    }
+
+    /// Reads only the channels of the specified view. See `ReadChannels::view` for details.
+    pub fn view(self, view: impl Into<Text>) -> ReadChannelsOfLevel<ReadView<C>, F> {
+        let view = view.into();
+        self.map_channels(|read_channels| ReadView { read_channels, view })
+    }
+
+    /// Reads the channels as a tree of channel groups. See `ReadChannels::channel_groups` for details.
+    pub fn channel_groups(self) -> ReadChannelsOfLevel<ReadChannelGroups<C>, F> {
+        self.map_channels(|read_channels| ReadChannelGroups { read_channels })
+    }
+
+    /// Converts luminance and chroma channels of the chosen level to rgb channels.
+    /// See `ReadChannels::luminance_chroma_as_rgb` for details.
+    pub fn luminance_chroma_as_rgb(self) -> ReadChannelsOfLevel<ReadLuminanceChromaAsRgb<C>, F> {
+        self.map_channels(|read_channels| ReadLuminanceChromaAsRgb { read_channels })
+    }
+}
+
+impl<C: ReadSpecificChannel, F> ReadChannelsOfLevel<C, F> {
+
+    /// Plan to read an additional channel from the chosen level, with the specified name.
+    /// If the channel cannot be found in the image when the image is read, the image will not be loaded.
+    pub fn required<Sample>(self, channel_name: impl Into<Text>) -> ReadChannelsOfLevel<ReadRequiredChannel<C, Sample>, F> {
+        self.map_channels(|read_channels| read_channels.required(channel_name))
+    }
+
+    /// Plan to read an additional channel from the chosen level, with the specified name.
+    /// If the file does not contain this channel, the specified default sample will be returned instead.
+    pub fn optional<Sample>(self, channel_name: impl Into<Text>, default_sample: Sample)
+        -> ReadChannelsOfLevel<ReadOptionalChannel<C, Sample>, F>
+    {
+        self.map_channels(|read_channels| read_channels.optional(channel_name, default_sample))
+    }
+
+    /// Using two closures, define how to store the pixels of the chosen level.
+    /// See `ReadSpecificChannel::collect_pixels` for details.
+    pub fn collect_pixels<Pixel, PixelStorage, CreatePixels, SetPixel>(
+        self, create_pixels: CreatePixels, set_pixel: SetPixel
+    ) -> ReadChannelsOfLevel<CollectPixels<C, Pixel, PixelStorage, CreatePixels, SetPixel>, F>
+        where
+            <C::RecursivePixelReader as RecursivePixelReader>::RecursivePixel: IntoTuple<Pixel>,
+            <C::RecursivePixelReader as RecursivePixelReader>::RecursiveChannelDescriptions: IntoNonRecursive,
+            CreatePixels: Fn(
+                Vec2<usize>,
+                &<<C::RecursivePixelReader as RecursivePixelReader>::RecursiveChannelDescriptions as IntoNonRecursive>::NonRecursive
+            ) -> PixelStorage,
+            SetPixel: Fn(&mut PixelStorage, Vec2<usize>, Pixel),
+    {
+        self.map_channels(|read_channels| read_channels.collect_pixels(create_pixels, set_pixel))
+    }
+}
+
+impl<C, Pixel, PixelStorage, CreatePixels, SetPixel, F> ReadChannelsOfLevel<CollectPixels<C, Pixel, PixelStorage, CreatePixels, SetPixel>, F> {
+
+    /// Specify which channels in a layer satisfy the requested channels, for example to also accept lowercase names.
+    /// By default, only channels with exactly the requested names are read.
+    pub fn with_channel_names(self, channel_names: ChannelNamePolicy) -> Self {
+        self.map_channels(|read_channels| read_channels.with_channel_names(channel_names))
+    }
+}
+
+/// Reads only one resolution level of each layer, chosen by a function.
+/// The size of each resulting layer is the size of the chosen level,
+/// and the chosen level is read as if it were the largest level.
+/// Created by `specific_resolution_level` or `closest_resolution_level`.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ReadSpecificLevel<ReadLayers, SelectLevel> {
+
+    /// Chooses the index of a level from the resolutions of all levels of a layer.
+    /// The resolutions are ordered as in the file, starting with the largest level.
+    pub select_level: SelectLevel,
+
+    /// The layers reading specification
+    pub read_layers: ReadLayers,
+}
+
+/// Processes pixel blocks of one resolution level per layer
+/// and accumulates them into the layers of the inner reader.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpecificLevelReader<LayersReader> {
+    selected_levels: Vec<Vec2<usize>>,
+    level_headers: Headers,
+    layers_reader: LayersReader,
+}
+
+impl<'s, L, F> ReadLayers<'s> for ReadSpecificLevel<L, F>
+    where L: ReadLayers<'s>, F: Fn(&[Vec2<usize>]) -> usize
+{
+    type Layers = L::Layers;
+    type Reader = SpecificLevelReader<L::Reader>;
+
+    fn create_layers_reader(&'s self, headers: &[Header]) -> Result<Self::Reader> {
+        let mut selected_levels = Vec::with_capacity(headers.len());
+        let mut level_headers = Headers::with_capacity(headers.len());
+
+        for header in headers {
+            let levels = resolution_levels(header);
+            let resolutions: Vec<Vec2<usize>> = levels.iter().map(|&(_, resolution)| resolution).collect();
+
+            let (level, resolution) = *levels.get((self.select_level)(&resolutions))
+                .ok_or(Error::invalid("selected resolution level index"))?;
+
+            selected_levels.push(level);
+            level_headers.push(level_header(header, resolution));
+        }
+
+        Ok(SpecificLevelReader {
+            layers_reader: self.read_layers.create_layers_reader(&level_headers)?,
+            selected_levels, level_headers,
+        })
+    }
+}
+
+impl<L> LayersReader for SpecificLevelReader<L> where L: LayersReader {
+    type Layers = L::Layers;
+
+    fn filter_block(&self, meta: &MetaData, tile: TileCoordinates, block: BlockIndex) -> bool {
+        tile.level_index == self.selected_levels[block.layer] && self.layers_reader.filter_block(
+            meta,
+            TileCoordinates { level_index: Vec2(0, 0), .. tile },
+            BlockIndex { level: Vec2(0, 0), .. block }
+        )
+    }
+
+    fn read_block(&mut self, _: &[Header], block: UncompressedBlock) -> UnitResult {
+        let index = BlockIndex { level: Vec2(0, 0), .. block.index };
+        self.layers_reader.read_block(&self.level_headers, UncompressedBlock { index, .. block })
+    }
+
+    fn read_deep_block(&mut self, _: &[Header], block: UncompressedDeepBlock) -> UnitResult {
+        let index = BlockIndex { level: Vec2(0, 0), .. block.index };
+        self.layers_reader.read_deep_block(&self.level_headers, UncompressedDeepBlock { index, .. block })
+    }
+
+    fn into_layers(self) -> Self::Layers {
+        self.layers_reader.into_layers()
+    }
+}
+
+/// The index and resolution of each level of the layer, starting with the largest level.
+pub(crate) fn resolution_levels(header: &Header) -> Vec<(Vec2<usize>, Vec2<usize>)> {
+    match header.blocks {
+        BlockDescription::Tiles(TileDescription { level_mode: LevelMode::MipMap, rounding_mode, .. }) =>
+            mip_map_levels(rounding_mode, header.layer_size)
+                .map(|(index, resolution)| (Vec2(index, index), resolution)).collect(),
+
+        BlockDescription::Tiles(TileDescription { level_mode: LevelMode::RipMap, rounding_mode, .. }) =>
+            rip_map_levels(rounding_mode, header.layer_size).collect(),
+
+        _ => vec![ (Vec2(0, 0), header.layer_size) ],
+    }
+}
+
+/// Replace the size of the header with the size of the level, keeping only one resolution level.
+fn level_header(header: &Header, resolution: Vec2<usize>) -> Header {
+    let blocks = match header.blocks {
+        BlockDescription::Tiles(tiles) => BlockDescription::Tiles(TileDescription { level_mode: LevelMode::Singular, .. tiles }),
+        BlockDescription::ScanLines => BlockDescription::ScanLines,
+    };
+
+    Header {
+        blocks,
+        layer_size: resolution,
+        chunk_count: compute_chunk_count(header.compression, resolution, blocks),
+        .. header.clone()
+    }
+}
+
+/// Chooses the level with the width and height closest to the target size.
+/// If two levels are equally close, the larger level is chosen.
+pub(crate) fn closest_level(target_size: Vec2<usize>) -> impl Fn(&[Vec2<usize>]) -> usize {
+    move |resolutions| {
+        let distance = |resolution: &Vec2<usize>| {
+            (resolution.width() as i64 - target_size.width() as i64).abs()
+                + (resolution.height() as i64 - target_size.height() as i64).abs()
+        };
+
+        resolutions.iter().enumerate()
+            .min_by_key(|(_, resolution)| distance(resolution))
+            .map_or(0, |(index, _)| index)
+    }
+}
@@ -0,0 +1,121 @@
+//! How to read luminance and chroma channels (`Y`, `RY`, `BY`) as rgb channels.
+
+use crate::image::read::layers::{ReadChannels, ChannelsReader};
+use crate::image::read::any_channels::{ReadAnyChannels, AnyChannelsReader};
+use crate::image::read::samples::{ReadFlatSamples, FlatSamplesReader};
+use crate::image::write::channels::{WritableChannels, ChannelsWriter};
+use crate::image::luminance_chroma::{is_luminance_chroma, rgb_channel_list, validate_luminance_sampling};
+use crate::meta::header::Header;
+use crate::meta::BlockDescription;
+use crate::block::{UncompressedBlock, UncompressedDeepBlock, BlockIndex};
+use crate::block::chunk::TileCoordinates;
+use crate::error::{Result, UnitResult};
+use crate::math::Vec2;
+
+
+/// Specify to convert the luminance and chroma channels of a layer to `R`, `G` and `B` channels,
+/// before passing them to the previously specified channel reader.
+/// Layers that do not contain luminance and chroma channels are read without conversion.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ReadLuminanceChromaAsRgb<ReadChannels> {
+
+    /// The channel reading specification, which receives the converted channels
+    pub read_channels: ReadChannels,
+}
+
+/// Processes pixel blocks from a file, converting luminance and chroma channels to rgb channels, if the layer contains any.
+#[derive(Debug, Clone)]
+pub struct LuminanceChromaReader<ChannelsReader> {
+    channels_reader: ChannelsReader,
+    luminance_chroma: Option<LuminanceChromaLayer>, // none if the layer does not contain luminance and chroma
+}
+
+/// The samples of all luminance and chroma blocks, which are converted only after the whole layer has been read,
+/// because the reconstruction of the chroma requires the neighbouring samples.
+#[derive(Debug, Clone)]
+struct LuminanceChromaLayer {
+    samples_reader: AnyChannelsReader<FlatSamplesReader>,
+    header: Header,
+
+    /// The header that the channel reader receives, containing the rgb channels instead of the luminance and chroma.
+    rgb_header: Header,
+}
+
+impl<'s, InnerChannels> ReadChannels<'s> for ReadLuminanceChromaAsRgb<InnerChannels> where InnerChannels: ReadChannels<'s> {
+    type Reader = LuminanceChromaReader<InnerChannels::Reader>;
+
+    fn create_channels_reader(&'s self, header: &Header) -> Result<Self::Reader> {
+        if header.deep || !is_luminance_chroma(&header.channels) {
+            return Ok(LuminanceChromaReader {
+                channels_reader: self.read_channels.create_channels_reader(header)?,
+                luminance_chroma: None,
+            });
+        }
+
+        // check everything that the conversion requires before any block is read,
+        // because the conversion happens in `into_channels`, which cannot fail
+        for channel in &header.channels.list {
+            if channel.name.eq("Y") { validate_luminance_sampling(channel.sampling)?; }
+        }
+
+        // only the largest resolution level is converted, so the rgb layer has no other levels
+        let rgb_header = Header {
+            channels: rgb_channel_list(&header.channels),
+            blocks: BlockDescription::ScanLines,
+            .. header.clone()
+        };
+
+        Ok(LuminanceChromaReader {
+            channels_reader: self.read_channels.create_channels_reader(&rgb_header)?,
+            luminance_chroma: Some(LuminanceChromaLayer {
+                samples_reader: ReadAnyChannels { read_samples: ReadFlatSamples }.create_channels_reader(header)?,
+                header: header.clone(),
+                rgb_header,
+            }),
+        })
+    }
+}
+
+impl<InnerReader: ChannelsReader> ChannelsReader for LuminanceChromaReader<InnerReader> {
+    type Channels = InnerReader::Channels;
+
+    fn filter_block(&self, tile: TileCoordinates) -> bool {
+        match &self.luminance_chroma {
+            Some(layer) => layer.samples_reader.filter_block(tile),
+            None => self.channels_reader.filter_block(tile),
+        }
+    }
+
+    fn read_block(&mut self, header: &Header, block: UncompressedBlock) -> UnitResult {
+        match &mut self.luminance_chroma {
+            Some(layer) => layer.samples_reader.read_block(header, block),
+            None => self.channels_reader.read_block(header, block),
+        }
+    }
+
+    fn read_deep_block(&mut self, header: &Header, block: UncompressedDeepBlock) -> UnitResult {
+        self.channels_reader.read_deep_block(header, block)
+    }
+
+    fn into_channels(self) -> Self::Channels {
+        let mut channels_reader = self.channels_reader;
+
+        if let Some(layer) = self.luminance_chroma {
+            let size = layer.header.layer_size;
+            let chromaticities = layer.header.shared_attributes.chromaticities.unwrap_or_default();
+
+            let rgb_channels = layer.samples_reader.into_channels()
+                .luminance_chroma_to_rgb(size, chromaticities)
+                .expect("luminance chroma channels not validated bug");
+
+            // pass the whole layer to the channel reader as a single block
+            let index = BlockIndex { layer: 0, pixel_position: Vec2(0, 0), pixel_size: size, level: Vec2(0, 0) };
+            let data = rgb_channels.create_writer(&layer.rgb_header).extract_uncompressed_block(&layer.rgb_header, index);
+
+            channels_reader.read_block(&layer.rgb_header, UncompressedBlock { index, data })
+                .expect("converted rgb block bug");
+        }
+
+        channels_reader.into_channels()
+    }
+}
@@ -28,9 +28,12 @@
 //! 1. `read_all_data_from_file(path)`:
 //!     All layers with arbitrary channels and all resolution levels are extracted from the file.
 //!
-//!     Note: Currently does not support deep data, and currently fails
+//!     Note: Does not support deep data, and fails
 //!     if any layer in the image contains deep data.
 //!
+//! 1. `read_all_flat_and_deep_layers_from_file(path)`:
+//!     All layers with arbitrary channels are loaded from the file, including layers with deep data.
+//!
 
 // The following three stages are internally used to read an image.
 // 1. `ReadImage` - The specification. Contains everything the user wants to tell us about loading an image.
@@ -48,24 +51,29 @@ pub mod any_channels;
 pub mod levels;
 pub mod samples;
 pub mod specific_channels;
+pub mod region;
+pub mod luminance_chroma;
+pub mod multi_view;
 
 use crate::error::{Result};
-use crate::image::read::samples::{ReadFlatSamples};
+use crate::image::read::samples::{ReadFlatSamples, ReadAnySamples};
 use std::path::Path;
-use crate::image::{AnyImage, AnyChannels, FlatSamples, Image, Layer, FlatImage, PixelLayersImage, RgbaChannels};
+use crate::image::{AnyImage, AnyChannels, FlatSamples, Image, Layer, FlatImage, DeepAndFlatImage, PixelLayersImage, RgbaChannels};
 use crate::image::read::image::ReadLayers;
 use crate::image::read::layers::ReadChannels;
 use crate::math::Vec2;
 use crate::prelude::{PixelImage};
 use crate::block::samples::FromNativeSample;
+use crate::image::read::specific_channels::ChannelNamePolicy;
 
 
 /// All resolution levels, all channels, all layers.
-/// Does not support deep data yet. Uses parallel decompression and relaxed error handling.
+/// Does not support deep data. Uses parallel decompression and relaxed error handling.
+/// Use `read_all_flat_and_deep_layers_from_file` for images that contain deep data.
 /// Inspect the source code of this function if you need customization.
 pub fn read_all_data_from_file(path: impl AsRef<Path>) -> Result<AnyImage> {
     read()
-        .no_deep_data() // TODO deep data
+        .no_deep_data()
         .all_resolution_levels()
         .all_channels()
         .all_layers()
@@ -73,8 +81,22 @@ pub fn read_all_data_from_file(path: impl AsRef<Path>) -> Result<AnyImage> {
         .from_file(path)
 }
 
-// FIXME do not throw error on deep data but just skip it!
+/// Deep data and flat data, no resolution levels, all channels, all layers.
+/// Each channel contains either `DeepSamples` or `FlatSamples`, depending on its layer.
+/// Uses relaxed error handling. Deep data is decompressed using a single thread.
+/// Inspect the source code of this function if you need customization.
+pub fn read_all_flat_and_deep_layers_from_file(path: impl AsRef<Path>) -> Result<DeepAndFlatImage> {
+    read()
+        .flat_and_deep_data()
+        .largest_resolution_level()
+        .all_channels()
+        .all_layers()
+        .all_attributes()
+        .from_file(path)
+}
+
 /// No deep data, no resolution levels, all channels, all layers.
+/// Fails if any layer in the image contains deep data.
 /// Uses parallel decompression and relaxed error handling.
 /// Inspect the source code of this function if you need customization.
 pub fn read_all_flat_layers_from_file(path: impl AsRef<Path>) -> Result<FlatImage> {
@@ -106,6 +128,8 @@ pub fn read_first_flat_layer_from_file(path: impl AsRef<Path>) -> Result<Image<L
 /// `Create` and `Set` can be closures, see the examples for more information.
 /// Inspect the source code of this function if you need customization.
 /// The alpha channel will contain the value `1.0` if no alpha channel can be found in the image.
+/// Layers with luminance and chroma channels (`Y`, `RY`, `BY`) are converted to rgb.
+/// Also accepts lowercase or long channel names, and greyscale layers, as defined by `ChannelNamePolicy::rgba()`.
 ///
 /// Using two closures, define how to store the pixels.
 /// The first closure creates an image, and the second closure inserts a single pixel.
@@ -125,6 +149,8 @@ pub fn read_all_rgba_layers_from_file<R,G,B,A, Set:'static, Create:'static, Pixe
         .no_deep_data()
         .largest_resolution_level()
         .rgba_channels(create, set_pixel)
+        .with_channel_names(ChannelNamePolicy::rgba())
+        .luminance_chroma_as_rgb()
         .all_layers()
         .all_attributes()
         .from_file(path)
@@ -135,6 +161,8 @@ pub fn read_all_rgba_layers_from_file<R,G,B,A, Set:'static, Create:'static, Pixe
 /// `Create` and `Set` can be closures, see the examples for more information.
 /// Inspect the source code of this function if you need customization.
 /// The alpha channel will contain the value `1.0` if no alpha channel can be found in the image.
+/// Layers with luminance and chroma channels (`Y`, `RY`, `BY`) are converted to rgb.
+/// Also accepts lowercase or long channel names, and greyscale layers, as defined by `ChannelNamePolicy::rgba()`.
 ///
 /// Using two closures, define how to store the pixels.
 /// The first closure creates an image, and the second closure inserts a single pixel.
@@ -154,6 +182,8 @@ pub fn read_first_rgba_layer_from_file<R,G,B,A, Set:'static, Create:'static, Pix
         .no_deep_data()
         .largest_resolution_level()
         .rgba_channels(create, set_pixel)
+        .with_channel_names(ChannelNamePolicy::rgba())
+        .luminance_chroma_as_rgb()
         .first_valid_layer()
         .all_attributes()
         .from_file(path)
@@ -172,10 +202,11 @@ pub struct ReadBuilder;
 ///
 ///     // the type of the this image depends on the chosen options
 ///     let image = read()
-///         .no_deep_data() // (currently required)
+///         .no_deep_data() // or `flat_and_deep_data()`
 ///         .largest_resolution_level() // or `all_resolution_levels()`
 ///         .all_channels() // or `rgba_channels(constructor, setter)`
-///         .all_layers() // or `first_valid_layer()`
+///         .luminance_chroma_as_rgb() // optional, converts `Y`, `RY`, `BY` to `R`, `G`, `B`
+///         .all_layers() // or `first_valid_layer()` or `layers_named(["beauty", "depth"])`
 ///         .all_attributes() // (currently required)
 ///         .on_progress(|progress| println!("progress: {:.1}", progress*100.0)) // optional
 ///         .from_file("image.exr").unwrap(); // or `from_buffered(my_byte_slice)`
@@ -186,14 +217,14 @@ pub struct ReadBuilder;
 /// 1. `read_all_rgba_layers_from_file`
 /// 1. `read_all_flat_layers_from_file`
 /// 1. `read_all_data_from_file`
+/// 1. `read_all_flat_and_deep_layers_from_file`
 ///
-// TODO not panic but skip deep layers!
 pub fn read() -> ReadBuilder { ReadBuilder }
 
 impl ReadBuilder {
 
     /// Specify to handle only one sample per channel, disabling "deep data".
-    // TODO not panic but skip deep layers!
+    /// Layers with deep data are skipped by `first_valid_layer()` and `all_valid_layers()`, and rejected by `all_layers()`.
     pub fn no_deep_data(self) -> ReadFlatSamples { ReadFlatSamples }
 
     // pub fn any_resolution_levels() -> ReadBuilder<> {}
@@ -203,5 +234,9 @@ impl ReadBuilder {
     // e. g. `let floats = reader.any_channels_with(|sample, f32_samples| f32_samples[index] = sample as f32)`
     // pub fn no_deep_data_with <S> (self, storage: S) -> FlatSamplesWith<S> {  }
 
-    // pub fn flat_and_deep_data(self) -> ReadAnySamples { ReadAnySamples }
+    /// Specify to handle any number of samples per pixel in layers with "deep data",
+    /// and one sample per pixel in all other layers.
+    /// If the file contains any deep layer, all blocks of the file are decompressed sequentially,
+    /// as if `non_parallel()` had been specified.
+    pub fn flat_and_deep_data(self) -> ReadAnySamples { ReadAnySamples }
 }
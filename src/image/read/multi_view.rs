@@ -0,0 +1,134 @@
+//! How to read a single view of a multi-view image.
+
+use crate::image::*;
+use crate::image::read::layers::{ReadChannels, ChannelsReader, LayerReader, SelectedLayersReader};
+use crate::image::read::image::ReadLayers;
+use crate::image::multi_view::{layer_contains_view, channels_in_view};
+use crate::meta::header::{Header, LayerAttributes};
+use crate::meta::attribute::{ChannelList, ChannelDescription};
+use crate::block::{UncompressedBlock, UncompressedDeepBlock};
+use crate::block::chunk::TileCoordinates;
+use crate::error::{Error, Result, UnitResult};
+
+
+/// Specify to read only the layers that contain a specific view of a multi-view image.
+/// Of single-part multi-view layers, only the channels of the view are read, with the view removed from their names.
+/// The chunks of all other layers are skipped without being decompressed.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ReadView<ReadChannels> {
+
+    /// The channel reading specification, which receives only the channels of the view
+    pub read_channels: ReadChannels,
+
+    /// The name of the view to be read, for example `right`
+    pub view: Text,
+}
+
+/// Processes pixel blocks from a file, passing only the channels of a single view to the channels reader.
+#[derive(Debug, Clone)]
+pub struct ViewChannelsReader<ChannelsReader> {
+    channels_reader: ChannelsReader,
+    single_part_view: Option<SinglePartView>, // none if the layer contains no other views
+}
+
+/// The channels of a single view inside a single-part multi-view layer.
+#[derive(Debug, Clone)]
+struct SinglePartView {
+
+    /// The header that the channels reader receives, containing only the channels of the view.
+    view_header: Header,
+
+    /// For each channel in the view header, the index of the channel in the original header.
+    channel_indices: SmallVec<[usize; 8]>,
+}
+
+impl<'s, C> ReadLayers<'s> for ReadView<C> where C: ReadChannels<'s> {
+    type Layers = Layers<<C::Reader as ChannelsReader>::Channels>;
+    type Reader = SelectedLayersReader<ViewChannelsReader<C::Reader>>;
+
+    fn create_layers_reader(&'s self, headers: &[Header]) -> Result<Self::Reader> {
+        let readers: Result<_> = headers.iter()
+            .map(|header| {
+                if !layer_contains_view(&header.own_attributes, &self.view) { return Ok(None) }
+                self.create_view_reader(header).map(Some)
+            })
+            .collect();
+
+        Ok(SelectedLayersReader {
+            layer_readers: readers?
+        })
+    }
+}
+
+impl<'s, C> ReadView<C> where C: ReadChannels<'s> {
+    fn create_view_reader(&'s self, header: &Header) -> Result<LayerReader<ViewChannelsReader<C::Reader>>> {
+        let views = match (&header.own_attributes.view_name, &header.own_attributes.multi_view_names) {
+            (None, Some(views)) => views,
+
+            // the layer contains only this view
+            _ => return LayerReader::new(header, ViewChannelsReader {
+                channels_reader: self.read_channels.create_channels_reader(header)?,
+                single_part_view: None,
+            }),
+        };
+
+        if header.deep {
+            return Err(Error::unsupported("reading a view of a deep single-part multi-view layer"));
+        }
+
+        let mut channels = channels_in_view(header.channels.list.iter().map(|channel| &channel.name), &self.view, views);
+        channels.sort_unstable_by(|(_, name), (_, other_name)| name.cmp(other_name));
+
+        let view_channels = channels.iter()
+            .map(|(index, name)| ChannelDescription { name: name.clone(), .. header.channels.list[*index].clone() })
+            .collect();
+
+        let view_header = Header {
+            channels: ChannelList::new(view_channels),
+
+            own_attributes: LayerAttributes {
+                multi_view_names: None,
+                view_name: Some(self.view.clone()),
+                .. header.own_attributes.clone()
+            },
+
+            .. header.clone()
+        };
+
+        let single_part_view = SinglePartView {
+            channel_indices: channels.iter().map(|&(index, _)| index).collect(),
+            view_header: view_header.clone(),
+        };
+
+        LayerReader::new(&view_header, ViewChannelsReader {
+            channels_reader: self.read_channels.create_channels_reader(&view_header)?,
+            single_part_view: Some(single_part_view),
+        })
+    }
+}
+
+impl<InnerReader: ChannelsReader> ChannelsReader for ViewChannelsReader<InnerReader> {
+    type Channels = InnerReader::Channels;
+
+    fn filter_block(&self, tile: TileCoordinates) -> bool {
+        self.channels_reader.filter_block(tile)
+    }
+
+    fn read_block(&mut self, header: &Header, block: UncompressedBlock) -> UnitResult {
+        let view = match &self.single_part_view {
+            Some(view) => view,
+            None => return self.channels_reader.read_block(header, block),
+        };
+
+        let view_block = block.select_channels(&header.channels, &view.view_header.channels, &view.channel_indices);
+        self.channels_reader.read_block(&view.view_header, view_block)
+    }
+
+    fn read_deep_block(&mut self, header: &Header, block: UncompressedDeepBlock) -> UnitResult {
+        self.channels_reader.read_deep_block(header, block)
+    }
+
+    fn into_channels(self) -> Self::Channels {
+        self.channels_reader.into_channels()
+    }
+}
@@ -0,0 +1,179 @@
+//! How to read only a rectangular section of each layer.
+//! Only the blocks that overlap the section are decompressed.
+
+use crate::image::*;
+use crate::meta::header::Header;
+use crate::meta::{MetaData, Headers, BlockDescription, compute_chunk_count};
+use crate::meta::attribute::{IntegerBounds, LevelMode};
+use crate::error::{Result, UnitResult, Error};
+use crate::block::{UncompressedBlock, UncompressedDeepBlock, BlockIndex};
+use crate::block::chunk::TileCoordinates;
+use crate::image::read::image::{ReadLayers, LayersReader};
+use crate::math::Vec2;
+
+/// Specify to read only the pixels inside a rectangle of each layer.
+/// The data window of each resulting layer is this rectangle.
+/// Pixels of the rectangle that are outside of the original data window keep their initial value.
+/// Only the largest resolution level is read.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ReadRegion<ReadLayers> {
+
+    /// The rectangle, in the same coordinate system as the data window of each layer.
+    pub region: IntegerBounds,
+
+    /// The layers reading specification
+    pub read_layers: ReadLayers,
+}
+
+/// Processes pixel blocks from a file, cropping them to a rectangle,
+/// and accumulates them into the layers of the inner reader.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RegionReader<LayersReader> {
+    region: IntegerBounds,
+    cropped_headers: Headers,
+    layers_reader: LayersReader,
+}
+
+impl<'s, L> ReadLayers<'s> for ReadRegion<L> where L: ReadLayers<'s> {
+    type Layers = L::Layers;
+    type Reader = RegionReader<L::Reader>;
+
+    fn create_layers_reader(&'s self, headers: &[Header]) -> Result<Self::Reader> {
+        self.region.validate(None)?;
+
+        let has_subsampling = headers.iter()
+            .flat_map(|header| header.channels.list.iter())
+            .any(|channel| channel.sampling != Vec2(1, 1));
+
+        if has_subsampling {
+            return Err(Error::unsupported("reading a region of subsampled channels"));
+        }
+
+        let cropped_headers: Headers = headers.iter()
+            .map(|header| crop_header(header, self.region))
+            .collect();
+
+        Ok(RegionReader {
+            region: self.region,
+            layers_reader: self.read_layers.create_layers_reader(&cropped_headers)?,
+            cropped_headers,
+        })
+    }
+}
+
+impl<L> LayersReader for RegionReader<L> where L: LayersReader {
+    type Layers = L::Layers;
+
+    fn filter_block(&self, meta: &MetaData, tile: TileCoordinates, block: BlockIndex) -> bool {
+        let header = &meta.headers[block.layer];
+
+        let overlaps_region = header.get_absolute_block_pixel_coordinates(tile)
+            .map(|bounds| bounds.with_origin(header.own_attributes.layer_position))
+            .map_or(true, |bounds| bounds.intersection(self.region).is_some()); // invalid tiles are reported by the decompressor
+
+        tile.is_largest_resolution_level() && overlaps_region
+            && self.layers_reader.filter_block(meta, tile, block)
+    }
+
+    fn read_block(&mut self, headers: &[Header], block: UncompressedBlock) -> UnitResult {
+        let header = &headers[block.index.layer];
+        let (block_bounds, cropped_index) = match self.crop_block_index(header, block.index) {
+            Some(bounds) => bounds,
+            None => return Ok(()),
+        };
+
+        let start_x = (cropped_index.pixel_position.x() as i32 + self.region.position.x() - block_bounds.position.x()) as usize;
+        let start_y = (cropped_index.pixel_position.y() as i32 + self.region.position.y() - block_bounds.position.y()) as usize;
+        let end_y = start_y + cropped_index.pixel_size.height();
+
+        let mut data = Vec::with_capacity(cropped_index.pixel_size.area() * header.channels.bytes_per_pixel);
+
+        for line in block.lines(&header.channels) {
+            let y = line.location.position.y() - block.index.pixel_position.y();
+            if y < start_y || y >= end_y { continue; }
+
+            let bytes_per_sample = header.channels.list[line.location.channel].sample_type.bytes_per_sample();
+            let start_byte = start_x * bytes_per_sample;
+            data.extend_from_slice(&line.value[start_byte .. start_byte + cropped_index.pixel_size.width() * bytes_per_sample]);
+        }
+
+        self.layers_reader.read_block(&self.cropped_headers, UncompressedBlock { index: cropped_index, data })
+    }
+
+    fn read_deep_block(&mut self, headers: &[Header], block: UncompressedDeepBlock) -> UnitResult {
+        let header = &headers[block.index.layer];
+        let (block_bounds, cropped_index) = match self.crop_block_index(header, block.index) {
+            Some(bounds) => bounds,
+            None => return Ok(()),
+        };
+
+        let start_x = (cropped_index.pixel_position.x() as i32 + self.region.position.x() - block_bounds.position.x()) as usize;
+        let start_y = (cropped_index.pixel_position.y() as i32 + self.region.position.y() - block_bounds.position.y()) as usize;
+        let end_x = start_x + cropped_index.pixel_size.width();
+        let end_y = start_y + cropped_index.pixel_size.height();
+
+        let sample_counts: Vec<u32> = block.sample_counts
+            .chunks_exact(block.index.pixel_size.width().max(1))
+            .take(end_y).skip(start_y)
+            .flat_map(|line| line[start_x .. end_x].iter().copied())
+            .collect();
+
+        let mut sample_data = Vec::new();
+
+        for line in block.lines(&header.channels) {
+            let y = line.location.position.y() - block.index.pixel_position.y();
+            if y < start_y || y >= end_y { continue; }
+
+            let bytes_per_sample = header.channels.list[line.location.channel].sample_type.bytes_per_sample();
+            let skipped_samples: usize = line.sample_counts[.. start_x].iter().map(|&count| count as usize).sum();
+            let cropped_samples: usize = line.sample_counts[start_x .. end_x].iter().map(|&count| count as usize).sum();
+
+            let start_byte = skipped_samples * bytes_per_sample;
+            sample_data.extend_from_slice(&line.value[start_byte .. start_byte + cropped_samples * bytes_per_sample]);
+        }
+
+        self.layers_reader.read_deep_block(
+            &self.cropped_headers,
+            UncompressedDeepBlock { index: cropped_index, sample_counts, sample_data }
+        )
+    }
+
+    fn into_layers(self) -> Self::Layers {
+        self.layers_reader.into_layers()
+    }
+}
+
+impl<L> RegionReader<L> {
+
+    /// Returns the absolute bounds of the block, and the index of the part of the block inside the region,
+    /// relative to the region. Returns `None` if the block is outside of the region.
+    fn crop_block_index(&self, header: &Header, index: BlockIndex) -> Option<(IntegerBounds, BlockIndex)> {
+        let block_bounds = IntegerBounds::new(index.pixel_position.to_i32(), index.pixel_size)
+            .with_origin(header.own_attributes.layer_position);
+
+        let cropped = block_bounds.intersection(self.region)?;
+        let position = cropped.position - self.region.position;
+
+        Some((block_bounds, BlockIndex {
+            pixel_position: Vec2(position.x() as usize, position.y() as usize),
+            pixel_size: cropped.size,
+            .. index
+        }))
+    }
+}
+
+/// Replace the data window of the header with the region, keeping only the largest resolution level.
+fn crop_header(header: &Header, region: IntegerBounds) -> Header {
+    let blocks = match header.blocks {
+        BlockDescription::Tiles(tiles) => BlockDescription::Tiles(TileDescription { level_mode: LevelMode::Singular, .. tiles }),
+        BlockDescription::ScanLines => BlockDescription::ScanLines,
+    };
+
+    Header {
+        blocks,
+        layer_size: region.size,
+        chunk_count: compute_chunk_count(header.compression, region.size, blocks),
+        own_attributes: LayerAttributes { layer_position: region.position, .. header.own_attributes.clone() },
+        .. header.clone()
+    }
+}
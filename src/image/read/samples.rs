@@ -2,20 +2,24 @@
 
 use crate::image::*;
 use crate::meta::header::{Header};
-use crate::error::{Result, UnitResult};
-use crate::block::lines::LineRef;
+use crate::error::{Result, UnitResult, Error};
+use crate::block::lines::{LineRef, DeepLineRef};
 use crate::math::Vec2;
 use crate::meta::attribute::{ChannelDescription, SampleType};
 use crate::image::read::any_channels::{SamplesReader, ReadSamples};
-use crate::image::read::levels::{ReadSamplesLevel, ReadAllLevels, ReadLargestLevel};
+use crate::image::read::levels::{ReadSamplesLevel, ReadAllLevels, ReadLargestLevel, ReadLevelBy, closest_level};
 use crate::block::chunk::TileCoordinates;
 // use crate::image::read::layers::ReadChannels;
 
-/// Specify to read only flat samples and no "deep data"
-// FIXME do not throw error on deep data but just skip it!
+/// Specify to read only flat samples and no "deep data".
+/// Layers containing deep data are rejected.
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct ReadFlatSamples;
-// pub struct ReadAnySamples;
+
+/// Specify to read flat samples and "deep data".
+/// Each channel will contain either `DeepSamples` or `FlatSamples`, depending on its layer.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct ReadAnySamples;
 
 impl ReadFlatSamples {
 
@@ -29,14 +33,41 @@ impl ReadFlatSamples {
     /// Specify to read all contained resolution levels from the image, if any.
     pub fn all_resolution_levels(self) -> ReadAllLevels<Self> { ReadAllLevels { read_samples: self } }
 
-    // TODO pub fn specific_resolution_level<F: Fn(&[Vec2<usize>])->usize >(self, select_level: F) -> ReadLevelBy<Self> { ReadAllLevels { read_samples: self } }
+    /// Specify to read only one resolution level of each layer, chosen by the specified function.
+    /// The function receives the resolutions of all levels of a layer, starting with the largest level,
+    /// and returns the index of the level to be read. Only the blocks of that level are decompressed.
+    pub fn specific_resolution_level<F>(self, select_level: F) -> ReadLevelBy<Self, F> where F: Fn(&[Vec2<usize>]) -> usize {
+        ReadLevelBy { read_samples: self, select_level }
+    }
+
+    /// Specify to read only the resolution level of each layer with the width and height closest to the specified size.
+    /// See `specific_resolution_level` for details.
+    pub fn closest_resolution_level(self, target_size: impl Into<Vec2<usize>>) -> ReadLevelBy<Self, impl Fn(&[Vec2<usize>]) -> usize> {
+        self.specific_resolution_level(closest_level(target_size.into()))
+    }
 }
 
+impl ReadAnySamples {
 
-/*pub struct AnySamplesReader { TODO
-    resolution: Vec2<usize>,
-    samples: DeepAndFlatSamples
-}*/
+    /// Specify to read only the highest resolution level, skipping all smaller variations.
+    pub fn largest_resolution_level(self) -> ReadLargestLevel<Self> { ReadLargestLevel { read_samples: self } }
+
+    /// Specify to read all contained resolution levels from the image, if any.
+    pub fn all_resolution_levels(self) -> ReadAllLevels<Self> { ReadAllLevels { read_samples: self } }
+
+    /// Specify to read only one resolution level of each layer, chosen by the specified function.
+    /// The function receives the resolutions of all levels of a layer, starting with the largest level,
+    /// and returns the index of the level to be read. Only the blocks of that level are decompressed.
+    pub fn specific_resolution_level<F>(self, select_level: F) -> ReadLevelBy<Self, F> where F: Fn(&[Vec2<usize>]) -> usize {
+        ReadLevelBy { read_samples: self, select_level }
+    }
+
+    /// Specify to read only the resolution level of each layer with the width and height closest to the specified size.
+    /// See `specific_resolution_level` for details.
+    pub fn closest_resolution_level(self, target_size: impl Into<Vec2<usize>>) -> ReadLevelBy<Self, impl Fn(&[Vec2<usize>]) -> usize> {
+        self.specific_resolution_level(closest_level(target_size.into()))
+    }
+}
 
 /// Processes pixel blocks from a file and accumulates them into a grid of samples, for example "Red" or "Alpha".
 #[derive(Debug, Clone, PartialEq)]
@@ -52,16 +83,20 @@ impl ReadSamples for ReadFlatSamples {
     type Reader = FlatSamplesReader;
 
     fn create_sample_reader(&self, header: &Header, channel: &ChannelDescription) -> Result<Self::Reader> {
-        self.create_samples_level_reader(header, channel, Vec2(0, 0), header.layer_size)
+        self.create_samples_level_reader(header, channel, Vec2(0, 0), channel.subsampled_resolution(header.layer_size))
     }
 }
 
 impl ReadSamplesLevel for ReadFlatSamples {
     type Reader = FlatSamplesReader;
 
-    fn create_samples_level_reader(&self, _header: &Header, channel: &ChannelDescription, level: Vec2<usize>, resolution: Vec2<usize>) -> Result<Self::Reader> {
+    fn create_samples_level_reader(&self, header: &Header, channel: &ChannelDescription, level: Vec2<usize>, resolution: Vec2<usize>) -> Result<Self::Reader> {
+        if header.deep {
+            return Err(Error::unsupported("deep data cannot be read as flat samples"));
+        }
+
         Ok(FlatSamplesReader {
-            level, resolution, // TODO sampling
+            level, resolution,
             samples: match channel.sample_type {
                 SampleType::F16 => FlatSamples::F16(vec![f16::ZERO; resolution.area()]),
                 SampleType::F32 => FlatSamples::F32(vec![0.0; resolution.area()]),
@@ -120,3 +155,186 @@ impl SamplesReader for FlatSamplesReader {
     }
 }
 
+
+/// Processes pixel blocks from a file and accumulates them into a grid of deep samples,
+/// where each pixel can contain any number of samples.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeepSamplesReader {
+    level: Vec2<usize>,
+    resolution: Vec2<usize>,
+
+    /// The lines in the order they were read from the file.
+    lines: Vec<DeepSamplesLine>,
+
+    /// The samples of all lines in the order they were read from the file.
+    values: FlatSamples,
+}
+
+/// A line of deep pixels that has been read by a `DeepSamplesReader`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct DeepSamplesLine {
+    position: Vec2<usize>,
+    sample_counts: Vec<u32>,
+    values: std::ops::Range<usize>,
+}
+
+/// Processes pixel blocks from a file and accumulates them into either deep or flat samples.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AnySamplesReader {
+
+    /// Reads a channel of a deep layer.
+    Deep(DeepSamplesReader),
+
+    /// Reads a channel of a flat layer.
+    Flat(FlatSamplesReader),
+}
+
+
+// only used when samples is directly inside a channel, without levels
+impl ReadSamples for ReadAnySamples {
+    type Reader = AnySamplesReader;
+
+    fn create_sample_reader(&self, header: &Header, channel: &ChannelDescription) -> Result<Self::Reader> {
+        self.create_samples_level_reader(header, channel, Vec2(0, 0), channel.subsampled_resolution(header.layer_size))
+    }
+}
+
+impl ReadSamplesLevel for ReadAnySamples {
+    type Reader = AnySamplesReader;
+
+    fn create_samples_level_reader(&self, header: &Header, channel: &ChannelDescription, level: Vec2<usize>, resolution: Vec2<usize>) -> Result<Self::Reader> {
+        if header.deep {
+            Ok(AnySamplesReader::Deep(DeepSamplesReader {
+                level, resolution,
+                lines: Vec::new(),
+                values: match channel.sample_type {
+                    SampleType::F16 => FlatSamples::F16(Vec::new()),
+                    SampleType::F32 => FlatSamples::F32(Vec::new()),
+                    SampleType::U32 => FlatSamples::U32(Vec::new()),
+                }
+            }))
+        }
+
+        else {
+            ReadFlatSamples.create_samples_level_reader(header, channel, level, resolution)
+                .map(AnySamplesReader::Flat)
+        }
+    }
+}
+
+
+impl SamplesReader for DeepSamplesReader {
+    type Samples = DeepSamples;
+
+    fn filter_block(&self, tile: TileCoordinates) -> bool {
+        tile.level_index == self.level
+    }
+
+    fn read_line(&mut self, _: LineRef<'_>) -> UnitResult {
+        Err(Error::invalid("flat line in deep channel"))
+    }
+
+    fn read_deep_line(&mut self, line: DeepLineRef<'_>) -> UnitResult {
+        let index = line.location;
+
+        // the index is generated by ourselves and must always be correct
+        debug_assert_eq!(index.level, self.level, "line should have been filtered");
+        debug_assert!(index.position.x() + index.sample_count <= self.resolution.width(), "line index calculation bug");
+        debug_assert!(index.position.y() < self.resolution.height(), "line index calculation bug");
+        debug_assert_eq!(line.sample_counts.len(), index.sample_count, "line sample counts bug");
+
+        let start_index = self.values.len();
+        let end_index = start_index + line.total_sample_count();
+
+        match &mut self.values {
+            FlatSamples::F16(samples) => {
+                samples.resize(end_index, f16::ZERO);
+                line.read_samples_into_slice(&mut samples[start_index .. end_index])?;
+            },
+
+            FlatSamples::F32(samples) => {
+                samples.resize(end_index, 0.0);
+                line.read_samples_into_slice(&mut samples[start_index .. end_index])?;
+            },
+
+            FlatSamples::U32(samples) => {
+                samples.resize(end_index, 0);
+                line.read_samples_into_slice(&mut samples[start_index .. end_index])?;
+            },
+        }
+
+        self.lines.push(DeepSamplesLine {
+            position: index.position,
+            sample_counts: line.sample_counts.to_vec(),
+            values: start_index .. end_index,
+        });
+
+        Ok(())
+    }
+
+    fn into_samples(self) -> DeepSamples {
+        let DeepSamplesReader { resolution, mut lines, values, .. } = self;
+
+        // the lines may have been read in any order, but the pixels are stored row after row
+        lines.sort_by_key(|line| (line.position.y(), line.position.x()));
+
+        let mut sample_counts = vec![0_u32; resolution.area()];
+        for line in &lines {
+            let start_index = line.position.flat_index_for_size(resolution);
+            sample_counts[start_index .. start_index + line.sample_counts.len()].copy_from_slice(&line.sample_counts);
+        }
+
+        let mut sample_offsets = Vec::with_capacity(sample_counts.len() + 1);
+        sample_offsets.push(0);
+
+        for count in sample_counts {
+            let previous_offset = *sample_offsets.last().expect("offsets should not be empty");
+            sample_offsets.push(previous_offset + count as usize);
+        }
+
+        fn collect_lines<T: Copy>(samples: &[T], lines: &[DeepSamplesLine]) -> Vec<T> {
+            lines.iter().flat_map(|line| samples[line.values.clone()].iter().copied()).collect()
+        }
+
+        DeepSamples {
+            sample_offsets,
+            values: match values {
+                FlatSamples::F16(samples) => FlatSamples::F16(collect_lines(&samples, &lines)),
+                FlatSamples::F32(samples) => FlatSamples::F32(collect_lines(&samples, &lines)),
+                FlatSamples::U32(samples) => FlatSamples::U32(collect_lines(&samples, &lines)),
+            }
+        }
+    }
+}
+
+impl SamplesReader for AnySamplesReader {
+    type Samples = DeepAndFlatSamples;
+
+    fn filter_block(&self, tile: TileCoordinates) -> bool {
+        match self {
+            AnySamplesReader::Deep(reader) => reader.filter_block(tile),
+            AnySamplesReader::Flat(reader) => reader.filter_block(tile),
+        }
+    }
+
+    fn read_line(&mut self, line: LineRef<'_>) -> UnitResult {
+        match self {
+            AnySamplesReader::Deep(reader) => reader.read_line(line),
+            AnySamplesReader::Flat(reader) => reader.read_line(line),
+        }
+    }
+
+    fn read_deep_line(&mut self, line: DeepLineRef<'_>) -> UnitResult {
+        match self {
+            AnySamplesReader::Deep(reader) => reader.read_deep_line(line),
+            AnySamplesReader::Flat(reader) => reader.read_deep_line(line),
+        }
+    }
+
+    fn into_samples(self) -> DeepAndFlatSamples {
+        match self {
+            AnySamplesReader::Deep(reader) => DeepAndFlatSamples::Deep(reader.into_samples()),
+            AnySamplesReader::Flat(reader) => DeepAndFlatSamples::Flat(reader.into_samples()),
+        }
+    }
+}
@@ -10,6 +10,7 @@ use crate::error::*;
 use crate::block::UncompressedBlock;
 use crate::image::read::layers::{ChannelsReader, ReadChannels};
 use crate::block::chunk::TileCoordinates;
+use crate::image::read::levels::create_levels;
 
 use std::marker::PhantomData;
 
@@ -24,7 +25,8 @@ pub trait ReadSpecificChannel: Sized + CheckDuplicates {
     type RecursivePixelReader: RecursivePixelReader;
 
     /// Create a separate internal reader for the pixels of the specific channel combination.
-    fn create_recursive_reader(&self, channels: &ChannelList) -> Result<Self::RecursivePixelReader>;
+    /// The policy decides which channel in the layer satisfies each requested channel.
+    fn create_recursive_reader(&self, channels: &ChannelList, names: &ChannelNamePolicy) -> Result<Self::RecursivePixelReader>;
 
     /// Plan to read an additional channel from the image, with the specified name.
     /// If the channel cannot be found in the image when the image is read, the image will not be loaded.
@@ -65,7 +67,7 @@ pub trait ReadSpecificChannel: Sized + CheckDuplicates {
             ) -> PixelStorage,
             SetPixel: Fn(&mut PixelStorage, Vec2<usize>, Pixel),
     {
-        CollectPixels { read_channels: self, set_pixel, create_pixels, px: Default::default() }
+        CollectPixels { read_channels: self, set_pixel, create_pixels, channel_names: ChannelNamePolicy::exact(), px: Default::default() }
     }
 }
 
@@ -87,6 +89,16 @@ pub trait RecursivePixelReader {
         &self, bytes: &'s[u8], pixels: &mut [FullPixel],
         get_pixel: impl Fn(&mut FullPixel) -> &mut Self::RecursivePixel
     );
+
+    /// Append the requested name and the source name of each channel that is read from a channel with another name.
+    fn collect_source_names(&self, source_names: &mut SmallVec<[(Text, Text); 4]>);
+}
+
+/// The requested name and the source name of each channel that is read from a channel with another name.
+fn source_names(pixel_reader: &impl RecursivePixelReader) -> SmallVec<[(Text, Text); 4]> {
+    let mut source_names = SmallVec::new();
+    pixel_reader.collect_source_names(&mut source_names);
+    source_names
 }
 
 // does not use the generic `Recursive` struct to reduce the number of angle brackets in the public api
@@ -110,14 +122,93 @@ pub struct ReadRequiredChannel<ReadChannels, Sample> {
 }
 
 /// Specifies how to collect all the specified channels into a number of individual pixels.
-#[derive(Copy, Clone, Debug)]
+#[derive(Clone, Debug)]
 pub struct CollectPixels<ReadChannels, Pixel, PixelStorage, CreatePixels, SetPixel> {
     read_channels: ReadChannels,
     create_pixels: CreatePixels,
     set_pixel: SetPixel,
+    channel_names: ChannelNamePolicy,
     px: PhantomData<(Pixel, PixelStorage)>,
 }
 
+/// Decides which channel in a layer satisfies a requested channel.
+/// By default, only the channel with exactly the requested name is used.
+/// The channel descriptions in the resulting `SpecificChannels` contain the requested channel names,
+/// so that writing the channels again produces the requested channels, for example `R`, `G` and `B` instead of `Y` three times.
+/// All other properties, like the sample type, are copied from the source channel that satisfied the requested channel.
+/// The `source_names` of the resulting `SpecificChannels` report which source channel was used.
+///
+/// ```
+/// use exr::prelude::*;
+///
+/// // also read `red`, `Red` or `r` into `R`, and fall back to `Y` for all color channels
+/// let names = ChannelNamePolicy::exact().ignore_case()
+///     .with_aliases("R", ["red", "Y"])
+///     .with_aliases("G", ["green", "Y"])
+///     .with_aliases("B", ["blue", "Y"]);
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ChannelNamePolicy {
+
+    /// Whether channel names are compared ignoring their case.
+    pub ignore_case: bool,
+
+    /// Alternative names of requested channels, tried in order if no channel with the requested name exists.
+    /// Contains the requested name and its aliases.
+    pub aliases: SmallVec<[(Text, SmallVec<[Text; 2]>); 4]>,
+}
+
+impl ChannelNamePolicy {
+
+    /// Only the channel with exactly the requested name satisfies a requested channel.
+    pub fn exact() -> Self { Self::default() }
+
+    /// Names that are commonly used for rgba channels, ignoring case:
+    /// `R|red`, `G|green`, `B|blue` and `A|alpha`. Greyscale images are read by using
+    /// the luminance channel `Y` instead of each of red, green, and blue that does not exist.
+    pub fn rgba() -> Self {
+        Self::exact().ignore_case()
+            .with_aliases("R", ["red", "Y"])
+            .with_aliases("G", ["green", "Y"])
+            .with_aliases("B", ["blue", "Y"])
+            .with_aliases("A", ["alpha"])
+    }
+
+    /// Compare channel names ignoring their case, for example, also read the channel `r` when `R` is requested.
+    pub fn ignore_case(self) -> Self { Self { ignore_case: true, .. self } }
+
+    /// When the requested channel does not exist, use the first existing channel with one of the alternative names.
+    /// Calling this again for the same requested channel appends more alternative names.
+    pub fn with_aliases<N: Into<Text>>(mut self, requested: impl Into<Text>, aliases: impl IntoIterator<Item=N>) -> Self {
+        let requested = requested.into();
+        let aliases = aliases.into_iter().map(Into::into);
+
+        match self.aliases.iter_mut().find(|(name, _)| name == &requested) {
+            Some((_, existing)) => existing.extend(aliases),
+            None => self.aliases.push((requested, aliases.collect())),
+        }
+
+        self
+    }
+
+    /// Find the channel that satisfies the requested channel, and its byte offset within a pixel.
+    /// Tries the requested name first, then all aliases in order.
+    pub fn find_channel<'c>(&self, requested: &Text, channels: &'c ChannelList) -> Option<(usize, &'c ChannelDescription)> {
+        let aliases = self.aliases.iter()
+            .find(|(name, _)| name == requested)
+            .map(|(_, aliases)| aliases.as_slice())
+            .unwrap_or(&[]);
+
+        std::iter::once(requested).chain(aliases).find_map(|name| {
+            channels.channels_with_byte_offset().find(|(_, channel)| &channel.name == name)
+                .or_else(|| if !self.ignore_case { None } else {
+                    let name = name.to_string();
+                    channels.channels_with_byte_offset().find(|(_, channel)| channel.name.eq_case_insensitive(&name))
+                })
+        })
+    }
+}
+
 impl<Inner: CheckDuplicates, Sample> CheckDuplicates for ReadRequiredChannel<Inner, Sample> {
     fn already_contains(&self, name: &Text) -> bool {
         &self.channel_name == name || self.previous_channels.already_contains(name)
@@ -130,6 +221,15 @@ impl<Inner: CheckDuplicates, Sample> CheckDuplicates for ReadOptionalChannel<Inn
     }
 }
 
+impl<ReadChannels, Pixel, PixelStorage, CreatePixels, SetPixel> CollectPixels<ReadChannels, Pixel, PixelStorage, CreatePixels, SetPixel> {
+
+    /// Specify which channels in a layer satisfy the requested channels, for example to also accept lowercase names.
+    /// By default, only channels with exactly the requested names are read.
+    pub fn with_channel_names(self, channel_names: ChannelNamePolicy) -> Self {
+        CollectPixels { channel_names, .. self }
+    }
+}
+
 impl<'s, InnerChannels, Pixel, PixelStorage, CreatePixels, SetPixel: 's>
 ReadChannels<'s> for CollectPixels<InnerChannels, Pixel, PixelStorage, CreatePixels, SetPixel>
     where
@@ -148,7 +248,7 @@ ReadChannels<'s> for CollectPixels<InnerChannels, Pixel, PixelStorage, CreatePix
     fn create_channels_reader(&'s self, header: &Header) -> Result<Self::Reader> {
         if header.deep { return Err(Error::invalid("`SpecificChannels` does not support deep data yet")) }
 
-        let pixel_reader = self.read_channels.create_recursive_reader(&header.channels)?;
+        let pixel_reader = self.read_channels.create_recursive_reader(&header.channels, &self.channel_names)?;
         let channel_descriptions = pixel_reader.get_descriptions().into_non_recursive();// TODO not call this twice
 
         let create = &self.create_pixels;
@@ -156,6 +256,7 @@ ReadChannels<'s> for CollectPixels<InnerChannels, Pixel, PixelStorage, CreatePix
 
         Ok(SpecificChannelsReader {
             set_pixel: &self.set_pixel,
+            subsampled_layer: SubsampledLayer::new(header),
             pixel_storage,
             pixel_reader,
             px: Default::default()
@@ -164,9 +265,10 @@ ReadChannels<'s> for CollectPixels<InnerChannels, Pixel, PixelStorage, CreatePix
 }
 
 /// The reader that holds the temporary data that is required to read some specified channels.
-#[derive(Copy, Clone, Debug)]
+#[derive(Clone, Debug)]
 pub struct SpecificChannelsReader<PixelStorage, SetPixel, PixelReader, Pixel> {
     set_pixel: SetPixel,
+    subsampled_layer: Option<SubsampledLayer>,
     pixel_storage: PixelStorage,
     pixel_reader: PixelReader,
     px: PhantomData<Pixel>
@@ -184,26 +286,269 @@ ChannelsReader for SpecificChannelsReader<PixelStorage, SetPixel, PxReader, Pixe
     fn filter_block(&self, tile: TileCoordinates) -> bool { tile.is_largest_resolution_level() } // TODO all levels
 
     fn read_block(&mut self, header: &Header, block: UncompressedBlock) -> UnitResult {
-        let mut pixels = vec![PxReader::RecursivePixel::default(); block.index.pixel_size.width()]; // TODO allocate once in self
+        match &mut self.subsampled_layer {
+            Some(subsampled_layer) => subsampled_layer.read_block(&block),
+            None => read_pixel_block(&self.pixel_reader, &self.set_pixel, &mut self.pixel_storage, header, block),
+        }
 
-        let byte_lines = block.data.chunks_exact(header.channels.bytes_per_pixel * block.index.pixel_size.width());
-        debug_assert_eq!(byte_lines.len(), block.index.pixel_size.height(), "invalid block lines split");
+        Ok(())
+    }
 
-        for (y_offset, line_bytes) in byte_lines.enumerate() { // TODO sampling
-            // this two-step copy method should be very cache friendly in theory, and also reduce sample_type lookup count
-            self.pixel_reader.read_pixels(line_bytes, &mut pixels, |px| px);
+    fn into_channels(mut self) -> Self::Channels {
+        if let Some(subsampled_layer) = &self.subsampled_layer {
+            subsampled_layer.read_pixels(&self.pixel_reader, &self.set_pixel, &mut self.pixel_storage);
+        }
 
-            for (x_offset, pixel) in pixels.iter().enumerate() {
-                let set_pixel = &self.set_pixel;
-                set_pixel(&mut self.pixel_storage, block.index.pixel_position + Vec2(x_offset, y_offset), pixel.into_tuple());
+        SpecificChannels {
+            channels: self.pixel_reader.get_descriptions().into_non_recursive(),
+            source_names: source_names(&self.pixel_reader),
+            pixels: self.pixel_storage,
+        }
+    }
+}
+
+
+/// Read the pixels of a block into the pixel storage, using the block position within its resolution level.
+fn read_pixel_block<PixelStorage, Pixel, PxReader>(
+    pixel_reader: &PxReader, set_pixel: impl Fn(&mut PixelStorage, Vec2<usize>, Pixel),
+    pixel_storage: &mut PixelStorage, header: &Header, block: UncompressedBlock
+)
+    where PxReader: RecursivePixelReader, PxReader::RecursivePixel: IntoTuple<Pixel>,
+{
+    let mut pixels = vec![PxReader::RecursivePixel::default(); block.index.pixel_size.width()]; // TODO allocate once in self
+
+    let byte_lines = block.data.chunks_exact(header.channels.bytes_per_pixel * block.index.pixel_size.width());
+    debug_assert_eq!(byte_lines.len(), block.index.pixel_size.height(), "invalid block lines split");
+
+    for (y_offset, line_bytes) in byte_lines.enumerate() {
+        // this two-step copy method should be very cache friendly in theory, and also reduce sample_type lookup count
+        pixel_reader.read_pixels(line_bytes, &mut pixels, |px| px);
+
+        for (x_offset, pixel) in pixels.iter().enumerate() {
+            set_pixel(pixel_storage, block.index.pixel_position + Vec2(x_offset, y_offset), pixel.into_tuple());
+        }
+    }
+}
+
+/// Collects the samples of a layer that contains subsampled channels.
+/// The pixels can only be assembled after all blocks have been read,
+/// because a block does not necessarily contain the samples that its pixels require.
+/// Each pixel uses the closest sample above and to the left of it.
+#[derive(Clone, Debug)]
+struct SubsampledLayer {
+    channels: ChannelList,
+    size: Vec2<usize>,
+
+    /// The native-endian sample bytes of each channel, in the reduced resolution of that channel.
+    samples: SmallVec<[Vec<u8>; 4]>,
+}
+
+impl SubsampledLayer {
+
+    /// Returns none if the layer contains no subsampled channels.
+    fn new(header: &Header) -> Option<Self> {
+        if header.channels.list.iter().all(|channel| channel.sampling == Vec2(1, 1)) {
+            return None;
+        }
+
+        Some(SubsampledLayer {
+            channels: header.channels.clone(),
+            size: header.layer_size,
+            samples: header.channels.list.iter()
+                .map(|channel| vec![0; channel.subsampled_pixels(header.layer_size) * channel.sample_type.bytes_per_sample()])
+                .collect(),
+        })
+    }
+
+    fn read_block(&mut self, block: &UncompressedBlock) {
+        for line in block.lines(&self.channels) {
+            let channel = &self.channels.list[line.location.channel];
+            let width = channel.subsampled_resolution(self.size).width();
+            let start = (line.location.position.y() * width + line.location.position.x()) * channel.sample_type.bytes_per_sample();
+
+            self.samples[line.location.channel][start .. start + line.value.len()].copy_from_slice(line.value);
+        }
+    }
+
+    /// Expand the subsampled channels to the full resolution, line by line, and then read all pixels.
+    fn read_pixels<PixelStorage, Pixel, PxReader>(
+        &self, pixel_reader: &PxReader, set_pixel: impl Fn(&mut PixelStorage, Vec2<usize>, Pixel),
+        pixel_storage: &mut PixelStorage
+    )
+        where PxReader: RecursivePixelReader, PxReader::RecursivePixel: IntoTuple<Pixel>,
+    {
+        let mut pixels = vec![PxReader::RecursivePixel::default(); self.size.width()];
+        let mut line_bytes = vec![0_u8; self.size.width() * self.channels.bytes_per_pixel];
+
+        for y in 0 .. self.size.height() {
+            let mut byte_index = 0;
+            for (channel, samples) in self.channels.list.iter().zip(&self.samples) {
+                let sample_size = channel.sample_type.bytes_per_sample();
+                let row_start = (y / channel.sampling.y()) * channel.subsampled_resolution(self.size).width();
+
+                for x in 0 .. self.size.width() {
+                    let start = (row_start + x / channel.sampling.x()) * sample_size;
+                    line_bytes[byte_index .. byte_index + sample_size].copy_from_slice(&samples[start .. start + sample_size]);
+                    byte_index += sample_size;
+                }
+            }
+
+            pixel_reader.read_pixels(&line_bytes, &mut pixels, |px| px);
+
+            for (x, pixel) in pixels.iter().enumerate() {
+                set_pixel(pixel_storage, Vec2(x, y), pixel.into_tuple());
             }
         }
+    }
+}
+
+
+/// Specifies to read the same specific channels from all resolution levels of a layer,
+/// storing the pixels of each level in a separate pixel storage.
+/// Call `required` or `optional` on this object to declare another channel to be read from the file.
+/// Call `collect_pixels` at last to define how the pixels of each level should be stored.
+#[derive(Copy, Clone, Debug)]
+pub struct ReadChannelLevels<ReadChannels> {
+    read_channels: ReadChannels,
+}
+
+impl ReadChannelLevels<ReadZeroChannels> {
+
+    /// Read the specific channels of all resolution levels. Declare the channels on the result of this function.
+    pub fn new() -> Self { ReadChannelLevels { read_channels: NoneMore } }
+}
+
+impl Default for ReadChannelLevels<ReadZeroChannels> {
+    fn default() -> Self { Self::new() }
+}
+
+impl<ReadChannels: ReadSpecificChannel> ReadChannelLevels<ReadChannels> {
+
+    /// Plan to read an additional channel from each resolution level, with the specified name.
+    /// If the channel cannot be found in the image when the image is read, the image will not be loaded.
+    /// The generic parameter can usually be inferred from the closure in `collect_pixels`.
+    pub fn required<Sample>(self, channel_name: impl Into<Text>) -> ReadChannelLevels<ReadRequiredChannel<ReadChannels, Sample>> {
+        ReadChannelLevels { read_channels: self.read_channels.required(channel_name) }
+    }
+
+    /// Plan to read an additional channel from each resolution level, with the specified name.
+    /// If the file does not contain this channel, the specified default sample will be returned instead.
+    /// The generic parameter can usually be inferred from the closure in `collect_pixels`.
+    pub fn optional<Sample>(self, channel_name: impl Into<Text>, default_sample: Sample)
+        -> ReadChannelLevels<ReadOptionalChannel<ReadChannels, Sample>>
+    {
+        ReadChannelLevels { read_channels: self.read_channels.optional(channel_name, default_sample) }
+    }
+
+    /// Using two closures, define how to store the pixels of each resolution level.
+    /// The first closure creates the pixel storage of a single level, given the size of that level,
+    /// and the second closure inserts a single pixel into the storage of a level.
+    /// The resulting channels contain a `Levels<PixelStorage>`.
+    pub fn collect_pixels<Pixel, PixelStorage, CreatePixels, SetPixel>(
+        self, create_pixels: CreatePixels, set_pixel: SetPixel
+    ) -> ReadChannelLevels<CollectPixels<ReadChannels, Pixel, PixelStorage, CreatePixels, SetPixel>>
+        where
+            <ReadChannels::RecursivePixelReader as RecursivePixelReader>::RecursivePixel: IntoTuple<Pixel>,
+            <ReadChannels::RecursivePixelReader as RecursivePixelReader>::RecursiveChannelDescriptions: IntoNonRecursive,
+            CreatePixels: Fn(
+                Vec2<usize>,
+                &<<ReadChannels::RecursivePixelReader as RecursivePixelReader>::RecursiveChannelDescriptions as IntoNonRecursive>::NonRecursive
+            ) -> PixelStorage,
+            SetPixel: Fn(&mut PixelStorage, Vec2<usize>, Pixel),
+    {
+        ReadChannelLevels { read_channels: self.read_channels.collect_pixels(create_pixels, set_pixel) }
+    }
+}
+
+impl<ReadChannels, Pixel, PixelStorage, CreatePixels, SetPixel> ReadChannelLevels<CollectPixels<ReadChannels, Pixel, PixelStorage, CreatePixels, SetPixel>> {
+
+    /// Specify which channels in a layer satisfy the requested channels, for example to also accept lowercase names.
+    /// By default, only channels with exactly the requested names are read.
+    pub fn with_channel_names(self, channel_names: ChannelNamePolicy) -> Self {
+        ReadChannelLevels { read_channels: self.read_channels.with_channel_names(channel_names) }
+    }
+}
+
+impl<'s, InnerChannels, Pixel, PixelStorage, CreatePixels, SetPixel: 's>
+ReadChannels<'s> for ReadChannelLevels<CollectPixels<InnerChannels, Pixel, PixelStorage, CreatePixels, SetPixel>>
+    where
+        InnerChannels: ReadSpecificChannel,
+        <InnerChannels::RecursivePixelReader as RecursivePixelReader>::RecursivePixel: IntoTuple<Pixel>,
+        <InnerChannels::RecursivePixelReader as RecursivePixelReader>::RecursiveChannelDescriptions: IntoNonRecursive,
+        CreatePixels: Fn(Vec2<usize>, &<<InnerChannels::RecursivePixelReader as RecursivePixelReader>::RecursiveChannelDescriptions as IntoNonRecursive>::NonRecursive) -> PixelStorage,
+        SetPixel: Fn(&mut PixelStorage, Vec2<usize>, Pixel),
+{
+    type Reader = SpecificChannelLevelsReader<
+        PixelStorage, &'s SetPixel,
+        InnerChannels::RecursivePixelReader,
+        Pixel,
+    >;
+
+    fn create_channels_reader(&'s self, header: &Header) -> Result<Self::Reader> {
+        if header.deep { return Err(Error::invalid("`SpecificChannels` does not support deep data yet")) }
+        let collect = &self.read_channels;
+
+        let pixel_reader = collect.read_channels.create_recursive_reader(&header.channels, &collect.channel_names)?;
+        let channel_descriptions = pixel_reader.get_descriptions().into_non_recursive();
+
+        let create = &collect.create_pixels;
+        let levels = create_levels(header, header.layer_size, |_, resolution| Ok(create(resolution, &channel_descriptions)))?;
+
+        Ok(SpecificChannelLevelsReader {
+            set_pixel: &collect.set_pixel,
+            subsampled_layer: SubsampledLayer::new(header),
+            levels,
+            pixel_reader,
+            px: Default::default()
+        })
+    }
+}
+
+/// The reader that holds the temporary data that is required to read some specified channels of all resolution levels.
+#[derive(Clone, Debug)]
+pub struct SpecificChannelLevelsReader<PixelStorage, SetPixel, PixelReader, Pixel> {
+    set_pixel: SetPixel,
+    subsampled_layer: Option<SubsampledLayer>, // only scan line images, which have a single level, can be subsampled
+    levels: Levels<PixelStorage>,
+    pixel_reader: PixelReader,
+    px: PhantomData<Pixel>
+}
+
+impl<PixelStorage, SetPixel, PxReader, Pixel>
+ChannelsReader for SpecificChannelLevelsReader<PixelStorage, SetPixel, PxReader, Pixel>
+    where PxReader: RecursivePixelReader,
+          PxReader::RecursivePixel: IntoTuple<Pixel>,
+          PxReader::RecursiveChannelDescriptions: IntoNonRecursive,
+          SetPixel: Fn(&mut PixelStorage, Vec2<usize>, Pixel),
+{
+    type Channels = SpecificChannels<Levels<PixelStorage>, <PxReader::RecursiveChannelDescriptions as IntoNonRecursive>::NonRecursive>;
+
+    fn filter_block(&self, _: TileCoordinates) -> bool { true }
+
+    fn read_block(&mut self, header: &Header, block: UncompressedBlock) -> UnitResult {
+        if let Some(subsampled_layer) = &mut self.subsampled_layer {
+            subsampled_layer.read_block(&block);
+        }
+        else {
+            let pixel_storage = self.levels.get_level_mut(block.index.level)?;
+            read_pixel_block(&self.pixel_reader, &self.set_pixel, pixel_storage, header, block);
+        }
 
         Ok(())
     }
 
-    fn into_channels(self) -> Self::Channels {
-        SpecificChannels { channels: self.pixel_reader.get_descriptions().into_non_recursive(), pixels: self.pixel_storage }
+    fn into_channels(mut self) -> Self::Channels {
+        if let Some(subsampled_layer) = &self.subsampled_layer {
+            let pixel_storage = self.levels.get_level_mut(Vec2(0, 0)).expect("subsampled resolution level bug");
+            subsampled_layer.read_pixels(&self.pixel_reader, &self.set_pixel, pixel_storage);
+        }
+
+
+        SpecificChannels {
+            channels: self.pixel_reader.get_descriptions().into_non_recursive(),
+            source_names: source_names(&self.pixel_reader),
+            pixels: self.levels,
+        }
     }
 }
 
@@ -214,7 +559,7 @@ pub type ReadZeroChannels = NoneMore;
 
 impl ReadSpecificChannel for NoneMore {
     type RecursivePixelReader = NoneMore;
-    fn create_recursive_reader(&self, _: &ChannelList) -> Result<Self::RecursivePixelReader> { Ok(NoneMore) }
+    fn create_recursive_reader(&self, _: &ChannelList, _: &ChannelNamePolicy) -> Result<Self::RecursivePixelReader> { Ok(NoneMore) }
 }
 
 impl<DefaultSample, ReadChannels> ReadSpecificChannel for ReadOptionalChannel<ReadChannels, DefaultSample>
@@ -222,16 +567,12 @@ impl<DefaultSample, ReadChannels> ReadSpecificChannel for ReadOptionalChannel<Re
 {
     type RecursivePixelReader = Recursive<ReadChannels::RecursivePixelReader, OptionalSampleReader<DefaultSample>>;
 
-    fn create_recursive_reader(&self, channels: &ChannelList) -> Result<Self::RecursivePixelReader> {
+    fn create_recursive_reader(&self, channels: &ChannelList, names: &ChannelNamePolicy) -> Result<Self::RecursivePixelReader> {
         debug_assert!(self.previous_channels.already_contains(&self.channel_name).not(), "duplicate channel name: {}", self.channel_name);
 
-        let inner_samples_reader = self.previous_channels.create_recursive_reader(channels)?;
-        let reader = channels.channels_with_byte_offset()
-            .find(|(_, channel)| channel.name == self.channel_name)
-            .map(|(channel_byte_offset, channel)| SampleReader {
-                channel_byte_offset, channel: channel.clone(),
-                px: Default::default()
-            });
+        let inner_samples_reader = self.previous_channels.create_recursive_reader(channels, names)?;
+        let reader = names.find_channel(&self.channel_name, channels)
+            .map(|(channel_byte_offset, channel)| SampleReader::new(&self.channel_name, channel_byte_offset, channel));
 
         Ok(Recursive::new(inner_samples_reader, OptionalSampleReader {
             reader, default_sample: self.default_sample,
@@ -244,16 +585,15 @@ impl<Sample, ReadChannels> ReadSpecificChannel for ReadRequiredChannel<ReadChann
 {
     type RecursivePixelReader = Recursive<ReadChannels::RecursivePixelReader, SampleReader<Sample>>;
 
-    fn create_recursive_reader(&self, channels: &ChannelList) -> Result<Self::RecursivePixelReader> {
-        let previous_samples_reader = self.previous_channels.create_recursive_reader(channels)?;
-        let (channel_byte_offset, channel) = channels.channels_with_byte_offset()
-                .find(|(_, channel)| channel.name == self.channel_name)
+    fn create_recursive_reader(&self, channels: &ChannelList, names: &ChannelNamePolicy) -> Result<Self::RecursivePixelReader> {
+        let previous_samples_reader = self.previous_channels.create_recursive_reader(channels, names)?;
+        let (channel_byte_offset, channel) = names.find_channel(&self.channel_name, channels)
                 .ok_or_else(|| Error::invalid(format!(
                     "layer does not contain all of your specified channels (`{}` is missing)",
                     self.channel_name
                 )))?;
 
-        Ok(Recursive::new(previous_samples_reader, SampleReader { channel_byte_offset, channel: channel.clone(), px: Default::default() }))
+        Ok(Recursive::new(previous_samples_reader, SampleReader::new(&self.channel_name, channel_byte_offset, channel)))
     }
 }
 
@@ -264,7 +604,12 @@ pub struct SampleReader<Sample> {
     /// to be multiplied with line width!
     channel_byte_offset: usize,
 
+    /// The description of the source channel, renamed to the requested channel.
     channel: ChannelDescription,
+
+    /// The name of the channel in the file, which may be an alias of the requested channel.
+    source_name: Text,
+
     px: PhantomData<Sample>
 }
 
@@ -276,6 +621,26 @@ pub struct OptionalSampleReader<DefaultSample> {
     default_sample: DefaultSample,
 }
 
+impl<Sample> SampleReader<Sample> {
+
+    /// Read the source channel as the requested channel.
+    fn new(requested_name: &Text, channel_byte_offset: usize, source_channel: &ChannelDescription) -> Self {
+        SampleReader {
+            channel_byte_offset,
+            channel: ChannelDescription { name: requested_name.clone(), .. source_channel.clone() },
+            source_name: source_channel.name.clone(),
+            px: Default::default()
+        }
+    }
+
+    /// Append the requested name and the source name, if the channel in the file has another name.
+    fn collect_source_name(&self, source_names: &mut SmallVec<[(Text, Text); 4]>) {
+        if self.source_name != self.channel.name {
+            source_names.push((self.channel.name.clone(), self.source_name.clone()));
+        }
+    }
+}
+
 impl<Sample: FromNativeSample> SampleReader<Sample> {
     fn read_own_samples<'s, FullPixel>(
         &self, bytes: &'s[u8], pixels: &mut [FullPixel],
@@ -317,6 +682,8 @@ impl RecursivePixelReader for NoneMore {
         &self, _: &'s[u8], _: &mut [FullPixel],
         _: impl Fn(&mut FullPixel) -> &mut NoneMore
     ){}
+
+    fn collect_source_names(&self, _: &mut SmallVec<[(Text, Text); 4]>) {}
 }
 
 impl<Sample, InnerReader: RecursivePixelReader>
@@ -336,6 +703,11 @@ impl<Sample, InnerReader: RecursivePixelReader>
         self.value.read_own_samples(bytes, pixels, |px| &mut get_pixel(px).value);
         self.inner.read_pixels(bytes, pixels, |px| &mut get_pixel(px).inner);
     }
+
+    fn collect_source_names(&self, source_names: &mut SmallVec<[(Text, Text); 4]>) {
+        self.inner.collect_source_names(source_names);
+        self.value.collect_source_name(source_names);
+    }
 }
 
 impl<Sample, InnerReader: RecursivePixelReader>
@@ -366,6 +738,14 @@ for Recursive<InnerReader, OptionalSampleReader<Sample>>
 
         self.inner.read_pixels(bytes, pixels, |px| &mut get_pixel(px).inner);
     }
+
+    fn collect_source_names(&self, source_names: &mut SmallVec<[(Text, Text); 4]>) {
+        self.inner.collect_source_names(source_names);
+
+        if let Some(reader) = &self.value.reader {
+            reader.collect_source_name(source_names);
+        }
+    }
 }
 
 
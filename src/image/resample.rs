@@ -0,0 +1,397 @@
+//! Resample flat samples to a different resolution,
+//! and generate the smaller resolution levels of a layer, as `exrmaketiled` does.
+//!
+//! Each level is computed directly from the full resolution samples,
+//! using a separable filter that is scaled to the size of the level.
+
+use crate::image::*;
+use crate::meta::{mip_map_levels, rip_map_levels, compute_level_count};
+use crate::math::RoundingMode;
+use crate::error::{Error, Result};
+
+
+/// The filter used to compute each sample of a smaller resolution level
+/// from the samples of the full resolution.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ResamplingFilter {
+
+    /// Averages all samples that are covered by the target sample. Fast, but blurry.
+    Box,
+
+    /// Weights the samples linearly by their distance to the target sample.
+    Triangle,
+
+    /// A windowed sinc filter with a radius of three target samples.
+    /// Preserves the most detail, but may produce ringing artifacts at hard edges.
+    Lanczos3,
+}
+
+/// How samples outside of the layer are computed when filtering at the border of a layer.
+/// Corresponds to the values of the `wrapmodes` attribute of a layer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WrapMode {
+
+    /// Repeat the sample at the border of the layer.
+    Clamp,
+
+    /// Continue with the samples of the opposite border, as if the layer was tiled.
+    Periodic,
+
+    /// Continue with the samples of the same border, as if the layer was mirrored.
+    Mirror,
+
+    /// Use zero for all samples outside of the layer.
+    Black,
+}
+
+/// Specifies how the smaller resolution levels of a layer are generated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LevelOptions {
+
+    /// The filter used to compute each sample of a level.
+    pub filter: ResamplingFilter,
+
+    /// Whether to round the level sizes up or down.
+    pub rounding_mode: RoundingMode,
+
+    /// The horizontal and vertical wrap modes.
+    /// If `None`, the `wrap_mode_name` attribute of the layer is used, or `WrapMode::Clamp` if the layer has none.
+    pub wrap_modes: Option<Vec2<WrapMode>>,
+}
+
+impl Default for LevelOptions {
+    fn default() -> Self {
+        LevelOptions { filter: ResamplingFilter::Box, rounding_mode: RoundingMode::Down, wrap_modes: None }
+    }
+}
+
+impl ResamplingFilter {
+
+    /// The distance from the center at which the weight of the filter becomes zero, in target samples.
+    pub fn radius(self) -> f32 {
+        match self {
+            ResamplingFilter::Box => 0.5,
+            ResamplingFilter::Triangle => 1.0,
+            ResamplingFilter::Lanczos3 => 3.0,
+        }
+    }
+
+    /// The weight of a sample at the specified distance from the center, in target samples.
+    pub fn weight(self, distance: f32) -> f32 {
+        match self {
+            ResamplingFilter::Box => if (-0.5 .. 0.5).contains(&distance) { 1.0 } else { 0.0 },
+            ResamplingFilter::Triangle => (1.0 - distance.abs()).max(0.0),
+            ResamplingFilter::Lanczos3 => {
+                if distance.abs() >= 3.0 { 0.0 }
+                else { sinc(distance) * sinc(distance / 3.0) }
+            },
+        }
+    }
+}
+
+fn sinc(x: f32) -> f32 {
+    if x == 0.0 { 1.0 }
+    else {
+        let x = x * std::f32::consts::PI;
+        x.sin() / x
+    }
+}
+
+impl WrapMode {
+
+    /// Parse the value of a `wrapmodes` attribute, for example `"clamp"` or `"periodic,clamp"`.
+    /// Contains the horizontal and the vertical wrap mode. Returns `None` for unknown modes.
+    pub fn parse_pair(text: &Text) -> Option<Vec2<WrapMode>> {
+        let text = text.to_string();
+        let mut modes = text.split(',').map(|mode| WrapMode::parse(mode.trim()));
+
+        let horizontal = modes.next()??;
+        let vertical = match modes.next() { Some(mode) => mode?, None => horizontal };
+
+        if modes.next().is_some() { None }
+        else { Some(Vec2(horizontal, vertical)) }
+    }
+
+    /// The horizontal and vertical wrap modes of a layer, parsed from its `wrap_mode_name` attribute.
+    /// Layers without this attribute use `WrapMode::Clamp`. Returns an error for unknown wrap modes.
+    pub fn from_attributes(attributes: &LayerAttributes) -> Result<Vec2<WrapMode>> {
+        match &attributes.wrap_mode_name {
+            Some(name) => WrapMode::parse_pair(name).ok_or(Error::invalid("wrap mode name")),
+            None => Ok(Vec2(WrapMode::Clamp, WrapMode::Clamp)),
+        }
+    }
+
+    /// Parse a single wrap mode, for example `"clamp"`.
+    pub fn parse(text: &str) -> Option<Self> {
+        match text {
+            "clamp" => Some(WrapMode::Clamp),
+            "periodic" => Some(WrapMode::Periodic),
+            "mirror" => Some(WrapMode::Mirror),
+            "black" => Some(WrapMode::Black),
+            _ => None,
+        }
+    }
+
+    /// The name of this wrap mode in a `wrapmodes` attribute.
+    pub fn name(self) -> &'static str {
+        match self {
+            WrapMode::Clamp => "clamp",
+            WrapMode::Periodic => "periodic",
+            WrapMode::Mirror => "mirror",
+            WrapMode::Black => "black",
+        }
+    }
+
+    /// The value of a `wrapmodes` attribute containing the horizontal and the vertical wrap mode.
+    pub fn pair_to_text(modes: Vec2<WrapMode>) -> Text {
+        if modes.x() == modes.y() { Text::from(modes.x().name()) }
+        else { Text::from(format!("{},{}", modes.x().name(), modes.y().name()).as_str()) }
+    }
+
+    /// Map a possibly out of bounds index into the range `0 .. size`.
+    /// Returns `None` if the sample is black.
+    pub fn wrap_index(self, index: i64, size: usize) -> Option<usize> {
+        let size = size as i64;
+        let in_bounds = (0 .. size).contains(&index);
+
+        let index = match self {
+            _ if in_bounds => index,
+            WrapMode::Clamp => index.max(0).min(size - 1),
+            WrapMode::Periodic => index.rem_euclid(size),
+            WrapMode::Black => return None,
+            WrapMode::Mirror => {
+                let index = index.rem_euclid(2 * size);
+                if index < size { index } else { 2 * size - 1 - index }
+            },
+        };
+
+        Some(index as usize)
+    }
+}
+
+
+/// The source indices and weights that contribute to each target sample along one axis.
+type AxisWeights = Vec<SmallVec<[(usize, f32); 8]>>;
+
+fn axis_weights(source_size: usize, target_size: usize, filter: ResamplingFilter, wrap_mode: WrapMode) -> AxisWeights {
+    let scale = (source_size as f32 / target_size as f32).max(1.0);
+    let support = filter.radius() * scale;
+
+    (0 .. target_size).map(|target_index| {
+        let center = (target_index as f32 + 0.5) * source_size as f32 / target_size as f32;
+        let first = (center - support).floor() as i64;
+        let last = (center + support).ceil() as i64;
+
+        let weights: SmallVec<[(Option<usize>, f32); 8]> = (first ..= last)
+            .map(|index| (
+                wrap_mode.wrap_index(index, source_size),
+                filter.weight((index as f32 + 0.5 - center) / scale)
+            ))
+            .filter(|&(_, weight)| weight != 0.0)
+            .collect();
+
+        // black samples still count towards the total weight, darkening the border
+        let total_weight: f32 = weights.iter().map(|&(_, weight)| weight).sum();
+
+        weights.into_iter()
+            .filter_map(|(index, weight)| index.map(|index| (index, weight / total_weight)))
+            .collect()
+    }).collect()
+}
+
+fn resample_values(
+    values: &[f32], size: Vec2<usize>, new_size: Vec2<usize>,
+    filter: ResamplingFilter, wrap_modes: Vec2<WrapMode>
+) -> Vec<f32>
+{
+    debug_assert_eq!(values.len(), size.area(), "sample count does not match size");
+
+    let horizontal_weights = axis_weights(size.width(), new_size.width(), filter, wrap_modes.x());
+    let vertical_weights = axis_weights(size.height(), new_size.height(), filter, wrap_modes.y());
+
+    let mut horizontal = Vec::with_capacity(new_size.width() * size.height());
+    for row in values.chunks_exact(size.width().max(1)) {
+        horizontal.extend(horizontal_weights.iter().map(|weights|
+            weights.iter().map(|&(x, weight)| row[x] * weight).sum::<f32>()
+        ));
+    }
+
+    let mut resampled = Vec::with_capacity(new_size.area());
+    for weights in &vertical_weights {
+        resampled.extend((0 .. new_size.width()).map(|x|
+            weights.iter().map(|&(y, weight)| horizontal[y * new_size.width() + x] * weight).sum::<f32>()
+        ));
+    }
+
+    resampled
+}
+
+impl FlatSamples {
+
+    /// Resample these samples, which have the specified resolution, to a new resolution.
+    /// The result has the same sample type. `u32` samples are rounded to the nearest integer.
+    pub fn resample(&self, size: Vec2<usize>, new_size: Vec2<usize>, filter: ResamplingFilter, wrap_modes: Vec2<WrapMode>) -> FlatSamples {
+        let values: Vec<f32> = self.values_as_f32().collect();
+        let resampled = resample_values(&values, size, new_size, filter, wrap_modes);
+
+        match self {
+            FlatSamples::F16(_) => FlatSamples::F16(resampled.into_iter().map(f16::from_f32).collect()),
+            FlatSamples::F32(_) => FlatSamples::F32(resampled),
+            FlatSamples::U32(_) => FlatSamples::U32(resampled.into_iter().map(|value| value.round() as u32).collect()),
+        }
+    }
+
+    /// Generate all resolution levels of these samples, which have the specified resolution,
+    /// using the wrap modes of the options, or `WrapMode::Clamp` if the options contain none.
+    /// The first level contains a copy of these samples.
+    pub fn generate_levels(&self, size: Vec2<usize>, level_mode: LevelMode, options: LevelOptions) -> Levels<FlatSamples> {
+        let wrap_modes = options.wrap_modes.unwrap_or(Vec2(WrapMode::Clamp, WrapMode::Clamp));
+        let round = options.rounding_mode;
+
+        let level = |level_size: Vec2<usize>| {
+            if level_size == size { self.clone() }
+            else { self.resample(size, level_size, options.filter, wrap_modes) }
+        };
+
+        match level_mode {
+            LevelMode::Singular => Levels::Singular(self.clone()),
+
+            LevelMode::MipMap => Levels::Mip {
+                rounding_mode: round,
+                level_data: mip_map_levels(round, size).map(|(_, level_size)| level(level_size)).collect(),
+            },
+
+            LevelMode::RipMap => Levels::Rip {
+                rounding_mode: round,
+                level_data: RipMaps {
+                    map_data: rip_map_levels(round, size).map(|(_, level_size)| level(level_size)).collect(),
+                    level_count: Vec2(compute_level_count(round, size.width()), compute_level_count(round, size.height())),
+                },
+            },
+        }
+    }
+}
+
+impl Layer<AnyChannels<FlatSamples>> {
+
+    /// Generate the mip map levels of all channels of this layer.
+    /// See `generate_levels` for details.
+    pub fn generate_mip_maps(&self, options: LevelOptions) -> Result<Layer<AnyChannels<Levels<FlatSamples>>>> {
+        self.generate_levels(LevelMode::MipMap, options)
+    }
+
+    /// Generate the rip map levels of all channels of this layer.
+    /// See `generate_levels` for details.
+    pub fn generate_rip_maps(&self, options: LevelOptions) -> Result<Layer<AnyChannels<Levels<FlatSamples>>>> {
+        self.generate_levels(LevelMode::RipMap, options)
+    }
+
+    /// Generate the resolution levels of all channels of this layer.
+    /// The wrap modes are taken from the options or from the `wrap_mode_name` attribute of the layer,
+    /// and are stored in the `wrap_mode_name` attribute of the resulting layer.
+    /// As resolution levels can only be stored in tiles, scan line layers are converted to 64×64 tiles.
+    ///
+    /// Returns an error for subsampled channels or an unknown `wrap_mode_name`.
+    pub fn generate_levels(&self, level_mode: LevelMode, options: LevelOptions) -> Result<Layer<AnyChannels<Levels<FlatSamples>>>> {
+        if self.channel_data.list.iter().any(|channel| channel.sampling != Vec2(1, 1)) {
+            return Err(Error::unsupported("resolution levels of subsampled channels"));
+        }
+
+        let wrap_modes = match options.wrap_modes {
+            Some(modes) => modes,
+            None => WrapMode::from_attributes(&self.attributes)?,
+        };
+
+        let options = LevelOptions { wrap_modes: Some(wrap_modes), .. options };
+
+        let list = self.channel_data.list.iter()
+            .map(|channel| AnyChannel {
+                name: channel.name.clone(),
+                quantize_linearly: channel.quantize_linearly,
+                sampling: channel.sampling,
+                sample_data: channel.sample_data.generate_levels(self.size, level_mode, options),
+            })
+            .collect();
+
+        let blocks = match self.encoding.blocks {
+            Blocks::ScanLines if level_mode != LevelMode::Singular => Blocks::Tiles(Vec2(64, 64)),
+            blocks => blocks,
+        };
+
+        Ok(Layer {
+            channel_data: AnyChannels { list },
+            attributes: LayerAttributes {
+                wrap_mode_name: Some(WrapMode::pair_to_text(wrap_modes)),
+                .. self.attributes.clone()
+            },
+            size: self.size,
+            encoding: Encoding { blocks, .. self.encoding },
+        })
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn wrap_indices() {
+        assert_eq!(WrapMode::Clamp.wrap_index(-2, 4), Some(0));
+        assert_eq!(WrapMode::Clamp.wrap_index(5, 4), Some(3));
+        assert_eq!(WrapMode::Periodic.wrap_index(-1, 4), Some(3));
+        assert_eq!(WrapMode::Periodic.wrap_index(4, 4), Some(0));
+        assert_eq!(WrapMode::Mirror.wrap_index(-1, 4), Some(0));
+        assert_eq!(WrapMode::Mirror.wrap_index(5, 4), Some(2));
+        assert_eq!(WrapMode::Black.wrap_index(-1, 4), None);
+        assert_eq!(WrapMode::Black.wrap_index(2, 4), Some(2));
+    }
+
+    #[test]
+    fn parse_wrap_modes() {
+        assert_eq!(WrapMode::parse_pair(&Text::from("clamp")), Some(Vec2(WrapMode::Clamp, WrapMode::Clamp)));
+        assert_eq!(WrapMode::parse_pair(&Text::from("periodic,mirror")), Some(Vec2(WrapMode::Periodic, WrapMode::Mirror)));
+        assert_eq!(WrapMode::parse_pair(&Text::from("repeat")), None);
+        assert_eq!(WrapMode::pair_to_text(Vec2(WrapMode::Periodic, WrapMode::Black)), Text::from("periodic,black"));
+    }
+
+    #[test]
+    fn box_filter_averages_pairs() {
+        let samples = FlatSamples::F32(vec![ 1.0, 3.0, 5.0, 7.0,  1.0, 3.0, 5.0, 7.0 ]);
+        let resampled = samples.resample(Vec2(4, 2), Vec2(2, 1), ResamplingFilter::Box, Vec2(WrapMode::Clamp, WrapMode::Clamp));
+        assert_eq!(resampled, FlatSamples::F32(vec![ 2.0, 6.0 ]));
+    }
+
+    #[test]
+    fn filters_preserve_constant_color() {
+        let samples = FlatSamples::F32(vec![ 0.5; 13 * 7 ]);
+
+        for &filter in &[ResamplingFilter::Box, ResamplingFilter::Triangle, ResamplingFilter::Lanczos3] {
+            for &wrap_mode in &[WrapMode::Clamp, WrapMode::Periodic, WrapMode::Mirror] {
+                let levels = samples.generate_levels(Vec2(13, 7), LevelMode::RipMap, LevelOptions {
+                    filter, rounding_mode: RoundingMode::Up, wrap_modes: Some(Vec2(wrap_mode, wrap_mode))
+                });
+
+                for level in levels.levels_as_slice() {
+                    assert!(level.values_as_f32().all(|value| (value - 0.5).abs() < 0.0001));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn black_border_darkens() {
+        let samples = FlatSamples::F32(vec![ 1.0; 4 ]);
+        let resampled = samples.resample(Vec2(4, 1), Vec2(2, 1), ResamplingFilter::Triangle, Vec2(WrapMode::Black, WrapMode::Black));
+        assert!(resampled.values_as_f32().all(|value| value < 1.0 && value > 0.5));
+    }
+
+    #[test]
+    fn level_sizes() {
+        let samples = FlatSamples::F16(vec![ f16::ONE; 10 * 3 ]);
+        let levels = samples.generate_levels(Vec2(10, 3), LevelMode::MipMap, LevelOptions::default());
+
+        let lengths: Vec<usize> = levels.levels_as_slice().iter().map(FlatSamples::len).collect();
+        assert_eq!(lengths, vec![ 30, 5, 2, 1 ]);
+    }
+}
@@ -0,0 +1,310 @@
+//! Look up filtered values of a layer with resolution levels at texture coordinates,
+//! for example to render a texture in a path tracer.
+//!
+//! Texture coordinates range from zero to one across the data window of the layer,
+//! with `u` going right and `v` going down. The center of the top left pixel is at `(0.5 / width, 0.5 / height)`.
+
+use crate::image::*;
+use crate::image::pixel_vec::PixelVec;
+use crate::image::resample::WrapMode;
+use crate::meta::compute_level_size;
+use crate::error::{Error, Result};
+
+
+/// How the pixels around a texture coordinate are combined.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TextureFilter {
+
+    /// Use the pixel that contains the texture coordinate, in the closest resolution level.
+    Nearest,
+
+    /// Interpolate the four pixels around the texture coordinate, in the closest resolution level.
+    Bilinear,
+
+    /// Interpolate the four pixels around the texture coordinate,
+    /// and then interpolate between the two closest resolution levels.
+    /// With rip maps, the closest levels are interpolated independently for each axis.
+    Trilinear,
+}
+
+/// A value that can be interpolated by a texture lookup.
+/// Implemented for `f32`, `f16`, and tuples of up to four texels.
+pub trait Texel: Copy + Default {
+
+    /// Interpolate linearly between this texel and the other texel.
+    /// Returns this texel for zero and the other texel for one.
+    fn lerp(self, other: Self, t: f32) -> Self;
+}
+
+/// A single resolution level that can be used in a texture.
+pub trait TextureLevel {
+
+    /// The value of a single pixel.
+    type Texel: Texel;
+
+    /// The pixel at the specified position in this level, which has the specified size.
+    fn texel(&self, position: Vec2<usize>, level_size: Vec2<usize>) -> Self::Texel;
+}
+
+/// Looks up filtered values of all resolution levels of a layer.
+/// Borrows the levels of a single channel or of a `SpecificChannels` pixel storage.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Texture<'levels, Level> {
+    levels: &'levels Levels<Level>,
+    size: Vec2<usize>,
+    wrap_modes: Vec2<WrapMode>,
+}
+
+
+impl Texel for f32 {
+    fn lerp(self, other: Self, t: f32) -> Self { self + (other - self) * t }
+}
+
+impl Texel for f16 {
+    fn lerp(self, other: Self, t: f32) -> Self { f16::from_f32(self.to_f32().lerp(other.to_f32(), t)) }
+}
+
+macro_rules! impl_texel_for_tuple {
+    ( $( $name: ident : $index: tt ),* ) => {
+        impl< $( $name: Texel ),* > Texel for ( $( $name, )* ) {
+            fn lerp(self, other: Self, t: f32) -> Self {
+                ( $( self.$index.lerp(other.$index, t), )* )
+            }
+        }
+    };
+}
+
+impl_texel_for_tuple!(A:0);
+impl_texel_for_tuple!(A:0, B:1);
+impl_texel_for_tuple!(A:0, B:1, C:2);
+impl_texel_for_tuple!(A:0, B:1, C:2, D:3);
+
+impl TextureLevel for FlatSamples {
+    type Texel = f32;
+
+    fn texel(&self, position: Vec2<usize>, level_size: Vec2<usize>) -> f32 {
+        self.value_by_flat_index(position.flat_index_for_size(level_size)).to_f32()
+    }
+}
+
+impl<Px: Texel> TextureLevel for PixelVec<Px> {
+    type Texel = Px;
+
+    fn texel(&self, position: Vec2<usize>, _: Vec2<usize>) -> Px {
+        self.pixels[position.flat_index_for_size(self.resolution)]
+    }
+}
+
+
+impl<'levels, Level: TextureLevel> Texture<'levels, Level> {
+
+    /// Create a texture from the levels of a layer with the specified full resolution.
+    pub fn new(levels: &'levels Levels<Level>, size: Vec2<usize>, wrap_modes: Vec2<WrapMode>) -> Self {
+        Texture { levels, size, wrap_modes }
+    }
+
+    /// Create a texture from the pixels of a layer with specific channels.
+    /// The wrap modes are taken from the `wrap_mode_name` attribute of the layer.
+    pub fn from_layer<Channels>(layer: &'levels Layer<SpecificChannels<Levels<Level>, Channels>>) -> Result<Self> {
+        Ok(Self::new(&layer.channel_data.pixels, layer.size, WrapMode::from_attributes(&layer.attributes)?))
+    }
+
+    /// The number of resolution levels along the x and y axis.
+    pub fn level_count(&self) -> Vec2<usize> {
+        match self.levels {
+            Levels::Singular(_) => Vec2(1, 1),
+            Levels::Mip { level_data, .. } => Vec2(level_data.len(), level_data.len()),
+            Levels::Rip { level_data, .. } => level_data.level_count,
+        }
+    }
+
+    /// The resolution of the specified level.
+    pub fn level_size(&self, level: Vec2<usize>) -> Vec2<usize> {
+        match self.levels {
+            Levels::Singular(_) => self.size,
+            Levels::Mip { rounding_mode, .. } | Levels::Rip { rounding_mode, .. } => Vec2(
+                compute_level_size(*rounding_mode, self.size.width(), level.x()),
+                compute_level_size(*rounding_mode, self.size.height(), level.y()),
+            ),
+        }
+    }
+
+    /// The fractional level index that matches the size of a lookup, for each axis.
+    /// The footprint is the size of the lookup in texture coordinates, for example the size of a pixel on the screen.
+    /// Mip maps use the larger of both axes, such that the result is never aliased.
+    pub fn level_of_detail(&self, footprint: Vec2<f32>) -> Vec2<f32> {
+        let level = |footprint: f32, size: usize| (footprint.abs() * size as f32).log2().max(0.0);
+        let level = Vec2(level(footprint.x(), self.size.width()), level(footprint.y(), self.size.height()));
+
+        match self.levels {
+            Levels::Rip { .. } => level,
+            _ => Vec2(level.x().max(level.y()), level.x().max(level.y())),
+        }
+    }
+
+    /// Look up the filtered value at the texture coordinate,
+    /// choosing the resolution levels that match the footprint of the lookup.
+    /// Rip maps allow different levels along each axis, for anisotropic footprints.
+    pub fn sample(&self, uv: Vec2<f32>, footprint: Vec2<f32>, filter: TextureFilter) -> Level::Texel {
+        self.sample_level_of_detail(uv, self.level_of_detail(footprint), filter)
+    }
+
+    /// Look up the filtered value at the texture coordinate, in the specified fractional level.
+    /// The level is clamped to the existing levels. Mip maps use the larger of both axes.
+    pub fn sample_level_of_detail(&self, uv: Vec2<f32>, level_of_detail: Vec2<f32>, filter: TextureFilter) -> Level::Texel {
+        let max_level = self.level_count() - Vec2(1, 1);
+
+        let level_of_detail = match self.levels {
+            Levels::Rip { .. } => level_of_detail,
+            _ => Vec2(level_of_detail.x().max(level_of_detail.y()), level_of_detail.x().max(level_of_detail.y())),
+        };
+
+        let clamp = |level: f32, max: usize| level.max(0.0).min(max as f32);
+        let level_of_detail = Vec2(clamp(level_of_detail.x(), max_level.x()), clamp(level_of_detail.y(), max_level.y()));
+        let closest_level = Vec2(level_of_detail.x().round() as usize, level_of_detail.y().round() as usize);
+
+        match filter {
+            TextureFilter::Nearest => self.nearest(uv, closest_level),
+            TextureFilter::Bilinear => self.bilinear(uv, closest_level),
+
+            TextureFilter::Trilinear => {
+                let lower = Vec2(level_of_detail.x().floor() as usize, level_of_detail.y().floor() as usize);
+                let upper = Vec2((lower.x() + 1).min(max_level.x()), (lower.y() + 1).min(max_level.y()));
+                let t = Vec2(level_of_detail.x() - lower.x() as f32, level_of_detail.y() - lower.y() as f32);
+
+                match self.levels {
+                    Levels::Rip { .. } => {
+                        let top = self.bilinear(uv, lower).lerp(self.bilinear(uv, Vec2(upper.x(), lower.y())), t.x());
+                        let bottom = self.bilinear(uv, Vec2(lower.x(), upper.y())).lerp(self.bilinear(uv, upper), t.x());
+                        top.lerp(bottom, t.y())
+                    },
+
+                    _ => self.bilinear(uv, lower).lerp(self.bilinear(uv, upper), t.x()),
+                }
+            },
+        }
+    }
+
+    /// The value of the pixel that contains the texture coordinate, in the specified level.
+    /// Panics if the level does not exist.
+    pub fn nearest(&self, uv: Vec2<f32>, level: Vec2<usize>) -> Level::Texel {
+        let size = self.level_size(level);
+        let position = Vec2((uv.x() * size.width() as f32).floor() as i64, (uv.y() * size.height() as f32).floor() as i64);
+        self.texel(level, size, position)
+    }
+
+    /// Interpolate the four pixels around the texture coordinate, in the specified level.
+    /// Panics if the level does not exist.
+    pub fn bilinear(&self, uv: Vec2<f32>, level: Vec2<usize>) -> Level::Texel {
+        let size = self.level_size(level);
+        let x = uv.x() * size.width() as f32 - 0.5;
+        let y = uv.y() * size.height() as f32 - 0.5;
+
+        let left = x.floor() as i64;
+        let top = y.floor() as i64;
+        let t = Vec2(x - left as f32, y - top as f32);
+
+        let upper = self.texel(level, size, Vec2(left, top)).lerp(self.texel(level, size, Vec2(left + 1, top)), t.x());
+        let lower = self.texel(level, size, Vec2(left, top + 1)).lerp(self.texel(level, size, Vec2(left + 1, top + 1)), t.x());
+        upper.lerp(lower, t.y())
+    }
+
+    /// The pixel at a possibly out of bounds position, using the wrap modes of this texture.
+    fn texel(&self, level: Vec2<usize>, size: Vec2<usize>, position: Vec2<i64>) -> Level::Texel {
+        let x = self.wrap_modes.x().wrap_index(position.x(), size.width());
+        let y = self.wrap_modes.y().wrap_index(position.y(), size.height());
+
+        match (x, y) {
+            (Some(x), Some(y)) => self.levels.get_level(level)
+                .expect("texture level index bug")
+                .texel(Vec2(x, y), size),
+
+            _ => Level::Texel::default(),
+        }
+    }
+}
+
+impl<'levels> Texture<'levels, FlatSamples> {
+
+    /// Create a texture from the levels of a single channel of a layer.
+    /// The wrap modes are taken from the `wrap_mode_name` attribute of the layer.
+    /// Returns an error if the layer has no channel with the specified name.
+    pub fn from_channel(layer: &'levels Layer<AnyChannels<Levels<FlatSamples>>>, channel_name: &str) -> Result<Self> {
+        let channel = layer.channel_data.list.iter()
+            .find(|channel| channel.name.eq(channel_name))
+            .ok_or(Error::invalid("texture channel name"))?;
+
+        Ok(Self::new(&channel.sample_data, layer.size, WrapMode::from_attributes(&layer.attributes)?))
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::image::resample::LevelOptions;
+
+    fn gradient(size: Vec2<usize>) -> FlatSamples {
+        FlatSamples::F32((0 .. size.area()).map(|index| (index % size.width()) as f32).collect())
+    }
+
+    #[test]
+    fn nearest_and_bilinear() {
+        let size = Vec2(4, 2);
+        let levels = Levels::Singular(gradient(size));
+        let clamp = Texture::new(&levels, size, Vec2(WrapMode::Clamp, WrapMode::Clamp));
+
+        assert_eq!(clamp.nearest(Vec2(0.6, 0.2), Vec2(0, 0)), 2.0);
+        assert_eq!(clamp.bilinear(Vec2(0.5, 0.5), Vec2(0, 0)), 2.0 - 0.5);
+        assert_eq!(clamp.bilinear(Vec2(0.0, 0.0), Vec2(0, 0)), 0.0);
+
+        let periodic = Texture::new(&levels, size, Vec2(WrapMode::Periodic, WrapMode::Clamp));
+        assert_eq!(periodic.bilinear(Vec2(0.0, 0.5), Vec2(0, 0)), 1.5);
+        assert_eq!(periodic.nearest(Vec2(-0.1, 0.5), Vec2(0, 0)), 3.0);
+
+        let black = Texture::new(&levels, size, Vec2(WrapMode::Black, WrapMode::Black));
+        assert_eq!(black.nearest(Vec2(1.1, 0.5), Vec2(0, 0)), 0.0);
+    }
+
+    #[test]
+    fn trilinear_interpolates_levels() {
+        let size = Vec2(8, 8);
+        let levels = Levels::Mip {
+            rounding_mode: RoundingMode::Down,
+            level_data: (0 .. 4).map(|level| FlatSamples::F32(vec![ level as f32; (8 >> level) * (8 >> level) ])).collect(),
+        };
+
+        let texture = Texture::new(&levels, size, Vec2(WrapMode::Clamp, WrapMode::Clamp));
+        assert_eq!(texture.level_count(), Vec2(4, 4));
+        assert_eq!(texture.level_of_detail(Vec2(0.5, 0.125)), Vec2(2.0, 2.0));
+
+        let uv = Vec2(0.3, 0.7);
+        assert_eq!(texture.sample_level_of_detail(uv, Vec2(1.25, 0.0), TextureFilter::Trilinear), 1.25);
+        assert_eq!(texture.sample_level_of_detail(uv, Vec2(1.25, 0.0), TextureFilter::Bilinear), 1.0);
+        assert_eq!(texture.sample_level_of_detail(uv, Vec2(9.0, 0.0), TextureFilter::Nearest), 3.0);
+    }
+
+    #[test]
+    fn rip_maps_are_anisotropic() {
+        let size = Vec2(8, 4);
+        let levels = FlatSamples::F32(vec![ 1.0; size.area() ])
+            .generate_levels(size, LevelMode::RipMap, LevelOptions::default());
+
+        let texture = Texture::new(&levels, size, Vec2(WrapMode::Clamp, WrapMode::Clamp));
+        assert_eq!(texture.level_count(), Vec2(4, 3));
+        assert_eq!(texture.level_of_detail(Vec2(0.5, 0.25)), Vec2(2.0, 0.0));
+        assert_eq!(texture.level_size(Vec2(2, 0)), Vec2(2, 4));
+        assert_eq!(texture.sample(Vec2(0.5, 0.5), Vec2(0.3, 0.1), TextureFilter::Trilinear), 1.0);
+    }
+
+    #[test]
+    fn pixel_vec_tuples() {
+        let size = Vec2(2, 1);
+        let levels = Levels::Singular(PixelVec::new(size, vec![ (0.0_f32, f16::ONE), (1.0, f16::ZERO) ]));
+        let texture = Texture::new(&levels, size, Vec2(WrapMode::Clamp, WrapMode::Clamp));
+
+        let (first, second) = texture.bilinear(Vec2(0.5, 0.5), Vec2(0, 0));
+        assert_eq!(first, 0.5);
+        assert_eq!(second, f16::from_f32(0.5));
+    }
+}
@@ -8,6 +8,7 @@ use crate::block::*;
 use crate::image::recursive::*;
 use crate::block::samples::*;
 use crate::image::write::samples::*;
+use crate::error::UnitResult;
 
 use std::marker::PhantomData;
 
@@ -21,6 +22,14 @@ pub trait WritableChannels<'slf> {
     ///  Generate the file meta data of whether and how resolution levels should be stored in the file
     fn infer_level_modes(&self) -> (LevelMode, RoundingMode);
 
+    /// Generate the file meta data regarding deep data.
+    /// Returns the maximum number of samples in a single pixel, or `None` if the channels are not deep.
+    fn infer_max_samples_per_pixel(&self) -> Option<usize> { None }
+
+    /// Check whether the channels can be described by a single header, before any meta data is inferred.
+    /// By default, all channels are valid.
+    fn validate_channels(&self) -> UnitResult { Ok(()) }
+
     /// The type of temporary writer
     type Writer: ChannelsWriter;
 
@@ -33,6 +42,12 @@ pub trait ChannelsWriter: Sync {
 
     /// Deliver a block of pixels, containing all channel data, to be stored in the file
     fn extract_uncompressed_block(&self, header: &Header, block: BlockIndex) -> Vec<u8>; // TODO return uncompressed block?
+
+    /// Deliver a block of deep pixels, containing all channel data, to be stored in the file.
+    /// Only called for deep layers. By default, returns an error, as most channels do not contain deep data.
+    fn extract_uncompressed_deep_block(&self, _header: &Header, _block: BlockIndex) -> Result<UncompressedDeepBlock> {
+        Err(Error::unsupported("deep data in these channels"))
+    }
 }
 
 
@@ -81,10 +96,29 @@ impl<'samples, Samples> WritableChannels<'samples> for AnyChannels<Samples>
         mode
     }
 
+    fn infer_max_samples_per_pixel(&self) -> Option<usize> {
+        let deep = self.list.iter().next().expect("zero channels in list")
+            .sample_data.infer_max_samples_per_pixel().is_some();
+
+        debug_assert!(
+            self.list.iter().skip(1).all(|channel| channel.sample_data.infer_max_samples_per_pixel().is_some() == deep),
+            "either all or no channels of a layer must be deep"
+        );
+
+        self.list.iter().map(|channel| channel.sample_data.infer_max_samples_per_pixel()).max().flatten()
+    }
+
     type Writer = AnyChannelsWriter<Samples::Writer>;
     fn create_writer(&'samples self, header: &Header) -> Self::Writer {
         let channels = self.list.iter()
-            .map(|chan| chan.sample_data.create_samples_writer(header))
+            .map(|chan| {
+                if chan.sampling == Vec2(1, 1) { chan.sample_data.create_samples_writer(header) }
+                else {
+                    // subsampled channels contain fewer samples than the layer contains pixels
+                    let subsampled_header = Header { layer_size: header.layer_size / chan.sampling, .. header.clone() };
+                    chan.sample_data.create_samples_writer(&subsampled_header)
+                }
+            })
             .collect();
 
         AnyChannelsWriter { channels }
@@ -103,6 +137,16 @@ impl<Samples> ChannelsWriter for AnyChannelsWriter<Samples> where Samples: Sampl
             self.channels[line_ref.location.channel].extract_line(line_ref)
         })
     }
+
+    fn extract_uncompressed_deep_block(&self, header: &Header, block_index: BlockIndex) -> Result<UncompressedDeepBlock> {
+        // all channels of a deep layer have the same number of samples in each pixel
+        let sample_counts = self.channels.first().expect("zero channels in list")
+            .extract_deep_sample_counts(block_index);
+
+        Ok(UncompressedDeepBlock::from_lines(&header.channels, block_index, sample_counts, |line_ref| {
+            self.channels[line_ref.location.channel].extract_deep_line(line_ref)
+        }))
+    }
 }
 
 
@@ -119,16 +163,7 @@ where
     <Channels as IntoRecursive>::Recursive: WritableChannelsDescription<<Storage::Pixel as IntoRecursive>::Recursive>,
 {
     fn infer_channel_list(&self) -> ChannelList {
-        let mut vec = self.channels.clone().into_recursive().channel_descriptions_list();
-        vec.sort_unstable_by_key(|channel:&ChannelDescription| channel.name.clone()); // TODO no clone?
-
-        debug_assert!(
-            // check for equal neighbors in sorted vec
-            vec.iter().zip(vec.iter().skip(1)).all(|(prev, next)| prev.name != next.name),
-            "specific channels contain duplicate channel names"
-        );
-
-        ChannelList::new(vec)
+        infer_specific_channel_list::<Channels, <Storage::Pixel as IntoRecursive>::Recursive>(&self.channels)
     }
 
     fn infer_level_modes(&self) -> (LevelMode, RoundingMode) {
@@ -169,29 +204,112 @@ for SpecificChannelsWriter<'channels, PxWriter, Storage, Channels>
         PxWriter: Sync + RecursivePixelWriter<<Storage::Pixel as IntoRecursive>::Recursive>,
 {
     fn extract_uncompressed_block(&self, header: &Header, block_index: BlockIndex) -> Vec<u8> {
-        let block_bytes = block_index.pixel_size.area() * header.channels.bytes_per_pixel;
-        let mut block_bytes = vec![0_u8; block_bytes];
+        extract_pixel_block(&self.channels.pixels, &self.recursive_channel_writer, header, block_index)
+    }
+}
 
-        let width = block_index.pixel_size.0;
-        let line_bytes = width * header.channels.bytes_per_pixel;
-        let byte_lines = block_bytes.chunks_exact_mut(line_bytes);
-        assert_eq!(byte_lines.len(), block_index.pixel_size.height(), "invalid block line splits");
 
-        //dbg!(width, line_bytes, header.channels.bytes_per_pixel, byte_lines.len());
+impl<'c, Channels, Storage>
+WritableChannels<'c> for SpecificChannels<Levels<Storage>, Channels>
+where
+    Storage: 'c + GetPixel,
+    Storage::Pixel: IntoRecursive,
+    Channels: 'c + Sync + Clone + IntoRecursive,
+    <Channels as IntoRecursive>::Recursive: WritableChannelsDescription<<Storage::Pixel as IntoRecursive>::Recursive>,
+{
+    fn infer_channel_list(&self) -> ChannelList {
+        infer_specific_channel_list::<Channels, <Storage::Pixel as IntoRecursive>::Recursive>(&self.channels)
+    }
 
-        let mut pixel_line = Vec::with_capacity(width);
+    fn infer_level_modes(&self) -> (LevelMode, RoundingMode) {
+        match &self.pixels {
+            Levels::Singular(_) => (LevelMode::Singular, RoundingMode::Down),
+            Levels::Mip { rounding_mode, .. } => (LevelMode::MipMap, *rounding_mode),
+            Levels::Rip { rounding_mode, .. } => (LevelMode::RipMap, *rounding_mode),
+        }
+    }
 
-        for (y, line_bytes) in byte_lines.enumerate() {
-            pixel_line.clear();
-            pixel_line.extend((0 .. width).map(|x|
-                self.channels.pixels.get_pixel(block_index.pixel_position + Vec2(x, y)).into_recursive()
-            ));
+    type Writer = SpecificChannelLevelsWriter<
+        'c,
+        <<Channels as IntoRecursive>::Recursive as WritableChannelsDescription<<Storage::Pixel as IntoRecursive>::Recursive>>::RecursiveWriter,
+        Storage,
+        Channels
+    >;
 
-            self.recursive_channel_writer.write_pixels(line_bytes, pixel_line.as_slice(), |px| px);
+    fn create_writer(&'c self, header: &Header) -> Self::Writer {
+        SpecificChannelLevelsWriter {
+            channels: self,
+            recursive_channel_writer: self.channels.clone().into_recursive().create_recursive_writer(&header.channels),
         }
+    }
+}
+
+/// A temporary writer for a layer of specific channels with multiple resolution levels
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct SpecificChannelLevelsWriter<'channels, PixelWriter, Storage, Channels> {
+    channels: &'channels SpecificChannels<Levels<Storage>, Channels>,
+    recursive_channel_writer: PixelWriter,
+}
+
+impl<'channels, PxWriter, Storage, Channels> ChannelsWriter
+for SpecificChannelLevelsWriter<'channels, PxWriter, Storage, Channels>
+    where
+        Channels: Sync,
+        Storage: GetPixel,
+        Storage::Pixel: IntoRecursive,
+        PxWriter: Sync + RecursivePixelWriter<<Storage::Pixel as IntoRecursive>::Recursive>,
+{
+    fn extract_uncompressed_block(&self, header: &Header, block_index: BlockIndex) -> Vec<u8> {
+        let level = self.channels.pixels.get_level(block_index.level).expect("invalid level index");
+        extract_pixel_block(level, &self.recursive_channel_writer, header, block_index)
+    }
+}
+
+/// Sort the channel descriptions alphabetically.
+fn infer_specific_channel_list<Channels, RecursivePixel>(channels: &Channels) -> ChannelList
+    where Channels: Clone + IntoRecursive, Channels::Recursive: WritableChannelsDescription<RecursivePixel>
+{
+    let mut vec = channels.clone().into_recursive().channel_descriptions_list();
+    vec.sort_unstable_by_key(|channel:&ChannelDescription| channel.name.clone()); // TODO no clone?
+
+    debug_assert!(
+        // check for equal neighbors in sorted vec
+        vec.iter().zip(vec.iter().skip(1)).all(|(prev, next)| prev.name != next.name),
+        "specific channels contain duplicate channel names"
+    );
+
+    ChannelList::new(vec)
+}
+
+/// Collect the bytes of a block from the pixel storage, using the block position within its resolution level.
+fn extract_pixel_block<Storage, PxWriter>(
+    pixels: &Storage, recursive_channel_writer: &PxWriter, header: &Header, block_index: BlockIndex
+) -> Vec<u8>
+    where
+        Storage: GetPixel,
+        Storage::Pixel: IntoRecursive,
+        PxWriter: RecursivePixelWriter<<Storage::Pixel as IntoRecursive>::Recursive>,
+{
+    let block_bytes = block_index.pixel_size.area() * header.channels.bytes_per_pixel;
+    let mut block_bytes = vec![0_u8; block_bytes];
+
+    let width = block_index.pixel_size.0;
+    let line_bytes = width * header.channels.bytes_per_pixel;
+    let byte_lines = block_bytes.chunks_exact_mut(line_bytes);
+    assert_eq!(byte_lines.len(), block_index.pixel_size.height(), "invalid block line splits");
 
-        block_bytes
+    let mut pixel_line = Vec::with_capacity(width);
+
+    for (y, line_bytes) in byte_lines.enumerate() {
+        pixel_line.clear();
+        pixel_line.extend((0 .. width).map(|x|
+            pixels.get_pixel(block_index.pixel_position + Vec2(x, y)).into_recursive()
+        ));
+
+        recursive_channel_writer.write_pixels(line_bytes, pixel_line.as_slice(), |px| px);
     }
+
+    block_bytes
 }
 
 /// A tuple containing either `ChannelsDescription` or `Option<ChannelsDescription>` entries.
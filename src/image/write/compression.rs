@@ -0,0 +1,149 @@
+//! Automatically choose the compression method of each layer.
+//!
+//! A few blocks of each layer are compressed with every lossless compression method,
+//! and the method with the smallest estimated size is chosen for the whole layer.
+
+use crate::meta::{Headers, compute_chunk_count};
+use crate::meta::header::Header;
+use crate::compression::Compression;
+use crate::block::{enumerate_ordered_header_block_indices, BlockIndex, UncompressedBlock};
+use crate::image::write::layers::{WritableLayers, LayersWriter};
+use crate::prelude::SmallVec;
+
+
+/// Specifies how the compression method of each layer is chosen automatically.
+/// Use `image.write().choose_compression(selection)` to apply it.
+///
+/// Only lossless compression methods are considered,
+/// and deep layers only consider the methods that support deep data.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CompressionSelection {
+
+    /// How many blocks of each layer are compressed to estimate the size of the layer.
+    /// The blocks are evenly distributed across the layer.
+    /// More blocks produce a better estimate, but take longer.
+    pub sample_block_count: usize,
+
+    /// A compression method is preferred over a slower method
+    /// if its estimated size is at most this fraction larger than the smallest estimated size.
+    /// For example, `0.05` chooses the fastest method that is at most five percent larger than the smallest one.
+    /// Zero always chooses the smallest method.
+    pub size_tolerance: f32,
+}
+
+impl CompressionSelection {
+
+    /// Choose the method that produces the smallest blocks,
+    /// by compressing up to eight blocks of each layer.
+    pub fn smallest() -> Self {
+        CompressionSelection { sample_block_count: 8, size_tolerance: 0.0 }
+    }
+
+    /// Choose the fastest method that produces blocks
+    /// at most the specified fraction larger than the smallest method,
+    /// by compressing up to eight blocks of each layer.
+    pub fn fastest_within(size_tolerance: f32) -> Self {
+        CompressionSelection { size_tolerance, .. Self::smallest() }
+    }
+}
+
+impl Default for CompressionSelection {
+    fn default() -> Self { Self::smallest() }
+}
+
+
+/// All compression methods that can be chosen, ordered from the fastest to the slowest.
+// dwa is never lossless
+const CANDIDATES: [Compression; 8] = [
+    Compression::Uncompressed, Compression::RLE,
+    Compression::B44, Compression::B44A, Compression::PIZ,
+    Compression::ZIP1(None), Compression::ZIP16(None), Compression::PXR24(None),
+];
+
+/// Whether the compression method reconstructs all pixels of the layer exactly.
+fn is_candidate_for(compression: Compression, header: &Header) -> bool {
+    if header.deep { compression.supports_deep_data() }
+    else { header.channels.list.iter().all(|channel| compression.is_lossless_for(channel.sample_type)) }
+}
+
+/// Choose the compression method of each layer by trial compression.
+/// Contains the original method of a layer if no candidate can be used for that layer.
+pub(crate) fn select_compression<'img, L: WritableLayers<'img>>(
+    layers: &'img L, headers: &[Header], selection: CompressionSelection
+) -> SmallVec<[Compression; 3]>
+{
+    // the estimated compressed byte size, for each candidate and each layer
+    let estimated_sizes: SmallVec<[SmallVec<[Option<usize>; 4]>; 8]> = CANDIDATES.iter()
+        .map(|&compression| estimate_sizes(layers, headers, compression, selection.sample_block_count))
+        .collect();
+
+    headers.iter().enumerate().map(|(layer_index, header)| {
+        let layer_sizes = estimated_sizes.iter()
+            .map(|sizes| sizes[layer_index]);
+
+        let smallest = match layer_sizes.clone().flatten().min() {
+            Some(smallest) => smallest,
+            None => return header.compression, // no candidate works for this layer, so keep the original method
+        };
+
+        let max_size = smallest as f64 * (1.0 + selection.size_tolerance.max(0.0) as f64);
+
+        CANDIDATES.iter().zip(layer_sizes)
+            .find(|&(_, size)| size.map_or(false, |size| size as f64 <= max_size))
+            .map(|(&compression, _)| compression)
+            .expect("smallest compression bug")
+    }).collect()
+}
+
+/// Compress a few blocks of each layer with the compression method, returning the estimated byte size of each layer.
+/// Contains `None` for layers where the compression method cannot be used.
+fn estimate_sizes<'img, L: WritableLayers<'img>>(
+    layers: &'img L, headers: &[Header], compression: Compression, sample_block_count: usize
+) -> SmallVec<[Option<usize>; 4]>
+{
+    let headers: Headers = headers.iter()
+        .map(|header|
+            if is_candidate_for(compression, header) { with_compression(header, compression) }
+            else { header.clone() }
+        )
+        .collect();
+
+    let writer = layers.create_writer(&headers);
+
+    headers.iter().enumerate().map(|(layer_index, header)|{
+        if !is_candidate_for(compression, header) { return None; }
+
+        let blocks: Vec<BlockIndex> = enumerate_ordered_header_block_indices(&headers)
+            .filter(|(_, block)| block.layer == layer_index)
+            .map(|(_, block)| block)
+            .collect();
+
+        let sample_count = sample_block_count.max(1).min(blocks.len());
+        let mut byte_size = 0;
+
+        for sample_index in 0 .. sample_count {
+            let block = blocks[sample_index * blocks.len() / sample_count];
+
+            let chunk = {
+                if header.deep { writer.extract_uncompressed_deep_block(&headers, block).and_then(|block| block.compress_to_chunk(&headers)) }
+                else { UncompressedBlock { index: block, data: writer.extract_uncompressed_block(&headers, block) }.compress_to_chunk(&headers) }
+            };
+
+            let mut bytes = Vec::new();
+            chunk.ok()?.write(&mut bytes, headers.len()).ok()?;
+            byte_size += bytes.len();
+        }
+
+        // the number of lines per block depends on the compression method, so compare the whole layer
+        Some(if sample_count == 0 { 0 } else { byte_size * blocks.len() / sample_count })
+    }).collect()
+}
+
+/// Clone the header, replacing the compression method and updating the number of chunks.
+pub(crate) fn with_compression(header: &Header, compression: Compression) -> Header {
+    Header {
+        compression,
+        chunk_count: compute_chunk_count(compression, header.layer_size, header.blocks),
+        .. header.clone()
+    }
+}
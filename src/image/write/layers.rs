@@ -2,7 +2,8 @@
 
 use crate::meta::header::{ImageAttributes, Header};
 use crate::meta::{Headers, compute_chunk_count};
-use crate::block::BlockIndex;
+use crate::block::{BlockIndex, UncompressedDeepBlock};
+use crate::error::{Result, UnitResult};
 use crate::image::{Layers, Layer};
 use crate::meta::attribute::{TileDescription};
 use crate::prelude::{SmallVec};
@@ -15,6 +16,9 @@ pub trait WritableLayers<'slf> {
     /// Generate the file meta data for this list of layers
     fn infer_headers(&self, image_attributes: &ImageAttributes) -> Headers;
 
+    /// Check whether the channels of each layer can be written, before any meta data is inferred.
+    fn validate_layers(&self) -> UnitResult;
+
     /// The type of temporary writer
     type Writer: LayersWriter;
 
@@ -27,6 +31,10 @@ pub trait LayersWriter: Sync {
 
     /// Deliver a block of pixels from a single layer to be stored in the file
     fn extract_uncompressed_block(&self, headers: &[Header], block: BlockIndex) -> Vec<u8>;
+
+    /// Deliver a block of deep pixels from a single deep layer to be stored in the file.
+    /// Only called for deep layers. Returns an error if the channels of the layer cannot deliver deep data.
+    fn extract_uncompressed_deep_block(&self, headers: &[Header], block: BlockIndex) -> Result<UncompressedDeepBlock>;
 }
 
 /// A temporary writer for an arbitrary list of layers
@@ -47,6 +55,10 @@ impl<'slf, Channels: 'slf> WritableLayers<'slf> for Layers<Channels> where Chann
         slice_infer_headers(self.as_slice(), image_attributes)
     }
 
+    fn validate_layers(&self) -> UnitResult {
+        self.iter().try_for_each(WritableLayers::validate_layers)
+    }
+
     type Writer = AllLayersWriter<Channels::Writer>;
     fn create_writer(&'slf self, headers: &[Header]) -> Self::Writer {
         slice_create_writer(self.as_slice(), headers)
@@ -86,6 +98,8 @@ impl<'slf, Channels: WritableChannels<'slf>> WritableLayers<'slf> for Layer<Chan
             self.encoding.compression, self.size, blocks
         );
 
+        let max_samples_per_pixel = self.channel_data.infer_max_samples_per_pixel();
+
         let header = Header {
             channels: self.channel_data.infer_channel_list(),
             compression: self.encoding.compression,
@@ -99,14 +113,18 @@ impl<'slf, Channels: WritableChannels<'slf>> WritableLayers<'slf> for Layer<Chan
             own_attributes: self.attributes.clone(),
 
 
-            deep: false, // TODO deep data
-            deep_data_version: None,
-            max_samples_per_pixel: None,
+            deep: max_samples_per_pixel.is_some(),
+            deep_data_version: max_samples_per_pixel.map(|_| 1),
+            max_samples_per_pixel,
         };
 
         smallvec![ header ]// TODO no array-vs-first
     }
 
+    fn validate_layers(&self) -> UnitResult {
+        self.channel_data.validate_channels()
+    }
+
     type Writer = LayerWriter</*'l,*/ Channels::Writer>;
     fn create_writer(&'slf self, headers: &[Header]) -> Self::Writer {
         let channels = self.channel_data
@@ -120,12 +138,20 @@ impl<C> LayersWriter for AllLayersWriter<C> where C: ChannelsWriter {
     fn extract_uncompressed_block(&self, headers: &[Header], block: BlockIndex) -> Vec<u8> {
         self.layers[block.layer].extract_uncompressed_block(std::slice::from_ref(&headers[block.layer]), block) // TODO no array-vs-first
     }
+
+    fn extract_uncompressed_deep_block(&self, headers: &[Header], block: BlockIndex) -> Result<UncompressedDeepBlock> {
+        self.layers[block.layer].extract_uncompressed_deep_block(std::slice::from_ref(&headers[block.layer]), block) // TODO no array-vs-first
+    }
 }
 
 impl<C> LayersWriter for LayerWriter<C> where C: ChannelsWriter {
     fn extract_uncompressed_block(&self, headers: &[Header], block: BlockIndex) -> Vec<u8> {
         self.channels.extract_uncompressed_block(headers.first().expect("invalid inferred header"), block) // TODO no array-vs-first
     }
+
+    fn extract_uncompressed_deep_block(&self, headers: &[Header], block: BlockIndex) -> Result<UncompressedDeepBlock> {
+        self.channels.extract_uncompressed_deep_block(headers.first().expect("invalid inferred header"), block) // TODO no array-vs-first
+    }
 }
 
 
@@ -134,6 +160,7 @@ impl<C> LayersWriter for LayerWriter<C> where C: ChannelsWriter {
 
 impl<'slf> WritableLayers<'slf> for NoneMore {
     fn infer_headers(&self, _: &ImageAttributes) -> Headers { SmallVec::new() }
+    fn validate_layers(&self) -> UnitResult { Ok(()) }
 
     type Writer = NoneMore;
     fn create_writer(&'slf self, _: &[Header]) -> Self::Writer { NoneMore }
@@ -148,6 +175,11 @@ impl<'slf, InnerLayers, Channels> WritableLayers<'slf> for Recursive<InnerLayers
         headers
     }
 
+    fn validate_layers(&self) -> UnitResult {
+        self.inner.validate_layers()?;
+        self.value.validate_layers()
+    }
+
     type Writer = RecursiveLayersWriter<InnerLayers::Writer, Channels::Writer>;
 
     fn create_writer(&'slf self, headers: &[Header]) -> Self::Writer {
@@ -168,6 +200,10 @@ impl LayersWriter for NoneMore {
     fn extract_uncompressed_block(&self, _: &[Header], _: BlockIndex) -> Vec<u8> {
         panic!("recursive length mismatch bug");
     }
+
+    fn extract_uncompressed_deep_block(&self, _: &[Header], _: BlockIndex) -> Result<UncompressedDeepBlock> {
+        panic!("recursive length mismatch bug");
+    }
 }
 
 impl<InnerLayersWriter, Channels> LayersWriter for RecursiveLayersWriter<InnerLayersWriter, Channels>
@@ -183,6 +219,17 @@ impl<InnerLayersWriter, Channels> LayersWriter for RecursiveLayersWriter<InnerLa
             self.inner.extract_uncompressed_block(headers, block)
         }
     }
+
+    fn extract_uncompressed_deep_block(&self, headers: &[Header], block: BlockIndex) -> Result<UncompressedDeepBlock> {
+        let (layer_index, layer) = &self.value;
+        if *layer_index == block.layer {
+            let header = headers.get(*layer_index).expect("layer index bug");
+            layer.extract_uncompressed_deep_block(std::slice::from_ref(header), block) // TODO no slice?
+        }
+        else {
+            self.inner.extract_uncompressed_deep_block(headers, block)
+        }
+    }
 }
 
 
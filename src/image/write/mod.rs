@@ -15,6 +15,7 @@
 pub mod layers;
 pub mod samples;
 pub mod channels;
+pub mod compression;
 
 
 
@@ -26,6 +27,10 @@ use crate::image::{Image, ignore_progress, SpecificChannels, IntoSample};
 use crate::image::write::layers::{WritableLayers, LayersWriter};
 use crate::math::Vec2;
 use crate::block::writer::ChunksWriter;
+use crate::block::{UncompressedBlock, BlockIndex};
+use crate::image::write::compression::{CompressionSelection, select_compression, with_compression};
+use crate::compression::Compression;
+use crate::prelude::SmallVec;
 
 /// An oversimplified function for "just write the damn file already" use cases.
 /// Have a look at the examples to see how you can write an image with more flexibility (it's not that hard).
@@ -74,6 +79,7 @@ impl<'img, WritableLayers> WritableImage<'img, WritableLayers> for &'img Image<W
             image: self,
             check_compatibility: true,
             parallel: true,
+            chosen_compression: None,
             on_progress: ignore_progress
         }
     }
@@ -87,6 +93,9 @@ pub struct WriteImageWithOptions<'img, Layers, OnProgress> {
     on_progress: OnProgress,
     check_compatibility: bool,
     parallel: bool,
+
+    /// The compression method of each layer, if chosen by `choose_compression`
+    chosen_compression: Option<SmallVec<[Compression; 3]>>,
 }
 
 
@@ -94,8 +103,17 @@ impl<'img, L, F> WriteImageWithOptions<'img, L, F>
     where L: WritableLayers<'img>, F: FnMut(f64)
 {
     /// Generate file meta data for this image. The meta data structure is close to the data in the file.
+    /// If `choose_compression` was specified, contains the compression method chosen for each layer.
     pub fn infer_meta_data(&self) -> Headers { // TODO this should perform all validity checks? and none after that?
-        self.image.layer_data.infer_headers(&self.image.attributes)
+        let headers = self.image.layer_data.infer_headers(&self.image.attributes);
+
+        match &self.chosen_compression {
+            Some(chosen) => headers.iter().zip(chosen)
+                .map(|(header, &compression)| with_compression(header, compression))
+                .collect(),
+
+            None => headers,
+        }
     }
 
     /// Do not compress multiple pixel blocks on multiple threads at once.
@@ -112,6 +130,20 @@ impl<'img, L, F> WriteImageWithOptions<'img, L, F>
     /// __You must care for not producing an invalid file yourself.__
     pub fn skip_compatibility_checks(self) -> Self { Self { check_compatibility: false, ..self } }
 
+    /// Ignore the compression method of each layer, and instead choose a lossless method automatically,
+    /// by compressing a few blocks of each layer with every lossless method.
+    /// Call `infer_meta_data()` to find out which method is chosen for each layer.
+    /// Deep layers only consider methods that support deep data.
+    /// The blocks are compressed once, when this function is called.
+    pub fn choose_compression(self, selection: CompressionSelection) -> Self {
+        // invalid layers are reported when writing the image, without choosing any compression method
+        if self.image.layer_data.validate_layers().is_err() { return self; }
+
+        let headers = self.image.layer_data.infer_headers(&self.image.attributes);
+        let chosen = select_compression(&self.image.layer_data, &headers, selection);
+        Self { chosen_compression: Some(chosen), ..self }
+    }
+
     /// Specify a function to be called regularly throughout the writing process.
     /// Replaces all previously specified progress functions in this reader.
     pub fn on_progress<OnProgress>(self, on_progress: OnProgress) -> WriteImageWithOptions<'img, L, OnProgress>
@@ -121,7 +153,8 @@ impl<'img, L, F> WriteImageWithOptions<'img, L, F>
             on_progress,
             image: self.image,
             check_compatibility: self.check_compatibility,
-            parallel: self.parallel
+            parallel: self.parallel,
+            chosen_compression: self.chosen_compression,
         }
     }
 
@@ -152,6 +185,8 @@ impl<'img, L, F> WriteImageWithOptions<'img, L, F>
     /// If your writer cannot seek, you can write to an in-memory vector of bytes first.
     #[must_use]
     pub fn to_buffered(self, write: impl Write + Seek) -> UnitResult {
+        self.image.layer_data.validate_layers()?;
+
         let headers = self.infer_meta_data();
         let layers = self.image.layer_data.create_writer(&headers);
 
@@ -159,6 +194,43 @@ impl<'img, L, F> WriteImageWithOptions<'img, L, F>
             write, headers, self.check_compatibility,
             move |meta, chunk_writer|{
 
+                if meta.headers.iter().any(|header| header.deep) {
+                    let mut chunk_writer = chunk_writer.on_progress(self.on_progress);
+                    let blocks = meta.enumerate_ordered_header_block_indices();
+                    let is_deep = |block_index: BlockIndex| meta.headers[block_index.layer].deep;
+
+                    // flat and deep blocks share the compressor, so flat layers are still compressed in parallel
+                    match if self.parallel { chunk_writer.parallel_blocks_compressor(&meta) } else { None } {
+                        Some(mut compressor) => for (index_in_header_increasing_y, block_index) in blocks {
+                            if is_deep(block_index) {
+                                let block = layers.extract_uncompressed_deep_block(&meta.headers, block_index)?;
+                                compressor.add_deep_block_to_compression_queue(index_in_header_increasing_y, block)?;
+                            }
+                            else {
+                                let data = layers.extract_uncompressed_block(&meta.headers, block_index);
+                                compressor.add_block_to_compression_queue(index_in_header_increasing_y, UncompressedBlock { index: block_index, data })?;
+                            }
+                        },
+
+                        None => {
+                            let mut compressor = chunk_writer.sequential_blocks_compressor(&meta);
+
+                            for (index_in_header_increasing_y, block_index) in blocks {
+                                if is_deep(block_index) {
+                                    let block = layers.extract_uncompressed_deep_block(&meta.headers, block_index)?;
+                                    compressor.compress_deep_block(index_in_header_increasing_y, block)?;
+                                }
+                                else {
+                                    let data = layers.extract_uncompressed_block(&meta.headers, block_index);
+                                    compressor.compress_block(index_in_header_increasing_y, UncompressedBlock { index: block_index, data })?;
+                                }
+                            }
+                        },
+                    }
+
+                    return Ok(());
+                }
+
                 let blocks = meta.collect_ordered_block_data(|block_index|
                      layers.extract_uncompressed_block(&meta.headers, block_index)
                 );
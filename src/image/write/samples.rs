@@ -2,20 +2,25 @@
 
 use crate::meta::attribute::{LevelMode, SampleType, TileDescription};
 use crate::meta::header::Header;
-use crate::block::lines::LineRefMut;
-use crate::image::{FlatSamples, Levels, RipMaps};
+use crate::block::lines::{LineRefMut, DeepLineRefMut};
+use crate::block::BlockIndex;
+use crate::image::{FlatSamples, DeepSamples, DeepAndFlatSamples, Levels, RipMaps};
 use crate::math::{Vec2, RoundingMode};
+use std::convert::TryFrom;
 use crate::meta::{rip_map_levels, mip_map_levels, rip_map_indices, mip_map_indices, BlockDescription};
 
 /// Enable an image with this sample grid to be written to a file.
 /// Also can contain multiple resolution levels.
 /// Usually contained within `Channels`.
 pub trait WritableSamples<'slf> {
-    // fn is_deep(&self) -> bool;
 
     /// Generate the file meta data regarding the number type of this storage
     fn sample_type(&self) -> SampleType;
 
+    /// Generate the file meta data regarding deep data.
+    /// Returns the maximum number of samples in a single pixel, or `None` if the samples are not deep.
+    fn infer_max_samples_per_pixel(&self) -> Option<usize> { None }
+
     /// Generate the file meta data regarding resolution levels
     fn infer_level_modes(&self) -> (LevelMode, RoundingMode);
 
@@ -33,6 +38,10 @@ pub trait WritableLevel<'slf> {
     /// Generate the file meta data regarding the number type of these samples
     fn sample_type(&self) -> SampleType;
 
+    /// Generate the file meta data regarding deep data.
+    /// Returns the maximum number of samples in a single pixel, or `None` if the samples are not deep.
+    fn infer_max_samples_per_pixel(&self) -> Option<usize> { None }
+
     /// The type of the temporary writer for this single level of samples
     type Writer: SamplesWriter;
 
@@ -45,6 +54,18 @@ pub trait SamplesWriter: Sync {
 
     /// Deliver a single short horizontal list of samples for a specific channel.
     fn extract_line(&self, line: LineRefMut<'_>);
+
+    /// Deliver the number of samples in each pixel of a deep block, row after row.
+    /// Only called for deep samples.
+    fn extract_deep_sample_counts(&self, _block: BlockIndex) -> Vec<u32> {
+        panic!("deep sample counts requested from flat samples bug")
+    }
+
+    /// Deliver a single short horizontal list of deep samples for a specific channel.
+    /// Only called for deep samples.
+    fn extract_deep_line(&self, _line: DeepLineRefMut<'_>) {
+        panic!("deep line requested from flat samples bug")
+    }
 }
 
 /// A temporary writer for a predefined non-deep sample storage
@@ -54,6 +75,24 @@ pub struct FlatSamplesWriter<'samples> {
     samples: &'samples FlatSamples
 }
 
+/// A temporary writer for a predefined deep sample storage
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct DeepSamplesWriter<'samples> {
+    resolution: Vec2<usize>, // respects resolution level
+    samples: &'samples DeepSamples
+}
+
+/// A temporary writer for samples that are either deep or flat
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum AnySamplesWriter<'samples> {
+
+    /// Writes deep samples
+    Deep(DeepSamplesWriter<'samples>),
+
+    /// Writes flat samples
+    Flat(FlatSamplesWriter<'samples>),
+}
+
 
 
 // used if no layers are used and the flat samples are directly inside the channels
@@ -119,6 +158,136 @@ impl<'samples> SamplesWriter for FlatSamplesWriter<'samples> {
 }
 
 
+// used if no layers are used and the deep samples are directly inside the channels
+impl<'samples> WritableSamples<'samples> for DeepSamples {
+    fn sample_type(&self) -> SampleType { WritableSamples::sample_type(&self.values) }
+    fn infer_max_samples_per_pixel(&self) -> Option<usize> { Some(self.max_pixel_sample_count()) }
+    fn infer_level_modes(&self) -> (LevelMode, RoundingMode) { (LevelMode::Singular, RoundingMode::Down) }
+
+    type Writer = DeepSamplesWriter<'samples>;
+    fn create_samples_writer(&'samples self, header: &Header) -> Self::Writer {
+        self.create_level_writer(header.layer_size)
+    }
+}
+
+// used if layers are used and the deep samples are inside the levels
+impl<'samples> WritableLevel<'samples> for DeepSamples {
+    fn sample_type(&self) -> SampleType { WritableSamples::sample_type(&self.values) }
+    fn infer_max_samples_per_pixel(&self) -> Option<usize> { Some(self.max_pixel_sample_count()) }
+
+    type Writer = DeepSamplesWriter<'samples>;
+    fn create_level_writer(&'samples self, size: Vec2<usize>) -> Self::Writer {
+        debug_assert_eq!(self.pixel_count(), size.area(), "deep sample offset count does not match the resolution");
+
+        DeepSamplesWriter {
+            resolution: size,
+            samples: self
+        }
+    }
+}
+
+impl<'samples> SamplesWriter for DeepSamplesWriter<'samples> {
+    fn extract_line(&self, _line: LineRefMut<'_>) {
+        panic!("flat line requested from deep samples bug")
+    }
+
+    fn extract_deep_sample_counts(&self, block: BlockIndex) -> Vec<u32> {
+        let image_width = self.resolution.width();
+
+        (0 .. block.pixel_size.height()).flat_map(|y| (0 .. block.pixel_size.width()).map(move |x| {
+            let position = block.pixel_position + Vec2(x, y);
+            let sample_count = self.samples.pixel_sample_count(position.y() * image_width + position.x());
+            u32::try_from(sample_count).expect("deep pixel sample count exceeds u32 range")
+        })).collect()
+    }
+
+    fn extract_deep_line(&self, line: DeepLineRefMut<'_>) {
+        let image_width = self.resolution.width();
+        debug_assert_ne!(image_width, 0, "image width calculation bug");
+
+        let start_pixel = line.location.position.y() * image_width + line.location.position.x();
+        let end_pixel = start_pixel + line.location.sample_count;
+
+        debug_assert!(
+            end_pixel <= self.samples.pixel_count(),
+            "for resolution {:?}, this is an invalid line: {:?}",
+            self.resolution, line.location
+        );
+
+        let start_index = self.samples.sample_offsets[start_pixel];
+        let end_index = self.samples.sample_offsets[end_pixel];
+
+        match &self.samples.values {
+            FlatSamples::F16(samples) => line.write_samples_from_slice(&samples[start_index .. end_index]),
+            FlatSamples::F32(samples) => line.write_samples_from_slice(&samples[start_index .. end_index]),
+            FlatSamples::U32(samples) => line.write_samples_from_slice(&samples[start_index .. end_index]),
+        }.expect("writing line bytes failed");
+    }
+}
+
+
+// used if no layers are used and the samples are directly inside the channels
+impl<'samples> WritableSamples<'samples> for DeepAndFlatSamples {
+    fn sample_type(&self) -> SampleType {
+        match self {
+            DeepAndFlatSamples::Deep(samples) => WritableSamples::sample_type(samples),
+            DeepAndFlatSamples::Flat(samples) => WritableSamples::sample_type(samples),
+        }
+    }
+
+    fn infer_max_samples_per_pixel(&self) -> Option<usize> {
+        match self {
+            DeepAndFlatSamples::Deep(samples) => WritableSamples::infer_max_samples_per_pixel(samples),
+            DeepAndFlatSamples::Flat(samples) => WritableSamples::infer_max_samples_per_pixel(samples),
+        }
+    }
+
+    fn infer_level_modes(&self) -> (LevelMode, RoundingMode) { (LevelMode::Singular, RoundingMode::Down) }
+
+    type Writer = AnySamplesWriter<'samples>;
+    fn create_samples_writer(&'samples self, header: &Header) -> Self::Writer {
+        self.create_level_writer(header.layer_size)
+    }
+}
+
+// used if layers are used and the samples are inside the levels
+impl<'samples> WritableLevel<'samples> for DeepAndFlatSamples {
+    fn sample_type(&self) -> SampleType { WritableSamples::sample_type(self) }
+    fn infer_max_samples_per_pixel(&self) -> Option<usize> { WritableSamples::infer_max_samples_per_pixel(self) }
+
+    type Writer = AnySamplesWriter<'samples>;
+    fn create_level_writer(&'samples self, size: Vec2<usize>) -> Self::Writer {
+        match self {
+            DeepAndFlatSamples::Deep(samples) => AnySamplesWriter::Deep(samples.create_level_writer(size)),
+            DeepAndFlatSamples::Flat(samples) => AnySamplesWriter::Flat(samples.create_level_writer(size)),
+        }
+    }
+}
+
+impl<'samples> SamplesWriter for AnySamplesWriter<'samples> {
+    fn extract_line(&self, line: LineRefMut<'_>) {
+        match self {
+            AnySamplesWriter::Deep(writer) => writer.extract_line(line),
+            AnySamplesWriter::Flat(writer) => writer.extract_line(line),
+        }
+    }
+
+    fn extract_deep_sample_counts(&self, block: BlockIndex) -> Vec<u32> {
+        match self {
+            AnySamplesWriter::Deep(writer) => writer.extract_deep_sample_counts(block),
+            AnySamplesWriter::Flat(writer) => writer.extract_deep_sample_counts(block),
+        }
+    }
+
+    fn extract_deep_line(&self, line: DeepLineRefMut<'_>) {
+        match self {
+            AnySamplesWriter::Deep(writer) => writer.extract_deep_line(line),
+            AnySamplesWriter::Flat(writer) => writer.extract_deep_line(line),
+        }
+    }
+}
+
+
 impl<'samples, LevelSamples> WritableSamples<'samples> for Levels<LevelSamples>
     where LevelSamples: WritableLevel<'samples>
 {
@@ -133,6 +302,10 @@ impl<'samples, LevelSamples> WritableSamples<'samples> for Levels<LevelSamples>
         sample_type
     }
 
+    fn infer_max_samples_per_pixel(&self) -> Option<usize> {
+        self.levels_as_slice().iter().map(|level| level.infer_max_samples_per_pixel()).max().flatten()
+    }
+
     fn infer_level_modes(&self) -> (LevelMode, RoundingMode) {
         match self {
             Levels::Singular(_) => (LevelMode::Singular, RoundingMode::Down),
@@ -202,4 +375,14 @@ impl<Samples> SamplesWriter for LevelsWriter<Samples> where Samples: SamplesWrit
         self.levels.get_level(line.location.level).expect("invalid level index") // TODO compute level size from line index??
             .extract_line(line)
     }
+
+    fn extract_deep_sample_counts(&self, block: BlockIndex) -> Vec<u32> {
+        self.levels.get_level(block.level).expect("invalid level index")
+            .extract_deep_sample_counts(block)
+    }
+
+    fn extract_deep_line(&self, line: DeepLineRefMut<'_>) {
+        self.levels.get_level(line.location.level).expect("invalid level index")
+            .extract_deep_line(line)
+    }
 }
@@ -68,11 +68,17 @@ pub mod prelude {
     pub use traits::*;
 
     pub use crate::image::write::{write_rgb_file, write_rgba_file};
+    pub use crate::image::write::compression::CompressionSelection;
+    pub use crate::image::resample::{LevelOptions, ResamplingFilter, WrapMode};
+    pub use crate::image::texture::{Texture, TextureFilter};
+    pub use crate::image::environment_map::{CubeFace, EnvironmentMapOptions};
+    pub use crate::image::read::specific_channels::ChannelNamePolicy;
     pub use crate::image::read::{
         read_first_rgba_layer_from_file,
         read_all_rgba_layers_from_file,
         read_all_data_from_file,
         read_all_flat_layers_from_file,
+        read_all_flat_and_deep_layers_from_file,
         read_first_flat_layer_from_file
     };
 
@@ -22,6 +22,9 @@ pub enum AttributeValue {
     /// This image is an environment map.
     EnvironmentMap(EnvironmentMap),
 
+    /// Whether the samples of a deep image are sorted and non-overlapping.
+    DeepImageState(DeepImageState),
+
     /// Film roll information.
     KeyCode(KeyCode),
 
@@ -227,8 +230,8 @@ pub struct ChannelList {
     /// The channels in this list.
     pub list: SmallVec<[ChannelDescription; 5]>,
 
-    /// The number of bytes that one pixel in this image needs.
-    // FIXME this needs to account for subsampling anywhere?
+    /// The number of bytes that one pixel in this image needs, ignoring subsampling.
+    /// Use `byte_size_of_section` to compute the byte size of a block with subsampled channels.
     pub bytes_per_pixel: usize, // FIXME only makes sense for flat images!
 
     /// The sample type of all channels, if all channels have the same type.
@@ -308,6 +311,24 @@ pub enum EnvironmentMap {
     Cube,
 }
 
+/// Describes whether the samples of each pixel in a deep image
+/// are sorted by depth and whether the samples overlap each other.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum DeepImageState {
+
+    /// The samples may be in any order and may overlap.
+    Messy,
+
+    /// The samples of each pixel are sorted by depth, but may overlap.
+    Sorted,
+
+    /// No samples overlap, but they may be in any order.
+    NonOverlapping,
+
+    /// The samples of each pixel are sorted by depth and do not overlap.
+    Tidy,
+}
+
 /// Uniquely identifies a motion picture film frame.
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
 pub struct KeyCode {
@@ -622,6 +643,18 @@ impl Text {
 
         string_chars.eq(self_chars)
     }
+
+    /// Joins the components with dots, like the channel name `diffuse.right.R`.
+    pub(crate) fn join_with_dots(components: &[&TextSlice]) -> Self {
+        let mut bytes = TextBytes::new();
+
+        for (index, component) in components.iter().enumerate() {
+            if index != 0 { bytes.push(b'.'); }
+            bytes.extend_from_slice(component);
+        }
+
+        Self::from_bytes_unchecked(bytes)
+    }
 }
 
 impl PartialEq<str> for Text {
@@ -720,6 +753,13 @@ impl ChannelList {
         }
     }
 
+    /// The number of bytes that the pixels inside a section of the data window need, respecting subsampling.
+    pub fn byte_size_of_section(&self, section: IntegerBounds) -> usize {
+        self.list.iter()
+            .map(|channel| channel.subsampled_section_resolution(section).area() * channel.sample_type.bytes_per_sample())
+            .sum()
+    }
+
     /// Iterate over the channels, and adds to each channel the byte offset of the channels sample type.
     /// Assumes the internal channel list is properly sorted.
     pub fn channels_with_byte_offset(&self) -> impl Iterator<Item=(usize, &ChannelDescription)> {
@@ -904,6 +944,15 @@ impl IntegerBounds {
         && subset.end().x() <= self.end().x()
         && subset.end().y() <= self.end().y()
     }
+
+    /// Returns the rectangle that is inside both rectangles, or `None` if they do not overlap.
+    pub fn intersection(self, other: Self) -> Option<Self> {
+        let start = self.position.max(other.position);
+        let end = self.end().min(other.end());
+
+        if end.x() <= start.x() || end.y() <= start.y() { None }
+        else { Some(IntegerBounds::new(start, Vec2((end.x() - start.x()) as usize, (end.y() - start.y()) as usize))) }
+    }
 }
 
 
@@ -1016,6 +1065,22 @@ impl ChannelDescription {
         dimensions / self.sampling
     }
 
+    /// The number of samples of this channel inside a section of the data window, respecting subsampling.
+    /// Only the pixels with coordinates divisible by the sampling rate contain a sample,
+    /// so a single line of a block may contain no samples of a subsampled channel.
+    pub fn subsampled_section_resolution(&self, section: IntegerBounds) -> Vec2<usize> {
+        // the number of multiples of `sampling` in `start .. start + length`
+        fn sample_count(start: i32, length: usize, sampling: usize) -> usize {
+            let (start, end, sampling) = (start as i64, start as i64 + length as i64, sampling as i64);
+            ((end - 1).div_euclid(sampling) - (start - 1).div_euclid(sampling)) as usize
+        }
+
+        Vec2(
+            sample_count(section.position.x(), section.size.width(), self.sampling.x()),
+            sample_count(section.position.y(), section.size.height(), self.sampling.y()),
+        )
+    }
+
     /// Number of bytes this would consume in an exr file.
     pub fn byte_size(&self) -> usize {
         self.name.null_terminated_byte_size()
@@ -1085,11 +1150,8 @@ impl ChannelDescription {
             return Err(Error::invalid("channel sampling factor not dividing data window size"));
         }
 
-        if self.sampling != Vec2(1,1) {
-            // TODO this must only be implemented in the crate::image module and child modules,
-            //      should not be too difficult
-
-            return Err(Error::unsupported("channel subsampling not supported yet"));
+        if !allow_sampling && self.sampling != Vec2(1,1) {
+            return Err(Error::unsupported("subsampling in deep or tiled images"));
         }
 
         Ok(())
@@ -1307,6 +1369,19 @@ impl TimeCode {
     }
 }
 
+/// The primaries and the white point of `Rec. ITU-R BT.709-3`,
+/// which should be assumed if a file does not have a chromaticities attribute.
+impl Default for Chromaticities {
+    fn default() -> Self {
+        Chromaticities {
+            red: Vec2(0.64, 0.33),
+            green: Vec2(0.30, 0.60),
+            blue: Vec2(0.15, 0.06),
+            white: Vec2(0.3127, 0.3290),
+        }
+    }
+}
+
 impl Chromaticities {
 
     /// Number of bytes this would consume in an exr file.
@@ -1352,10 +1427,10 @@ impl Compression {
         match self {
             Uncompressed => 0_u8,
             RLE => 1_u8,
-            ZIP1 => 2_u8,
-            ZIP16 => 3_u8,
+            ZIP1(_) => 2_u8,
+            ZIP16(_) => 3_u8,
             PIZ => 4_u8,
-            PXR24 => 5_u8,
+            PXR24(_) => 5_u8,
             B44 => 6_u8,
             B44A => 7_u8,
             DWAA(_) => 8_u8,
@@ -1370,14 +1445,15 @@ impl Compression {
         Ok(match u8::read(read)? {
             0 => Uncompressed,
             1 => RLE,
-            2 => ZIP1,
-            3 => ZIP16,
+            2 => ZIP1(None),
+            3 => ZIP16(None),
             4 => PIZ,
-            5 => PXR24,
+            5 => PXR24(None),
             6 => B44,
             7 => B44A,
             8 => DWAA(None),
             9 => DWAB(None),
+            10 | 11 => return Err(Error::unsupported("htj2k compression method")),
             _ => return Err(Error::unsupported("unknown compression method")),
         })
     }
@@ -1412,6 +1488,39 @@ impl EnvironmentMap {
     }
 }
 
+impl DeepImageState {
+
+    /// Number of bytes this would consume in an exr file.
+    pub fn byte_size() -> usize {
+        u8::BYTE_SIZE
+    }
+
+    /// Without validation, write this instance to the byte stream.
+    pub fn write<W: Write>(self, write: &mut W) -> UnitResult {
+        use self::DeepImageState::*;
+        match self {
+            Messy => 0_u8,
+            Sorted => 1_u8,
+            NonOverlapping => 2_u8,
+            Tidy => 3_u8,
+        }.write(write)?;
+
+        Ok(())
+    }
+
+    /// Read the value without validating.
+    pub fn read<R: Read>(read: &mut R) -> Result<Self> {
+        use self::DeepImageState::*;
+        Ok(match u8::read(read)? {
+            0 => Messy,
+            1 => Sorted,
+            2 => NonOverlapping,
+            3 => Tidy,
+            _ => return Err(Error::invalid("deep image state attribute value")),
+        })
+    }
+}
+
 impl KeyCode {
 
     /// Number of bytes this would consume in an exr file.
@@ -1672,6 +1781,7 @@ impl AttributeValue {
             Chromaticities(_) => self::Chromaticities::byte_size(),
             Compression(_) => self::Compression::byte_size(),
             EnvironmentMap(_) => self::EnvironmentMap::byte_size(),
+            DeepImageState(_) => self::DeepImageState::byte_size(),
 
             KeyCode(_) => self::KeyCode::byte_size(),
             LineOrder(_) => self::LineOrder::byte_size(),
@@ -1713,6 +1823,7 @@ impl AttributeValue {
             Chromaticities(_) =>  ty::CHROMATICITIES,
             Compression(_) =>  ty::COMPRESSION,
             EnvironmentMap(_) =>  ty::ENVIRONMENT_MAP,
+            DeepImageState(_) =>  ty::DEEP_IMAGE_STATE,
             KeyCode(_) =>  ty::KEY_CODE,
             LineOrder(_) =>  ty::LINE_ORDER,
             Matrix3x3(_) =>  ty::F32MATRIX3X3,
@@ -1749,6 +1860,7 @@ impl AttributeValue {
             Chromaticities(ref value) => value.write(write)?,
             Compression(value) => value.write(write)?,
             EnvironmentMap(value) => value.write(write)?,
+            DeepImageState(value) => value.write(write)?,
 
             KeyCode(value) => value.write(write)?,
             LineOrder(value) => value.write(write)?,
@@ -1832,6 +1944,7 @@ impl AttributeValue {
                 ty::CHROMATICITIES  => Chromaticities(self::Chromaticities::read(reader)?),
                 ty::COMPRESSION     => Compression(self::Compression::read(reader)?),
                 ty::ENVIRONMENT_MAP => EnvironmentMap(self::EnvironmentMap::read(reader)?),
+                ty::DEEP_IMAGE_STATE => DeepImageState(self::DeepImageState::read(reader)?),
 
                 ty::KEY_CODE   => KeyCode(self::KeyCode::read(reader)?),
                 ty::LINE_ORDER => LineOrder(self::LineOrder::read(reader)?),
@@ -1965,6 +2078,7 @@ pub mod type_names {
         CHROMATICITIES: b"chromaticities",
         COMPRESSION:    b"compression",
         ENVIRONMENT_MAP:b"envmap",
+        DEEP_IMAGE_STATE:b"deepImageState",
         KEY_CODE:       b"keycode",
         LINE_ORDER:     b"lineOrder",
         F32MATRIX3X3:   b"m33f",
@@ -211,7 +211,7 @@ pub struct LayerAttributes {
     pub world_to_normalized_device: Option<Matrix4x4>,
 
     /// Specifies whether the pixels in a deep image are sorted and non-overlapping.
-    pub deep_image_state: Option<Rational>,
+    pub deep_image_state: Option<DeepImageState>,
 
     /// If the image was cropped, contains the original data window.
     pub original_data_window: Option<IntegerBounds>,
@@ -531,21 +531,17 @@ impl Header {
                 tile.coordinates
             },
 
-            CompressedBlock::ScanLine(ref block) => {
-                let size = self.compression.scan_lines_per_block() as i32;
-                let y = (block.y_coordinate - self.own_attributes.layer_position.y()) / size;
-
-                if y < 0 {
-                    return Err(Error::invalid("scan block y coordinate"));
-                }
+            CompressedBlock::DeepTile(ref tile) => {
+                tile.coordinates
+            },
 
-                TileCoordinates {
-                    tile_index: Vec2(0, y as usize),
-                    level_index: Vec2(0, 0)
-                }
+            CompressedBlock::ScanLine(ref block) => {
+                self.get_scan_line_block_tile_coordinates(block.y_coordinate)?
             },
 
-            _ => return Err(Error::unsupported("deep data not supported yet"))
+            CompressedBlock::DeepScanLine(ref block) => {
+                self.get_scan_line_block_tile_coordinates(block.y_coordinate)?
+            },
         })
     }
 
@@ -615,6 +611,7 @@ impl Header {
 
         self.data_window().validate(None)?;
         self.shared_attributes.display_window.validate(None)?;
+        self.compression.validate()?;
 
         if strict {
             if is_multilayer {
@@ -814,7 +811,7 @@ impl Header {
                         (name::MULTI_VIEW, TextVector(value)) => layer_attributes.multi_view_names = Some(value),
                         (name::WORLD_TO_CAMERA, Matrix4x4(value)) => layer_attributes.world_to_camera = Some(value),
                         (name::WORLD_TO_NDC, Matrix4x4(value)) => layer_attributes.world_to_normalized_device = Some(value),
-                        (name::DEEP_IMAGE_STATE, Rational(value)) => layer_attributes.deep_image_state = Some(value),
+                        (name::DEEP_IMAGE_STATE, DeepImageState(value)) => layer_attributes.deep_image_state = Some(value),
                         (name::ORIGINAL_DATA_WINDOW, IntegerBounds(value)) => layer_attributes.original_data_window = Some(value),
                         (name::DWA_COMPRESSION_LEVEL, F32(value)) => dwa_compression_level = Some(value),
                         (name::PREVIEW, Preview(value)) => layer_attributes.preview = Some(value),
@@ -930,9 +927,11 @@ impl Header {
         use crate::meta::header::standard_names::*;
         use AttributeValue::*;
 
-        let (block_type, tiles) = match self.blocks {
-            BlockDescription::ScanLines => (attribute::BlockType::ScanLine, None),
-            BlockDescription::Tiles(tiles) => (attribute::BlockType::Tile, Some(tiles))
+        let (block_type, tiles) = match (self.blocks, self.deep) {
+            (BlockDescription::ScanLines, false) => (attribute::BlockType::ScanLine, None),
+            (BlockDescription::Tiles(tiles), false) => (attribute::BlockType::Tile, Some(tiles)),
+            (BlockDescription::ScanLines, true) => (attribute::BlockType::DeepScanLine, None),
+            (BlockDescription::Tiles(tiles), true) => (attribute::BlockType::DeepTile, Some(tiles)),
         };
 
         fn usize_as_i32(value: usize) -> AttributeValue {
@@ -988,7 +987,7 @@ impl Header {
             MULTI_VIEW: TextVector = &self.own_attributes.multi_view_names,
             WORLD_TO_CAMERA: Matrix4x4 = &self.own_attributes.world_to_camera,
             WORLD_TO_NDC: Matrix4x4 = &self.own_attributes.world_to_normalized_device,
-            DEEP_IMAGE_STATE: Rational = &self.own_attributes.deep_image_state,
+            DEEP_IMAGE_STATE: DeepImageState = &self.own_attributes.deep_image_state,
             ORIGINAL_DATA_WINDOW: IntegerBounds = &self.own_attributes.original_data_window,
             CHROMATICITIES: Chromaticities = &self.shared_attributes.chromaticities,
             PREVIEW: Preview = &self.own_attributes.preview,
@@ -33,6 +33,9 @@ pub fn fuzzed(){
 
         let _ = read().no_deep_data().all_resolution_levels().all_channels()
             .all_layers().all_attributes().pedantic().from_file(file);
+
+        let _ = read().flat_and_deep_data().largest_resolution_level().all_channels()
+            .all_layers().all_attributes().pedantic().from_file(file);
     }
 }
 
@@ -80,6 +83,10 @@ pub fn damaged(){
                 let _full = read_all_data_from_file(file)?;
             }
 
+            {
+                let _deep = read_all_flat_and_deep_layers_from_file(file)?;
+            }
+
             Ok(())
         });
 
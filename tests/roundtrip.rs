@@ -130,7 +130,16 @@ fn round_trip_all_files_rgba() {
         PathBuf::from("tests/images/valid/openexr/TestImages/GrayRampsDiagonal.exr"),
         PathBuf::from("tests/images/valid/openexr/TestImages/GrayRampsHorizontal.exr"),
         PathBuf::from("tests/images/valid/openexr/TestImages/WideFloatRange.exr"),
-        PathBuf::from("tests/images/valid/openexr/IlmfmlmflmTest/v1.7.test.tiled.exr")
+        PathBuf::from("tests/images/valid/openexr/IlmfmlmflmTest/v1.7.test.tiled.exr"),
+
+        // these files only contain luminance and chroma channels, see `round_trip_luminance_chroma_file_as_rgba`
+        PathBuf::from("tests/images/valid/openexr/Chromaticities/Rec709_YC.exr"),
+        PathBuf::from("tests/images/valid/openexr/Chromaticities/XYZ_YC.exr"),
+        PathBuf::from("tests/images/valid/openexr/LuminanceChroma/CrissyField.exr"),
+        PathBuf::from("tests/images/valid/openexr/LuminanceChroma/Flowers.exr"),
+        PathBuf::from("tests/images/valid/openexr/LuminanceChroma/MtTamNorth.exr"),
+        PathBuf::from("tests/images/valid/openexr/LuminanceChroma/StarField.exr"),
+        PathBuf::from("tests/images/valid/openexr/IlmfmlmflmTest/comp_b44.exr"),
     ];
 
     println!("checking rgba feature set");
@@ -159,6 +168,142 @@ fn round_trip_all_files_rgba() {
 
 // TODO compare rgba vs rgb images for color content, and rgb vs rgb(a?)
 
+#[test]
+fn read_deep_data() {
+    let path = "tests/images/valid/openexr/v2/Stereo/Trunks.exr";
+    let image = read_all_flat_and_deep_layers_from_file(path).unwrap();
+    assert_eq!(image.layer_data.len(), 2);
+
+    for layer in &image.layer_data {
+        let channels = &layer.channel_data.list;
+        assert_eq!(channels.iter().map(|channel| channel.name.to_string()).collect::<Vec<_>>(), vec!["A", "B", "G", "R", "Z"]);
+
+        let deep_samples: Vec<&DeepSamples> = channels.iter()
+            .map(|channel| match &channel.sample_data {
+                DeepAndFlatSamples::Deep(samples) => samples,
+                DeepAndFlatSamples::Flat(_) => panic!("channel `{}` should be deep", channel.name),
+            })
+            .collect();
+
+        let sample_offsets = &deep_samples[0].sample_offsets;
+        assert_eq!(sample_offsets.len(), layer.size.area() + 1);
+        assert!(sample_offsets.windows(2).all(|offsets| offsets[0] <= offsets[1]));
+        assert!(deep_samples[0].total_sample_count() > 0);
+
+        // all channels of a layer have the same number of samples in each pixel
+        for samples in &deep_samples {
+            assert_eq!(&samples.sample_offsets, sample_offsets);
+            assert_eq!(samples.total_sample_count(), *sample_offsets.last().unwrap());
+        }
+
+        assert!(deep_samples[0].values.values_as_f32().all(|alpha| alpha > 0.0 && alpha <= 1.0));
+        assert!(deep_samples[4].values.values_as_f32().all(|depth| depth > 0.0));
+    }
+
+    // readers without deep data support reject or skip the deep layers
+    assert!(matches!(read_all_flat_layers_from_file(path), Err(Error::NotSupported(_))));
+    assert!(matches!(read_first_flat_layer_from_file(path), Err(Error::NotSupported(_))));
+}
+
+#[test]
+fn round_trip_deep_data() {
+    let path = "tests/images/valid/openexr/v2/LowResLeftView/Trunks.exr";
+    let image = read_all_flat_and_deep_layers_from_file(path).unwrap();
+
+    let encodings = [
+        Encoding { compression: Compression::Uncompressed, blocks: Blocks::ScanLines, line_order: LineOrder::Increasing },
+        Encoding { compression: Compression::RLE, blocks: Blocks::ScanLines, line_order: LineOrder::Decreasing },
+        Encoding { compression: Compression::ZIP1(None), blocks: Blocks::Tiles(Vec2(32, 16)), line_order: LineOrder::Increasing },
+        Encoding { compression: Compression::ZIP16(None), blocks: Blocks::ScanLines, line_order: LineOrder::Increasing },
+    ];
+
+    for &encoding in &encodings {
+        let mut image = image.clone();
+        for layer in &mut image.layer_data { layer.encoding = encoding; }
+
+        let mut tmp_bytes = Vec::new();
+        image.write().to_buffered(Cursor::new(&mut tmp_bytes)).unwrap();
+
+        let image2 = read().flat_and_deep_data().largest_resolution_level().all_channels().all_layers().all_attributes()
+            .pedantic().from_buffered(Cursor::new(tmp_bytes.as_slice())).unwrap();
+
+        image.assert_equals_result(&image2);
+    }
+}
+
+#[test]
+fn flatten_deep_data() {
+    let image = read_all_flat_and_deep_layers_from_file("tests/images/valid/openexr/v2/LowResLeftView/Trunks.exr").unwrap();
+    let flat_image = image.flatten(exr::image::deep::FlattenOptions::default()).unwrap();
+
+    for layer in &flat_image.layer_data {
+        let alpha = &layer.channel_data.list.iter().find(|channel| channel.name.eq("A")).unwrap().sample_data;
+        assert_eq!(alpha.len(), layer.size.area());
+        assert!(alpha.values_as_f32().all(|alpha| alpha >= 0.0 && alpha <= 1.001));
+        assert!(alpha.values_as_f32().any(|alpha| alpha > 0.0));
+    }
+
+    let mut tmp_bytes = Vec::new();
+    flat_image.write().to_buffered(Cursor::new(&mut tmp_bytes)).unwrap();
+
+    let flat_image2 = read().no_deep_data().largest_resolution_level().all_channels().all_layers().all_attributes()
+        .pedantic().from_buffered(Cursor::new(tmp_bytes.as_slice())).unwrap();
+
+    flat_image.assert_equals_result(&flat_image2);
+}
+
+#[test]
+fn round_trip_deep_and_flat_layers() {
+    let size = Vec2(3, 2);
+
+    // pixel sample counts: 0, 1, 3, 2, 0, 1
+    let sample_offsets = vec![0, 0, 1, 4, 6, 6, 7];
+    let depth = DeepSamples { sample_offsets: sample_offsets.clone(), values: FlatSamples::F32(vec![1.0, 2.0, 3.5, 4.0, 0.5, 0.25, 8.0]) };
+    let alpha = DeepSamples { sample_offsets, values: FlatSamples::F16((0 .. 7).map(|index| f16::from_f32(index as f32 / 7.0)).collect()) };
+
+    let deep_layer = Layer::new(
+        size, LayerAttributes::named("deep"),
+        Encoding { compression: Compression::ZIP1(None), blocks: Blocks::Tiles(Vec2(2, 2)), line_order: LineOrder::Increasing },
+        AnyChannels::sort(smallvec::smallvec![
+            AnyChannel::new("Z", DeepAndFlatSamples::Deep(depth)),
+            AnyChannel::new("A", DeepAndFlatSamples::Deep(alpha)),
+        ])
+    );
+
+    let flat_layer = Layer::new(
+        size, LayerAttributes::named("flat"), Encoding::FAST_LOSSLESS,
+        AnyChannels::sort(smallvec::smallvec![
+            AnyChannel::new("Y", DeepAndFlatSamples::Flat(FlatSamples::F32(vec![0.0, 1.0, 2.0, 3.0, 4.0, 5.0]))),
+        ])
+    );
+
+    let image = Image::from_layers(ImageAttributes::new(IntegerBounds::from_dimensions(size)), vec![deep_layer, flat_layer]);
+
+    let mut tmp_bytes = Vec::new();
+    image.write().to_buffered(Cursor::new(&mut tmp_bytes)).unwrap();
+
+    // deep and flat blocks are compressed in parallel, but written in the same order as without parallelism
+    let mut sequential_bytes = Vec::new();
+    image.write().non_parallel().to_buffered(Cursor::new(&mut sequential_bytes)).unwrap();
+    assert_eq!(tmp_bytes, sequential_bytes);
+
+    let image2 = read().flat_and_deep_data().largest_resolution_level().all_channels().all_layers().all_attributes()
+        .pedantic().from_buffered(Cursor::new(tmp_bytes.as_slice())).unwrap();
+
+    image.assert_equals_result(&image2);
+
+    let meta = MetaData::read_from_buffered(Cursor::new(tmp_bytes.as_slice()), true).unwrap();
+    assert!(meta.headers[0].deep && !meta.headers[1].deep);
+    assert_eq!(meta.headers[0].max_samples_per_pixel, Some(3));
+    assert_eq!(meta.headers[0].deep_data_version, Some(1));
+
+    // the flat layer can still be read without deep data support
+    let flat = read().no_deep_data().largest_resolution_level().all_channels().first_valid_layer().all_attributes()
+        .from_buffered(Cursor::new(tmp_bytes.as_slice())).unwrap();
+
+    assert_eq!(flat.layer_data.attributes.layer_name, Some("flat".into()));
+}
+
 
 #[test]
 fn round_trip_parallel_files() {
@@ -312,7 +457,8 @@ fn pxr24_expect_error_on_big_endian(){
 #[test]
 #[cfg(target_endian = "little")] // TODO big endian pxr24
 fn roundtrip_pxr24() {
-    test_mixed_roundtrip_with_compression(Compression::PXR24)
+    test_mixed_roundtrip_with_compression(Compression::PXR24(None));
+    test_mixed_roundtrip_with_compression(Compression::PXR24(Some(10)));
 }
 
 #[test]
@@ -322,12 +468,34 @@ fn roundtrip_rle() {
 
 #[test]
 fn roundtrip_zip1() {
-    test_mixed_roundtrip_with_compression(Compression::ZIP1)
+    test_mixed_roundtrip_with_compression(Compression::ZIP1(None))
 }
 
 #[test]
 fn roundtrip_zip16() {
-    test_mixed_roundtrip_with_compression(Compression::ZIP16)
+    test_mixed_roundtrip_with_compression(Compression::ZIP16(None))
+}
+
+#[test]
+fn roundtrip_zip_compression_levels() {
+    test_mixed_roundtrip_with_compression(Compression::ZIP1(Some(1)));
+    test_mixed_roundtrip_with_compression(Compression::ZIP16(Some(9)));
+
+    // the level is not stored in the file, so it is ignored when comparing the methods
+    assert!(Compression::ZIP1(Some(9)).same_method(Compression::zip1()));
+    assert!(!Compression::ZIP1(Some(9)).same_method(Compression::ZIP16(Some(9))));
+    assert_ne!(Compression::ZIP1(Some(9)), Compression::ZIP1(None));
+    assert_eq!(Compression::pxr24().with_deflate_level(1), Compression::PXR24(Some(1)));
+    assert_eq!(Compression::RLE.with_deflate_level(1), Compression::RLE);
+
+    let layer = Layer::new(
+        Vec2(8, 8), LayerAttributes::default(),
+        Encoding { compression: Compression::ZIP16(Some(11)), .. Encoding::UNCOMPRESSED },
+        SpecificChannels::build().with_channel("Y").with_pixel_fn(|_| (0.5_f32,))
+    );
+
+    let mut bytes = Vec::new();
+    assert!(Image::from_layer(layer).write().to_buffered(Cursor::new(&mut bytes)).is_err());
 }
 
 #[test]
@@ -340,6 +508,47 @@ fn roundtrip_b44a() {
     test_mixed_roundtrip_with_compression(Compression::B44A)
 }
 
+#[test]
+fn roundtrip_dwaa() {
+    test_mixed_roundtrip_with_compression(Compression::DWAA(None))
+}
+
+#[test]
+fn roundtrip_dwab() {
+    test_mixed_roundtrip_with_compression(Compression::DWAB(Some(90.0)))
+}
+
+#[test]
+fn roundtrip_chosen_compression() {
+    let size = Vec2(64, 48);
+    let pixels = PixelVec::new(size, (0 .. size.area())
+        .map(|index| ((index % 7) as f32, f16::from_f32((index / 64) as f32), 1.0_f32))
+        .collect());
+
+    let image = Image::from_channels(size, SpecificChannels::rgb(pixels));
+
+    let writer = image.write().choose_compression(CompressionSelection::smallest());
+    let chosen = writer.infer_meta_data()[0].compression;
+    assert!(!chosen.may_loose_data());
+    assert_ne!(chosen, Compression::PXR24(None), "pxr24 is lossy for f32 channels");
+
+    let mut bytes = Vec::new();
+    writer.to_buffered(Cursor::new(&mut bytes)).unwrap();
+
+    let meta = MetaData::read_from_buffered(Cursor::new(&bytes), false).unwrap();
+    assert_eq!(meta.headers[0].compression, chosen);
+
+    let read_image = read().no_deep_data().largest_resolution_level()
+        .rgb_channels(PixelVec::<(f32,f16,f32)>::constructor, PixelVec::set_pixel)
+        .first_valid_layer().all_attributes().from_buffered(Cursor::new(&bytes)).unwrap();
+
+    assert_eq!(read_image.layer_data.channel_data.pixels, image.layer_data.channel_data.pixels);
+
+    // any method is within an unlimited tolerance, so the fastest method is chosen
+    let fastest = image.write().choose_compression(CompressionSelection::fastest_within(f32::INFINITY));
+    assert_eq!(fastest.infer_meta_data()[0].compression, Compression::Uncompressed);
+}
+
 #[test]
 fn roundtrip_piz() {
     test_mixed_roundtrip_with_compression(Compression::PIZ)
@@ -382,3 +591,774 @@ fn test_mixed_roundtrip_with_compression(compression: Compression) {
     lossy_image.assert_equals_result(&lossy_image);
     original_image.assert_equals_result(&lossy_image);
 }
+
+#[test]
+fn roundtrip_only_region() {
+    let size = Vec2(64, 48);
+    let layer_position = Vec2(3, -2);
+    let pixel = |position: Vec2<usize>| (position.x() as f32, position.y() as f32, (position.x() * position.y()) as f32);
+
+    let region = IntegerBounds::new((10, 5), (20, 30));
+    let region_pixel = |position: Vec2<usize>| pixel(Vec2(
+        (position.x() as i32 + region.position.x() - layer_position.x()) as usize,
+        (position.y() as i32 + region.position.y() - layer_position.y()) as usize,
+    ));
+
+    for blocks in [Blocks::ScanLines, Blocks::Tiles(Vec2(16, 16))] {
+        for compression in [Compression::Uncompressed, Compression::ZIP16(None), Compression::PIZ] {
+            let layer = Layer::new(
+                size, LayerAttributes { layer_position, .. LayerAttributes::named("region") },
+                Encoding { compression, blocks, line_order: LineOrder::Increasing },
+                SpecificChannels::rgb(PixelVec::new(size, (0 .. size.area()).map(|index| pixel(Vec2(index % size.0, index / size.0))).collect()))
+            );
+
+            let mut bytes = Vec::new();
+            Image::from_layer(layer).write().to_buffered(Cursor::new(&mut bytes)).unwrap();
+
+            let specific = read().no_deep_data().largest_resolution_level()
+                .rgb_channels(PixelVec::<(f32,f32,f32)>::constructor, PixelVec::set_pixel)
+                .first_valid_layer().all_attributes().only_region(region)
+                .from_buffered(Cursor::new(&bytes)).unwrap();
+
+            let layer = &specific.layer_data;
+            assert_eq!(layer.size, region.size);
+            assert_eq!(layer.attributes.layer_position, region.position);
+
+            for (index, &value) in layer.channel_data.pixels.pixels.iter().enumerate() {
+                assert_eq!(value, region_pixel(Vec2(index % region.size.0, index / region.size.0)));
+            }
+
+            let any = read().no_deep_data().largest_resolution_level().all_channels()
+                .first_valid_layer().all_attributes().only_region(region)
+                .from_buffered(Cursor::new(&bytes)).unwrap();
+
+            let layer = &any.layer_data;
+            assert_eq!(layer.size, region.size);
+
+            // channels are sorted alphabetically: B, G, R
+            let blue: Vec<f32> = layer.channel_data.list[0].sample_data.values_as_f32().collect();
+            for (index, value) in blue.into_iter().enumerate() {
+                assert_eq!(value, region_pixel(Vec2(index % region.size.0, index / region.size.0)).2);
+            }
+        }
+    }
+}
+
+#[test]
+fn read_specific_resolution_level() {
+    for name in ["Bonita.exr", "ColorCodedLevels.exr"] {
+        let path = Path::new("tests/images/valid/openexr/MultiResolution").join(name);
+
+        let all_levels = read().no_deep_data().all_resolution_levels().all_channels()
+            .first_valid_layer().all_attributes().from_file(&path).unwrap();
+
+        let level_count = all_levels.layer_data.channel_data.list[0].sample_data.levels_as_slice().len();
+        assert!(level_count > 2);
+
+        let selected = read().no_deep_data()
+            .specific_resolution_level(|resolutions: &[Vec2<usize>]| { assert_eq!(resolutions.len(), level_count); 2 })
+            .all_channels().first_valid_layer().all_attributes()
+            .from_file(&path).unwrap();
+
+        for (channel, selected_channel) in all_levels.layer_data.channel_data.list.iter().zip(&selected.layer_data.channel_data.list) {
+            let level = &channel.sample_data.levels_as_slice()[2];
+            assert_eq!(selected.layer_data.size.area(), level.len());
+            assert_eq!(level, &selected_channel.sample_data);
+        }
+
+        let closest = read().no_deep_data()
+            .closest_resolution_level(selected.layer_data.size)
+            .rgba_channels(PixelVec::<(f32,f32,f32,f32)>::constructor, PixelVec::set_pixel)
+            .first_valid_layer().all_attributes()
+            .from_file(&path).unwrap();
+
+        assert_eq!(closest.layer_data.size, selected.layer_data.size);
+    }
+}
+
+#[test]
+fn read_rgba_channels_of_all_resolution_levels() {
+    let path = "tests/images/valid/openexr/MultiResolution/ColorCodedLevels.exr";
+
+    let any_channels = read().no_deep_data().all_resolution_levels().all_channels()
+        .first_valid_layer().all_attributes().from_file(path).unwrap();
+
+    let rgba = read().no_deep_data().all_resolution_levels()
+        .rgba_channels(PixelVec::<(f32,f32,f32,f32)>::constructor, PixelVec::set_pixel)
+        .first_valid_layer().all_attributes().from_file(path).unwrap();
+
+    let red_levels = &any_channels.layer_data.channel_data.list.iter()
+        .find(|channel| channel.name.eq("R")).unwrap().sample_data;
+
+    let rgba_levels = &rgba.layer_data.channel_data.pixels;
+    assert_eq!(rgba_levels.level_mode(), red_levels.level_mode());
+    assert_eq!(rgba_levels.levels_as_slice().len(), red_levels.levels_as_slice().len());
+
+    for (pixels, red) in rgba_levels.levels_as_slice().iter().zip(red_levels.levels_as_slice()) {
+        let pixel_reds: Vec<f32> = pixels.pixels.iter().map(|&(r,_,_,_)| r).collect();
+        let reds: Vec<f32> = red.values_as_f32().collect();
+        assert_eq!(pixel_reds, reds);
+    }
+}
+
+#[test]
+fn roundtrip_specific_channels_with_resolution_levels() {
+    let path = "tests/images/valid/openexr/MultiResolution/ColorCodedLevels.exr";
+
+    let image = read().no_deep_data().all_resolution_levels()
+        .rgba_channels(PixelVec::<(f16,f16,f16,f16)>::constructor, PixelVec::set_pixel)
+        .first_valid_layer().all_attributes().from_file(path).unwrap();
+
+    assert_ne!(image.layer_data.channel_data.pixels.level_mode(), exr::meta::attribute::LevelMode::Singular);
+
+    let mut bytes = Vec::new();
+    image.write().to_buffered(Cursor::new(&mut bytes)).unwrap();
+
+    let image2 = read().no_deep_data().all_resolution_levels()
+        .rgba_channels(PixelVec::<(f16,f16,f16,f16)>::constructor, PixelVec::set_pixel)
+        .first_valid_layer().all_attributes().from_buffered(Cursor::new(&bytes)).unwrap();
+
+    let meta = MetaData::read_from_buffered(Cursor::new(&bytes), false).unwrap();
+    assert_eq!(meta.headers[0].blocks, image_meta_blocks(path));
+    assert_eq!(image.layer_data.channel_data.pixels, image2.layer_data.channel_data.pixels);
+
+    fn image_meta_blocks(path: &str) -> exr::meta::BlockDescription {
+        MetaData::read_from_file(path, false).unwrap().headers[0].blocks
+    }
+}
+
+#[test]
+fn roundtrip_generated_mip_maps() {
+    let size = Vec2(37, 20);
+    let layer = Layer::new(
+        size, LayerAttributes::named("generated"), Encoding::FAST_LOSSLESS,
+        AnyChannels::sort(smallvec::smallvec![
+            AnyChannel::new("Y", FlatSamples::F32((0 .. size.area()).map(|index| (index % 7) as f32).collect())),
+        ])
+    );
+
+    let options = LevelOptions { filter: ResamplingFilter::Lanczos3, wrap_modes: Some(Vec2(WrapMode::Periodic, WrapMode::Clamp)), .. LevelOptions::default() };
+    let mip_layer = layer.generate_mip_maps(options).unwrap();
+    assert_eq!(mip_layer.attributes.wrap_mode_name, Some("periodic,clamp".into()));
+
+    let mut bytes = Vec::new();
+    Image::from_layer(mip_layer.clone()).write().to_buffered(Cursor::new(&mut bytes)).unwrap();
+
+    let image = read().no_deep_data().all_resolution_levels().all_channels()
+        .first_valid_layer().all_attributes().from_buffered(Cursor::new(&bytes)).unwrap();
+
+    assert_eq!(image.layer_data.attributes.wrap_mode_name, mip_layer.attributes.wrap_mode_name);
+    assert_eq!(image.layer_data.channel_data.list[0].sample_data, mip_layer.channel_data.list[0].sample_data);
+}
+
+#[test]
+fn random_access_with_block_cache() {
+    use exr::block::reader::Reader;
+    use exr::block::samples::Sample;
+
+    let size = Vec2(70, 45);
+    let layer = Layer::new(
+        size, LayerAttributes::named("cached"),
+        Encoding { compression: Compression::ZIP16(None), blocks: Blocks::Tiles(Vec2(16, 16)), line_order: LineOrder::Increasing },
+        AnyChannels::sort(smallvec::smallvec![
+            AnyChannel::new("Y", FlatSamples::F32((0 .. size.area()).map(|index| index as f32).collect())),
+            AnyChannel::new("A", FlatSamples::F16((0 .. size.area()).map(|index| f16::from_f32((index % 100) as f32)).collect())),
+        ])
+    ).generate_mip_maps(LevelOptions::default()).unwrap();
+
+    let mut bytes = Vec::new();
+    Image::from_layer(layer.clone()).write().to_buffered(Cursor::new(&mut bytes)).unwrap();
+
+    let max_bytes = 3 * 16 * 16 * 6;
+    let cache = Reader::read_from_buffered(Cursor::new(&bytes), true).unwrap()
+        .into_block_cache(true, max_bytes).unwrap();
+
+    let check_level = |level_index: usize| {
+        for (channel_index, channel) in layer.channel_data.list.iter().enumerate() {
+            let level = &channel.sample_data.levels_as_slice()[level_index];
+            let level_size = Vec2(size.width() >> level_index, size.height() >> level_index);
+
+            for y in 0 .. level_size.height() {
+                for x in 0 .. level_size.width() {
+                    let expected = level.value_by_flat_index(y * level_size.width() + x);
+                    let sample: Sample = cache.get_sample(0, Vec2(level_index, level_index), channel_index, Vec2(x, y)).unwrap();
+                    assert_eq!(sample.to_f32().to_bits(), expected.to_f32().to_bits());
+                }
+            }
+        }
+    };
+
+    // access the cache from multiple threads at once
+    vec![ 0, 1, 3 ].into_par_iter().for_each(check_level);
+
+    assert!(cache.cached_byte_size() <= max_bytes);
+    assert!(cache.get_tile(0, Vec2(0, 0), Vec2(9, 0)).is_err());
+    assert_eq!(cache.get_tile(0, Vec2(1, 1), Vec2(2, 1)).unwrap().index.pixel_size, Vec2(3, 6));
+}
+
+#[test]
+fn roundtrip_converted_environment_map() {
+    let path = "tests/images/valid/openexr/MultiResolution/";
+    let read_layer = |name: &str| read().no_deep_data().largest_resolution_level().all_channels()
+        .first_valid_layer().all_attributes().from_file(format!("{}{}", path, name)).unwrap().layer_data;
+
+    let lat_long = read_layer("OrientationLatLong.exr");
+    let expected_cube = read_layer("OrientationCube.exr");
+    assert_eq!(lat_long.attributes.environment_map, Some(exr::meta::attribute::EnvironmentMap::LatitudeLongitude));
+
+    let face_size = expected_cube.size.width();
+    let cube = lat_long.to_cube_map(face_size, EnvironmentMapOptions::default()).unwrap();
+    assert_eq!(cube.size, expected_cube.size);
+
+    let mut bytes = Vec::new();
+    Image::from_layer(cube.clone()).write().to_buffered(Cursor::new(&mut bytes)).unwrap();
+
+    let image = read().no_deep_data().largest_resolution_level().all_channels()
+        .first_valid_layer().all_attributes().from_buffered(Cursor::new(&bytes)).unwrap();
+
+    assert_eq!(image.layer_data.attributes.environment_map, Some(exr::meta::attribute::EnvironmentMap::Cube));
+
+    // the converted faces resemble the cube map converted by the original library
+    for (converted, expected) in image.layer_data.channel_data.list.iter().zip(&expected_cube.channel_data.list) {
+        assert_eq!(converted.name, expected.name);
+
+        let difference: f32 = converted.sample_data.values_as_f32().zip(expected.sample_data.values_as_f32())
+            .map(|(converted, expected)| (converted - expected).abs()).sum();
+
+        let average_difference = difference / cube.size.area() as f32;
+        assert!(average_difference < 0.05, "{} differs by {}", converted.name, average_difference);
+    }
+}
+
+#[test]
+fn read_selected_layers() {
+    let path = "tests/images/valid/openexr/Beachball/multipart.0001.exr";
+
+    let all_layers = read().no_deep_data().largest_resolution_level().all_channels()
+        .all_layers().all_attributes().from_file(path).unwrap().layer_data;
+
+    assert!(all_layers.len() > 2);
+    let names: Vec<Text> = all_layers.iter()
+        .map(|layer| layer.attributes.layer_name.clone().unwrap())
+        .collect();
+
+    let selected_names = [ names[2].clone(), names[0].clone(), Text::from("does not exist") ];
+    let named = read().no_deep_data().largest_resolution_level().all_channels()
+        .layers_named(selected_names.iter().cloned()).all_attributes().from_file(path).unwrap().layer_data;
+
+    // selected layers keep the order of the file
+    assert_eq!(named.len(), 2);
+    assert_eq!(named[0], all_layers[0]);
+    assert_eq!(named[1], all_layers[2]);
+
+    // unselected layers are not required to contain the requested channels
+    let rgba_layers = read().no_deep_data().largest_resolution_level()
+        .rgba_channels(PixelVec::<(f32,f32,f32,f32)>::constructor, PixelVec::set_pixel)
+        .layers_where(|header| header.channels.find_index_of_channel(&Text::from("R")).is_some())
+        .all_attributes().from_file(path).unwrap().layer_data;
+
+    let expected_count = all_layers.iter()
+        .filter(|layer| layer.channel_data.list.iter().any(|channel| channel.name.eq("R")))
+        .count();
+
+    assert!(expected_count > 0);
+    assert_eq!(rgba_layers.len(), expected_count);
+}
+
+#[test]
+fn read_all_valid_layers() {
+    use std::cell::RefCell;
+
+    let size = Vec2(9, 7);
+    let color_layer = Layer::new(
+        size, LayerAttributes::named("beauty"), Encoding::FAST_LOSSLESS,
+        SpecificChannels::rgb(|position: Vec2<usize>| (position.x() as f32, position.y() as f32, 0.5_f32))
+    );
+
+    let depth_layer = Layer::new(
+        size, LayerAttributes::named("depth"), Encoding::FAST_LOSSLESS,
+        SpecificChannels::build().with_channel("Z").with_pixel_fn(|position: Vec2<usize>| (position.area() as f32,))
+    );
+
+    let mut bytes = Vec::new();
+    Image::empty(ImageAttributes::new(IntegerBounds::from_dimensions(size)))
+        .with_layer(depth_layer).with_layer(color_layer)
+        .write().to_buffered(Cursor::new(&mut bytes)).unwrap();
+
+    let read_rgba = || read().no_deep_data().largest_resolution_level()
+        .rgb_channels(PixelVec::<(f32,f32,f32)>::constructor, PixelVec::set_pixel);
+
+    assert!(read_rgba().all_layers().all_attributes().from_buffered(Cursor::new(&bytes)).is_err());
+
+    let skipped = RefCell::new(Vec::new());
+    let image = read_rgba()
+        .all_valid_layers()
+        .on_skipped_layer(|layer| skipped.borrow_mut().push(layer))
+        .all_attributes().from_buffered(Cursor::new(&bytes)).unwrap();
+
+    assert_eq!(image.layer_data.len(), 1);
+    assert_eq!(image.layer_data[0].attributes.layer_name, Some(Text::from("beauty")));
+    assert_eq!(image.layer_data[0].channel_data.pixels.pixels[10], (1.0, 1.0, 0.5));
+
+    let skipped = skipped.into_inner();
+    assert_eq!(skipped.len(), 1);
+    assert_eq!(skipped[0].layer_name, Some(Text::from("depth")));
+}
+
+#[test]
+fn roundtrip_sparsely_subsampled_channels() {
+    let size = Vec2(6, 8);
+    let channel = |name: &str, sampling: Vec2<usize>, offset: f32| {
+        let sample_count = (size.width() / sampling.x()) * (size.height() / sampling.y());
+        AnyChannel {
+            sampling,
+            .. AnyChannel::new(name, FlatSamples::F32((0 .. sample_count).map(|index| index as f32 + offset).collect()))
+        }
+    };
+
+    // none of the channels contains samples in the odd rows, so blocks of a single line may be empty
+    let channels = AnyChannels::sort(smallvec::smallvec![
+        channel("BY", Vec2(2, 2), 0.5),
+        channel("RY", Vec2(2, 2), 0.25),
+        channel("Y", Vec2(1, 2), 0.0),
+    ]);
+
+    for &compression in &[Compression::Uncompressed, Compression::RLE, Compression::ZIP1(None), Compression::ZIP16(None)] {
+        let layer = Layer::new(size, LayerAttributes::default(), Encoding { compression, .. Encoding::UNCOMPRESSED }, channels.clone());
+        let image = Image::from_layer(layer);
+
+        let mut bytes = Vec::new();
+        image.write().to_buffered(Cursor::new(&mut bytes)).unwrap();
+
+        let image2 = read().no_deep_data().largest_resolution_level().all_channels()
+            .first_valid_layer().all_attributes().from_buffered(Cursor::new(&bytes)).unwrap();
+
+        assert_eq!(image2.layer_data.channel_data, image.layer_data.channel_data, "{}", compression);
+    }
+}
+
+#[test]
+fn roundtrip_subsampled_channels() {
+    let paths = [
+        "tests/images/valid/openexr/LuminanceChroma/Flowers.exr",
+        "tests/images/valid/openexr/Chromaticities/Rec709_YC.exr",
+    ];
+
+    for path in paths.iter() {
+        let image = read().no_deep_data().largest_resolution_level().all_channels()
+            .first_valid_layer().all_attributes().from_file(path).unwrap();
+
+        let size = image.layer_data.size;
+        let channels = &image.layer_data.channel_data.list;
+
+        for name in ["RY", "BY"].iter() {
+            let channel = channels.iter().find(|channel| channel.name.eq(name)).unwrap();
+            assert_eq!(channel.sampling, Vec2(2, 2));
+            assert_eq!(channel.sample_data.len(), ((size.width() + 1) / 2) * ((size.height() + 1) / 2));
+        }
+
+        let mut bytes = Vec::new();
+        image.write().to_buffered(Cursor::new(&mut bytes)).unwrap();
+
+        let image2 = read().no_deep_data().largest_resolution_level().all_channels()
+            .first_valid_layer().all_attributes().from_buffered(Cursor::new(&bytes)).unwrap();
+
+        image.assert_equals_result(&image2);
+    }
+}
+
+#[test]
+fn read_luminance_chroma_as_rgb() {
+    let path = "tests/images/valid/openexr/Chromaticities/";
+    let read_rgba = |name: &str| read_first_rgba_layer_from_file(
+        format!("{}{}", path, name), PixelVec::<(f32,f32,f32,f32)>::constructor, PixelVec::set_pixel
+    ).unwrap().layer_data;
+
+    for (rgb_name, luminance_chroma_name) in [("Rec709.exr", "Rec709_YC.exr"), ("XYZ.exr", "XYZ_YC.exr")].iter() {
+        let expected = read_rgba(rgb_name);
+        let converted = read_rgba(luminance_chroma_name);
+
+        assert_eq!(converted.size, expected.size);
+        assert_eq!(converted.channel_data.channels.0.name, Text::from("R"));
+
+        // the luminance chroma files were created by the original library from the rgb files
+        let difference: f32 = converted.channel_data.pixels.pixels.iter().zip(&expected.channel_data.pixels.pixels)
+            .map(|(&(r, g, b, _), &(er, eg, eb, _))| (r - er).abs() + (g - eg).abs() + (b - eb).abs())
+            .sum();
+
+        let average_difference = difference / (3 * expected.size.area()) as f32;
+        assert!(average_difference < 0.01, "{} differs by {}", luminance_chroma_name, average_difference);
+    }
+}
+
+#[test]
+fn round_trip_luminance_chroma_file_as_rgba() {
+    let image_reader = read()
+        .no_deep_data()
+        .largest_resolution_level()
+        .rgba_channels(PixelVec::<(f32,f32,f32,f32)>::constructor, PixelVec::set_pixel)
+        .luminance_chroma_as_rgb()
+        .first_valid_layer()
+        .all_attributes()
+        .non_parallel();
+
+    let image = image_reader.clone().from_file("tests/images/valid/openexr/LuminanceChroma/Flowers.exr").unwrap();
+    assert_eq!(image.layer_data.encoding.compression, Compression::B44);
+
+    let mut tmp_bytes = Vec::new();
+    image.write().non_parallel().to_buffered(&mut Cursor::new(&mut tmp_bytes)).unwrap();
+    let image2 = image_reader.from_buffered(Cursor::new(&tmp_bytes)).unwrap();
+
+    assert_eq!(image.attributes, image2.attributes);
+    assert_eq!(image.layer_data.attributes, image2.layer_data.attributes);
+    assert_eq!(image.layer_data.channel_data.channels, image2.layer_data.channel_data.channels);
+
+    let pixels = &image.layer_data.channel_data.pixels.pixels;
+    let pixels2 = &image2.layer_data.channel_data.pixels.pixels;
+    assert_eq!(pixels.len(), pixels2.len());
+
+    // b44 quantizes each block of 4x4 samples relative to the largest sample in the block.
+    // the rgb samples converted from luminance and chroma contain highly saturated colors,
+    // where dark samples lie next to bright samples in the same block, so these dark samples lose more precision
+    // than `assert_equals_result` allows: 5 of 1.7 million samples exceed that tolerance,
+    // while the average error is below 0.1% and the largest error is below 20% of the magnitude of the sample
+    let errors: Vec<f32> = pixels.iter().zip(pixels2)
+        .flat_map(|(&(r, g, b, a), &(r2, g2, b2, a2))| vec![ (r, r2), (g, g2), (b, b2), (a, a2) ])
+        .map(|(sample, sample2)| (sample - sample2).abs() / sample.abs().max(sample2.abs()).max(1.0))
+        .collect();
+
+    let average_error = errors.iter().sum::<f32>() / errors.len() as f32;
+    let max_error = errors.iter().cloned().fold(0.0, f32::max);
+
+    assert!(average_error < 0.001, "average b44 error {}", average_error);
+    assert!(max_error < 0.25, "maximum b44 error {}", max_error);
+}
+
+#[test]
+fn roundtrip_luminance_chroma() {
+    let path = "tests/images/valid/openexr/Chromaticities/Rec709.exr";
+    let image = read().no_deep_data().largest_resolution_level().all_channels()
+        .first_valid_layer().all_attributes().from_file(path).unwrap();
+
+    let chromaticities = image.attributes.chromaticities.unwrap_or_default();
+    let luminance_chroma = Layer {
+        encoding: Encoding { compression: Compression::B44, .. image.layer_data.encoding },
+        .. image.layer_data.to_luminance_chroma(chromaticities).unwrap()
+    };
+
+    let names: Vec<&Text> = luminance_chroma.channel_data.list.iter().map(|channel| &channel.name).collect();
+    assert_eq!(names, vec![ &Text::from("BY"), &Text::from("RY"), &Text::from("Y") ]);
+    assert_eq!(luminance_chroma.channel_data.list[0].sampling, Vec2(2, 2));
+
+    let mut bytes = Vec::new();
+    Image::new(image.attributes.clone(), luminance_chroma).write().to_buffered(Cursor::new(&mut bytes)).unwrap();
+
+    let converted = read().no_deep_data().largest_resolution_level().all_channels()
+        .luminance_chroma_as_rgb().first_valid_layer().all_attributes()
+        .from_buffered(Cursor::new(&bytes)).unwrap();
+
+    assert_eq!(converted.layer_data.encoding.compression, Compression::B44);
+
+    for (converted, original) in converted.layer_data.channel_data.list.iter().zip(&image.layer_data.channel_data.list) {
+        assert_eq!(converted.name, original.name);
+        assert_eq!(converted.sampling, Vec2(1, 1));
+
+        let difference: f32 = converted.sample_data.values_as_f32().zip(original.sample_data.values_as_f32())
+            .map(|(converted, original)| (converted - original).abs()).sum();
+
+        let average_difference = difference / image.layer_data.size.area() as f32;
+        assert!(average_difference < 0.01, "{} differs by {}", converted.name, average_difference);
+    }
+}
+
+#[test]
+fn read_subsampled_luminance_as_rgb() {
+    let channel = |name: &str| AnyChannel {
+        sampling: Vec2(2, 2),
+        .. AnyChannel::new(name, FlatSamples::F16(vec![f16::ONE; 4]))
+    };
+
+    let layer = Layer::new(
+        Vec2(4, 4), LayerAttributes::default(), Encoding::UNCOMPRESSED,
+        AnyChannels::sort(smallvec::smallvec![ channel("Y"), channel("RY"), channel("BY") ])
+    );
+
+    let mut bytes = Vec::new();
+    Image::from_layer(layer).write().to_buffered(Cursor::new(&mut bytes)).unwrap();
+
+    // the conversion requires luminance with full resolution, which is checked before reading any pixels.
+    // reading all layers reports the error of the layer, instead of skipping the layer like `first_valid_layer` would
+    let converted = read().no_deep_data().largest_resolution_level().all_channels()
+        .luminance_chroma_as_rgb().all_layers().all_attributes()
+        .from_buffered(Cursor::new(&bytes));
+
+    match converted {
+        Err(Error::NotSupported(message)) => assert!(message.contains("subsampled luminance"), "unexpected error: {}", message),
+        other => panic!("expected subsampled luminance to be unsupported, but got {:?}", other.map(|_| ())),
+    }
+}
+
+#[test]
+fn read_rgba_with_channel_name_policy() {
+    let size = Vec2(7, 5);
+    let layer = Layer::new(
+        size, LayerAttributes::default(), Encoding::FAST_LOSSLESS,
+        SpecificChannels::build()
+            .with_channel("red").with_channel("g").with_channel("Blue")
+            .with_pixel_fn(|position: Vec2<usize>| (position.x() as f32, position.y() as f32, 0.5_f32))
+    );
+
+    let mut bytes = Vec::new();
+    Image::from_layer(layer).write().to_buffered(Cursor::new(&mut bytes)).unwrap();
+
+    let read_rgba = || read().no_deep_data().largest_resolution_level()
+        .rgba_channels(PixelVec::<(f32,f32,f32,f32)>::constructor, PixelVec::set_pixel);
+
+    assert!(read_rgba().first_valid_layer().all_attributes().from_buffered(Cursor::new(&bytes)).is_err());
+
+    let image = read_rgba().with_channel_names(ChannelNamePolicy::rgba())
+        .first_valid_layer().all_attributes().from_buffered(Cursor::new(&bytes)).unwrap();
+
+    // the descriptions contain the requested channel names, and the layer reports the source channels
+    let channels = &image.layer_data.channel_data;
+    let (red, green, blue, alpha) = &channels.channels;
+    assert_eq!((&red.name, &green.name, &blue.name), (&Text::from("R"), &Text::from("G"), &Text::from("B")));
+    assert!(alpha.is_none());
+    assert_eq!(channels.pixels.pixels[8], (1.0, 1.0, 0.5, 1.0));
+
+    assert_eq!(channels.source_name(&red.name), &Text::from("red"));
+    assert_eq!(channels.source_name(&green.name), &Text::from("g"));
+    assert_eq!(channels.source_name(&blue.name), &Text::from("Blue"));
+    assert_eq!(channels.source_names.len(), 3);
+
+    // aliases are only used if the requested channel does not exist
+    let names = ChannelNamePolicy::exact().with_aliases("R", ["Blue", "red"]);
+    let image = read().no_deep_data().largest_resolution_level().specific_channels()
+        .required("R").collect_pixels(PixelVec::<(f32,)>::constructor, PixelVec::set_pixel)
+        .with_channel_names(names).first_valid_layer().all_attributes().from_buffered(Cursor::new(&bytes)).unwrap();
+
+    assert_eq!(image.layer_data.channel_data.channels.0.name, Text::from("R"));
+    assert_eq!(image.layer_data.channel_data.pixels.pixels[8], (0.5,));
+    assert_eq!(image.layer_data.channel_data.source_name(&Text::from("R")), &Text::from("Blue"));
+
+    // reading channels with their exact names does not report any source names
+    let exact = read().no_deep_data().largest_resolution_level().specific_channels()
+        .required("red").collect_pixels(PixelVec::<(f32,)>::constructor, PixelVec::set_pixel)
+        .first_valid_layer().all_attributes().from_buffered(Cursor::new(&bytes)).unwrap();
+
+    assert!(exact.layer_data.channel_data.source_names.is_empty());
+}
+
+#[test]
+fn read_greyscale_as_rgba() {
+    let path = "tests/images/valid/openexr/LuminanceChroma/Garden.exr";
+    let image = read_first_rgba_layer_from_file(path, PixelVec::<(f32,f32,f32,f32)>::constructor, PixelVec::set_pixel).unwrap();
+
+    let (red, green, blue, alpha) = &image.layer_data.channel_data.channels;
+    assert_eq!((&red.name, &green.name, &blue.name), (&Text::from("R"), &Text::from("G"), &Text::from("B")));
+    assert!(alpha.is_none());
+
+    for requested in [&red.name, &green.name, &blue.name] {
+        assert_eq!(image.layer_data.channel_data.source_name(requested), &Text::from("Y"));
+    }
+
+    let luminance = read().no_deep_data().largest_resolution_level().all_channels()
+        .first_valid_layer().all_attributes().from_file(path).unwrap()
+        .layer_data.channel_data.list[0].sample_data.values_as_f32().collect::<Vec<f32>>();
+
+    assert_eq!(luminance.len(), image.layer_data.channel_data.pixels.pixels.len());
+    for (&(r, g, b, a), y) in image.layer_data.channel_data.pixels.pixels.iter().zip(luminance) {
+        assert_eq!((r, g, b, a), (y, y, y, 1.0));
+    }
+}
+
+#[test]
+fn roundtrip_greyscale_as_rgba() {
+    let path = "tests/images/valid/openexr/LuminanceChroma/Garden.exr";
+    let image = read_first_rgba_layer_from_file(path, PixelVec::<(f32,f32,f32,f32)>::constructor, PixelVec::set_pixel).unwrap();
+
+    let mut bytes = Vec::new();
+    image.write().to_buffered(Cursor::new(&mut bytes)).unwrap();
+
+    // the luminance is written to three separate rgb channels, instead of three channels named `Y`
+    let written = read().no_deep_data().largest_resolution_level().all_channels()
+        .first_valid_layer().all_attributes().from_buffered(Cursor::new(&bytes)).unwrap();
+
+    let names: Vec<String> = written.layer_data.channel_data.list.iter().map(|channel| channel.name.to_string()).collect();
+    assert_eq!(names, vec!["B", "G", "R"]);
+
+    let image2 = read().no_deep_data().largest_resolution_level()
+        .rgba_channels(PixelVec::<(f32,f32,f32,f32)>::constructor, PixelVec::set_pixel)
+        .first_valid_layer().all_attributes().from_buffered(Cursor::new(&bytes)).unwrap();
+
+    image.assert_equals_result(&image2);
+}
+
+#[test]
+fn read_single_part_view() {
+    let path = "tests/images/valid/openexr/MultiView/Adjuster.exr";
+
+    let full_layer = read().no_deep_data().largest_resolution_level().all_channels()
+        .first_valid_layer().all_attributes().from_file(path).unwrap().layer_data;
+
+    for view in ["center", "left", "right"] {
+        let layers = read().no_deep_data().largest_resolution_level().all_channels()
+            .view(view).all_attributes().from_file(path).unwrap().layer_data;
+
+        assert_eq!(layers.len(), 1);
+        let layer = &layers[0];
+
+        let names: Vec<String> = layer.channel_data.list.iter().map(|channel| channel.name.to_string()).collect();
+        assert_eq!(names, ["B", "G", "R"]);
+        assert_eq!(layer.attributes.view_name, Some(Text::from(view)));
+        assert_eq!(layer.attributes.multi_view_names, None);
+
+        assert_eq!(full_layer.view(view).as_ref(), Some(layer));
+    }
+
+    // the default view is stored without a prefix
+    let center = full_layer.view("center").unwrap();
+    assert_eq!(center.channel_data.list[2].sample_data, full_layer.channel_data.list[2].sample_data);
+
+    let missing_view = read().no_deep_data().largest_resolution_level().all_channels()
+        .view("top").all_attributes().from_file(path).unwrap();
+
+    assert!(missing_view.layer_data.is_empty());
+
+    let rgba_right = read().no_deep_data().largest_resolution_level()
+        .rgba_channels(PixelVec::<(f32,f32,f32,f32)>::constructor, PixelVec::set_pixel)
+        .view("right").all_attributes().from_file(path).unwrap();
+
+    let right = full_layer.view("right").unwrap();
+    let red_samples: Vec<f32> = right.channel_data.list[2].sample_data.values_as_f32().collect();
+    let red_pixels: Vec<f32> = rgba_right.layer_data[0].channel_data.pixels.pixels.iter().map(|pixel| pixel.0).collect();
+    assert_eq!(red_samples, red_pixels);
+}
+
+#[test]
+fn read_multi_part_view() {
+    let read_view = |path: &str, view: &str| read()
+        .no_deep_data().largest_resolution_level().all_channels()
+        .view(view).all_attributes().from_file(path).unwrap().layer_data;
+
+    let multi_part = read_view("tests/images/valid/openexr/Beachball/multipart.0001.exr", "left");
+    let layer_names: Vec<String> = multi_part.iter().map(|layer| layer.attributes.layer_name.as_ref().unwrap().to_string()).collect();
+    assert_eq!(layer_names, ["depth_left", "forward_left", "whitebarmask_left", "rgba_left"]);
+    assert!(multi_part.iter().all(|layer| layer.attributes.view_name == Some(Text::from("left"))));
+
+    let single_part = read_view("tests/images/valid/openexr/Beachball/singlepart.0001.exr", "left");
+    assert_eq!(single_part.len(), 1);
+
+    // both files contain the same channels per view
+    let single_part_names: Vec<String> = single_part[0].channel_data.list.iter().map(|channel| channel.name.to_string()).collect();
+    let mut multi_part_names: Vec<String> = multi_part.iter().flat_map(|layer| layer.channel_data.list.iter()).map(|channel| channel.name.to_string()).collect();
+    multi_part_names.sort();
+    assert_eq!(single_part_names, multi_part_names);
+}
+
+#[test]
+fn roundtrip_multi_view() {
+    let size = Vec2(9, 6);
+
+    let create_channels = |offset: f32| AnyChannels::sort(smallvec::smallvec![
+        AnyChannel::new("B", FlatSamples::F32((0 .. size.area()).map(|index| offset + index as f32).collect())),
+        AnyChannel::new("diffuse.R", FlatSamples::F16(vec![f16::from_f32(offset); size.area()])),
+        AnyChannel::new("R", FlatSamples::U32((0 .. size.area()).map(|index| offset as u32 * index as u32).collect())),
+    ]);
+
+    let views = [("left", create_channels(1.0)), ("right", create_channels(2.0))];
+
+    // single-part
+    let layer = Layer::from_views(size, LayerAttributes::named("stereo"), Encoding::FAST_LOSSLESS, views.clone()).unwrap();
+    let names: Vec<String> = layer.channel_data.list.iter().map(|channel| channel.name.to_string()).collect();
+    assert_eq!(names, ["B", "R", "diffuse.left.R", "diffuse.right.R", "right.B", "right.R"]);
+
+    let mut bytes = Vec::new();
+    Image::from_layer(layer).write().to_buffered(Cursor::new(&mut bytes)).unwrap();
+
+    for (view, channels) in &views {
+        let layers = read().no_deep_data().largest_resolution_level().all_channels()
+            .view(*view).all_attributes().from_buffered(Cursor::new(&bytes)).unwrap().layer_data;
+
+        assert_eq!(layers.len(), 1);
+        assert_eq!(&layers[0].channel_data, channels);
+    }
+
+    // multi-part
+    let layers = exr::image::multi_view::layers_from_views(views.iter().map(|(view, channels)|
+        (*view, Layer::new(size, LayerAttributes::named("rgba"), Encoding::FAST_LOSSLESS, channels.clone()))
+    )).unwrap();
+
+    let mut bytes = Vec::new();
+    Image::from_layers(ImageAttributes::new(IntegerBounds::from_dimensions(size)), layers)
+        .write().to_buffered(Cursor::new(&mut bytes)).unwrap();
+
+    let layers = read().no_deep_data().largest_resolution_level().all_channels()
+        .view("right").all_attributes().from_buffered(Cursor::new(&bytes)).unwrap().layer_data;
+
+    assert_eq!(layers.len(), 1);
+    assert_eq!(layers[0].attributes.layer_name, Some(Text::from("rgba.right")));
+    assert_eq!(layers[0].channel_data, views[1].1);
+
+    let duplicate_views = Layer::from_views(size, LayerAttributes::default(), Encoding::FAST_LOSSLESS, [views[0].clone(), views[0].clone()]);
+    assert!(duplicate_views.is_err());
+}
+
+#[test]
+fn roundtrip_channel_groups() {
+    use exr::image::channel_groups::ChannelGroups;
+
+    let size = Vec2(7, 5);
+    let create_channels = |names: &[&str], value: f32| AnyChannels::sort(names.iter()
+        .map(|name| AnyChannel::new(*name, FlatSamples::F32((0 .. size.area()).map(|index| value + index as f32).collect())))
+        .collect()
+    );
+
+    let groups = ChannelGroups::new(create_channels(&["A"], 1.0))
+        .with_group("diffuse.direct", create_channels(&["B", "G", "R"], 2.0))
+        .with_group("diffuse.indirect", create_channels(&["R"], 3.0))
+        .with_group("specular", AnyChannels::sort(smallvec::smallvec![
+            AnyChannel::new("Z", FlatSamples::F16(vec![f16::ONE; size.area()])),
+        ]));
+
+    let mut bytes = Vec::new();
+    Image::from_channels(size, groups.clone()).write().to_buffered(Cursor::new(&mut bytes)).unwrap();
+
+    let flat = read().no_deep_data().largest_resolution_level().all_channels()
+        .first_valid_layer().all_attributes().from_buffered(Cursor::new(&bytes)).unwrap();
+
+    let names: Vec<String> = flat.layer_data.channel_data.list.iter().map(|channel| channel.name.to_string()).collect();
+    assert_eq!(names, ["A", "diffuse.direct.B", "diffuse.direct.G", "diffuse.direct.R", "diffuse.indirect.R", "specular.Z"]);
+    assert_eq!(flat.layer_data.channel_data.list[4].sample_data, groups.lookup_group(b"diffuse.indirect").unwrap().list[0].sample_data);
+
+    let grouped = read().no_deep_data().largest_resolution_level().all_channels().channel_groups()
+        .first_valid_layer().all_attributes().from_buffered(Cursor::new(&bytes)).unwrap();
+
+    assert_eq!(grouped.layer_data.channel_data, groups);
+}
+
+#[test]
+fn read_channel_groups() {
+    let path = "tests/images/valid/openexr/Beachball/singlepart.0001.exr";
+
+    let flat = read().no_deep_data().largest_resolution_level().all_channels()
+        .first_valid_layer().all_attributes().from_file(path).unwrap().layer_data;
+
+    let grouped = read().no_deep_data().largest_resolution_level().all_channels().channel_groups()
+        .first_valid_layer().all_attributes().from_file(path).unwrap().layer_data;
+
+    for channel in &flat.channel_data.list {
+        let name = channel.name.bytes();
+        let (group_path, channel_name) = match name.iter().rposition(|&byte| byte == b'.') {
+            Some(dot) => (&name[.. dot], &name[dot + 1 ..]),
+            None => (&[][..], name),
+        };
+
+        let group = grouped.channel_data.lookup_group(group_path).expect("group not found");
+        let grouped_channel = group.list.iter().find(|grouped| grouped.name.bytes() == channel_name).expect("channel not found");
+        assert_eq!(grouped_channel.sample_data, channel.sample_data);
+    }
+
+    let group_channel_count: usize = grouped.channel_data.groups().iter().map(|(_, group)| group.list.len()).sum();
+    assert_eq!(group_channel_count, flat.channel_data.list.len());
+}